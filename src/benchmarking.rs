@@ -26,6 +26,8 @@ use frame_benchmarking::{
 use frame_support::{
 	dispatch::UnfilteredDispatchable,
 	traits::{EnsureOrigin, Get},
+	weights::Weight,
+	BoundedVec,
 };
 use frame_system::RawOrigin as SystemOrigin;
 use sp_runtime::traits::Bounded;
@@ -41,6 +43,8 @@ fn create_default_asset<T: Config<I>, I: 'static>(
 	let caller: T::AccountId = whitelisted_caller();
 	let caller_lookup = T::Lookup::unlookup(caller.clone());
 	T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance());
+	// The caller is the custodian of the asset under any `CustodianRequired` policy.
+	Custodian::<T, I>::put(caller.clone());
 	let root = SystemOrigin::Root.into();
 	assert!(Assets::<T, I>::force_create(
 		root,
@@ -65,11 +69,35 @@ fn create_default_minted_asset<T: Config<I>, I: 'static>(
 		SystemOrigin::Signed(caller.clone()).into(),
 		Default::default(),
 		amount,
+		None,
 	)
 	.is_ok());
 	(caller, caller_lookup)
 }
 
+/// A well-formed CIDv0 for `data_ipfs`.
+fn valid_cid() -> Vec<u8> {
+	b"QmR65i22uNRpz7gdLpmNSGGJHLxZfSNtEy37yMBxfUnuHx".to_vec()
+}
+
+/// Project details with strings of `StringLimit` bytes.
+fn max_project_details<T: Config<I>, I: 'static>() -> ProjectDetails<Vec<u8>> {
+	ProjectDetails {
+		standard: RegistryStandard::Verra,
+		methodology_code: vec![b'V'; T::StringLimit::get() as usize],
+		vintage: 2021,
+		country: *b"KZ",
+		project_id: vec![b'1'; T::StringLimit::get() as usize],
+	}
+}
+
+/// An `https` project url of `StringLimit` bytes, accepted by `StrictMetadata`.
+fn max_url<T: Config<I>, I: 'static>() -> Vec<u8> {
+	let mut url = b"https://".to_vec();
+	url.resize(T::StringLimit::get() as usize, b'a');
+	url
+}
+
 fn swap_is_sufficient<T: Config<I>, I: 'static>(s: &mut bool) {
 	Asset::<T, I>::mutate(&AssetId::default(), |maybe_a| {
 		if let Some(ref mut a) = maybe_a {
@@ -89,7 +117,8 @@ fn add_consumers<T: Config<I>, I: 'static>(minter: T::AccountId, n: u32) {
 		assert!(Assets::<T, I>::mint(
 			origin.clone().into(),
 			Default::default(),
-			100u32.into()
+			100u32.into(),
+			None
 		)
 		.is_ok());
 		assert!(Assets::<T, I>::transfer(
@@ -113,7 +142,8 @@ fn add_sufficients<T: Config<I>, I: 'static>(minter: T::AccountId, n: u32) {
 		assert!(Assets::<T, I>::mint(
 			origin.clone().into(),
 			Default::default(),
-			100u32.into()
+			100u32.into(),
+			None
 		)
 		.is_ok());
 		assert!(Assets::<T, I>::transfer(
@@ -135,6 +165,7 @@ fn add_approvals<T: Config<I>, I: 'static>(minter: T::AccountId, n: u32) {
 		origin.clone().into(),
 		Default::default(),
 		(100 * (n + 1)).into(),
+		None,
 	)
 	.unwrap();
 	for i in 0..n {
@@ -163,7 +194,26 @@ benchmarks_instance_pallet! {
 	set_custodian {
 		let custodian: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&custodian, DepositBalanceOf::<T, I>::max_value());
+		Custodian::<T, I>::kill();
+	}: _(SystemOrigin::Root, custodian.clone())
+	verify {
+		assert_last_event::<T, I>(Event::CustodianSet { custodian }.into());
+	}
+
+	propose_custodian {
+		let custodian: T::AccountId = account("custodian", 0, SEED);
 	}: _(SystemOrigin::Root, custodian.clone())
+	verify {
+		assert!(PendingCustodian::<T, I>::get().map_or(false, |(pending, _)| pending == custodian));
+	}
+
+	accept_custodian {
+		let custodian: T::AccountId = account("custodian", 0, SEED);
+		Assets::<T, I>::propose_custodian(SystemOrigin::Root.into(), custodian.clone())?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::CustodianChangeDelay::get(),
+		);
+	}: _(SystemOrigin::Signed(custodian.clone()))
 	verify {
 		assert_last_event::<T, I>(Event::CustodianSet { custodian }.into());
 	}
@@ -179,23 +229,33 @@ benchmarks_instance_pallet! {
 			asset_id: id, name: Default::default(), symbol: Default::default(), decimals: 9, is_frozen: false }.into());
 	}
 
+	create_with_vintage {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), Default::default(), 2021)
+	verify {
+		let id = Assets::<T, I>::get_current_asset_id(&caller).unwrap();
+		assert_eq!(Assets::<T, I>::asset_vintage(id), Some(2021));
+	}
+
 	set_project_data {
 		let caller: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
 		let name = "Token".as_bytes().to_vec();
 		let symbol = "Token".as_bytes().to_vec();
-		let url = vec![0u8; T::StringLimit::get() as usize];
-		let data_ipfs = vec![0u8; T::StringLimit::get() as usize];
+		let url = max_url::<T, I>();
+		let data_ipfs = valid_cid();
 
 		Assets::<T, I>::create(SystemOrigin::Signed(caller.clone()).into(), name, symbol)?;
 		let id = Assets::<T, I>::get_current_asset_id(&caller).unwrap();
-	}: _(SystemOrigin::Signed(caller.clone()), id, url.clone(), data_ipfs.clone())
+	}: _(SystemOrigin::Signed(caller.clone()), id, url.clone(), data_ipfs.clone(), Some(max_project_details::<T, I>()))
 	verify {
-		assert_last_event::<T, I>(Event::MetadataUpdated {
+		assert_event::<T, I>(Event::MetadataUpdated {
 			asset_id: id,
 			url,
 			data_ipfs,
 		}.into());
+		assert_last_event::<T, I>(Event::ProjectDetailsSet { asset_id: id }.into());
 	}
 
 	force_create {
@@ -220,20 +280,89 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Destroyed { asset_id: Default::default() }.into());
 	}
 
+	start_destroy {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::DestructionStarted { asset_id: Default::default() }.into());
+	}
+
+	destroy_accounts {
+		let c in 1 .. T::RemoveItemsLimit::get();
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		add_sufficients::<T, I>(caller.clone(), c - 1);
+		Assets::<T, I>::start_destroy(SystemOrigin::Signed(caller.clone()).into(), Default::default())?;
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::AccountsDestroyed {
+			asset_id: Default::default(),
+			accounts_destroyed: c,
+			accounts_remaining: 0,
+		}.into());
+	}
+
+	destroy_approvals {
+		let a in 1 .. T::RemoveItemsLimit::get();
+		let (caller, _) = create_default_asset::<T, I>(true);
+		add_approvals::<T, I>(caller.clone(), a);
+		Assets::<T, I>::start_destroy(SystemOrigin::Signed(caller.clone()).into(), Default::default())?;
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalsDestroyed {
+			asset_id: Default::default(),
+			approvals_destroyed: a,
+			approvals_remaining: 0,
+		}.into());
+	}
+
+	finish_destroy {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		Assets::<T, I>::start_destroy(SystemOrigin::Signed(caller.clone()).into(), Default::default())?;
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::Destroyed { asset_id: Default::default() }.into());
+	}
+
 	mint {
 		let (caller, caller_lookup) = create_default_asset::<T, I>(true);
 		let amount = T::Balance::from(100u32);
-	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), amount)
+		let ranges: Vec<_> = (1..T::MaxSerialRanges::get() as u64)
+			.map(|i| SerialRange {
+				start: i * 1000,
+				end: i * 1000 + 99,
+				unit: 1u32.into(),
+				consumed: 100u32.into(),
+			})
+			.collect();
+		SerialRanges::<T, I>::insert(AssetId::default(), BoundedVec::try_from(ranges).unwrap());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), amount, Some((0, 99)))
 	verify {
+		assert_eq!(Account::<T, I>::get(AssetId::default(), &caller).map(|a| a.balance), Some(amount));
 		assert_last_event::<T, I>(Event::Issued { asset_id: Default::default(), owner: caller, total_supply: amount }.into());
 	}
 
 	burn {
 		let amount = T::Balance::from(100u32);
 		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
-	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, amount)
+		let reference = vec![0u8; T::StringLimit::get() as usize];
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, amount, CustodialBurnReason::Invalidation, Some(reference))
 	verify {
-		assert_last_event::<T, I>(Event::CarbonCreditsBurned { account: caller, asset_id: Default::default(), amount }.into());
+		assert_last_event::<T, I>(Event::CustodialBurned { account: caller, asset_id: Default::default(), amount, reason: CustodialBurnReason::Invalidation }.into());
+	}
+
+	self_burn {
+		let amount = T::Balance::from(100u32);
+		let (caller, _) = create_default_minted_asset::<T, I>(true, amount);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		Assets::<T, I>::fund_sponsorship_pot(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			T::SponsoredRetirementFee::get(),
+		)?;
+		let note = vec![0u8; T::StringLimit::get() as usize];
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), amount, Some(note))
+	verify {
+		assert_eq!(RetirementCount::<T, I>::get(&caller), 1);
 	}
 
 	transfer {
@@ -246,6 +375,18 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Transferred { asset_id: Default::default(), from: caller, to: target, amount }.into());
 	}
 
+	transfer_with_memo {
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let memo: BoundedVec<u8, T::MemoLimit> =
+			vec![0u8; T::MemoLimit::get() as usize].try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), target_lookup, amount, memo.clone())
+	verify {
+		assert_last_event::<T, I>(Event::TransferredWithMemo { asset_id: Default::default(), from: caller, to: target, amount, memo: memo.into_inner() }.into());
+	}
+
 	transfer_keep_alive {
 		let mint_amount = T::Balance::from(200u32);
 		let amount = T::Balance::from(100u32);
@@ -272,9 +413,15 @@ benchmarks_instance_pallet! {
 
 	freeze {
 		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
-	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup)
+		let reason: BoundedVec<u8, T::StringLimit> =
+			vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, Some(reason.clone()))
 	verify {
-		assert_last_event::<T, I>(Event::Frozen { asset_id: Default::default(), who: caller }.into());
+		assert_last_event::<T, I>(Event::Frozen {
+			asset_id: Default::default(),
+			who: caller,
+			reason: Some(reason.into_inner()),
+		}.into());
 	}
 
 	thaw {
@@ -283,6 +430,7 @@ benchmarks_instance_pallet! {
 			SystemOrigin::Signed(caller.clone()).into(),
 			Default::default(),
 			caller_lookup.clone(),
+			None,
 		)?;
 	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup)
 	verify {
@@ -291,9 +439,14 @@ benchmarks_instance_pallet! {
 
 	freeze_asset {
 		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
-	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
+		let reason: BoundedVec<u8, T::StringLimit> =
+			vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), Some(reason.clone()))
 	verify {
-		assert_last_event::<T, I>(Event::AssetFrozen { asset_id: Default::default() }.into());
+		assert_last_event::<T, I>(Event::AssetFrozen {
+			asset_id: Default::default(),
+			reason: Some(reason.into_inner()),
+		}.into());
 	}
 
 	thaw_asset {
@@ -301,6 +454,7 @@ benchmarks_instance_pallet! {
 		Assets::<T, I>::freeze_asset(
 			SystemOrigin::Signed(caller.clone()).into(),
 			Default::default(),
+			None,
 		)?;
 	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
 	verify {
@@ -312,6 +466,31 @@ benchmarks_instance_pallet! {
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
 	}: _(SystemOrigin::Signed(caller), Default::default(), target_lookup)
+	verify {
+		assert!(PendingOwner::<T, I>::get(AssetId::default()).map_or(false, |(owner, _)| owner == target));
+	}
+
+	accept_ownership {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		Assets::<T, I>::transfer_ownership(
+			SystemOrigin::Signed(caller).into(),
+			Default::default(),
+			target_lookup,
+		)?;
+	}: _(SystemOrigin::Signed(target.clone()), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::OwnerChanged { asset_id: Default::default(), owner: target }.into());
+	}
+
+	force_transfer_ownership {
+		let _ = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::force_transfer_ownership { id: Default::default(), owner: target_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
 	verify {
 		assert_last_event::<T, I>(Event::OwnerChanged { asset_id: Default::default(), owner: target }.into());
 	}
@@ -323,7 +502,7 @@ benchmarks_instance_pallet! {
 		let name = vec![0u8; n as usize];
 		let symbol = vec![0u8; s as usize];
 		let url = vec![0u8; n as usize];
-		let data_ipfs = vec![0u8; s as usize];
+		let data_ipfs = valid_cid();
 		let decimals = 12;
 
 		create_default_asset::<T, I>(true);
@@ -349,7 +528,7 @@ benchmarks_instance_pallet! {
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
 		let dummy = vec![0u8; T::StringLimit::get() as usize];
 		Assets::<T, I>::force_set_metadata(SystemOrigin::Root.into(), 
-			Default::default(), dummy.clone(),dummy.clone(),dummy.clone(), dummy, 12, false)?;
+			Default::default(), dummy.clone(),dummy.clone(),dummy, valid_cid(), 12, false)?;
 
 		let origin = T::ForceOrigin::successful_origin();
 		let call = Call::<T, I>::force_clear_metadata { id: Default::default() };
@@ -425,6 +604,37 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::ApprovalCancelled { asset_id: id, owner: caller, delegate }.into());
 	}
 
+	approve_transfer_with_expiry {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let amount = 100u32.into();
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	}: _(SystemOrigin::Signed(caller.clone()), id, delegate_lookup, amount, expiry)
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalExpirySet { asset_id: id, owner: caller, delegate, expiry }.into());
+	}
+
+	clean_expired_approval {
+		let (owner, owner_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		T::Currency::make_free_balance_be(&owner, DepositBalanceOf::<T, I>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let origin = SystemOrigin::Signed(owner.clone()).into();
+		Assets::<T, I>::approve_transfer_with_expiry(origin, id, delegate_lookup.clone(), 100u32.into(), expiry)?;
+		frame_system::Pallet::<T>::set_block_number(expiry);
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(SystemOrigin::Signed(caller), id, owner_lookup, delegate_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalCancelled { asset_id: id, owner, delegate }.into());
+	}
+
 	force_cancel_approval {
 		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
@@ -440,5 +650,831 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::ApprovalCancelled { asset_id: id, owner: caller, delegate }.into());
 	}
 
+	confirm_retirement_registration {
+		let custodian: T::AccountId = whitelisted_caller();
+		Custodian::<T, I>::put(custodian.clone());
+		let account: T::AccountId = account("account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+		let amount = T::Balance::from(100u32);
+		BurnCertificate::<T, I>::insert(
+			&account,
+			AssetId::default(),
+			BurnCertificateEntry { amount, last_block: Zero::zero() },
+		);
+		let external_ref = vec![0u8; T::StringLimit::get() as usize];
+	}: _(SystemOrigin::Signed(custodian), account_lookup, Default::default(), amount, external_ref.clone())
+	verify {
+		assert_last_event::<T, I>(Event::RetirementRegistrationConfirmed {
+			account, asset_id: Default::default(), amount, external_ref }.into());
+	}
+
+	mint_reserve_backed {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		Custodian::<T, I>::put(caller.clone());
+		let reserve: T::AccountId = account("reserve", 0, SEED);
+		let reserve_lookup = T::Lookup::unlookup(reserve.clone());
+		T::ReserveAccounts::add(&reserve);
+		let amount = T::Balance::from(100u32);
+	}: _(SystemOrigin::Signed(caller), Default::default(), reserve_lookup, amount)
+	verify {
+		assert_last_event::<T, I>(Event::ReserveBackedIssued { asset_id: Default::default(), owner: reserve, amount }.into());
+	}
+
+	redeem_retirement_voucher {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let signer = T::BenchmarkHelper::voucher_signer();
+		let amount = T::Balance::from(100u32);
+		assert!(Assets::<T, I>::do_mint(Default::default(), &signer, amount, None).is_ok());
+		let voucher = RetirementVoucher {
+			signer: signer.clone(),
+			asset_id: Default::default(),
+			amount,
+			nonce: 0,
+			expiry: T::BlockNumber::max_value(),
+		};
+		let message = Assets::<T, I>::retirement_voucher_message(&voucher);
+		let signature = T::BenchmarkHelper::sign_voucher(&signer, &message);
+	}: _(SystemOrigin::Signed(caller), voucher, signature)
+	verify {
+		assert_last_event::<T, I>(Event::RetirementVoucherRedeemed { signer, asset_id: Default::default(), amount, nonce: 0 }.into());
+	}
+
+	self_burn_signed {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let signer = T::BenchmarkHelper::voucher_signer();
+		let amount = T::Balance::from(100u32);
+		assert!(Assets::<T, I>::do_mint(Default::default(), &signer, amount, None).is_ok());
+		let payload = SignedBurnPayload {
+			signer: signer.clone(),
+			asset_id: Default::default(),
+			amount,
+			nonce: 0,
+			genesis_hash: frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()),
+			expiry: T::BlockNumber::max_value(),
+		};
+		let signature =
+			T::BenchmarkHelper::sign_voucher(&signer, &Assets::<T, I>::signed_burn_message(&payload));
+	}: _(SystemOrigin::Signed(caller.clone()), payload, signature)
+	verify {
+		assert_last_event::<T, I>(Event::SignedBurnExecuted {
+			signer, asset_id: Default::default(), amount, nonce: 0, relayer: caller }.into());
+	}
+
+	retire_from {
+		let (owner, owner_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		T::Currency::make_free_balance_be(&owner, DepositBalanceOf::<T, I>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		whitelist_account!(delegate);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let amount = 100u32.into();
+		let origin = SystemOrigin::Signed(owner.clone()).into();
+		Assets::<T, I>::approve_transfer(origin, id, delegate_lookup, amount)?;
+
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let beneficiary_lookup = T::Lookup::unlookup(beneficiary.clone());
+	}: _(SystemOrigin::Signed(delegate.clone()), id, owner_lookup, amount, Some(beneficiary_lookup))
+	verify {
+		assert!(Approvals::<T, I>::get((id, &owner, &delegate)).is_none());
+		assert_eq!(BurnCertificate::<T, I>::get(&beneficiary, id).map(|c| c.amount), Some(amount));
+	}
+
+	pause {
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::pause { ops: PAUSE_ALL };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::OperationsPaused { ops: PAUSE_ALL, paused: PAUSE_ALL }.into());
+	}
+
+	unpause {
+		Paused::<T, I>::put(PAUSE_ALL);
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::unpause {};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::OperationsUnpaused.into());
+	}
+
+	submit_project {
+		let (caller, _) = create_default_asset::<T, I>(true);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::ProjectStatusChanged {
+			asset_id: Default::default(), status: ProjectStatus::Submitted, by: caller }.into());
+	}
+
+	approve_project {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let custodian = Assets::<T, I>::custodian_of(
+			AssetId::default(),
+			&Asset::<T, I>::get(AssetId::default()).unwrap(),
+		)?;
+		ProjectStatusOf::<T, I>::insert(AssetId::default(), ProjectStatus::AuditorApproved);
+	}: _(SystemOrigin::Signed(custodian.clone()), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::ProjectStatusChanged {
+			asset_id: Default::default(), status: ProjectStatus::CustodianApproved, by: custodian }.into());
+	}
+
+	transfer_all {
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), target_lookup, false)
+	verify {
+		assert_last_event::<T, I>(Event::Transferred { asset_id: Default::default(), from: caller, to: target, amount }.into());
+	}
+
+	increase_allowance {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let amount = 100u32.into();
+	}: _(SystemOrigin::Signed(caller.clone()), id, delegate_lookup, amount)
+	verify {
+		assert_last_event::<T, I>(Event::AllowanceChanged { asset_id: id, owner: caller, delegate, amount }.into());
+	}
+
+	decrease_allowance {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let amount = 100u32.into();
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::approve_transfer(origin, id, delegate_lookup.clone(), amount)?;
+	}: _(SystemOrigin::Signed(caller.clone()), id, delegate_lookup, amount)
+	verify {
+		assert_last_event::<T, I>(Event::AllowanceChanged { asset_id: id, owner: caller, delegate, amount: Zero::zero() }.into());
+	}
+
+	approve_with_signature {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let owner = T::BenchmarkHelper::voucher_signer();
+		T::Currency::make_free_balance_be(&owner, DepositBalanceOf::<T, I>::max_value());
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let amount = T::Balance::from(100u32);
+		let deadline = T::BlockNumber::max_value();
+		let permit = ApprovalPermit {
+			owner: owner.clone(),
+			asset_id: Default::default(),
+			delegate: delegate.clone(),
+			amount,
+			nonce: 0,
+			deadline,
+			genesis_hash: frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()),
+		};
+		let message = Assets::<T, I>::approval_permit_message(&permit);
+		let signature = T::BenchmarkHelper::sign_voucher(&owner, &message);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), owner.clone(), delegate, amount, deadline, signature)
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalPermitted { owner, nonce: 0, relayer: caller }.into());
+	}
+
+	freeze_amount {
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, amount)
+	verify {
+		assert_last_event::<T, I>(Event::AmountFrozen {
+			asset_id: Default::default(), who: caller, amount, frozen: amount }.into());
+	}
+
+	unfreeze_amount {
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::freeze_amount(origin, Default::default(), caller_lookup.clone(), amount)?;
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, amount)
+	verify {
+		assert_last_event::<T, I>(Event::AmountThawed {
+			asset_id: Default::default(), who: caller, amount, frozen: Zero::zero() }.into());
+	}
+
+	sponsor_account {
+		let (caller, _) = create_default_asset::<T, I>(false);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let new_owner: T::AccountId = account("owner", 0, SEED);
+		let new_owner_lookup = T::Lookup::unlookup(new_owner.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), new_owner_lookup, Some(Default::default()))
+	verify {
+		assert!(SponsoredAccounts::<T, I>::contains_key(&new_owner));
+	}
+
+	clawback_sponsorship {
+		let (caller, _) = create_default_asset::<T, I>(false);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let new_owner: T::AccountId = account("owner", 0, SEED);
+		let new_owner_lookup = T::Lookup::unlookup(new_owner.clone());
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::sponsor_account(origin, new_owner_lookup.clone(), Some(Default::default()))?;
+		frame_system::Pallet::<T>::set_block_number(T::SponsorshipClawbackPeriod::get().saturating_add(One::one()));
+	}: _(SystemOrigin::Signed(caller.clone()), new_owner_lookup)
+	verify {
+		assert!(!SponsoredAccounts::<T, I>::contains_key(&new_owner));
+	}
+
+	export_retirement {
+		let amount = T::Balance::from(100u32);
+		let (caller, _) = create_default_minted_asset::<T, I>(true, amount);
+		let dest_registry = vec![0u8; T::StringLimit::get() as usize];
+		let dest_ref = vec![0u8; T::StringLimit::get() as usize];
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), amount, dest_registry, dest_ref)
+	verify {
+		assert!(ExportAttestations::<T, I>::contains_key(0));
+	}
+
+	add_to_whitelist {
+		let (caller, caller_lookup) = create_default_asset::<T, I>(true);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::AddedToWhitelist { asset_id: Default::default(), who: caller }.into());
+	}
+
+	remove_from_whitelist {
+		let (caller, caller_lookup) = create_default_asset::<T, I>(true);
+		Assets::<T, I>::add_to_whitelist(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			caller_lookup.clone(),
+		)?;
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::RemovedFromWhitelist { asset_id: Default::default(), who: caller }.into());
+	}
+
+	set_whitelist_enforcement {
+		let (caller, _) = create_default_asset::<T, I>(true);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), true)
+	verify {
+		assert_last_event::<T, I>(Event::WhitelistEnforcementSet { asset_id: Default::default(), enabled: true }.into());
+	}
+
+	gc_approvals {
+		let a in 1 .. 5_00;
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let owner_lookup = T::Lookup::unlookup(owner.clone());
+		T::Currency::make_free_balance_be(&owner, T::Currency::minimum_balance());
+		T::Currency::deposit_creating(&owner, T::ApprovalDeposit::get() * a.into());
+		for i in 0..a {
+			let delegate: T::AccountId = account("approval", i, SEED);
+			assert!(Assets::<T, I>::do_approve_transfer(Default::default(), &owner, &delegate, 100u32.into(), None).is_ok());
+		}
+		// The owner stopped holding the asset at block zero, and its grace period has passed.
+		OrphanedApprovalsSince::<T, I>::insert(AssetId::default(), &owner, T::BlockNumber::zero());
+		frame_system::Pallet::<T>::set_block_number(T::ApprovalGcGracePeriod::get());
+	}: _(SystemOrigin::Signed(caller), Default::default(), owner_lookup, a)
+	verify {
+		assert_eq!(Asset::<T, I>::get(AssetId::default()).unwrap().approvals, 0);
+	}
+
+	set_attribute {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let key = vec![0u8; T::AttributeKeyLimit::get() as usize];
+		let value = vec![0u8; T::AttributeValueLimit::get() as usize];
+	}: _(SystemOrigin::Signed(caller), Default::default(), key.clone(), value.clone())
+	verify {
+		assert_last_event::<T, I>(Event::AttributeSet { asset_id: Default::default(), key, value }.into());
+	}
+
+	clear_attribute {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let key = vec![0u8; T::AttributeKeyLimit::get() as usize];
+		let value = vec![0u8; T::AttributeValueLimit::get() as usize];
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::set_attribute(origin, Default::default(), key.clone(), value)?;
+	}: _(SystemOrigin::Signed(caller), Default::default(), key.clone())
+	verify {
+		assert_last_event::<T, I>(Event::AttributeCleared { asset_id: Default::default(), key }.into());
+	}
+
+	owner_batch {
+		let c in 1 .. T::MaxOwnerBatch::get();
+		let (caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let mut calls = Vec::new();
+		for i in 0 .. c {
+			let key = vec![i as u8];
+			let origin = SystemOrigin::Signed(caller.clone()).into();
+			Assets::<T, I>::set_attribute(origin, Default::default(), key.clone(), vec![0u8])?;
+			calls.push(OwnerCall::ClearAttribute { key });
+		}
+	}: _(SystemOrigin::Signed(caller), Default::default(), calls)
+	verify {
+		assert_last_event::<T, I>(Event::OwnerBatchExecuted { asset_id: Default::default(), calls: c }.into());
+	}
+
+	retire_co2e {
+		let a in 1 .. T::MaxRetirementBasket::get();
+		let amount = T::Balance::from(100u32);
+		let (caller, _) = create_default_minted_asset::<T, I>(true, amount * (a + 1).into());
+		let assets = vec![(AssetId::default(), amount); a as usize];
+	}: _(SystemOrigin::Signed(caller.clone()), assets)
+	verify {
+		assert_eq!(Co2eRetirementCount::<T, I>::get(&caller), 1);
+	}
+
+	set_organization {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let organization = vec![0u8; T::StringLimit::get() as usize];
+	}: _(SystemOrigin::Signed(caller.clone()), organization.clone())
+	verify {
+		assert_last_event::<T, I>(Event::OrganizationSet { account: caller, organization }.into());
+	}
+
+	grant_retirement_quota {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let organization = vec![0u8; T::StringLimit::get() as usize];
+		Assets::<T, I>::set_organization(SystemOrigin::Signed(caller.clone()).into(), organization)?;
+		let sub: T::AccountId = account("sub", 0, SEED);
+		let sub_lookup = T::Lookup::unlookup(sub.clone());
+		let amount = T::Balance::from(100u32);
+	}: _(SystemOrigin::Signed(caller.clone()), sub_lookup, Default::default(), amount)
+	verify {
+		assert_last_event::<T, I>(Event::RetirementQuotaGranted {
+			organization: caller, sub, asset_id: Default::default(), per_period: amount }.into());
+	}
+
+	self_burn_from_org {
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let organization = vec![0u8; T::StringLimit::get() as usize];
+		Assets::<T, I>::set_organization(SystemOrigin::Signed(caller.clone()).into(), organization)?;
+		let sub: T::AccountId = account("sub", 0, SEED);
+		Assets::<T, I>::grant_retirement_quota(
+			SystemOrigin::Signed(caller.clone()).into(),
+			T::Lookup::unlookup(sub.clone()),
+			Default::default(),
+			amount,
+		)?;
+		let note = vec![0u8; T::StringLimit::get() as usize];
+	}: _(SystemOrigin::Signed(sub), caller_lookup, Default::default(), amount, Some(note))
+	verify {
+		assert_eq!(RetirementCount::<T, I>::get(&caller), 1);
+	}
+
+	clear_organization {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let organization = vec![0u8; T::StringLimit::get() as usize];
+		assert!(Assets::<T, I>::set_organization(SystemOrigin::Signed(caller.clone()).into(), organization).is_ok());
+	}: _(SystemOrigin::Signed(caller.clone()))
+	verify {
+		assert_last_event::<T, I>(Event::OrganizationCleared { account: caller }.into());
+	}
+
+	register_organization {
+		let organization = vec![0u8; T::StringLimit::get() as usize];
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::register_organization {
+			organization: organization.clone(),
+			owner: T::Lookup::unlookup(owner.clone()),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::OrganizationRegistered { organization, owner }.into());
+	}
+
+	approve_organization_member {
+		let caller: T::AccountId = whitelisted_caller();
+		Custodian::<T, I>::put(caller.clone());
+		let member: T::AccountId = account("member", 0, SEED);
+		T::Currency::make_free_balance_be(&member, DepositBalanceOf::<T, I>::max_value());
+		let organization = vec![0u8; T::StringLimit::get() as usize];
+		let bounded: BoundedVec<u8, T::StringLimit> = organization.clone().try_into().unwrap();
+		OrganizationOwners::<T, I>::insert(bounded, account::<T::AccountId>("owner", 0, SEED));
+		Assets::<T, I>::set_organization(SystemOrigin::Signed(member.clone()).into(), organization.clone())?;
+	}: _(SystemOrigin::Signed(caller), T::Lookup::unlookup(member.clone()))
+	verify {
+		assert_last_event::<T, I>(Event::OrganizationMemberApproved { account: member, organization }.into());
+	}
+
+	set_alias {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let alias = vec![0u8; T::StringLimit::get() as usize];
+		assert!(Assets::<T, I>::set_alias(SystemOrigin::Signed(caller.clone()).into(), vec![1u8]).is_ok());
+	}: _(SystemOrigin::Signed(caller.clone()), alias.clone())
+	verify {
+		assert_last_event::<T, I>(Event::AliasSet { account: caller, alias }.into());
+	}
+
+	clear_alias {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let alias = vec![0u8; T::StringLimit::get() as usize];
+		assert!(Assets::<T, I>::set_alias(SystemOrigin::Signed(caller.clone()).into(), alias).is_ok());
+	}: _(SystemOrigin::Signed(caller.clone()))
+	verify {
+		assert_last_event::<T, I>(Event::AliasCleared { account: caller }.into());
+	}
+
+	mint_batch {
+		let m in 1 .. T::MaxMintBatch::get();
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let amount = T::Balance::from(100u32);
+		let mints = vec![(AssetId::default(), amount); m as usize];
+	}: _(SystemOrigin::Signed(caller.clone()), mints)
+	verify {
+		assert_eq!(Assets::<T, I>::balance(AssetId::default(), &caller), amount * m.into());
+	}
+
+	ack_outbox {
+		let n in 1 .. T::MaxOutbox::get();
+		let caller: T::AccountId = whitelisted_caller();
+		OutboxAuthorities::<T, I>::insert(&caller, ());
+		for _ in 0..n {
+			Assets::<T, I>::note_outbox(OutboxKind::Minted, Default::default(), &caller, 100u32.into());
+		}
+	}: _(SystemOrigin::Signed(caller), (n - 1).into())
+	verify {
+		assert_last_event::<T, I>(Event::OutboxAcknowledged { up_to: (n - 1).into() }.into());
+	}
+
+	report_custodian_sla {
+		let interval = T::SlaReportInterval::get();
+		frame_system::Pallet::<T>::set_block_number(interval.saturating_add(1u32.into()));
+		Assets::<T, I>::note_unconfirmed_retirement(100u32.into());
+		let report = Assets::<T, I>::custodian_sla_report(interval);
+	}: _(SystemOrigin::None, report.clone())
+	verify {
+		assert_eq!(CustodianSla::<T, I>::get(), Some(report));
+	}
+
+	set_asset_badges {
+		create_default_asset::<T, I>(true);
+		let verifier: T::AccountId = account("verifier", 0, SEED);
+		T::Verifiers::add(&verifier);
+		let badges = sdg_badge(15) | BIODIVERSITY_BADGE;
+	}: _(SystemOrigin::Signed(verifier.clone()), Default::default(), badges, Some(valid_cid()))
+	verify {
+		assert_last_event::<T, I>(Event::AssetBadgesSet { asset_id: Default::default(), badges, verifier }.into());
+	}
+
+	set_co_owner {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let co_owner: T::AccountId = account("co_owner", 0, SEED);
+		let co_owner_lookup = T::Lookup::unlookup(co_owner.clone());
+	}: _(SystemOrigin::Signed(caller), Default::default(), Some(co_owner_lookup))
+	verify {
+		assert_last_event::<T, I>(
+			Event::CoOwnerSet { asset_id: Default::default(), co_owner: Some(co_owner) }.into()
+		);
+	}
+
+	propose_owner_action {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let co_owner: T::AccountId = account("co_owner", 0, SEED);
+		let co_owner_lookup = T::Lookup::unlookup(co_owner);
+		Assets::<T, I>::set_co_owner(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			Some(co_owner_lookup),
+		)?;
+		let witness = Asset::<T, I>::get(AssetId::default()).unwrap().destroy_witness();
+	}: destroy(SystemOrigin::Signed(caller), Default::default(), witness)
+	verify {
+		assert_last_event::<T, I>(
+			Event::OwnerActionProposed { asset_id: Default::default(), action_id: 0 }.into()
+		);
+	}
+
+	confirm_owner_action {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		Custodian::<T, I>::put(caller.clone());
+		let name = "Token".as_bytes().to_vec();
+		Assets::<T, I>::create(SystemOrigin::Signed(caller.clone()).into(), name.clone(), name)?;
+		let id = Assets::<T, I>::get_current_asset_id(&caller).unwrap();
+		let co_owner: T::AccountId = account("co_owner", 0, SEED);
+		let co_owner_lookup = T::Lookup::unlookup(co_owner.clone());
+		Assets::<T, I>::set_co_owner(
+			SystemOrigin::Signed(caller.clone()).into(),
+			id,
+			Some(co_owner_lookup),
+		)?;
+		let url = max_url::<T, I>();
+		Assets::<T, I>::set_project_data(
+			SystemOrigin::Signed(caller).into(),
+			id,
+			url,
+			valid_cid(),
+			None,
+		)?;
+	}: _(SystemOrigin::Signed(co_owner), id, 0, None)
+	verify {
+		assert_last_event::<T, I>(Event::OwnerActionConfirmed { asset_id: id, action_id: 0 }.into());
+	}
+
+	cancel_owner_action {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let co_owner: T::AccountId = account("co_owner", 0, SEED);
+		let co_owner_lookup = T::Lookup::unlookup(co_owner);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target);
+		Assets::<T, I>::set_co_owner(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			Some(co_owner_lookup),
+		)?;
+		Assets::<T, I>::transfer_ownership(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			target_lookup,
+		)?;
+	}: _(SystemOrigin::Signed(caller), Default::default(), 0)
+	verify {
+		assert_last_event::<T, I>(
+			Event::OwnerActionCancelled { asset_id: Default::default(), action_id: 0 }.into()
+		);
+	}
+
+	add_viewer_key {
+		let viewer: T::AccountId = account("viewer", 0, SEED);
+		let viewer_lookup = T::Lookup::unlookup(viewer.clone());
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::add_viewer_key { viewer: viewer_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::ViewerKeyAdded { viewer }.into());
+	}
+
+	remove_viewer_key {
+		let viewer: T::AccountId = account("viewer", 0, SEED);
+		let viewer_lookup = T::Lookup::unlookup(viewer.clone());
+		let origin = T::ForceOrigin::successful_origin();
+		Assets::<T, I>::add_viewer_key(origin.clone(), viewer_lookup.clone())?;
+		let call = Call::<T, I>::remove_viewer_key { viewer: viewer_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::ViewerKeyRemoved { viewer }.into());
+	}
+
+	add_outbox_authority {
+		let authority: T::AccountId = account("authority", 0, SEED);
+		let authority_lookup = T::Lookup::unlookup(authority.clone());
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::add_outbox_authority { authority: authority_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::OutboxAuthorityAdded { authority }.into());
+	}
+
+	remove_outbox_authority {
+		let authority: T::AccountId = account("authority", 0, SEED);
+		let authority_lookup = T::Lookup::unlookup(authority.clone());
+		let origin = T::ForceOrigin::successful_origin();
+		Assets::<T, I>::add_outbox_authority(origin.clone(), authority_lookup.clone())?;
+		let call = Call::<T, I>::remove_outbox_authority { authority: authority_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::OutboxAuthorityRemoved { authority }.into());
+	}
+
+	set_compliance_window {
+		let window: Option<(T::BlockNumber, T::BlockNumber)> = Some((1u32.into(), 100u32.into()));
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::set_compliance_window { window };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::ComplianceWindowSet { window }.into());
+	}
+
+	set_compliance_market {
+		create_default_asset::<T, I>(true);
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::set_compliance_market { id: Default::default(), enabled: true };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::ComplianceMarketSet { asset_id: Default::default(), enabled: true }.into());
+	}
+
+	register_methodology {
+		let name = vec![0u8; T::StringLimit::get() as usize];
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::register_methodology { methodology: 1, name: name.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::MethodologyRegistered { methodology: 1, name }.into());
+	}
+
+	deregister_methodology {
+		let name = vec![0u8; T::StringLimit::get() as usize];
+		Assets::<T, I>::register_methodology(SystemOrigin::Root.into(), 1, name)?;
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::deregister_methodology { methodology: 1 };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::MethodologyDeregistered { methodology: 1 }.into());
+	}
+
+	set_asset_methodology {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let name = vec![0u8; T::StringLimit::get() as usize];
+		Assets::<T, I>::register_methodology(SystemOrigin::Root.into(), 1, name.clone())?;
+		Assets::<T, I>::register_methodology(SystemOrigin::Root.into(), 2, name)?;
+		Assets::<T, I>::set_asset_methodology(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			1,
+		)?;
+	}: _(SystemOrigin::Signed(caller), Default::default(), 2)
+	verify {
+		assert_last_event::<T, I>(
+			Event::AssetMethodologySet { asset_id: Default::default(), methodology: 2 }.into()
+		);
+	}
+
+	on_idle_base {
+	}: { Assets::<T, I>::do_housekeeping(Weight::from_ref_time(u64::MAX)) }
+	verify {
+		assert!(HousekeepingCursor::<T, I>::get().is_some());
+	}
+
+	on_idle_item {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let deposit = T::ApprovalDeposit::get();
+		T::Deposits::hold(&caller, deposit)?;
+		Approvals::<T, I>::insert(
+			(AssetId::default(), &caller, &delegate),
+			Approval { amount: Zero::zero(), deposit, expiry: None },
+		);
+		Asset::<T, I>::mutate(AssetId::default(), |details| {
+			details.as_mut().unwrap().approvals.saturating_inc()
+		});
+		HousekeepingCursor::<T, I>::put((HousekeepingStage::Approvals, BoundedVec::default()));
+		let weight = T::WeightInfo::on_idle_base().saturating_add(T::WeightInfo::on_idle_item());
+	}: { Assets::<T, I>::do_housekeeping(weight) }
+	verify {
+		assert_last_event::<T, I>(
+			Event::HousekeepingDone { metadata: 0, approvals: 1, accounts: 0 }.into()
+		);
+	}
+
+	set_asset_splits {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let splits: Vec<(T::AccountId, Perbill)> = (0..T::MaxSplits::get())
+			.map(|i| (account("recipient", i, SEED), Perbill::from_percent(1)))
+			.collect();
+	}: _(SystemOrigin::Signed(caller), Default::default(), splits.clone())
+	verify {
+		assert_last_event::<T, I>(
+			Event::AssetSplitsSet { asset_id: Default::default(), splits }.into()
+		);
+	}
+
+	transfer_with_splits {
+		let s in 0 .. T::MaxSplits::get();
+		let amount = T::Balance::from(100_000u32);
+		let (caller, _) = create_default_minted_asset::<T, I>(true, amount);
+		let splits: Vec<(T::AccountId, Perbill)> = (0..s)
+			.map(|i| (account("recipient", i, SEED), Perbill::from_percent(1)))
+			.collect();
+		Assets::<T, I>::set_asset_splits(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			splits.clone(),
+		)?;
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), target_lookup, amount, splits)
+	verify {
+		assert!(Assets::<T, I>::balance(Default::default(), &target) > Zero::zero());
+	}
+
+	set_asset_custodian {
+		let (_, _) = create_default_asset::<T, I>(true);
+		let custodian: T::AccountId = account("custodian", 0, SEED);
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::set_asset_custodian {
+			id: Default::default(),
+			custodian: Some(custodian.clone()),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		let activates_at =
+			frame_system::Pallet::<T>::block_number() + T::CustodianChangeDelay::get();
+		assert_last_event::<T, I>(Event::AssetCustodianProposed {
+			asset_id: Default::default(), custodian: Some(custodian), activates_at }.into());
+	}
+
+	accept_asset_custodian {
+		let (_, _) = create_default_asset::<T, I>(true);
+		let custodian: T::AccountId = account("custodian", 0, SEED);
+		Assets::<T, I>::set_asset_custodian(
+			T::ForceOrigin::successful_origin(),
+			Default::default(),
+			Some(custodian.clone()),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::CustodianChangeDelay::get(),
+		);
+	}: _(SystemOrigin::Signed(custodian.clone()), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::AssetCustodianSet {
+			asset_id: Default::default(), custodian: Some(custodian) }.into());
+	}
+
+	request_mint {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let amount = T::Balance::from(100u32);
+		let pending = T::MaxPendingMintRequests::get().saturating_sub(1);
+		for _ in 0..pending {
+			Assets::<T, I>::request_mint(
+				SystemOrigin::Signed(caller.clone()).into(),
+				Default::default(),
+				amount,
+				valid_cid(),
+			)?;
+		}
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), amount, valid_cid())
+	verify {
+		assert_last_event::<T, I>(Event::MintRequested {
+			request_id: pending as u64, asset_id: Default::default(), owner: caller, amount }.into());
+	}
+
+	approve_mint {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let amount = T::Balance::from(100u32);
+		Assets::<T, I>::request_mint(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			amount,
+			valid_cid(),
+		)?;
+	}: _(SystemOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert_last_event::<T, I>(Event::MintRequestApproved {
+			request_id: 0, asset_id: Default::default(), custodian: caller }.into());
+	}
+
+	reject_mint {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let amount = T::Balance::from(100u32);
+		Assets::<T, I>::request_mint(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			amount,
+			valid_cid(),
+		)?;
+		let reason = vec![0u8; T::StringLimit::get() as usize];
+	}: _(SystemOrigin::Signed(caller.clone()), 0, reason.clone())
+	verify {
+		assert_last_event::<T, I>(Event::MintRequestRejected {
+			request_id: 0, asset_id: Default::default(), reason }.into());
+	}
+
+	retire {
+		let amount = T::Balance::from(100u32);
+		let (caller, _) = create_default_minted_asset::<T, I>(true, amount);
+		let name = vec![0u8; T::StringLimit::get() as usize];
+		let reason = vec![0u8; T::StringLimit::get() as usize];
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), amount, name, reason, 1_700_000_000)
+	verify {
+		assert!(RetirementCertificates::<T, I>::contains_key(0));
+	}
+
+	fund_sponsorship_pot {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let amount = T::SponsoredRetirementFee::get().saturating_mul(100u32.into());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), amount)
+	verify {
+		assert_last_event::<T, I>(Event::SponsorshipPotFunded {
+			asset_id: Default::default(), funder: caller, amount }.into());
+	}
+
+	withdraw_sponsorship_pot {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let amount = T::SponsoredRetirementFee::get().saturating_mul(100u32.into());
+		Assets::<T, I>::fund_sponsorship_pot(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			amount,
+		)?;
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::SponsorshipPotWithdrawn {
+			asset_id: Default::default(), funder: caller, amount }.into());
+	}
+
 	impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -0,0 +1,247 @@
+// This file is part of Evercity.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! IPFS content identifiers stored in `data_ipfs`.
+//!
+//! Accepts a CIDv0 (base58btc `Qm...`) and a CIDv1 in base32 (`b...`), base58btc (`z...`) or
+//! base16 (`f...`) multibase. The content multicodec and the multihash function must be known
+//! and the digest must have the length of its hash function. A CID is stored as [`Cid`], in
+//! binary without the multibase. Text longer than [`MAX_TEXT_LEN`] is rejected before it is
+//! decoded, so the cost of parsing is bounded whatever the input.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec, RuntimeDebug};
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
+
+/// Multicodecs of the content accepted in a CIDv1: raw, dag-pb, dag-cbor and dag-json.
+const CODECS: [u64; 4] = [0x55, 0x70, 0x71, 0x0129];
+
+/// Multicodec of the content of a CIDv0: dag-pb.
+const DAG_PB: u64 = 0x70;
+
+/// Multihash functions accepted in a CID with their digest lengths: sha2-256, sha2-512,
+/// blake3 and blake2b-256.
+const HASHES: [(u64, u64); 4] = [(0x12, 32), (0x13, 64), (0x1e, 32), (0xb220, 32)];
+
+/// The length of the longest well-formed CID text: a base16 CIDv1 of dag-json content with a
+/// sha2-512 digest.
+pub const MAX_TEXT_LEN: usize = 139;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// A well-formed CID in binary, e.g. 42 bytes for a sha2-256 digest instead of the 46 to 59
+/// bytes of its text forms.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Cid {
+	/// The CID version, 0 or 1.
+	pub version: u8,
+	/// The multicodec of the content, dag-pb for a CIDv0.
+	pub codec: u32,
+	/// The multihash function of the digest.
+	pub hash: u32,
+	/// The digest of the content.
+	pub digest: BoundedVec<u8, ConstU32<64>>,
+}
+
+impl Cid {
+	/// Parse the text form of a CID, `None` if it is not well-formed.
+	pub fn parse(text: &[u8]) -> Option<Self> {
+		if text.len() > MAX_TEXT_LEN {
+			return None
+		}
+		if text.len() == 46 && text.starts_with(b"Qm") {
+			let bytes = decode_base58(text)?;
+			let (hash, digest) = read_multihash(&bytes)?;
+			return Self::new(0, DAG_PB, hash, digest)
+		}
+		let bytes = match text.split_first() {
+			Some((b'b', rest)) => decode_base32(rest),
+			Some((b'z', rest)) => decode_base58(rest),
+			Some((b'f', rest)) => decode_base16(rest),
+			_ => None,
+		}?;
+		let mut input = &bytes[..];
+		if read_varint(&mut input)? != 1 {
+			return None
+		}
+		let codec = read_varint(&mut input).filter(|codec| CODECS.contains(codec))?;
+		let (hash, digest) = read_multihash(input)?;
+		Self::new(1, codec, hash, digest)
+	}
+
+	fn new(version: u8, codec: u64, hash: u64, digest: &[u8]) -> Option<Self> {
+		Some(Self {
+			version,
+			codec: codec.try_into().ok()?,
+			hash: hash.try_into().ok()?,
+			digest: digest.to_vec().try_into().ok()?,
+		})
+	}
+
+	/// The canonical text form: base58btc for a CIDv0 and base32 for a CIDv1, whatever
+	/// multibase it was parsed from.
+	pub fn to_text(&self) -> Vec<u8> {
+		let mut multihash = Vec::with_capacity(self.digest.len() + 4);
+		write_varint(&mut multihash, self.hash.into());
+		write_varint(&mut multihash, self.digest.len() as u64);
+		multihash.extend_from_slice(&self.digest);
+		if self.version == 0 {
+			return encode_base58(&multihash)
+		}
+		let mut bytes = Vec::with_capacity(multihash.len() + 4);
+		write_varint(&mut bytes, 1);
+		write_varint(&mut bytes, self.codec.into());
+		bytes.extend(multihash);
+		let mut text = b"b".to_vec();
+		text.extend(encode_base32(&bytes));
+		text
+	}
+}
+
+/// Whether `cid` is a well-formed CID.
+pub fn is_valid(cid: &[u8]) -> bool {
+	Cid::parse(cid).is_some()
+}
+
+/// Read a multihash of a known function with a digest of the right length: the function and
+/// the digest.
+fn read_multihash(mut input: &[u8]) -> Option<(u64, &[u8])> {
+	let code = read_varint(&mut input)?;
+	let len = read_varint(&mut input)?;
+	(HASHES.contains(&(code, len)) && input.len() as u64 == len).then(|| (code, input))
+}
+
+/// Read an unsigned varint of at most 9 bytes from the front of `input`.
+fn read_varint(input: &mut &[u8]) -> Option<u64> {
+	let mut value = 0u64;
+	for (i, byte) in input.iter().enumerate().take(9) {
+		value |= u64::from(byte & 0x7f) << (7 * i);
+		if byte & 0x80 == 0 {
+			*input = &input[i + 1..];
+			return Some(value)
+		}
+	}
+	None
+}
+
+/// Write `value` as an unsigned varint.
+fn write_varint(output: &mut Vec<u8>, mut value: u64) {
+	while value >= 0x80 {
+		output.push(value as u8 | 0x80);
+		value >>= 7;
+	}
+	output.push(value as u8);
+}
+
+pub(crate) fn encode_base58(input: &[u8]) -> Vec<u8> {
+	// Little-endian base58 digits of the big number.
+	let mut digits: Vec<u8> = Vec::with_capacity(input.len() * 138 / 100 + 1);
+	for byte in input {
+		let mut carry = u32::from(*byte);
+		for digit in digits.iter_mut() {
+			carry += u32::from(*digit) << 8;
+			*digit = (carry % 58) as u8;
+			carry /= 58;
+		}
+		while carry > 0 {
+			digits.push((carry % 58) as u8);
+			carry /= 58;
+		}
+	}
+	// Every leading zero byte is written as the zero digit.
+	let zeros = input.iter().take_while(|byte| **byte == 0).count();
+	sp_std::iter::repeat(BASE58_ALPHABET[0])
+		.take(zeros)
+		.chain(digits.iter().rev().map(|digit| BASE58_ALPHABET[*digit as usize]))
+		.collect()
+}
+
+pub(crate) fn decode_base58(input: &[u8]) -> Option<Vec<u8>> {
+	// Little-endian big number, reversed at the end.
+	let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+	for c in input {
+		let mut carry = BASE58_ALPHABET.iter().position(|a| a == c)? as u32;
+		for byte in bytes.iter_mut() {
+			carry += u32::from(*byte) * 58;
+			*byte = carry as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			bytes.push(carry as u8);
+			carry >>= 8;
+		}
+	}
+	let zeros = input.iter().take_while(|c| **c == BASE58_ALPHABET[0]).count();
+	bytes.extend(sp_std::iter::repeat(0).take(zeros));
+	bytes.reverse();
+	Some(bytes)
+}
+
+fn decode_base32(input: &[u8]) -> Option<Vec<u8>> {
+	let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+	let (mut buffer, mut bits) = (0u32, 0u32);
+	for c in input {
+		buffer = (buffer << 5) | BASE32_ALPHABET.iter().position(|a| a == c)? as u32;
+		bits += 5;
+		if bits >= 8 {
+			bits -= 8;
+			bytes.push((buffer >> bits) as u8);
+			buffer &= (1 << bits) - 1;
+		}
+	}
+	// Only zero padding of less than one character may remain.
+	if bits < 5 && buffer == 0 {
+		Some(bytes)
+	} else {
+		None
+	}
+}
+
+fn encode_base32(input: &[u8]) -> Vec<u8> {
+	let mut text = Vec::with_capacity((input.len() * 8 + 4) / 5);
+	let (mut buffer, mut bits) = (0u32, 0u32);
+	for byte in input {
+		buffer = (buffer << 8) | u32::from(*byte);
+		bits += 8;
+		while bits >= 5 {
+			bits -= 5;
+			text.push(BASE32_ALPHABET[(buffer >> bits) as usize & 31]);
+		}
+		buffer &= (1 << bits) - 1;
+	}
+	// The rest is padded with zero bits to a whole character.
+	if bits > 0 {
+		text.push(BASE32_ALPHABET[(buffer << (5 - bits)) as usize & 31]);
+	}
+	text
+}
+
+pub(crate) fn decode_base16(input: &[u8]) -> Option<Vec<u8>> {
+	if input.len() % 2 != 0 {
+		return None
+	}
+	input.chunks(2).map(|pair| Some(hex_value(pair[0])? << 4 | hex_value(pair[1])?)).collect()
+}
+
+fn hex_value(c: u8) -> Option<u8> {
+	match c {
+		b'0'..=b'9' => Some(c - b'0'),
+		b'a'..=b'f' => Some(c - b'a' + 10),
+		_ => None,
+	}
+}
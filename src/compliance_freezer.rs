@@ -0,0 +1,142 @@
+// This file is part of Evercity.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Compliance Freezer Pallet
+//!
+//! Reference implementation of the [`FrozenBalance`](crate::FrozenBalance) hook of the carbon
+//! assets pallet backed by on-chain storage.
+//!
+//! The `FreezeOrigin` (e.g. a compliance officer or governance) sets a frozen amount per asset
+//! and account via `set_freeze` and removes it via `clear_freeze`. The frozen amount cannot be
+//! transferred or burned by the account holder.
+//!
+//! To use it, add the pallet to `construct_runtime!` and set
+//! `type Freezer = ComplianceFreezer` in the carbon assets pallet configuration.
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{AssetId, FrozenBalance};
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AtLeast32BitUnsigned, StaticLookup};
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	/// The module configuration trait.
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The units in which frozen balances are recorded. Must match the carbon assets balance.
+		type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+
+		/// The origin which may set and clear freezes.
+		type FreezeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+	}
+
+	#[pallet::storage]
+	/// Frozen amount of a specific asset for a specific account.
+	pub(super) type ComplianceFreezes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `amount` of asset `asset_id` was frozen on the account `who`.
+		FreezeSet { asset_id: AssetId, who: T::AccountId, amount: T::Balance },
+		/// The freeze of asset `asset_id` on the account `who` was removed.
+		FreezeCleared { asset_id: AssetId, who: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account has no freeze for the asset.
+		NotFrozen,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Freeze `amount` of asset `id` on the account `who`, replacing any previous freeze.
+		///
+		/// The origin must conform to `FreezeOrigin`.
+		///
+		/// Emits `FreezeSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(Weight::from_ref_time(15_000_000).saturating_add(T::DbWeight::get().writes(1)))]
+		pub fn set_freeze(
+			origin: OriginFor<T>,
+			id: AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ComplianceFreezes::<T>::insert(id, &who, amount);
+			Self::deposit_event(Event::FreezeSet { asset_id: id, who, amount });
+			Ok(())
+		}
+
+		/// Remove the freeze of asset `id` on the account `who`.
+		///
+		/// The origin must conform to `FreezeOrigin`.
+		///
+		/// Emits `FreezeCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(Weight::from_ref_time(15_000_000).saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+		pub fn clear_freeze(
+			origin: OriginFor<T>,
+			id: AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(ComplianceFreezes::<T>::contains_key(id, &who), Error::<T>::NotFrozen);
+			ComplianceFreezes::<T>::remove(id, &who);
+			Self::deposit_event(Event::FreezeCleared { asset_id: id, who });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Get the frozen amount of asset `id` on the account `who`, if any.
+		pub fn frozen(id: AssetId, who: &T::AccountId) -> Option<T::Balance> {
+			ComplianceFreezes::<T>::get(id, who)
+		}
+	}
+
+	impl<T: Config> FrozenBalance<AssetId, T::AccountId, T::Balance> for Pallet<T> {
+		fn frozen_balance(asset: AssetId, who: &T::AccountId) -> Option<T::Balance> {
+			ComplianceFreezes::<T>::get(asset, who)
+		}
+
+		fn died(asset: AssetId, who: &T::AccountId) {
+			ComplianceFreezes::<T>::remove(asset, who);
+		}
+	}
+}
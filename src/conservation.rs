@@ -0,0 +1,165 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Supply conservation check for tests and try-runtime.
+//!
+//! With the `conservation-check` feature (enabled by `try-runtime` and in the pallet tests), every
+//! asset whose balances or supply change is noted in `ConservationTouched`. The
+//! `CheckSupplyConservation` signed extension recomputes the supply of the noted assets from
+//! `Account` after every extrinsic and rejects the extrinsic, logging its index, if it differs
+//! from `AssetDetails::supply`. Changes made outside of extrinsics are checked by the
+//! `try_state` hook. Without the feature nothing is noted and the extension does nothing.
+//!
+//! The recomputation iterates all the accounts of the noted assets and is not weighed, so the
+//! feature must not be enabled in production runtimes.
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	pallet_prelude::RuntimeDebug,
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+use sp_std::marker::PhantomData;
+
+/// Asset whose supply differs from the sum of the balances of its accounts.
+#[cfg(any(test, feature = "conservation-check"))]
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct SupplyMismatch<Balance> {
+	/// The asset which broke conservation.
+	pub asset_id: AssetId,
+	/// `AssetDetails::supply` of the asset.
+	pub supply: Balance,
+	/// The sum of the balances in `Account` of the asset.
+	pub accounted: Balance,
+}
+
+/// `InvalidTransaction::Custom` code of an extrinsic which broke the supply conservation.
+pub const SUPPLY_NOT_CONSERVED: u8 = 1;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Note that the balances or the supply of asset `id` changed in the current extrinsic.
+	pub(super) fn note_touched(id: AssetId) {
+		#[cfg(any(test, feature = "conservation-check"))]
+		ConservationTouched::<T, I>::mutate(|touched| {
+			if !touched.contains(&id) {
+				touched.push(id);
+			}
+		});
+		#[cfg(not(any(test, feature = "conservation-check")))]
+		let _ = id;
+	}
+
+	/// Recompute the supply of the assets touched since the last check from `Account`.
+	///
+	/// Assets in destruction are skipped, as `destroy_accounts` leaves their supply as is.
+	#[cfg(any(test, feature = "conservation-check"))]
+	pub fn check_supply_conservation() -> Result<(), SupplyMismatch<T::Balance>> {
+		for id in ConservationTouched::<T, I>::take() {
+			let details = match Asset::<T, I>::get(id) {
+				Some(details) if details.status != AssetStatus::Destroying => details,
+				_ => continue,
+			};
+			let accounted = Account::<T, I>::iter_prefix_values(id)
+				.fold(Zero::zero(), |sum: T::Balance, account| sum.saturating_add(account.balance));
+			if accounted != details.supply {
+				return Err(SupplyMismatch { asset_id: id, supply: details.supply, accounted })
+			}
+		}
+		Ok(())
+	}
+
+	/// Check the supply of the touched assets, logging the extrinsic index and the asset if it
+	/// is not conserved.
+	pub(super) fn ensure_supply_conserved() -> Result<(), &'static str> {
+		#[cfg(any(test, feature = "conservation-check"))]
+		if let Err(mismatch) = Self::check_supply_conservation() {
+			frame_support::log::error!(
+				target: "runtime::carbon-assets",
+				"supply conservation broken by extrinsic {:?}: {:?}",
+				frame_system::Pallet::<T>::extrinsic_index(),
+				mismatch,
+			);
+			return Err("supply conservation broken")
+		}
+		Ok(())
+	}
+}
+
+/// Signed extension checking the supply conservation of the touched assets after every
+/// extrinsic, rejecting it with `SUPPLY_NOT_CONSERVED` otherwise. It is meant for test and
+/// try-runtime builds, see the `conservation` module.
+#[derive(Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T, I))]
+pub struct CheckSupplyConservation<T: Config<I> + Send + Sync, I: 'static + Send + Sync = ()>(
+	PhantomData<(T, I)>,
+);
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> CheckSupplyConservation<T, I> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> Default
+	for CheckSupplyConservation<T, I>
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config<I> + Send + Sync, I: 'static + Send + Sync> SignedExtension
+	for CheckSupplyConservation<T, I>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "CheckSupplyConservation";
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn post_dispatch(
+		_pre: Option<Self::Pre>,
+		_info: &DispatchInfoOf<Self::Call>,
+		_post_info: &PostDispatchInfoOf<Self::Call>,
+		_len: usize,
+		_result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		Pallet::<T, I>::ensure_supply_conserved()
+			.map_err(|_| InvalidTransaction::Custom(SUPPLY_NOT_CONSERVED).into())
+	}
+}
@@ -0,0 +1,170 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Getters of the pallet constants for other pallets of the runtime.
+//!
+//! The constants are in the metadata for clients, but pallets built on top of carbon assets
+//! (e.g. a marketplace or bonds) would otherwise need their own `Get` items wired to the same
+//! runtime parameters to derive their limits and deposits. They can call these associated
+//! functions of `Pallet` instead.
+
+use super::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The deposit reserved for creating an asset, see `Config::AssetDeposit`.
+	pub fn asset_deposit() -> DepositBalanceOf<T, I> {
+		T::AssetDeposit::get()
+	}
+
+	/// The deposit reserved for a non-provider asset account, see
+	/// `Config::AssetAccountDeposit`.
+	pub fn asset_account_deposit() -> DepositBalanceOf<T, I> {
+		T::AssetAccountDeposit::get()
+	}
+
+	/// The base deposit of the asset metadata, see `Config::MetadataDepositBase`.
+	pub fn metadata_deposit_base() -> DepositBalanceOf<T, I> {
+		T::MetadataDepositBase::get()
+	}
+
+	/// The deposit per byte of the asset metadata, see `Config::MetadataDepositPerByte`.
+	pub fn metadata_deposit_per_byte() -> DepositBalanceOf<T, I> {
+		T::MetadataDepositPerByte::get()
+	}
+
+	/// The deposit of `bytes` bytes of metadata, e.g. of the name and the symbol of an asset.
+	pub fn metadata_deposit(bytes: u32) -> DepositBalanceOf<T, I> {
+		T::MetadataDepositPerByte::get()
+			.saturating_mul(bytes.into())
+			.saturating_add(T::MetadataDepositBase::get())
+	}
+
+	/// The base deposit of an asset attribute, see `Config::AttributeDepositBase`.
+	pub fn attribute_deposit_base() -> DepositBalanceOf<T, I> {
+		T::AttributeDepositBase::get()
+	}
+
+	/// The deposit of an asset attribute with a key and a value of `bytes` bytes in total.
+	pub fn attribute_deposit(bytes: u32) -> DepositBalanceOf<T, I> {
+		T::MetadataDepositPerByte::get()
+			.saturating_mul(bytes.into())
+			.saturating_add(T::AttributeDepositBase::get())
+	}
+
+	/// The deposit reserved for a transfer approval, see `Config::ApprovalDeposit`.
+	pub fn approval_deposit() -> DepositBalanceOf<T, I> {
+		T::ApprovalDeposit::get()
+	}
+
+	/// The reward per item removed by the cleanup calls, see `Config::CleanupReward`.
+	pub fn cleanup_reward() -> DepositBalanceOf<T, I> {
+		T::CleanupReward::get()
+	}
+
+	/// The maximum length of a name or symbol, see `Config::StringLimit`.
+	pub fn string_limit() -> u32 {
+		T::StringLimit::get()
+	}
+
+	/// The maximum length of an attribute key, see `Config::AttributeKeyLimit`.
+	pub fn attribute_key_limit() -> u32 {
+		T::AttributeKeyLimit::get()
+	}
+
+	/// The maximum length of an attribute value, see `Config::AttributeValueLimit`.
+	pub fn attribute_value_limit() -> u32 {
+		T::AttributeValueLimit::get()
+	}
+
+	/// The maximum length of a transfer memo, see `Config::MemoLimit`.
+	pub fn memo_limit() -> u32 {
+		T::MemoLimit::get()
+	}
+
+	/// The maximum number of mints in `mint_batch`, see `Config::MaxMintBatch`.
+	pub fn max_mint_batch() -> u32 {
+		T::MaxMintBatch::get()
+	}
+
+	/// The maximum number of assets an account may own when it calls `create`, see
+	/// `Config::MaxAssetsPerAccount`.
+	pub fn max_assets_per_account() -> u32 {
+		T::MaxAssetsPerAccount::get()
+	}
+
+	/// The maximum number of assets retired by `retire_co2e`, see
+	/// `Config::MaxRetirementBasket`.
+	pub fn max_retirement_basket() -> u32 {
+		T::MaxRetirementBasket::get()
+	}
+
+	/// The maximum number of recipients of the asset splits, see `Config::MaxSplits`.
+	pub fn max_splits() -> u32 {
+		T::MaxSplits::get()
+	}
+
+	/// The maximum number of freezes of an asset-account placed through `MutateFreeze`, see
+	/// `Config::MaxFreezes`.
+	pub fn max_freezes() -> u32 {
+		T::MaxFreezes::get()
+	}
+
+	/// The maximum number of calls in one `owner_batch`, see `Config::MaxOwnerBatch`.
+	pub fn max_owner_batch() -> u32 {
+		T::MaxOwnerBatch::get()
+	}
+
+	/// The maximum number of items removed in one `destroy_accounts` or `destroy_approvals`
+	/// call, see `Config::RemoveItemsLimit`.
+	pub fn remove_items_limit() -> u32 {
+		T::RemoveItemsLimit::get()
+	}
+
+	/// The length of the retirement quota periods, see `Config::QuotaPeriod`.
+	pub fn quota_period() -> T::BlockNumber {
+		T::QuotaPeriod::get()
+	}
+
+	/// The number of blocks before a proposed custodian can accept the role, see
+	/// `Config::CustodianChangeDelay`.
+	pub fn custodian_change_delay() -> T::BlockNumber {
+		T::CustodianChangeDelay::get()
+	}
+
+	/// The number of blocks a proposed owner has to accept the ownership, see
+	/// `Config::OwnershipAcceptancePeriod`.
+	pub fn ownership_acceptance_period() -> T::BlockNumber {
+		T::OwnershipAcceptancePeriod::get()
+	}
+
+	/// The amount drawn from a sponsorship pot for a sponsored `self_burn`, see
+	/// `Config::SponsoredRetirementFee`.
+	pub fn sponsored_retirement_fee() -> DepositBalanceOf<T, I> {
+		T::SponsoredRetirementFee::get()
+	}
+
+	/// The amount sent to a sponsored account, see `Config::SponsorAmount`.
+	pub fn sponsor_amount() -> DepositBalanceOf<T, I> {
+		T::SponsorAmount::get()
+	}
+
+	/// The number of blocks before an account sponsorship can be clawed back, see
+	/// `Config::SponsorshipClawbackPeriod`.
+	pub fn sponsorship_clawback_period() -> T::BlockNumber {
+		T::SponsorshipClawbackPeriod::get()
+	}
+}
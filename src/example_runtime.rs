@@ -0,0 +1,274 @@
+// This file is part of Evercity.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Example runtime wiring the carbon assets pallet.
+//!
+//! A compiling reference for integrators, built only with the `example-runtime` feature. It
+//! configures `frame_system`, `pallet_timestamp`, `pallet_balances`,
+//! `pallet_randomness_collective_flip` and the carbon assets pallet with the values of the
+//! README configuration, and [`testnet_genesis`] builds a genesis config in the shape of the
+//! node template chain spec.
+//!
+//! The evercity accounts pallet lives in its own repository and is not a dependency of this
+//! crate, so it is not part of the example. A runtime using it adds it to `construct_runtime!`
+//! next to `CarbonAssets` and typically uses its role holders as `Verifiers` and `Auditors`.
+
+use crate as pallet_carbon_assets;
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstBool, ConstU128, ConstU16, ConstU32, ConstU64, SortedMembers},
+	weights::constants::WEIGHT_PER_SECOND,
+	PalletId,
+};
+use sp_core::H256;
+use sp_runtime::{
+	generic,
+	traits::{AccountIdConversion, AccountIdLookup, BlakeTwo256, IdentifyAccount, Verify},
+	MultiAddress, MultiSignature, Perbill,
+};
+use sp_std::prelude::*;
+
+/// Signature of the extrinsics and of the retirement vouchers.
+pub type Signature = MultiSignature;
+/// Account id derived from the signer of a [`Signature`].
+pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+/// Balance of the native currency and of the carbon assets.
+pub type Balance = u128;
+/// Block number.
+pub type BlockNumber = u32;
+/// Account nonce.
+pub type Index = u32;
+
+/// Block header.
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+/// Block of the example runtime.
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+/// Signed extensions checked for every extrinsic.
+pub type SignedExtra = (
+	frame_system::CheckNonZeroSender<Runtime>,
+	frame_system::CheckSpecVersion<Runtime>,
+	frame_system::CheckTxVersion<Runtime>,
+	frame_system::CheckGenesis<Runtime>,
+	frame_system::CheckEra<Runtime>,
+	frame_system::CheckNonce<Runtime>,
+	frame_system::CheckWeight<Runtime>,
+);
+/// Extrinsic of the example runtime.
+pub type UncheckedExtrinsic =
+	generic::UncheckedExtrinsic<MultiAddress<AccountId, ()>, RuntimeCall, Signature, SignedExtra>;
+
+pub const MILLISECS_PER_BLOCK: u64 = 6000;
+pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
+pub const HOURS: BlockNumber = MINUTES * 60;
+pub const DAYS: BlockNumber = HOURS * 24;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage},
+		CarbonAssets: pallet_carbon_assets::{Pallet, Call, Storage, Config<T>, Event<T>, ValidateUnsigned},
+	}
+);
+
+parameter_types! {
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::with_sensible_defaults(
+			WEIGHT_PER_SECOND.saturating_mul(2),
+			Perbill::from_percent(75),
+		);
+	pub BlockLength: frame_system::limits::BlockLength =
+		frame_system::limits::BlockLength::max_with_normal_ratio(5 * 1024 * 1024, Perbill::from_percent(75));
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = BlockWeights;
+	type BlockLength = BlockLength;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = Index;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = AccountIdLookup<AccountId, ()>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU32<2400>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<{ MILLISECS_PER_BLOCK / 2 }>;
+	type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<500>;
+	type AccountStore = System;
+	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+}
+
+impl pallet_randomness_collective_flip::Config for Runtime {}
+
+/// Empty account list for `ReserveAccounts`, `Verifiers` and `Auditors`. A production runtime
+/// takes them from a membership pallet instead.
+pub struct NoMembers;
+impl SortedMembers<AccountId> for NoMembers {
+	fn sorted_members() -> Vec<AccountId> {
+		Vec::new()
+	}
+}
+
+parameter_types! {
+	pub const CarbonAssetDeposit: Balance = 0;
+	pub const CarbonAssetAccountDeposit: Balance = 0;
+	pub const CarbonMetadataDepositBase: Balance = 0;
+	pub const CarbonMetadataDepositPerByte: Balance = 0;
+	pub const CarbonApprovalDeposit: Balance = 0;
+	pub const CarbonSponsoredRetirementFee: Balance = 1_000_000_000;
+	pub CarbonSponsoredFeeDestination: AccountId = PalletId(*b"py/trsry").into_account_truncating();
+	pub const CarbonCleanupReward: Balance = 0;
+	pub const CarbonSponsorAmount: Balance = 0;
+	pub const CarbonAttributeDepositBase: Balance = 0;
+	pub const CarbonStringLimit: u32 = 140;
+}
+
+impl pallet_carbon_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Currency = Balances;
+	type Deposits = pallet_carbon_assets::NativeDeposits<Balances>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type AssetDeposit = CarbonAssetDeposit;
+	type AssetAccountDeposit = CarbonAssetAccountDeposit;
+	type MetadataDepositBase = CarbonMetadataDepositBase;
+	type MetadataDepositPerByte = CarbonMetadataDepositPerByte;
+	type AttributeDepositBase = CarbonAttributeDepositBase;
+	type AttributeKeyLimit = ConstU32<32>;
+	type AttributeValueLimit = ConstU32<64>;
+	type MaxMintBatch = ConstU32<50>;
+	type MaxAssetsPerAccount = ConstU32<100>;
+	type MaxSplits = ConstU32<5>;
+	type MemoLimit = ConstU32<64>;
+	type QuotaPeriod = ConstU32<{ 30 * DAYS }>;
+	type CustodianChangeDelay = ConstU32<{ 2 * DAYS }>;
+	type OwnershipAcceptancePeriod = ConstU32<{ 7 * DAYS }>;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type CleanupReward = CarbonCleanupReward;
+	type ApprovalGcGracePeriod = ConstU32<{ 30 * DAYS }>;
+	type MaxRetirementBasket = ConstU32<20>;
+	type ApprovalDeposit = CarbonApprovalDeposit;
+	type StringLimit = CarbonStringLimit;
+	type Freezer = ();
+	type Screening = ();
+	type MetadataValidator = pallet_carbon_assets::StrictMetadata;
+	type OnCarbonTransfer = ();
+	type Extra = ();
+	type WeightInfo = pallet_carbon_assets::weights::SubstrateWeight<Runtime>;
+	type Randomness = RandomnessCollectiveFlip;
+	type ReserveAccounts = NoMembers;
+	type Verifiers = NoMembers;
+	type Auditors = NoMembers;
+	type OnDustRemoval = ();
+	type AllowMintWhenFrozen = ConstBool<false>;
+	type CustodianRequired = ConstBool<true>;
+	type LedgerEvents = ConstBool<false>;
+	type AccountStats = ConstBool<false>;
+	type MethodologyRequired = ConstBool<true>;
+	type ProjectApprovalRequired = ConstBool<true>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type MaxOutbox = ConstU32<0>;
+	type MaxCustodianLog = ConstU32<1000>;
+	type MaxSerialRanges = ConstU32<100>;
+	type SlaReportInterval = ConstU32<0>;
+	type SponsorAmount = CarbonSponsorAmount;
+	type SponsorshipClawbackPeriod = ConstU32<{ 90 * DAYS }>;
+	type FreezeIdentifier = [u8; 8];
+	type MaxFreezes = ConstU32<4>;
+	type MaxOwnerBatch = ConstU32<8>;
+	type MaxPendingOwnerActions = ConstU32<10>;
+	type MaxPendingMintRequests = ConstU32<10>;
+	type SponsoredRetirementFee = CarbonSponsoredRetirementFee;
+	type SponsoredFeeDestination = CarbonSponsoredFeeDestination;
+	type SponsoredRetirementInterval = ConstU32<HOURS>;
+	type OffchainWorker = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ExampleBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ExampleBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_carbon_assets::BenchmarkHelper<AccountId, Signature> for ExampleBenchmarkHelper {
+	fn voucher_signer() -> AccountId {
+		sp_io::crypto::sr25519_generate(sp_core::crypto::key_types::DUMMY, None).into()
+	}
+
+	fn sign_voucher(signer: &AccountId, message: &[u8]) -> Signature {
+		let public = sp_core::sr25519::Public::from_raw(*AsRef::<[u8; 32]>::as_ref(signer));
+		sp_io::crypto::sr25519_sign(sp_core::crypto::key_types::DUMMY, &public, message)
+			.expect("key generated by voucher_signer; qed")
+			.into()
+	}
+}
+
+/// Genesis config in the shape of the node template `testnet_genesis`: `wasm_binary` is the
+/// runtime code, `custodian` becomes the carbon assets Custodian and every account of
+/// `endowed_accounts` gets `1 << 60` of the native currency.
+pub fn testnet_genesis(
+	wasm_binary: &[u8],
+	custodian: AccountId,
+	endowed_accounts: Vec<AccountId>,
+) -> GenesisConfig {
+	GenesisConfig {
+		system: SystemConfig { code: wasm_binary.to_vec() },
+		balances: BalancesConfig {
+			balances: endowed_accounts.into_iter().map(|k| (k, 1 << 60)).collect(),
+		},
+		carbon_assets: CarbonAssetsConfig {
+			custodian: Some(custodian),
+			assets: Vec::new(),
+			metadata: Vec::new(),
+			accounts: Vec::new(),
+		},
+	}
+}
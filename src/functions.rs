@@ -18,7 +18,12 @@
 //! Functions for the Assets pallet.
 
 use super::*;
-use frame_support::{traits::{Get, Randomness}, BoundedVec};
+use frame_support::{
+	dispatch::Pays,
+	traits::{Get, Randomness},
+	weights::{Weight, WeightToFee},
+	BoundedVec,
+};
 use codec::Encode;
 
 #[must_use]
@@ -64,48 +69,307 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Asset::<T, I>::get(id).map(|x| x.supply)
 	}
 
+	/// Get the number of accounts holding the asset `id`, if the asset exists.
+	pub fn asset_accounts(id: AssetId) -> Option<u32> {
+		Asset::<T, I>::get(id).map(|x| x.accounts)
+	}
+
+	/// Get the number of accounts holding the asset `id` with a self-sufficient reference, if the
+	/// asset exists.
+	pub fn asset_sufficients(id: AssetId) -> Option<u32> {
+		Asset::<T, I>::get(id).map(|x| x.sufficients)
+	}
+
+	/// Get the number of transfer-approvals of the asset `id`, if the asset exists.
+	pub fn asset_approvals(id: AssetId) -> Option<u32> {
+		Asset::<T, I>::get(id).map(|x| x.approvals)
+	}
 
+	/// Get the version of the Call/Event surface, including the capabilities enabled by the
+	/// configuration.
+	pub fn interface_version() -> InterfaceVersion {
+		let mut version = INTERFACE_VERSION;
+		if T::MaxOutbox::get() > 0 {
+			version = version.with(Capability::Outbox);
+		}
+		if T::LedgerEvents::get() {
+			version = version.with(Capability::LedgerEvents);
+		}
+		if T::AccountStats::get() {
+			version = version.with(Capability::AccountStats);
+		}
+		if !T::SlaReportInterval::get().is_zero() {
+			version = version.with(Capability::CustodianSla);
+		}
+		version
+	}
 
-	pub(super) fn new_account(
+	/// Collect all the data stored about the account `who`: holdings, owned assets, approvals,
+	/// burn certificates, deposits and freezes.
+	///
+	/// Iterates over all assets and approvals, so it is meant for runtime API queries only.
+	pub(super) fn account_dump(
 		who: &T::AccountId,
-		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
-		maybe_deposit: Option<DepositBalanceOf<T, I>>,
-	) -> Result<ExistenceReason<DepositBalanceOf<T, I>>, DispatchError> {
-		let accounts = d.accounts.checked_add(1).ok_or(ArithmeticError::Overflow)?;
-		let reason = if let Some(deposit) = maybe_deposit {
-			ExistenceReason::DepositHeld(deposit)
-		} else if d.is_sufficient {
-			frame_system::Pallet::<T>::inc_sufficients(who);
-			d.sufficients += 1;
-			ExistenceReason::Sufficient
-		} else {
-			frame_system::Pallet::<T>::inc_consumers(who).map_err(|_| Error::<T, I>::NoProvider)?;
-			ExistenceReason::Consumer
+	) -> AccountDump<T::AccountId, T::Balance, DepositBalanceOf<T, I>> {
+		let mut dump = AccountDump {
+			assets: Vec::new(),
+			owned_assets: Vec::new(),
+			approvals_given: Vec::new(),
+			approvals_received: Vec::new(),
+			burn_certificates: BurnCertificate::<T, I>::iter_prefix(who)
+				.map(|(id, certificate)| (id, certificate.amount))
+				.collect(),
 		};
-		d.accounts = accounts;
-		Ok(reason)
+
+		for (id, details) in Asset::<T, I>::iter() {
+			if let Some(account) = Account::<T, I>::get(id, who) {
+				dump.assets.push(AccountAssetDump {
+					asset_id: id,
+					balance: account.balance,
+					is_frozen: account.is_frozen,
+					frozen_balance: Self::frozen_balance(id, who, &account),
+					deposit: match account.reason {
+						ExistenceReason::DepositHeld(deposit) => Some(deposit),
+						_ => None,
+					},
+				});
+			}
+			if &details.owner == who {
+				let deposit = details.deposit.saturating_add(Metadata::<T, I>::get(id).deposit);
+				dump.owned_assets.push((id, deposit));
+			}
+		}
+
+		for ((id, owner, delegate), approval) in Approvals::<T, I>::iter() {
+			if &owner == who {
+				dump.approvals_given.push((id, delegate, approval.amount, approval.deposit));
+			} else if &delegate == who {
+				dump.approvals_received.push((id, owner, approval.amount));
+			}
+		}
+		dump
 	}
 
-	pub(super) fn dead_account(
-		who: &T::AccountId,
-		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
-		reason: &ExistenceReason<DepositBalanceOf<T, I>>,
-		force: bool,
-	) -> DeadConsequence {
-		match *reason {
-			ExistenceReason::Consumer => frame_system::Pallet::<T>::dec_consumers(who),
-			ExistenceReason::Sufficient => {
-				d.sufficients = d.sufficients.saturating_sub(1);
-				frame_system::Pallet::<T>::dec_sufficients(who);
+	/// Iterate over the ids of all the existing assets.
+	///
+	/// Every item costs a storage read.
+	pub fn asset_ids() -> impl Iterator<Item = AssetId> {
+		<Self as fungibles::InspectEnumerable<T::AccountId>>::asset_ids()
+	}
+
+	/// Iterate over the ids of the assets `who` has an account in, from the `AccountAssets`
+	/// index.
+	pub fn account_asset_ids(who: &T::AccountId) -> impl Iterator<Item = AssetId> {
+		AccountAssets::<T, I>::iter_key_prefix(who)
+	}
+
+	/// Get the assets whose metadata symbol equals `symbol` ignoring ASCII case, e.g. to warn
+	/// about a duplicated ticker before creating an asset.
+	pub fn symbol_exists(symbol: &[u8]) -> Vec<AssetId> {
+		match Self::symbol_key(symbol) {
+			Some(key) => SymbolAssets::<T, I>::iter_key_prefix(key).collect(),
+			None => Vec::new(),
+		}
+	}
+
+	/// Get the balances of all the assets held by `who`.
+	pub(super) fn account_balances(who: &T::AccountId) -> Vec<(AssetId, T::Balance)> {
+		Self::account_asset_ids(who)
+			.filter_map(|id| Account::<T, I>::get(id, who).map(|account| (id, account.balance)))
+			.collect()
+	}
+
+	/// Get up to `limit` live approvals of `owner` for asset `id` as delegates and approved
+	/// amounts, starting after the delegate `cursor`, e.g. the last delegate of the previous
+	/// page.
+	pub fn approvals_of(
+		owner: &T::AccountId,
+		id: AssetId,
+		cursor: Option<&T::AccountId>,
+		limit: u32,
+	) -> Vec<(T::AccountId, T::Balance)> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let live = |(delegate, approval): (T::AccountId, Approval<_, _, _>)| {
+			(!approval.is_expired(&now)).then(|| (delegate, approval.amount))
+		};
+		match cursor {
+			Some(cursor) => {
+				let start = Approvals::<T, I>::hashed_key_for((id, owner, cursor));
+				Approvals::<T, I>::iter_from(start)
+					.take_while(|((asset, approver, _), _)| *asset == id && approver == owner)
+					.filter_map(|((_, _, delegate), approval)| live((delegate, approval)))
+					.take(limit as usize)
+					.collect()
 			},
-			ExistenceReason::DepositRefunded => {},
-			ExistenceReason::DepositHeld(_) if !force => return Keep,
-			ExistenceReason::DepositHeld(_) => {},
+			None => Approvals::<T, I>::iter_prefix((id, owner))
+				.filter_map(live)
+				.take(limit as usize)
+				.collect(),
+		}
+	}
+
+	/// Get up to `limit` entries of the `RetirementRegistry` with their certificate ids, starting
+	/// at the certificate id `start`.
+	pub fn retirement_records(
+		start: u64,
+		limit: u32,
+	) -> Vec<(u64, RetirementRecord<T::AccountId, T::Balance, T::BlockNumber>)> {
+		let end = NextCertificateId::<T, I>::get().min(start.saturating_add(limit.into()));
+		(start..end)
+			.filter_map(|certificate_id| {
+				RetirementRegistry::<T, I>::get(certificate_id).map(|record| (certificate_id, record))
+			})
+			.collect()
+	}
+
+	/// Get the holders of asset `id` with their balances.
+	pub fn asset_holders(id: AssetId) -> Vec<(T::AccountId, T::Balance)> {
+		Account::<T, I>::iter_prefix(id).map(|(who, account)| (who, account.balance)).collect()
+	}
+
+	/// Whether `who` is an authorized viewer key.
+	pub fn is_viewer(who: &T::AccountId) -> bool {
+		ViewerKeys::<T, I>::contains_key(who)
+	}
+
+	/// The message to be signed by the viewer of the `request`: the SCALE encoding of
+	/// `(VIEW_DOMAIN, pallet name, request)`.
+	pub fn view_request_message(request: &ViewRequest<T::AccountId, T::BlockNumber>) -> Vec<u8> {
+		let name = <Pallet<T, I> as frame_support::traits::PalletInfoAccess>::name();
+		(VIEW_DOMAIN, name.as_bytes(), request).encode()
+	}
+
+	/// Check that the `request` is signed by a viewer key in `ViewerKeys` and is not expired.
+	pub fn authorize_view(
+		request: &ViewRequest<T::AccountId, T::BlockNumber>,
+		signature: &T::OffchainSignature,
+	) -> Result<(), ViewError> {
+		ensure!(Self::is_viewer(&request.viewer), ViewError::NotViewer);
+		ensure!(
+			signature.verify(&Self::view_request_message(request)[..], &request.viewer),
+			ViewError::BadSignature
+		);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= request.valid_until,
+			ViewError::Expired
+		);
+		Ok(())
+	}
+
+	/// Get the `account_dump` of the `ViewQuery::AccountDump` of an authorized `request`.
+	pub fn view_account_dump(
+		request: ViewRequest<T::AccountId, T::BlockNumber>,
+		signature: T::OffchainSignature,
+	) -> Result<AccountDump<T::AccountId, T::Balance, DepositBalanceOf<T, I>>, ViewError> {
+		Self::authorize_view(&request, &signature)?;
+		match request.query {
+			ViewQuery::AccountDump(who) => Ok(Self::account_dump(&who)),
+			_ => Err(ViewError::WrongQuery),
+		}
+	}
+
+	/// Get the `account_balances` of the `ViewQuery::AccountBalances` of an authorized
+	/// `request`.
+	pub fn view_account_balances(
+		request: ViewRequest<T::AccountId, T::BlockNumber>,
+		signature: T::OffchainSignature,
+	) -> Result<Vec<(AssetId, T::Balance)>, ViewError> {
+		Self::authorize_view(&request, &signature)?;
+		match request.query {
+			ViewQuery::AccountBalances(who) => Ok(Self::account_balances(&who)),
+			_ => Err(ViewError::WrongQuery),
+		}
+	}
+
+	/// Get the `position_statement` of the `ViewQuery::PositionStatement` of an authorized
+	/// `request`.
+	pub fn view_position_statement(
+		request: ViewRequest<T::AccountId, T::BlockNumber>,
+		signature: T::OffchainSignature,
+	) -> Result<Vec<AssetPosition<T::Balance>>, ViewError> {
+		Self::authorize_view(&request, &signature)?;
+		match request.query {
+			ViewQuery::PositionStatement(who) => Ok(Self::position_statement(&who)),
+			_ => Err(ViewError::WrongQuery),
 		}
-		d.accounts = d.accounts.saturating_sub(1);
-		Remove
 	}
 
+	/// Get the `asset_holders` of the `ViewQuery::AssetHolders` of an authorized `request`.
+	pub fn view_asset_holders(
+		request: ViewRequest<T::AccountId, T::BlockNumber>,
+		signature: T::OffchainSignature,
+	) -> Result<Vec<(T::AccountId, T::Balance)>, ViewError> {
+		Self::authorize_view(&request, &signature)?;
+		match request.query {
+			ViewQuery::AssetHolders(id) => Ok(Self::asset_holders(id)),
+			_ => Err(ViewError::WrongQuery),
+		}
+	}
+
+	/// Get the assets burned by `who` with the amounts stored in its burn certificates.
+	pub fn burn_certificates(who: &T::AccountId) -> Vec<(AssetId, T::Balance)> {
+		BurnCertificate::<T, I>::iter_prefix(who)
+			.map(|(id, certificate)| (id, certificate.amount))
+			.collect()
+	}
+
+	/// Get the position of `who` in every asset it holds: balance, frozen amount, live approvals
+	/// given and received, retired amount and the current metadata of the asset.
+	///
+	/// Iterates over the approvals of the held assets, so it is meant for runtime API queries
+	/// only.
+	pub(super) fn position_statement(who: &T::AccountId) -> Vec<AssetPosition<T::Balance>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		Self::account_asset_ids(who)
+			.filter_map(|id| {
+				let account = Account::<T, I>::get(id, who)?;
+				let mut approvals_out = T::Balance::zero();
+				let mut approvals_in = T::Balance::zero();
+				for ((owner, delegate), approval) in Approvals::<T, I>::iter_prefix((id,)) {
+					if approval.expiry.map_or(false, |expiry| expiry <= now) {
+						continue
+					}
+					if &owner == who {
+						approvals_out.saturating_accrue(approval.amount);
+					} else if &delegate == who {
+						approvals_in.saturating_accrue(approval.amount);
+					}
+				}
+				let frozen = if account.is_frozen {
+					account.balance
+				} else {
+					Self::frozen_balance(id, who, &account).unwrap_or_default()
+				};
+				let metadata = Metadata::<T, I>::get(id);
+				Some(AssetPosition {
+					asset_id: id,
+					balance: account.balance,
+					frozen,
+					approvals_out,
+					approvals_in,
+					retired: BurnCertificate::<T, I>::get(who, id).map_or(Zero::zero(), |c| c.amount),
+					name: metadata.name.to_vec(),
+					symbol: metadata.symbol.to_vec(),
+					decimals: metadata.decimals,
+				})
+			})
+			.collect()
+	}
+
+	/// Get the details of the asset `id`, if the asset exists.
+	pub fn asset_details(
+		id: AssetId,
+	) -> Option<AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>> {
+		Asset::<T, I>::get(id)
+	}
+
+	/// Get the circulating supply of an asset `id`: the total supply without the reserve-backed
+	/// part held by the `ReserveAccounts`.
+	pub fn circulating_supply(id: AssetId) -> T::Balance {
+		Self::total_supply(id).saturating_sub(ReservedSupply::<T, I>::get(id))
+	}
+
+
 	/// Returns `true` when the balance of `account` can be increased by `amount`.
 	///
 	/// - `id`: The id of the asset that should be increased.
@@ -123,10 +387,25 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Some(details) => details,
 			None => return DepositConsequence::UnknownAsset,
 		};
+		Self::can_increase_with(&details, Self::maybe_balance(id, who), who, amount, increase_supply)
+	}
+
+	/// Same as `can_increase` but works on already fetched asset `details` and the current
+	/// balance of `who` (`None` if the asset-account doesn't exist).
+	pub(super) fn can_increase_with(
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		maybe_balance: Option<T::Balance>,
+		who: &T::AccountId,
+		amount: T::Balance,
+		increase_supply: bool,
+	) -> DepositConsequence {
+		if details.status == AssetStatus::Destroying {
+			return DepositConsequence::UnknownAsset
+		}
 		if increase_supply && details.supply.checked_add(&amount).is_none() {
 			return DepositConsequence::Overflow
 		}
-		if let Some(balance) = Self::maybe_balance(id, who) {
+		if let Some(balance) = maybe_balance {
 			if balance.checked_add(&amount).is_none() {
 				return DepositConsequence::Overflow
 			}
@@ -152,21 +431,43 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		amount: T::Balance,
 		keep_alive: bool,
 	) -> WithdrawConsequence<T::Balance> {
-		use WithdrawConsequence::*;
 		let details = match Asset::<T, I>::get(id) {
 			Some(details) => details,
-			None => return UnknownAsset,
+			None => return WithdrawConsequence::UnknownAsset,
 		};
+		Self::can_decrease_with(
+			id,
+			&details,
+			Account::<T, I>::get(id, who).as_ref(),
+			who,
+			amount,
+			keep_alive,
+		)
+	}
+
+	/// Same as `can_decrease` but works on already fetched asset `details` and asset-account of
+	/// `who`.
+	pub(super) fn can_decrease_with(
+		id: AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		maybe_account: Option<&AssetAccountOf<T, I>>,
+		who: &T::AccountId,
+		amount: T::Balance,
+		keep_alive: bool,
+	) -> WithdrawConsequence<T::Balance> {
+		use WithdrawConsequence::*;
 		if details.supply.checked_sub(&amount).is_none() {
 			return Underflow
 		}
-		if details.is_frozen {
-			return Frozen
+		match details.status {
+			AssetStatus::Live => {},
+			AssetStatus::Frozen => return Frozen,
+			AssetStatus::Destroying => return UnknownAsset,
 		}
 		if amount.is_zero() {
 			return Success
 		}
-		let account = match Account::<T, I>::get(id, who) {
+		let account = match maybe_account {
 			Some(a) => a,
 			None => return NoFunds,
 		};
@@ -174,7 +475,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Frozen
 		}
 		if let Some(rest) = account.balance.checked_sub(&amount) {
-			if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
+			if let Some(frozen) = Self::frozen_balance(id, who, account) {
 				match frozen.checked_add(&details.min_balance) {
 					Some(required) if rest < required => return Frozen,
 					None => return Overflow,
@@ -208,12 +509,285 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		keep_alive: bool,
 	) -> Result<T::Balance, DispatchError> {
 		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
-		ensure!(!details.is_frozen, Error::<T, I>::Frozen);
+		Self::reducible_balance_with(
+			id,
+			&details,
+			Account::<T, I>::get(id, who).as_ref(),
+			who,
+			keep_alive,
+		)
+	}
+
+	/// The amount of asset `id` of `who` which cannot be transferred: the amount frozen with
+	/// `freeze_amount` in the asset-`account` of `who`, the amount locked by holds and freezes
+	/// (see `held_or_frozen`) and the amount frozen by the `Freezer`. `None` if none of them
+	/// locked anything.
+	pub(super) fn frozen_balance(
+		id: AssetId,
+		who: &T::AccountId,
+		account: &AssetAccountOf<T, I>,
+	) -> Option<T::Balance> {
+		let locked = account
+			.frozen
+			.saturating_add(Self::held_or_frozen(id, who, Holds::<T, I>::get(id, who)));
+		match T::Freezer::frozen_balance(id, who) {
+			Some(frozen) => Some(frozen.saturating_add(locked)),
+			None => (!locked.is_zero()).then(|| locked),
+		}
+	}
+
+	/// The amount of asset `id` of `who` locked by the `held` amount and the freezes of
+	/// `MutateFreeze`. Like in pallet-balances, the freezes overlap the held amount.
+	pub(super) fn held_or_frozen(id: AssetId, who: &T::AccountId, held: T::Balance) -> T::Balance {
+		let frozen = Freezes::<T, I>::get(id, who).iter().map(|f| f.amount).max();
+		held.max(frozen.unwrap_or_else(Zero::zero))
+	}
+
+	/// Whether `amount` more of asset `id` of `who` can be put on hold: the asset and the
+	/// account must not be frozen and the balance must cover everything locked afterwards plus
+	/// the minimum balance.
+	pub(super) fn can_hold(id: AssetId, who: &T::AccountId, amount: T::Balance) -> bool {
+		let details = match Asset::<T, I>::get(id) {
+			Some(details) if details.status == AssetStatus::Live => details,
+			_ => return false,
+		};
+		let account = match Account::<T, I>::get(id, who) {
+			Some(account) if !account.is_frozen => account,
+			_ => return false,
+		};
+		let held = match Holds::<T, I>::get(id, who).checked_add(&amount) {
+			Some(held) => held,
+			None => return false,
+		};
+		T::Freezer::frozen_balance(id, who)
+			.unwrap_or_else(Zero::zero)
+			.saturating_add(account.frozen)
+			.saturating_add(Self::held_or_frozen(id, who, held))
+			.checked_add(&details.min_balance)
+			.map_or(false, |required| required <= account.balance)
+	}
+
+	/// Put `amount` of asset `id` of `who` on hold.
+	///
+	/// Emits `Held`.
+	pub(super) fn do_hold(id: AssetId, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		ensure!(Self::can_hold(id, who, amount), Error::<T, I>::BalanceLow);
+		Self::note_hold(id, who, amount);
+		Ok(())
+	}
+
+	/// Add `amount` to the amount of asset `id` held in the account of `who`, which must cover
+	/// it.
+	///
+	/// Emits `Held`.
+	pub(super) fn note_hold(id: AssetId, who: &T::AccountId, amount: T::Balance) {
+		if amount.is_zero() {
+			return
+		}
+		Holds::<T, I>::mutate(id, who, |held| held.saturating_accrue(amount));
+		Self::deposit_event(Event::Held { asset_id: id, who: who.clone(), amount });
+	}
+
+	/// Release `amount` of asset `id` held in the account of `who`, or as much as is held if
+	/// `best_effort` is set. Returns the released amount.
+	///
+	/// Emits `Released`.
+	pub(super) fn do_release(
+		id: AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		best_effort: bool,
+	) -> Result<T::Balance, DispatchError> {
+		let held = Holds::<T, I>::get(id, who);
+		let amount = if best_effort { amount.min(held) } else { amount };
+		let rest = held.checked_sub(&amount).ok_or(Error::<T, I>::NotEnoughHeld)?;
+		if amount.is_zero() {
+			return Ok(amount)
+		}
+		if rest.is_zero() {
+			Holds::<T, I>::remove(id, who);
+		} else {
+			Holds::<T, I>::insert(id, who, rest);
+		}
+		Self::deposit_event(Event::Released { asset_id: id, who: who.clone(), amount });
+		Ok(amount)
+	}
+
+	/// Move `amount` of asset `id` held in the account of `source` to `dest`, putting it on hold
+	/// there if `on_hold` is set. With `best_effort`, at most the held amount is moved. Returns
+	/// the moved amount.
+	///
+	/// Emits `Released`, `Transferred` and `Held` if `on_hold`.
+	pub(super) fn do_transfer_held(
+		id: AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		best_effort: bool,
+		on_hold: bool,
+	) -> Result<T::Balance, DispatchError> {
+		frame_support::storage::with_storage_layer(|| {
+			let amount = Self::do_release(id, source, amount, best_effort)?;
+			if amount.is_zero() {
+				return Ok(amount)
+			}
+			// The held amount is above the minimum balance, so `source` stays alive.
+			let f = TransferFlags { keep_alive: true, best_effort: false, burn_dust: false };
+			let moved = Self::do_transfer(id, source, dest, amount, None, f)?;
+			if on_hold {
+				Self::note_hold(id, dest, moved);
+			}
+			Ok(moved)
+		})
+	}
+
+	/// Set the freeze `freeze_id` of asset `id` on the asset-account of `who` to `amount`,
+	/// removing it if `amount` is zero. Freezes may exceed the balance.
+	pub(super) fn do_set_freeze(
+		id: AssetId,
+		freeze_id: &T::FreezeIdentifier,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		ensure!(Account::<T, I>::contains_key(id, who), Error::<T, I>::NoAccount);
+		let mut freezes = Freezes::<T, I>::get(id, who);
+		if let Some(i) = freezes.iter().position(|f| &f.id == freeze_id) {
+			if amount.is_zero() {
+				freezes.remove(i);
+			} else {
+				freezes[i].amount = amount;
+			}
+		} else if !amount.is_zero() {
+			freezes
+				.try_push(IdAmount { id: *freeze_id, amount })
+				.map_err(|_| Error::<T, I>::TooManyFreezes)?;
+		}
+		if freezes.is_empty() {
+			Freezes::<T, I>::remove(id, who);
+		} else {
+			Freezes::<T, I>::insert(id, who, freezes);
+		}
+		Ok(())
+	}
+
+	/// Whether `who` is in the transfer whitelist of asset `id`.
+	pub fn is_whitelisted(id: AssetId, who: &T::AccountId) -> bool {
+		TransferWhitelist::<T, I>::contains_key(id, who)
+	}
+
+	/// Whether transfers of asset `id` are locked, i.e. it is a compliance-market asset and the
+	/// current block is within the `ComplianceWindow`.
+	pub fn is_compliance_locked(id: AssetId) -> bool {
+		ComplianceMarketAssets::<T, I>::contains_key(id) &&
+			ComplianceWindow::<T, I>::get().map_or(false, |(start, end)| {
+				let now = frame_system::Pallet::<T>::block_number();
+				start <= now && now <= end
+			})
+	}
+
+	/// Add `who` to the transfer whitelist of asset `id`, or remove it if `add` is `false`.
+	/// `origin` must be the admin of the asset.
+	///
+	/// Emits `AddedToWhitelist` or `RemovedFromWhitelist`.
+	pub(super) fn do_set_whitelisted(
+		id: AssetId,
+		origin: &T::AccountId,
+		who: T::AccountId,
+		add: bool,
+	) -> DispatchResult {
+		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(origin == &d.admin, Error::<T, I>::NoPermission);
+		if add {
+			TransferWhitelist::<T, I>::insert(id, &who, ());
+			Self::deposit_event(Event::AddedToWhitelist { asset_id: id, who });
+		} else {
+			TransferWhitelist::<T, I>::remove(id, &who);
+			Self::deposit_event(Event::RemovedFromWhitelist { asset_id: id, who });
+		}
+		Ok(())
+	}
+
+	/// Enforce the transfer whitelist of asset `id`, or lift it if `enabled` is `false`.
+	/// `origin` must be the admin of the asset.
+	///
+	/// Emits `WhitelistEnforcementSet`.
+	pub(super) fn do_set_whitelist_enforcement(
+		id: AssetId,
+		origin: &T::AccountId,
+		enabled: bool,
+	) -> DispatchResult {
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(origin == &d.admin, Error::<T, I>::NoPermission);
+			d.whitelist_enabled = enabled;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::WhitelistEnforcementSet { asset_id: id, enabled });
+		Ok(())
+	}
+
+	/// Freeze `amount` more of asset `id` in the asset-account of `who`, or thaw `amount` of the
+	/// frozen amount if `freeze` is `false`. The frozen amount is at most the balance of `who`.
+	///
+	/// Emits `AmountFrozen` or `AmountThawed` with the new frozen amount.
+	pub(super) fn do_freeze_amount(
+		id: AssetId,
+		origin: &T::AccountId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		freeze: bool,
+	) -> DispatchResult {
+		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(origin == &d.freezer, Error::<T, I>::NoPermission);
+
+		let frozen = Account::<T, I>::try_mutate(id, who, |maybe_account| {
+			let account = maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?;
+			account.frozen = if freeze {
+				let frozen =
+					account.frozen.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+				ensure!(frozen <= account.balance, Error::<T, I>::BalanceLow);
+				frozen
+			} else {
+				account.frozen.checked_sub(&amount).ok_or(Error::<T, I>::NotEnoughFrozen)?
+			};
+			Ok::<_, DispatchError>(account.frozen)
+		})?;
+
+		if freeze {
+			Self::note_custodian_action(CustodianAction::Freeze, id, Some(who), amount);
+			Self::deposit_event(Event::AmountFrozen {
+				asset_id: id,
+				who: who.clone(),
+				amount,
+				frozen,
+			});
+		} else {
+			Self::note_custodian_action(CustodianAction::Thaw, id, Some(who), amount);
+			Self::deposit_event(Event::AmountThawed {
+				asset_id: id,
+				who: who.clone(),
+				amount,
+				frozen,
+			});
+		}
+		Ok(())
+	}
+
+	/// Same as `reducible_balance` but works on already fetched asset `details` and asset-account
+	/// of `who`.
+	pub(super) fn reducible_balance_with(
+		id: AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		maybe_account: Option<&AssetAccountOf<T, I>>,
+		who: &T::AccountId,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		Self::ensure_live(details)?;
 
-		let account = Account::<T, I>::get(id, who).ok_or(Error::<T, I>::NoAccount)?;
+		let account = maybe_account.ok_or(Error::<T, I>::NoAccount)?;
 		ensure!(!account.is_frozen, Error::<T, I>::Frozen);
 
-		let amount = if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
+		let amount = if let Some(frozen) = Self::frozen_balance(id, who, account) {
 			// Frozen balance: account CANNOT be deleted
 			let required =
 				frozen.checked_add(&details.min_balance).ok_or(ArithmeticError::Overflow)?;
@@ -235,6 +809,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Make preparatory checks for debiting some funds from an account. Flags indicate requirements
 	/// of the debit.
 	///
+	/// - `details`: The details of the asset `id`.
+	/// - `maybe_account`: The asset-account of `target`, if it exists.
 	/// - `amount`: The amount desired to be debited. The actual amount returned for debit may be
 	///   less (in the case of `best_effort` being `true`) or greater by up to the minimum balance
 	///   less one.
@@ -249,14 +825,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// If no valid debit can be made then return an `Err`.
 	pub(super) fn prep_debit(
 		id: AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		maybe_account: Option<&AssetAccountOf<T, I>>,
 		target: &T::AccountId,
 		amount: T::Balance,
 		f: DebitFlags,
 	) -> Result<T::Balance, DispatchError> {
-		let actual = Self::reducible_balance(id, target, f.keep_alive)?.min(amount);
+		let actual =
+			Self::reducible_balance_with(id, details, maybe_account, target, f.keep_alive)?
+				.min(amount);
 		ensure!(f.best_effort || actual >= amount, Error::<T, I>::BalanceLow);
 
-		let conseq = Self::can_decrease(id, target, actual, f.keep_alive);
+		let conseq =
+			Self::can_decrease_with(id, details, maybe_account, target, actual, f.keep_alive);
 		let actual = match conseq.into_result() {
 			Ok(dust) => actual.saturating_add(dust), //< guaranteed by reducible_balance
 			Err(e) => {
@@ -271,6 +852,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Make preparatory checks for crediting some funds from an account. Flags indicate
 	/// requirements of the credit.
 	///
+	/// - `details`: The details of the asset.
+	/// - `maybe_balance`: The current balance of `dest`, if its asset-account exists.
 	/// - `amount`: The amount desired to be credited.
 	/// - `debit`: The amount by which some other account has been debited. If this is greater than
 	///   `amount`, then the `burn_dust` parameter takes effect.
@@ -284,7 +867,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///
 	/// If no valid credit can be made then return an `Err`.
 	pub(super) fn prep_credit(
-		id: AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		maybe_balance: Option<T::Balance>,
 		dest: &T::AccountId,
 		amount: T::Balance,
 		debit: T::Balance,
@@ -294,7 +878,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			(true, Some(dust)) => (amount, Some(dust)),
 			_ => (debit, None),
 		};
-		Self::can_increase(id, dest, credit, false).into_result()?;
+		Self::can_increase_with(details, maybe_balance, dest, credit, false).into_result()?;
 		Ok((credit, maybe_burn))
 	}
 
@@ -303,8 +887,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(!Account::<T, I>::contains_key(id, &who), Error::<T, I>::AlreadyExists);
 		let deposit = T::AssetAccountDeposit::get();
 		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 		let reason = Self::new_account(&who, &mut details, Some(deposit))?;
-		T::Currency::reserve(&who, deposit)?;
+		T::Deposits::hold(&who, deposit)?;
+		Self::note_deposit(Some(id), DepositPurpose::Account, Zero::zero(), deposit);
 		Asset::<T, I>::insert(&id, details);
 		Account::<T, I>::insert(
 			id,
@@ -312,10 +898,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			AssetAccountOf::<T, I> {
 				balance: Zero::zero(),
 				is_frozen: false,
+				frozen: Zero::zero(),
 				reason,
 				extra: T::Extra::default(),
 			},
 		);
+		AccountAssets::<T, I>::insert(&who, id, ());
 		Ok(())
 	}
 
@@ -326,19 +914,125 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 
 		ensure!(account.balance.is_zero() || allow_burn, Error::<T, I>::WouldBurn);
-		ensure!(!details.is_frozen, Error::<T, I>::Frozen);
+		ensure!(details.status != AssetStatus::Frozen, Error::<T, I>::Frozen);
 		ensure!(!account.is_frozen, Error::<T, I>::Frozen);
 
-		T::Currency::unreserve(&who, deposit);
+		T::Deposits::release(&who, deposit);
+		Self::note_deposit(Some(id), DepositPurpose::Account, deposit, Zero::zero());
 
 		if let Remove = Self::dead_account(&who, &mut details, &account.reason, false) {
 			Account::<T, I>::remove(id, &who);
 		} else {
 			debug_assert!(false, "refund did not result in dead account?!");
 		}
+		details.supply = details.supply.saturating_sub(account.balance);
 		Asset::<T, I>::insert(&id, details);
+		Self::note_touched(id);
+		Self::note_ledger_entry(LedgerOp::Burn, id, Some(&who), None, account.balance);
+		Self::handle_dust(id, account.balance);
 		// Executing a hook here is safe, since it is not in a `mutate`.
-		T::Freezer::died(id, &who);
+		Self::account_died(id, &who);
+		Ok(())
+	}
+
+	/// Send `SponsorAmount` of the custodian `sponsor` to the account `new_owner` which does not
+	/// exist yet through `Deposits`, and create its asset-account of `touch`, if any, sending the
+	/// `AssetAccountDeposit` along. The custodian of the asset `touch` may sponsor as well.
+	///
+	/// Emits `AccountSponsored`.
+	pub(super) fn do_sponsor_account(
+		sponsor: &T::AccountId,
+		new_owner: T::AccountId,
+		touch: Option<AssetId>,
+	) -> DispatchResult {
+		match touch {
+			Some(id) => Self::ensure_custodian_of(id, sponsor)?,
+			None => Self::ensure_custodian(sponsor)?,
+		}
+		ensure!(
+			!frame_system::Pallet::<T>::account_exists(&new_owner) &&
+				!SponsoredAccounts::<T, I>::contains_key(&new_owner),
+			Error::<T, I>::NotNewAccount
+		);
+
+		let mut amount = T::SponsorAmount::get();
+		if touch.is_some() {
+			amount = amount.saturating_add(T::AssetAccountDeposit::get());
+		}
+		T::Deposits::hold(sponsor, amount)?;
+		T::Deposits::transfer_held(sponsor, &new_owner, amount)?;
+		T::Deposits::release(&new_owner, amount);
+		if let Some(id) = touch {
+			Self::do_touch(id, new_owner.clone())?;
+		}
+		SponsoredAccounts::<T, I>::insert(
+			&new_owner,
+			Sponsorship {
+				sponsor: sponsor.clone(),
+				amount,
+				asset_id: touch,
+				since: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+
+		Self::deposit_event(Event::AccountSponsored {
+			who: new_owner,
+			sponsor: sponsor.clone(),
+			amount,
+			asset_id: touch,
+		});
+		Ok(())
+	}
+
+	/// Settle the sponsorship of `who` by its sponsor or the custodian `origin` after the
+	/// `SponsorshipClawbackPeriod`. Unless `who` has sent a transaction, the deposit of its empty
+	/// pre-touched asset-account is released and the recorded sponsored amount is returned to
+	/// the sponsor through `Deposits`, or nothing if `who` cannot pay all of it. If the
+	/// asset-account cannot be refunded, e.g. because it holds assets, its deposit stays held
+	/// and at most `SponsorAmount` is returned. Funds `who` received from others are never
+	/// taken.
+	///
+	/// Emits `SponsorshipClosed`.
+	pub(super) fn do_clawback_sponsorship(
+		origin: &T::AccountId,
+		who: T::AccountId,
+	) -> DispatchResult {
+		let sponsorship = SponsoredAccounts::<T, I>::get(&who).ok_or(Error::<T, I>::NotSponsored)?;
+		if origin != &sponsorship.sponsor {
+			Self::ensure_custodian(origin)?;
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(
+			now >= sponsorship.since.saturating_add(T::SponsorshipClawbackPeriod::get()),
+			Error::<T, I>::SponsorshipNotExpired
+		);
+
+		let mut clawed_back = Zero::zero();
+		if frame_system::Pallet::<T>::account_nonce(&who).is_zero() {
+			let mut amount = sponsorship.amount;
+			if let Some(id) = sponsorship.asset_id {
+				// Only an empty asset-account with a deposit is refunded.
+				if Self::do_refund(id, who.clone(), false).is_err() {
+					amount = amount.min(T::SponsorAmount::get());
+				}
+			}
+			let returned = frame_support::storage::with_storage_layer(|| -> DispatchResult {
+				T::Deposits::hold(&who, amount)?;
+				T::Deposits::transfer_held(&who, &sponsorship.sponsor, amount)?;
+				T::Deposits::release(&sponsorship.sponsor, amount);
+				Ok(())
+			});
+			if returned.is_ok() {
+				clawed_back = amount;
+			}
+		}
+		SponsoredAccounts::<T, I>::remove(&who);
+
+		Self::deposit_event(Event::SponsorshipClosed {
+			who,
+			sponsor: sponsorship.sponsor,
+			clawed_back,
+		});
 		Ok(())
 	}
 
@@ -353,69 +1047,198 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		amount: T::Balance,
 		maybe_check_issuer: Option<T::AccountId>,
 	) -> DispatchResult {
-		Self::increase_balance(id, beneficiary, amount, |details| -> DispatchResult {
-			if let Some(check_issuer) = maybe_check_issuer {
-				ensure!(check_issuer == details.issuer, Error::<T, I>::NoPermission);
-			}
-			debug_assert!(
-				T::Balance::max_value() - details.supply >= amount,
-				"checked in prep; qed"
-			);
-			details.supply = details.supply.saturating_add(amount);
-			Ok(())
+		Self::increase_balance(id, beneficiary, amount, |details, account| -> DispatchResult {
+			Self::mint_check(id, beneficiary, details, account, amount, maybe_check_issuer, None)
 		})?;
-		Self::deposit_event(Event::Issued {
-			asset_id: id,
-			owner: beneficiary.clone(),
-			total_supply: amount,
-		});
+		Self::note_vintage(id, |totals| totals.issued.saturating_accrue(amount));
+		Self::deposit_event_indexed(
+			id,
+			Event::Issued { asset_id: id, owner: beneficiary.clone(), total_supply: amount },
+		);
 		Ok(())
 	}
 
-	/// Increases the asset `id` balance of `beneficiary` by `amount`.
+	/// Increases the asset `id` balance of the owner of the asset by `amount`.
 	///
-	/// LOW-LEVEL: Does not alter the supply of asset or emit an event. Use `do_mint` if you need
-	/// that. This is not intended to be used alone.
-	///
-	/// Will return an error or will increase the amount by exactly `amount`.
-	pub(super) fn increase_balance(
+	/// Same as `do_mint`, but the asset details are read only once to find out the owner.
+	pub(super) fn do_mint_to_owner(
 		id: AssetId,
-		beneficiary: &T::AccountId,
 		amount: T::Balance,
-		check: impl FnOnce(
-			&mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
-		) -> DispatchResult,
+		maybe_check_issuer: Option<T::AccountId>,
+		serials: Option<(u64, u64)>,
 	) -> DispatchResult {
-		if amount.is_zero() {
-			return Ok(())
+		let owner = Self::mint_into_owner(id, amount, maybe_check_issuer, serials)?;
+		Self::deposit_event_indexed(id, Event::Issued { asset_id: id, owner, total_supply: amount });
+		Ok(())
+	}
+
+	/// Mint `amount` of asset `id` to the owner of the asset, checking the issuer if
+	/// `maybe_check_issuer` is given and recording the `serials` of the minted credits.
+	///
+	/// LOW-LEVEL: Does not emit an event. Returns the owner of the asset.
+	fn mint_into_owner(
+		id: AssetId,
+		amount: T::Balance,
+		maybe_check_issuer: Option<T::AccountId>,
+		serials: Option<(u64, u64)>,
+	) -> Result<T::AccountId, DispatchError> {
+		let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		let owner = details.owner.clone();
+		let by_custodian = maybe_check_issuer.is_some();
+		ensure!(serials.is_none() || !amount.is_zero(), Error::<T, I>::BadSerialRange);
+		if !amount.is_zero() {
+			Self::increase_balance_with(id, &mut details, &owner, amount, |details, account| {
+				Self::mint_check(id, &owner, details, account, amount, maybe_check_issuer, serials)
+			})?;
+			Asset::<T, I>::insert(id, details);
+			Self::note_vintage(id, |totals| totals.issued.saturating_accrue(amount));
+			Self::note_outbox(OutboxKind::Minted, id, &owner, amount);
+			if by_custodian {
+				Self::note_custodian_action(CustodianAction::Mint, id, Some(&owner), amount);
+			}
 		}
+		Ok(owner)
+	}
 
-		Self::can_increase(id, beneficiary, amount, true).into_result()?;
-		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
-			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+	/// Mint every amount of the `batch` to the owner of its asset, checking that `issuer` is
+	/// the custodian of every asset.
+	///
+	/// Emits a single `BatchIssued`.
+	pub(super) fn do_mint_batch(
+		issuer: T::AccountId,
+		batch: BoundedVec<(AssetId, T::Balance), T::MaxMintBatch>,
+	) -> DispatchResult {
+		ensure!(!batch.is_empty(), Error::<T, I>::BadBatch);
+		let mut issued = Vec::with_capacity(batch.len());
+		for (id, amount) in batch {
+			let owner = Self::mint_into_owner(id, amount, Some(issuer.clone()), None)?;
+			issued.push((id, owner, amount));
+		}
+		Self::deposit_event(Event::BatchIssued { issued });
+		Ok(())
+	}
+
+	/// Check the issuer, screen the `beneficiary`, record the `serials` and increase the supply
+	/// of the asset by `amount`.
+	///
+	/// Unless `AllowMintWhenFrozen` is set, fails if the asset or the asset-account of
+	/// `beneficiary` is frozen. The `account` is the asset-account already read by
+	/// `increase_balance_with`, so it is not read again.
+	fn mint_check(
+		id: AssetId,
+		beneficiary: &T::AccountId,
+		details: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		account: Option<&AssetAccountOf<T, I>>,
+		amount: T::Balance,
+		maybe_check_issuer: Option<T::AccountId>,
+		serials: Option<(u64, u64)>,
+	) -> DispatchResult {
+		if let Some(check_issuer) = maybe_check_issuer {
+			Self::ensure_asset_custodian(id, details, &check_issuer)?;
+		}
+		T::Screening::screen(id, None, beneficiary).map_err(Error::<T, I>::ScreeningRejected)?;
+		if T::ProjectApprovalRequired::get() {
+			ensure!(
+				ProjectStatusOf::<T, I>::get(id) == ProjectStatus::CustodianApproved,
+				Error::<T, I>::ProjectNotApproved
+			);
+		}
+		if T::MethodologyRequired::get() {
+			let methodology = AssetMethodology::<T, I>::get(id).ok_or(Error::<T, I>::NoMethodology)?;
+			ensure!(
+				Methodologies::<T, I>::contains_key(methodology),
+				Error::<T, I>::UnknownMethodology
+			);
+		}
+		if !T::AllowMintWhenFrozen::get() {
+			ensure!(details.status != AssetStatus::Frozen, Error::<T, I>::AssetFrozen);
+			ensure!(!account.map_or(false, |a| a.is_frozen), Error::<T, I>::AccountFrozen);
+		}
+		Self::note_serial_range(id, details.supply, amount, serials)?;
+		debug_assert!(
+			T::Balance::max_value() - details.supply >= amount,
+			"checked in prep; qed"
+		);
+		details.supply = details.supply.saturating_add(amount);
+		Ok(())
+	}
+
+	/// Increases the asset `id` balance of `beneficiary` by `amount`.
+	///
+	/// LOW-LEVEL: Does not alter the supply of asset or emit an event. Use `do_mint` if you need
+	/// that. This is not intended to be used alone. `check` is given the asset details and the
+	/// asset-account of `beneficiary`, if it exists.
+	///
+	/// Will return an error or will increase the amount by exactly `amount`.
+	pub(super) fn increase_balance(
+		id: AssetId,
+		beneficiary: &T::AccountId,
+		amount: T::Balance,
+		check: impl FnOnce(
+			&mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+			Option<&AssetAccountOf<T, I>>,
+		) -> DispatchResult,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
 
-			check(details)?;
+		let mut details = Asset::<T, I>::get(id).ok_or(TokenError::UnknownAsset)?;
+		Self::increase_balance_with(id, &mut details, beneficiary, amount, check)?;
+		Asset::<T, I>::insert(id, details);
+		Ok(())
+	}
 
-			Account::<T, I>::try_mutate(id, beneficiary, |maybe_account| -> DispatchResult {
-				match maybe_account {
-					Some(ref mut account) => {
-						account.balance.saturating_accrue(amount);
-					},
-					maybe_account @ None => {
-						// Note this should never fail as it's already checked by `can_increase`.
-						ensure!(amount >= details.min_balance, TokenError::BelowMinimum);
-						*maybe_account = Some(AssetAccountOf::<T, I> {
-							balance: amount,
-							reason: Self::new_account(beneficiary, details, None)?,
-							is_frozen: false,
-							extra: T::Extra::default(),
-						});
-					},
-				}
-				Ok(())
-			})?;
-			Ok(())
-		})?;
+	/// Same as `increase_balance` but works on already fetched asset `details`.
+	///
+	/// Writes the asset-account of `beneficiary`, but not the asset `details`: the caller is
+	/// responsible for storing them.
+	pub(super) fn increase_balance_with(
+		id: AssetId,
+		details: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		beneficiary: &T::AccountId,
+		amount: T::Balance,
+		check: impl FnOnce(
+			&mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+			Option<&AssetAccountOf<T, I>>,
+		) -> DispatchResult,
+	) -> DispatchResult {
+		ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+		Self::ensure_not_paused(PAUSE_MINT)?;
+		let maybe_account = Account::<T, I>::get(id, beneficiary);
+		Self::can_increase_with(
+			details,
+			maybe_account.as_ref().map(|a| a.balance),
+			beneficiary,
+			amount,
+			true,
+		)
+		.into_result()?;
+
+		check(details, maybe_account.as_ref())?;
+
+		let account = match maybe_account {
+			Some(mut account) => {
+				account.balance.saturating_accrue(amount);
+				account
+			},
+			None => {
+				// Note this should never fail as it's already checked by `can_increase_with`.
+				ensure!(amount >= details.min_balance, TokenError::BelowMinimum);
+				let account = AssetAccountOf::<T, I> {
+					balance: amount,
+					reason: Self::new_account(beneficiary, details, None)?,
+					is_frozen: false,
+					frozen: Zero::zero(),
+					extra: T::Extra::default(),
+				};
+				AccountAssets::<T, I>::insert(beneficiary, id, ());
+				account
+			},
+		};
+		Account::<T, I>::insert(id, beneficiary, account);
+		Self::note_touched(id);
+		Self::note_ledger_entry(LedgerOp::Mint, id, None, Some(beneficiary), amount);
 		Ok(())
 	}
 
@@ -434,16 +1257,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		f: DebitFlags,
 	) -> Result<T::Balance, DispatchError> {
 		let actual = Self::decrease_balance(id, target, amount, f, |actual, details| {
-			// Check admin rights.
+			// Check custodian rights.
 			if let Some(check_admin) = maybe_check_admin {
-				ensure!(check_admin == details.admin, Error::<T, I>::NoPermission);
+				Self::ensure_asset_custodian(id, details, &check_admin)?;
 			}
 
 			details.supply = details.supply.saturating_sub(actual);
 
 			Ok(())
 		})?;
-		Self::deposit_event(Event::Burned { asset_id: id, owner: target.clone(), balance: actual });
+		Self::note_reserve_debit(id, target, actual);
+		Self::deposit_event_indexed(
+			id,
+			Event::Burned { asset_id: id, owner: target.clone(), balance: actual },
+		);
 		Ok(actual)
 	}
 
@@ -469,37 +1296,35 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Ok(amount)
 		}
 
-		let actual = Self::prep_debit(id, target, amount, f)?;
-		let mut target_died: Option<DeadConsequence> = None;
-
-		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
-			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
-
-			check(actual, details)?;
+		let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+		Self::ensure_not_paused(PAUSE_BURN)?;
+		let maybe_account = Account::<T, I>::get(id, target);
+		let actual = Self::prep_debit(id, &details, maybe_account.as_ref(), target, amount, f)?;
+		let mut account = maybe_account.ok_or(Error::<T, I>::NoAccount)?;
 
-			Account::<T, I>::try_mutate(id, target, |maybe_account| -> DispatchResult {
-				let mut account = maybe_account.take().ok_or(Error::<T, I>::NoAccount)?;
-				debug_assert!(account.balance >= actual, "checked in prep; qed");
-
-				// Make the debit.
-				account.balance = account.balance.saturating_sub(actual);
-				if account.balance < details.min_balance {
-					debug_assert!(account.balance.is_zero(), "checked in prep; qed");
-					target_died = Some(Self::dead_account(target, details, &account.reason, false));
-					if let Some(Remove) = target_died {
-						return Ok(())
-					}
-				};
-				*maybe_account = Some(account);
-				Ok(())
-			})?;
+		check(actual, &mut details)?;
 
-			Ok(())
-		})?;
+		// Make the debit.
+		debug_assert!(account.balance >= actual, "checked in prep; qed");
+		account.balance = account.balance.saturating_sub(actual);
+		let mut target_died: Option<DeadConsequence> = None;
+		if account.balance < details.min_balance {
+			debug_assert!(account.balance.is_zero(), "checked in prep; qed");
+			target_died = Some(Self::dead_account(target, &mut details, &account.reason, false));
+		}
+		if let Some(Remove) = target_died {
+			Account::<T, I>::remove(id, target);
+		} else {
+			Account::<T, I>::insert(id, target, account);
+		}
+		Asset::<T, I>::insert(id, details);
+		Self::note_touched(id);
+		Self::note_ledger_entry(LedgerOp::Burn, id, Some(target), None, actual);
 
-		// Execute hook outside of `mutate`.
+		// Execute hook after the storage is updated.
 		if let Some(Remove) = target_died {
-			T::Freezer::died(id, target);
+			Self::account_died(id, target);
 		}
 		Ok(actual)
 	}
@@ -523,13 +1348,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let (balance, died) =
 			Self::transfer_and_die(id, source, dest, amount, maybe_need_admin, f)?;
 		if let Some(Remove) = died {
-			T::Freezer::died(id, source);
+			Self::account_died(id, source);
 		}
 		Ok(balance)
 	}
 
 	/// Same as `do_transfer` but it does not execute the `FrozenBalance::died` hook and
 	/// instead returns whether and how the `source` account died in this operation.
+	///
+	/// The asset details and both asset-accounts are read once and written once.
 	fn transfer_and_die(
 		id: AssetId,
 		source: &T::AccountId,
@@ -543,27 +1370,70 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Ok((amount, None))
 		}
 
-		// Figure out the debit and credit, together with side-effects.
-		let debit = Self::prep_debit(id, source, amount, f.into())?;
-		let (credit, maybe_burn) = Self::prep_credit(id, dest, amount, debit, f.burn_dust)?;
+		let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		let result =
+			Self::transfer_and_die_with(id, &mut details, source, dest, amount, maybe_need_admin, f)?;
+		if source != dest {
+			Asset::<T, I>::insert(id, details);
+		}
+		Ok(result)
+	}
 
-		let mut source_account =
-			Account::<T, I>::get(id, &source).ok_or(Error::<T, I>::NoAccount)?;
-		let mut source_died: Option<DeadConsequence> = None;
+	/// Same as `transfer_and_die`, but the asset details are passed by the caller, which writes
+	/// them back. Lets the callers doing more than one change of the details (e.g. several
+	/// payments or removal of the approval) read and write them once.
+	fn transfer_and_die_with(
+		id: AssetId,
+		details: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		maybe_need_admin: Option<T::AccountId>,
+		f: TransferFlags,
+	) -> Result<(T::Balance, Option<DeadConsequence>), DispatchError> {
+		// Early exit if no-op.
+		if amount.is_zero() {
+			return Ok((amount, None))
+		}
 
-		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
-			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+		Self::ensure_not_paused(PAUSE_TRANSFER)?;
+		// Forced transfers of the admin are neither screened nor restricted to the whitelist or
+		// by the compliance window, so it can move the assets out of rejected accounts.
+		if maybe_need_admin.is_none() {
+			T::Screening::screen(id, Some(source), dest).map_err(Error::<T, I>::ScreeningRejected)?;
+			ensure!(
+				!details.whitelist_enabled ||
+					(Self::is_whitelisted(id, source) && Self::is_whitelisted(id, dest)),
+				Error::<T, I>::NotWhitelisted
+			);
+			ensure!(!Self::is_compliance_locked(id), Error::<T, I>::ComplianceLocked);
+		}
+		let maybe_source_account = Account::<T, I>::get(id, source);
+		let maybe_dest_account =
+			if source == dest { None } else { Account::<T, I>::get(id, dest) };
 
-			// Check admin rights.
-			if let Some(need_admin) = maybe_need_admin {
-				ensure!(need_admin == details.admin, Error::<T, I>::NoPermission);
-			}
+		// Figure out the debit and credit, together with side-effects.
+		let debit =
+			Self::prep_debit(id, details, maybe_source_account.as_ref(), source, amount, f.into())?;
+		let dest_balance = if source == dest {
+			maybe_source_account.as_ref().map(|a| a.balance)
+		} else {
+			maybe_dest_account.as_ref().map(|a| a.balance)
+		};
+		let (credit, maybe_burn) =
+			Self::prep_credit(details, dest_balance, dest, amount, debit, f.burn_dust)?;
 
-			// Skip if source == dest
-			if source == dest {
-				return Ok(())
-			}
+		let mut source_account = maybe_source_account.ok_or(Error::<T, I>::NoAccount)?;
+		let mut source_died: Option<DeadConsequence> = None;
+
+		// Check admin rights.
+		if let Some(need_admin) = maybe_need_admin {
+			ensure!(need_admin == details.admin, Error::<T, I>::NoPermission);
+		}
 
+		// Skip if source == dest
+		if source != dest {
 			// Burn any dust if needed.
 			if let Some(burn) = maybe_burn {
 				// Debit dust from supply; this will not saturate since it's already checked in
@@ -576,185 +1446,1421 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			debug_assert!(source_account.balance >= debit, "checked in prep; qed");
 			source_account.balance = source_account.balance.saturating_sub(debit);
 
-			Account::<T, I>::try_mutate(id, &dest, |maybe_account| -> DispatchResult {
-				match maybe_account {
-					Some(ref mut account) => {
-						// Calculate new balance; this will not saturate since it's already checked
-						// in prep.
-						debug_assert!(
-							account.balance.checked_add(&credit).is_some(),
-							"checked in prep; qed"
-						);
-						account.balance.saturating_accrue(credit);
-					},
-					maybe_account @ None => {
-						*maybe_account = Some(AssetAccountOf::<T, I> {
-							balance: credit,
-							is_frozen: false,
-							reason: Self::new_account(dest, details, None)?,
-							extra: T::Extra::default(),
-						});
-					},
-				}
-				Ok(())
-			})?;
+			// Assets leaving the reserve accounts are no longer backed by the reserve.
+			if !T::ReserveAccounts::contains(dest) {
+				Self::note_reserve_debit(id, source, debit);
+			}
+
+			let dest_account = match maybe_dest_account {
+				Some(mut account) => {
+					// Calculate new balance; this will not saturate since it's already checked
+					// in prep.
+					debug_assert!(
+						account.balance.checked_add(&credit).is_some(),
+						"checked in prep; qed"
+					);
+					account.balance.saturating_accrue(credit);
+					account
+				},
+				None => {
+					let account = AssetAccountOf::<T, I> {
+						balance: credit,
+						is_frozen: false,
+						frozen: Zero::zero(),
+						reason: Self::new_account(dest, details, None)?,
+						extra: T::Extra::default(),
+					};
+					AccountAssets::<T, I>::insert(dest, id, ());
+					account
+				},
+			};
+			Account::<T, I>::insert(id, dest, dest_account);
 
 			// Remove source account if it's now dead.
 			if source_account.balance < details.min_balance {
 				debug_assert!(source_account.balance.is_zero(), "checked in prep; qed");
 				source_died =
 					Some(Self::dead_account(source, details, &source_account.reason, false));
-				if let Some(Remove) = source_died {
-					Account::<T, I>::remove(id, &source);
-					return Ok(())
-				}
 			}
-			Account::<T, I>::insert(id, &source, &source_account);
-			Ok(())
-		})?;
-
-		Self::deposit_event(Event::Transferred {
-			asset_id: id,
-			from: source.clone(),
-			to: dest.clone(),
-			amount: credit,
-		});
-		Ok((credit, source_died))
-	}
+			if let Some(Remove) = source_died {
+				Account::<T, I>::remove(id, source);
+			} else {
+				Account::<T, I>::insert(id, source, &source_account);
+			}
+			Self::note_touched(id);
 
-	/// Create a new asset without taking a deposit.
-	///
-	/// * `id`: The `AssetId` you want the new asset to have. Must not already be in use.
-	/// * `owner`: The owner, issuer, admin, and freezer of this asset upon creation.
-	/// * `is_sufficient`: Whether this asset needs users to have an existential deposit to hold
-	///   this asset.
-	/// * `min_balance`: The minimum balance a user is allowed to have of this asset before they are
-	///   considered dust and cleaned up.
-	pub(super) fn do_force_create(
-		id: AssetId,
-		owner: T::AccountId,
-		is_sufficient: bool,
-		min_balance: T::Balance,
-	) -> DispatchResult {
-		ensure!(!Asset::<T, I>::contains_key(id), Error::<T, I>::InUse);
-		ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
+			Self::note_ledger_entry(LedgerOp::Transfer, id, Some(source), Some(dest), credit);
+			Self::note_activity(source, |a| a.transfers_out.saturating_inc());
+			Self::note_activity(dest, |a| a.transfers_in.saturating_inc());
+			if let Some(burn) = maybe_burn {
+				Self::note_ledger_entry(LedgerOp::Dust, id, Some(source), None, burn);
+				Self::handle_dust(id, burn);
+			}
+		}
 
-		Asset::<T, I>::insert(
+		Self::deposit_event_indexed(
 			id,
-			AssetDetails {
-				owner: owner.clone(),
-				issuer: owner.clone(),
-				admin: owner.clone(),
-				freezer: owner.clone(),
-				supply: Zero::zero(),
-				deposit: Zero::zero(),
-				min_balance,
-				is_sufficient,
-				accounts: 0,
-				sufficients: 0,
-				approvals: 0,
-				is_frozen: false,
+			Event::Transferred {
+				asset_id: id,
+				from: source.clone(),
+				to: dest.clone(),
+				amount: credit,
 			},
 		);
-		Self::deposit_event(Event::ForceCreated { asset_id: id, owner });
-		Ok(())
+		Ok((credit, source_died))
 	}
 
-	/// Destroy an existing asset.
+	/// Create a new carbon asset owned by `owner`, taking the `AssetDeposit`.
 	///
-	/// * `id`: The asset you want to destroy.
-	/// * `witness`: Witness data needed about the current state of the asset, used to confirm
-	///   complexity of the operation.
-	/// * `maybe_check_owner`: An optional check before destroying the asset, if the provided
-	///   account is the owner of that asset. Can be used for authorization checks.
-	pub(super) fn do_destroy(
-		id: AssetId,
-		witness: DestroyWitness,
-		maybe_check_owner: Option<T::AccountId>,
-	) -> Result<DestroyWitness, DispatchError> {
-		let mut dead_accounts: Vec<T::AccountId> = vec![];
+	/// The custodian becomes the issuer, admin and freezer of the asset. Without a custodian
+	/// the owner takes these roles, unless `CustodianRequired` is set. The metadata is set
+	/// with the given `name`, `symbol` and `decimals`, at most `CO2E_DECIMALS`, so retirements
+	/// convert to tonnes of CO2e exactly. The asset is bound to the `vintage` year, if any.
+	///
+	/// The minimum balance is one smallest unit, i.e. one tonne for assets without decimals.
+	///
+	/// Returns the generated `AssetId`.
+	pub(super) fn do_create(
+		owner: &T::AccountId,
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+		vintage: Option<u16>,
+		decimals: u8,
+	) -> Result<AssetId, DispatchError> {
+		ensure!(decimals <= CO2E_DECIMALS, Error::<T, I>::BadDecimals);
+		let custodian = Custodian::<T, I>::get();
+		ensure!(
+			custodian.as_ref() == Some(owner) ||
+				OwnedAssetCount::<T, I>::get(owner) < T::MaxAssetsPerAccount::get(),
+			Error::<T, I>::TooManyOwnedAssets
+		);
+		let admin = match custodian {
+			Some(custodian) => custodian,
+			None if T::CustodianRequired::get() => return Err(Error::<T, I>::NoCustodian.into()),
+			None => owner.clone(),
+		};
 
-		let result_witness: DestroyWitness = Asset::<T, I>::try_mutate_exists(
-			id,
-			|maybe_details| -> Result<DestroyWitness, DispatchError> {
-				let mut details = maybe_details.take().ok_or(Error::<T, I>::Unknown)?;
-				if let Some(check_owner) = maybe_check_owner {
-					ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
-				}
-				ensure!(details.accounts <= witness.accounts, Error::<T, I>::BadWitness);
-				ensure!(details.sufficients <= witness.sufficients, Error::<T, I>::BadWitness);
-				ensure!(details.approvals <= witness.approvals, Error::<T, I>::BadWitness);
+		// Other pallets call this outside of a dispatch, so a rejected metadata must not leave
+		// an asset without metadata behind.
+		frame_support::storage::with_storage_layer(|| {
+			let id = Self::get_new_asset_id(owner)?;
 
-				for (who, v) in Account::<T, I>::drain_prefix(id) {
-					// We have to force this as it's destroying the entire asset class.
-					// This could mean that some accounts now have irreversibly reserved
-					// funds.
-					let _ = Self::dead_account(&who, &mut details, &v.reason, true);
-					dead_accounts.push(who);
-				}
-				debug_assert_eq!(details.accounts, 0);
-				debug_assert_eq!(details.sufficients, 0);
+			let deposit = T::AssetDeposit::get();
+			T::Deposits::hold(owner, deposit)?;
+			Self::note_deposit(Some(id), DepositPurpose::Asset, Zero::zero(), deposit);
 
-				let metadata = Metadata::<T, I>::take(&id);
-				T::Currency::unreserve(
-					&details.owner,
-					details.deposit.saturating_add(metadata.deposit),
-				);
+			Asset::<T, I>::insert(
+				id,
+				AssetDetails {
+					owner: owner.clone(),
+					issuer: admin.clone(),
+					admin: admin.clone(),
+					freezer: admin,
+					supply: Zero::zero(),
+					deposit,
+					min_balance: One::one(),
+					is_sufficient: false,
+					accounts: 0,
+					sufficients: 0,
+					approvals: 0,
+					status: AssetStatus::Live,
+					whitelist_enabled: false,
+				},
+			);
+			Self::note_owner(id, None, Some(owner));
+			Self::deposit_event_indexed(id, Event::Created { asset_id: id, creator: owner.clone() });
+			Self::note_outbox(OutboxKind::Created, id, owner, Zero::zero());
+			if let Some(vintage) = vintage {
+				AssetVintage::<T, I>::insert(id, vintage);
+				Self::deposit_event(Event::AssetVintageSet { asset_id: id, vintage });
+			}
 
-				for ((owner, _), approval) in Approvals::<T, I>::drain_prefix((&id,)) {
-					T::Currency::unreserve(&owner, approval.deposit);
-				}
-				Self::deposit_event(Event::Destroyed { asset_id: id });
+			Self::do_set_metadata(id, owner, name, symbol, decimals)?;
+			Ok(id)
+		})
+	}
 
-				Ok(DestroyWitness {
-					accounts: details.accounts,
-					sufficients: details.sufficients,
-					approvals: details.approvals,
-				})
-			},
-		)?;
+	/// Burn `amount` of asset `id` from `who` as a voluntary retirement executed by `initiator`
+	/// and record it in the `BurnCertificate` of `who` and in `RetirementAnchors`.
+	///
+	/// Returns the actual amount burned and the certificate id of the retirement.
+	pub(super) fn do_retire(
+		id: AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		note: Option<BoundedVec<u8, T::StringLimit>>,
+		initiator: &T::AccountId,
+	) -> Result<(T::Balance, u64), DispatchError> {
+		Self::do_retire_for(id, who, who, amount, note, initiator)
+	}
 
-		// Execute hooks outside of `mutate`.
-		for who in dead_accounts {
-			T::Freezer::died(id, &who);
+	/// Burn `amount` of asset `id` from `who` and store the retirement under `beneficiary`:
+	/// its `BurnCertificate`, retirement anchor and the outbox message. A zero `amount` is
+	/// rejected with `ZeroRetirement`.
+	///
+	/// Returns the actual amount burned and the certificate id of the retirement.
+	pub(super) fn do_retire_for(
+		id: AssetId,
+		who: &T::AccountId,
+		beneficiary: &T::AccountId,
+		amount: T::Balance,
+		note: Option<BoundedVec<u8, T::StringLimit>>,
+		initiator: &T::AccountId,
+	) -> Result<(T::Balance, u64), DispatchError> {
+		ensure!(!amount.is_zero(), Error::<T, I>::ZeroRetirement);
+		let f = DebitFlags { keep_alive: false, best_effort: false };
+		let actual = Self::decrease_balance(id, who, amount, f, |actual, details| {
+			details.supply = details.supply.saturating_sub(actual);
+
+			Ok(())
+		})?;
+		Self::note_reserve_debit(id, who, actual);
+		Self::deposit_event_indexed(id, Event::Burned { asset_id: id, owner: who.clone(), balance: actual });
+
+		let certificate_id =
+			Self::note_retirement_record(who, beneficiary, id, actual, RetirementReason::SelfRetirement);
+		Self::note_burn_certificate(beneficiary, id, actual, initiator, certificate_id);
+		let anchor = Self::note_retirement_anchor(beneficiary, id, actual, note);
+		let serials = Self::consume_serials(id, who, actual);
+		if !serials.is_empty() {
+			RetirementSerials::<T, I>::insert(anchor, serials);
 		}
-		Ok(result_witness)
+		Self::note_activity(beneficiary, |a| a.retirements.saturating_inc());
+		Self::note_outbox(OutboxKind::Retired, id, beneficiary, actual);
+		Ok((actual, certificate_id))
 	}
 
-	/// Creates an approval from `owner` to spend `amount` of asset `id` tokens by 'delegate'
-	/// while reserving `T::ApprovalDeposit` from owner
+	/// Retire `amount` of asset `id` from `who` like `do_retire` and store the `certificate`
+	/// under the certificate id of the retirement.
 	///
-	/// If an approval already exists, the new amount is added to such existing approval
-	pub(super) fn do_approve_transfer(
+	/// The beneficiary name is also the note of the retirement anchor.
+	pub(super) fn do_retire_with_certificate(
 		id: AssetId,
-		owner: &T::AccountId,
-		delegate: &T::AccountId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		certificate: RetirementCertificate<BoundedVec<u8, T::StringLimit>>,
+	) -> DispatchResult {
+		let note = Some(certificate.beneficiary_name.clone());
+		let (_, certificate_id) = Self::do_retire(id, who, amount, note, who)?;
+		RetirementCertificates::<T, I>::insert(certificate_id, certificate);
+		Ok(())
+	}
+
+	/// Take the next certificate id of a retirement or custodial burn from `NextCertificateId`.
+	fn next_certificate_id() -> u64 {
+		NextCertificateId::<T, I>::mutate(|next| {
+			let certificate_id = *next;
+			next.saturating_inc();
+			certificate_id
+		})
+	}
+
+	/// Retire `amount` of asset `id` from the balance of `owner` within the approval of
+	/// `owner` to `delegate`, storing the retirement under `beneficiary`.
+	///
+	/// The approval is used up like in `do_transfer_approved`.
+	pub(super) fn do_retire_from(
+		id: AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		beneficiary: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let mut approved =
+			Approvals::<T, I>::get((id, owner, delegate)).ok_or(Error::<T, I>::Unapproved)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(!approved.is_expired(&now), Error::<T, I>::ApprovalExpired);
+		let remaining = approved.amount.checked_sub(&amount).ok_or(Error::<T, I>::Unapproved)?;
+
+		Self::do_retire_for(id, owner, beneficiary, amount, None, delegate)?;
+
+		if remaining.is_zero() {
+			let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			Self::take_approval(id, owner, delegate, &mut details);
+			Asset::<T, I>::insert(id, details);
+		} else {
+			approved.amount = remaining;
+			Approvals::<T, I>::insert((id, owner, delegate), approved);
+		}
+		Ok(())
+	}
+
+	/// Store the retirement of `amount` of asset `id` by `who` under its content hash: the
+	/// `Hashing` of the SCALE-encoded `(who, id, amount, block_number, note, index)`, where
+	/// `index` is the number of the previous anchored retirements of `who`.
+	///
+	/// Emits `RetirementAnchored` and returns the anchor.
+	pub(super) fn note_retirement_anchor(
+		who: &T::AccountId,
+		id: AssetId,
+		amount: T::Balance,
+		note: Option<BoundedVec<u8, T::StringLimit>>,
+	) -> T::Hash {
+		let index = RetirementCount::<T, I>::mutate(who, |count| {
+			let index = *count;
+			count.saturating_inc();
+			index
+		});
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let anchor = <T::Hashing as sp_runtime::traits::Hash>::hash_of(&(
+			who,
+			id,
+			amount,
+			block_number,
+			&note,
+			index,
+		));
+		RetirementAnchors::<T, I>::insert(
+			anchor,
+			RetirementAnchor { account: who.clone(), asset_id: id, amount, block_number, note, index },
+		);
+		Self::deposit_event(Event::RetirementAnchored {
+			account: who.clone(),
+			asset_id: id,
+			amount,
+			anchor,
+		});
+		anchor
+	}
+
+	/// Burn `amount` of asset `id` from `who` to move it to the `dest_registry` and record the
+	/// export attestation with the consumed serials. Nothing is stored as a retirement.
+	///
+	/// Emits `Burned` and `CreditsExported`.
+	pub(super) fn do_export_retirement(
+		id: AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		dest_registry: BoundedVec<u8, T::StringLimit>,
+		dest_ref: BoundedVec<u8, T::StringLimit>,
+	) -> DispatchResult {
+		let f = DebitFlags { keep_alive: false, best_effort: false };
+		let actual = Self::do_burn(id, who, amount, None, f)?;
+		let serials = Self::consume_serials(id, who, actual);
+		TotalExported::<T, I>::mutate(id, |total| total.saturating_accrue(actual));
+
+		let export_id = NextExportId::<T, I>::mutate(|next| {
+			let export_id = *next;
+			next.saturating_inc();
+			export_id
+		});
+		ExportAttestations::<T, I>::insert(
+			export_id,
+			ExportAttestation {
+				account: who.clone(),
+				asset_id: id,
+				amount: actual,
+				dest_registry,
+				dest_ref,
+				serials,
+				block_number: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+		Self::note_outbox(OutboxKind::Exported, id, who, actual);
+		Self::deposit_event_indexed(
+			id,
+			Event::CreditsExported { export_id, asset_id: id, who: who.clone(), amount: actual },
+		);
+		Ok(())
+	}
+
+	/// Get the retirement stored under the `anchor` hash.
+	pub fn retirement_anchor(
+		anchor: T::Hash,
+	) -> Option<
+		RetirementAnchor<T::AccountId, T::Balance, T::BlockNumber, BoundedVec<u8, T::StringLimit>>,
+	> {
+		RetirementAnchors::<T, I>::get(anchor)
+	}
+
+	/// One whole unit of asset `id` in its smallest units: `10^decimals` of the asset metadata.
+	pub(super) fn whole_unit(id: AssetId) -> Result<T::Balance, DispatchError> {
+		let ten = T::Balance::from(10u32);
+		let mut unit = T::Balance::one();
+		for _ in 0..Metadata::<T, I>::get(id).decimals {
+			unit = unit.checked_mul(&ten).ok_or(ArithmeticError::Overflow)?;
+		}
+		Ok(unit)
+	}
+
+	/// Split `amount` of asset `id` into whole units and the rest at the decimals of the asset,
+	/// so frontends display the amount without floating point rounding. Returns `None` if the
+	/// asset does not exist.
+	pub fn display_amount(id: AssetId, amount: T::Balance) -> Option<AmountDisplay<T::Balance>> {
+		if !Asset::<T, I>::contains_key(id) {
+			return None
+		}
+		let unit = Self::whole_unit(id).ok()?;
+		Some(AmountDisplay {
+			whole: amount / unit,
+			fraction: amount % unit,
+			decimals: Metadata::<T, I>::get(id).decimals,
+		})
+	}
+
+	/// Convert `amount` of asset `id` to tonnes of CO2e with `CO2E_DECIMALS` decimals, using
+	/// the decimals of the asset metadata. Precision beyond `CO2E_DECIMALS` is truncated.
+	pub(super) fn normalize_co2e(
+		id: AssetId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		let decimals = Metadata::<T, I>::get(id).decimals;
+		let ten = T::Balance::from(10u32);
+		let mut normalized = amount;
+		for _ in decimals..CO2E_DECIMALS {
+			normalized = normalized.checked_mul(&ten).ok_or(ArithmeticError::Overflow)?;
+		}
+		for _ in CO2E_DECIMALS..decimals {
+			normalized /= ten;
+		}
+		Ok(normalized)
+	}
+
+	/// Retire every asset of the `basket` from `who` and store a consolidated record of the
+	/// retirement in tonnes of CO2e in `Co2eRetirements`. The record holds the amounts actually
+	/// burned, including the dust burned with them.
+	///
+	/// Emits `Co2eRetired`.
+	pub(super) fn do_retire_co2e(
+		who: &T::AccountId,
+		mut basket: BoundedVec<(AssetId, T::Balance), T::MaxRetirementBasket>,
+	) -> DispatchResult {
+		ensure!(!basket.is_empty(), Error::<T, I>::BadBasket);
+
+		let mut co2e = T::Balance::zero();
+		for (id, amount) in basket.iter_mut() {
+			let (actual, _) = Self::do_retire(*id, who, *amount, None, who)?;
+			*amount = actual;
+			co2e = co2e
+				.checked_add(&Self::normalize_co2e(*id, actual)?)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		let index = Co2eRetirementCount::<T, I>::mutate(who, |count| {
+			let index = *count;
+			count.saturating_inc();
+			index
+		});
+		let record = Co2eRetirement {
+			basket,
+			co2e,
+			block_number: frame_system::Pallet::<T>::block_number(),
+		};
+		Co2eRetirements::<T, I>::insert(who, index, record);
+		Self::deposit_event(Event::Co2eRetired { account: who.clone(), index, co2e });
+		Ok(())
+	}
+
+	/// Record the serial range `serials` of `amount` minted for asset `id` with the current
+	/// `supply` in `SerialRanges`. Every serial stands for one whole unit of the asset.
+	///
+	/// An asset is either minted with serial ranges from its first mint on, or without them.
+	fn note_serial_range(
+		id: AssetId,
+		supply: T::Balance,
+		amount: T::Balance,
+		serials: Option<(u64, u64)>,
+	) -> DispatchResult {
+		let mut ranges = SerialRanges::<T, I>::get(id);
+		let (start, end) = match serials {
+			Some(serials) => serials,
+			None => {
+				ensure!(ranges.is_empty(), Error::<T, I>::SerialRangeRequired);
+				return Ok(())
+			},
+		};
+		ensure!(!ranges.is_empty() || supply.is_zero(), Error::<T, I>::BadSerialRange);
+		let count = end
+			.checked_sub(start)
+			.and_then(|span| span.checked_add(1))
+			.and_then(|count| <T::Balance as TryFrom<u64>>::try_from(count).ok())
+			.ok_or(Error::<T, I>::BadSerialRange)?;
+		let unit = Self::whole_unit(id)?;
+		ensure!(count.checked_mul(&unit) == Some(amount), Error::<T, I>::BadSerialRange);
+
+		let index = ranges.partition_point(|range| range.start < start);
+		let after_previous = index == 0 || ranges[index - 1].end < start;
+		let before_next = ranges.get(index).map_or(true, |range| end < range.start);
+		ensure!(after_previous && before_next, Error::<T, I>::SerialRangeOverlap);
+		ranges
+			.try_insert(index, SerialRange { start, end, unit, consumed: Zero::zero() })
+			.map_err(|_| Error::<T, I>::TooManySerialRanges)?;
+		SerialRanges::<T, I>::insert(id, ranges);
+		Ok(())
+	}
+
+	/// Consume `amount` of the serial ranges of asset `id` for the retirement or the custodial
+	/// burn of `who`, the lowest serials first. A serial consumed in part is reported by every
+	/// retirement consuming a part of it.
+	///
+	/// Emits `SerialsConsumed` and returns the consumed serials, if the asset has serial ranges.
+	pub(super) fn consume_serials(
+		id: AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> BoundedVec<(u64, u64), T::MaxSerialRanges> {
+		let mut consumed = BoundedVec::default();
+		let mut ranges = SerialRanges::<T, I>::get(id);
+		let mut left = amount;
+		for range in ranges.iter_mut() {
+			if left.is_zero() {
+				break
+			}
+			let count: T::Balance = (range.end - range.start).saturating_add(1).saturated_into();
+			let take = count.saturating_mul(range.unit).saturating_sub(range.consumed).min(left);
+			if take.is_zero() {
+				continue
+			}
+			let first = range.start + (range.consumed / range.unit).saturated_into::<u64>();
+			range.consumed.saturating_accrue(take);
+			let last = range.start + ((range.consumed - One::one()) / range.unit).saturated_into::<u64>();
+			// at most one consumed range per serial range
+			let _ = consumed.try_push((first, last));
+			left.saturating_reduce(take);
+		}
+		if consumed.is_empty() {
+			return consumed
+		}
+		SerialRanges::<T, I>::insert(id, ranges);
+		Self::deposit_event(Event::SerialsConsumed {
+			asset_id: id,
+			account: who.clone(),
+			ranges: consumed.to_vec(),
+		});
+		consumed
+	}
+
+	/// Get the serial ranges minted for asset `id`, with the number of consumed serials.
+	pub fn serial_ranges(id: AssetId) -> Vec<SerialRange<T::Balance>> {
+		SerialRanges::<T, I>::get(id).into_inner()
+	}
+
+	/// Get the serials consumed by the retirement stored under the `anchor` hash.
+	pub fn retirement_serials(anchor: T::Hash) -> Vec<(u64, u64)> {
+		RetirementSerials::<T, I>::get(anchor).map_or_else(Vec::new, |ranges| ranges.into_inner())
+	}
+
+	/// Mint `amount` of asset `id` to the reserve account `beneficiary` and add it to the
+	/// `ReservedSupply` of the asset.
+	///
+	/// Emits `ReserveBackedIssued`.
+	pub(super) fn do_mint_reserve_backed(
+		id: AssetId,
+		beneficiary: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		ensure!(T::ReserveAccounts::contains(beneficiary), Error::<T, I>::NotReserveAccount);
+		Self::increase_balance(id, beneficiary, amount, |details, account| -> DispatchResult {
+			Self::mint_check(id, beneficiary, details, account, amount, None, None)
+		})?;
+		Self::note_vintage(id, |totals| totals.issued.saturating_accrue(amount));
+		ReservedSupply::<T, I>::mutate(id, |reserved| reserved.saturating_accrue(amount));
+		Self::note_custodian_action(CustodianAction::Mint, id, Some(beneficiary), amount);
+		Self::deposit_event(Event::ReserveBackedIssued {
+			asset_id: id,
+			owner: beneficiary.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Reduce the `ReservedSupply` of asset `id` by `amount` burned or transferred from `who`,
+	/// if `who` is one of the `ReserveAccounts`.
+	pub(super) fn note_reserve_debit(id: AssetId, who: &T::AccountId, amount: T::Balance) {
+		if T::ReserveAccounts::contains(who) {
+			Self::release_reserved_supply(id, amount);
+		}
+	}
+
+	/// Reduce the `ReservedSupply` of asset `id` by `amount`, removing it when it reaches zero.
+	fn release_reserved_supply(id: AssetId, amount: T::Balance) {
+		ReservedSupply::<T, I>::mutate_exists(id, |maybe_reserved| {
+			*maybe_reserved = maybe_reserved
+				.map(|reserved| reserved.saturating_sub(amount))
+				.filter(|reserved| !reserved.is_zero());
+		});
+	}
+
+	/// Release the `ReservedSupply` held by the `outgoing` accounts, which are no longer
+	/// `ReserveAccounts`: their balances become part of the circulating supply.
+	pub(super) fn release_reserve_accounts(outgoing: &[T::AccountId]) {
+		for who in outgoing {
+			for (id, _) in AccountAssets::<T, I>::iter_prefix(who) {
+				if let Some(account) = Account::<T, I>::get(id, who) {
+					Self::release_reserved_supply(id, account.balance);
+				}
+			}
+		}
+	}
+
+	/// The message to be signed by the signer of the retirement `voucher`: the SCALE encoding of
+	/// `(VOUCHER_DOMAIN, pallet name, genesis hash, voucher)`.
+	pub fn retirement_voucher_message(
+		voucher: &RetirementVoucher<T::AccountId, T::Balance, T::BlockNumber>,
+	) -> Vec<u8> {
+		let name = <Pallet<T, I> as frame_support::traits::PalletInfoAccess>::name();
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+		(VOUCHER_DOMAIN, name.as_bytes(), genesis_hash, voucher).encode()
+	}
+
+	/// Verify the `signature` of the retirement `voucher` and retire the voucher amount from its
+	/// signer on behalf of the `submitter`.
+	///
+	/// Emits `RetirementVoucherRedeemed`.
+	pub(super) fn do_redeem_retirement_voucher(
+		submitter: &T::AccountId,
+		voucher: RetirementVoucher<T::AccountId, T::Balance, T::BlockNumber>,
+		signature: T::OffchainSignature,
+	) -> DispatchResult {
+		ensure!(
+			signature.verify(&Self::retirement_voucher_message(&voucher)[..], &voucher.signer),
+			Error::<T, I>::BadSignature
+		);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= voucher.expiry,
+			Error::<T, I>::VoucherExpired
+		);
+		ensure!(
+			!RedeemedVouchers::<T, I>::contains_key(&voucher.signer, voucher.nonce),
+			Error::<T, I>::VoucherAlreadyRedeemed
+		);
+
+		Self::do_retire(voucher.asset_id, &voucher.signer, voucher.amount, None, submitter)?;
+		RedeemedVouchers::<T, I>::insert(&voucher.signer, voucher.nonce, ());
+
+		Self::deposit_event(Event::RetirementVoucherRedeemed {
+			signer: voucher.signer,
+			asset_id: voucher.asset_id,
+			amount: voucher.amount,
+			nonce: voucher.nonce,
+		});
+		Ok(())
+	}
+
+	/// The message signed for the signed burn `payload`: the SCALE encoding of
+	/// `(SIGNED_BURN_DOMAIN, pallet name, payload)`.
+	pub fn signed_burn_message(
+		payload: &SignedBurnPayload<T::AccountId, T::Balance, T::BlockNumber, T::Hash>,
+	) -> Vec<u8> {
+		let name = <Pallet<T, I> as frame_support::traits::PalletInfoAccess>::name();
+		(SIGNED_BURN_DOMAIN, name.as_bytes(), payload).encode()
+	}
+
+	/// Burn the amount of the signed burn `payload` from its signer on behalf of the `relayer`,
+	/// if the signature, the genesis hash, the expiry and the nonce are valid.
+	pub(super) fn do_self_burn_signed(
+		relayer: T::AccountId,
+		payload: SignedBurnPayload<T::AccountId, T::Balance, T::BlockNumber, T::Hash>,
+		signature: T::OffchainSignature,
+	) -> DispatchResult {
+		ensure!(
+			signature.verify(&Self::signed_burn_message(&payload)[..], &payload.signer),
+			Error::<T, I>::BadSignature
+		);
+		ensure!(
+			payload.genesis_hash == frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()),
+			Error::<T, I>::WrongGenesisHash
+		);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= payload.expiry,
+			Error::<T, I>::PayloadExpired
+		);
+		ensure!(
+			SignedBurnNonce::<T, I>::get(&payload.signer) == payload.nonce,
+			Error::<T, I>::BadNonce
+		);
+
+		Self::do_retire(payload.asset_id, &payload.signer, payload.amount, None, &relayer)?;
+		SignedBurnNonce::<T, I>::insert(&payload.signer, payload.nonce.saturating_add(1));
+
+		Self::deposit_event(Event::SignedBurnExecuted {
+			signer: payload.signer,
+			asset_id: payload.asset_id,
+			amount: payload.amount,
+			nonce: payload.nonce,
+			relayer,
+		});
+		Ok(())
+	}
+
+	/// The message to be signed by the owner of the approval `permit`: the SCALE encoding of
+	/// `(PERMIT_DOMAIN, pallet name, permit)`.
+	pub fn approval_permit_message(
+		permit: &ApprovalPermit<T::AccountId, T::Balance, T::BlockNumber, T::Hash>,
+	) -> Vec<u8> {
+		let name = <Pallet<T, I> as frame_support::traits::PalletInfoAccess>::name();
+		(PERMIT_DOMAIN, name.as_bytes(), permit).encode()
+	}
+
+	/// Verify the `signature` of the approval `permit` by its owner and approve the permit
+	/// amount on behalf of the `relayer`, if the deadline has not passed.
+	///
+	/// Emits `ApprovedTransfer` and `ApprovalPermitted`.
+	pub(super) fn do_approve_with_signature(
+		relayer: T::AccountId,
+		permit: ApprovalPermit<T::AccountId, T::Balance, T::BlockNumber, T::Hash>,
+		signature: T::OffchainSignature,
+	) -> DispatchResult {
+		ensure!(
+			signature.verify(&Self::approval_permit_message(&permit)[..], &permit.owner),
+			Error::<T, I>::BadSignature
+		);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= permit.deadline,
+			Error::<T, I>::PayloadExpired
+		);
+
+		Self::do_approve_transfer(
+			permit.asset_id,
+			&permit.owner,
+			&permit.delegate,
+			permit.amount,
+			None,
+		)?;
+		PermitNonce::<T, I>::insert(&permit.owner, permit.nonce.saturating_add(1));
+
+		Self::deposit_event(Event::ApprovalPermitted {
+			owner: permit.owner,
+			nonce: permit.nonce,
+			relayer,
+		});
+		Ok(())
+	}
+
+	/// Add the burn of `amount` of asset `id` from `who` by the custodian to `CustodialBurns`.
+	///
+	/// Emits `CarbonCreditsBurned` and `CustodialBurned`.
+	pub(super) fn note_custodial_burn(
+		who: &T::AccountId,
+		id: AssetId,
+		amount: T::Balance,
+		reason: CustodialBurnReason,
+		reference: Option<BoundedVec<u8, T::StringLimit>>,
+		custodian: &T::AccountId,
+	) {
+		let index = CustodialBurnCount::<T, I>::mutate(who, |count| {
+			let index = *count;
+			count.saturating_inc();
+			index
+		});
+		let record = CustodialBurnRecord {
+			asset_id: id,
+			amount,
+			reason,
+			reference,
+			block_number: frame_system::Pallet::<T>::block_number(),
+		};
+		CustodialBurns::<T, I>::insert(who, index, record);
+		let certificate_id =
+			Self::note_retirement_record(who, who, id, amount, RetirementReason::Custodial(reason));
+		Self::note_custodian_action(CustodianAction::Burn, id, Some(who), amount);
+		Self::consume_serials(id, who, amount);
+		Self::deposit_event(Event::CarbonCreditsBurned {
+			account: who.clone(),
+			asset_id: id,
+			amount,
+			kind: BurnKind::CustodialBurn,
+			initiator: custodian.clone(),
+			alias: AccountAlias::<T, I>::get(who).map(|(alias, _)| alias),
+			certificate_id,
+		});
+		Self::deposit_event(Event::CustodialBurned {
+			account: who.clone(),
+			asset_id: id,
+			amount,
+			reason,
+		});
+	}
+
+	/// Record `amount` of asset `id` burned from `who` for `beneficiary` in the
+	/// `RetirementRegistry` under the next certificate id, which is returned.
+	pub(super) fn note_retirement_record(
+		who: &T::AccountId,
+		beneficiary: &T::AccountId,
+		id: AssetId,
+		amount: T::Balance,
+		reason: RetirementReason,
+	) -> u64 {
+		let certificate_id = Self::next_certificate_id();
+		RetirementRegistry::<T, I>::insert(
+			certificate_id,
+			RetirementRecord {
+				account: who.clone(),
+				asset_id: id,
+				amount,
+				block_number: frame_system::Pallet::<T>::block_number(),
+				beneficiary: beneficiary.clone(),
+				reason,
+			},
+		);
+		Self::deposit_event(Event::RetirementRegistered {
+			certificate_id,
+			account: who.clone(),
+			asset_id: id,
+			amount,
+		});
+		certificate_id
+	}
+
+	/// Add `amount` of asset `id` retired by `initiator` to the `BurnCertificate` of `who`.
+	///
+	/// Emits `CarbonCreditsBurned` with the `certificate_id` of the retirement.
+	pub(super) fn note_burn_certificate(
+		who: &T::AccountId,
+		id: AssetId,
+		amount: T::Balance,
+		initiator: &T::AccountId,
+		certificate_id: u64,
+	) {
+		BurnCertificate::<T, I>::mutate(who, id, |maybe_certificate| {
+			let certificate = maybe_certificate.get_or_insert_with(Default::default);
+			certificate.amount.saturating_accrue(amount);
+			certificate.last_block = frame_system::Pallet::<T>::block_number();
+		});
+		AssetBurnCertificate::<T, I>::mutate(id, who, |burned| {
+			*burned = Some(burned.unwrap_or_default().saturating_add(amount));
+		});
+		TotalRetired::<T, I>::mutate(id, |total| total.saturating_accrue(amount));
+		Self::note_vintage(id, |totals| totals.retired.saturating_accrue(amount));
+		Self::note_unconfirmed_retirement(amount);
+		if let Some((organization, _)) = AccountOrganization::<T, I>::get(who) {
+			if OrganizationMembers::<T, I>::contains_key(&organization, who) {
+				OrganizationRetired::<T, I>::mutate(organization, id, |retired| {
+					retired.saturating_accrue(amount)
+				});
+			}
+		}
+		Self::deposit_event(Event::CarbonCreditsBurned {
+			account: who.clone(),
+			asset_id: id,
+			amount,
+			kind: BurnKind::SelfRetirement,
+			initiator: initiator.clone(),
+			alias: AccountAlias::<T, I>::get(who).map(|(alias, _)| alias),
+			certificate_id,
+		});
+	}
+
+	/// Get the accounts which burned asset `id` with the amounts stored in their burn
+	/// certificates.
+	pub fn asset_burn_certificates(id: AssetId) -> Vec<(T::AccountId, T::Balance)> {
+		AssetBurnCertificate::<T, I>::iter_prefix(id).collect()
+	}
+
+	/// Get the amounts of assets retired by all the accounts of the `organization`.
+	pub fn organization_retired(organization: Vec<u8>) -> Vec<(AssetId, T::Balance)> {
+		match BoundedVec::<u8, T::StringLimit>::try_from(organization) {
+			Ok(organization) => OrganizationRetired::<T, I>::iter_prefix(organization).collect(),
+			Err(_) => Vec::new(),
+		}
+	}
+
+	/// Set the retirement quota of `sub` drawn from the balance of the organization account
+	/// `organization`. Zero `per_period` removes the quota.
+	pub(super) fn do_grant_retirement_quota(
+		organization: &T::AccountId,
+		sub: T::AccountId,
+		id: AssetId,
+		per_period: T::Balance,
+	) -> DispatchResult {
+		ensure!(
+			AccountOrganization::<T, I>::contains_key(organization),
+			Error::<T, I>::NoOrganization
+		);
+		if per_period.is_zero() {
+			RetirementQuotas::<T, I>::remove((organization, &sub, id));
+		} else {
+			RetirementQuotas::<T, I>::mutate((organization, &sub, id), |maybe_quota| {
+				let quota = maybe_quota.get_or_insert_with(|| RetirementQuota {
+					per_period,
+					period_start: Self::quota_period_start(),
+					used: Zero::zero(),
+				});
+				quota.per_period = per_period;
+			});
+		}
+		Self::deposit_event(Event::RetirementQuotaGranted {
+			organization: organization.clone(),
+			sub,
+			asset_id: id,
+			per_period,
+		});
+		Ok(())
+	}
+
+	/// Retire `amount` of asset `id` from the balance of `organization` within the retirement
+	/// quota of `sub`. The quota is charged with the amount actually burned, including the
+	/// dust burned with it.
+	pub(super) fn do_self_burn_from_org(
+		sub: &T::AccountId,
+		organization: &T::AccountId,
+		id: AssetId,
+		amount: T::Balance,
+		note: Option<BoundedVec<u8, T::StringLimit>>,
+	) -> DispatchResult {
+		let mut quota =
+			RetirementQuotas::<T, I>::get((organization, sub, id)).ok_or(Error::<T, I>::NoQuota)?;
+		let period_start = Self::quota_period_start();
+		if quota.period_start != period_start {
+			quota.period_start = period_start;
+			quota.used = Zero::zero();
+		}
+		ensure!(
+			quota.used.checked_add(&amount).map_or(false, |used| used <= quota.per_period),
+			Error::<T, I>::QuotaExceeded
+		);
+
+		let (actual, _) = Self::do_retire(id, organization, amount, note, sub)?;
+		let used = quota.used.checked_add(&actual).ok_or(ArithmeticError::Overflow)?;
+		ensure!(used <= quota.per_period, Error::<T, I>::QuotaExceeded);
+		quota.used = used;
+		RetirementQuotas::<T, I>::insert((organization, sub, id), quota);
+		Ok(())
+	}
+
+	/// The first block of the current `QuotaPeriod`.
+	fn quota_period_start() -> T::BlockNumber {
+		let now = frame_system::Pallet::<T>::block_number();
+		let period = T::QuotaPeriod::get();
+		if period.is_zero() {
+			Zero::zero()
+		} else {
+			now - now % period
+		}
+	}
+
+	/// Register `who` with the `organization`, reserving the deposit. The approval of `who` as
+	/// a member of its previous organization is removed, and the owner of the `organization`
+	/// is approved at once.
+	pub(super) fn do_set_organization(
+		who: &T::AccountId,
+		organization: Vec<u8>,
+	) -> DispatchResult {
+		let bounded: BoundedVec<u8, T::StringLimit> =
+			organization.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+
+		let deposit = Self::metadata_deposit(organization.len() as u32);
+		let (old_organization, old_deposit) = match AccountOrganization::<T, I>::get(who) {
+			Some((old_organization, old_deposit)) => (Some(old_organization), old_deposit),
+			None => (None, Zero::zero()),
+		};
+		if let Some(old_organization) = old_organization.filter(|old| old != &bounded) {
+			OrganizationMembers::<T, I>::remove(old_organization, who);
+		}
+		if OrganizationOwners::<T, I>::get(&bounded).as_ref() == Some(who) {
+			OrganizationMembers::<T, I>::insert(&bounded, who, ());
+		}
+		if deposit > old_deposit {
+			T::Deposits::hold(who, deposit - old_deposit)?;
+		} else {
+			T::Deposits::release(who, old_deposit - deposit);
+		}
+		Self::note_deposit(None, DepositPurpose::Organization, old_deposit, deposit);
+
+		AccountOrganization::<T, I>::insert(who, (bounded, deposit));
+		Self::deposit_event(Event::OrganizationSet { account: who.clone(), organization });
+		Ok(())
+	}
+
+	/// Approve `who` as a member of the organization it is registered with, if `approver` is
+	/// the owner of the organization or the custodian.
+	///
+	/// Emits `OrganizationMemberApproved`.
+	pub(super) fn do_approve_organization_member(
+		approver: &T::AccountId,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		let (organization, _) =
+			AccountOrganization::<T, I>::get(who).ok_or(Error::<T, I>::NoOrganization)?;
+		let owner =
+			OrganizationOwners::<T, I>::get(&organization).ok_or(Error::<T, I>::UnknownOrganization)?;
+		if approver != &owner {
+			Self::ensure_custodian(approver)?;
+		}
+		OrganizationMembers::<T, I>::insert(&organization, who, ());
+		Self::deposit_event(Event::OrganizationMemberApproved {
+			account: who.clone(),
+			organization: organization.into_inner(),
+		});
+		Ok(())
+	}
+
+	/// Register the display `alias` of `who`, reserving the deposit and releasing the previous
+	/// alias of `who`.
+	pub(super) fn do_set_alias(who: &T::AccountId, alias: Vec<u8>) -> DispatchResult {
+		ensure!(!alias.is_empty(), Error::<T, I>::BadMetadata);
+		let bounded: BoundedVec<u8, T::StringLimit> =
+			alias.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+		if let Some(holder) = AliasAccount::<T, I>::get(&bounded) {
+			ensure!(&holder == who, Error::<T, I>::AliasTaken);
+		}
+
+		let deposit = Self::metadata_deposit(alias.len() as u32);
+		let (old_alias, old_deposit) = match AccountAlias::<T, I>::get(who) {
+			Some((old_alias, old_deposit)) => (Some(old_alias), old_deposit),
+			None => (None, Zero::zero()),
+		};
+		if deposit > old_deposit {
+			T::Deposits::hold(who, deposit - old_deposit)?;
+		} else {
+			T::Deposits::release(who, old_deposit - deposit);
+		}
+		Self::note_deposit(None, DepositPurpose::Alias, old_deposit, deposit);
+
+		if let Some(old_alias) = old_alias {
+			AliasAccount::<T, I>::remove(old_alias);
+		}
+		AliasAccount::<T, I>::insert(&bounded, who);
+		AccountAlias::<T, I>::insert(who, (bounded, deposit));
+		Self::deposit_event(Event::AliasSet { account: who.clone(), alias });
+		Ok(())
+	}
+
+	/// Record that `amount` of asset `id` burned by `who` was retired in the external registry.
+	///
+	/// The total confirmed amount cannot exceed the `BurnCertificate` of `who`.
+	pub(super) fn do_confirm_retirement_registration(
+		who: &T::AccountId,
+		id: AssetId,
+		amount: T::Balance,
+		external_ref: Vec<u8>,
+	) -> DispatchResult {
+		let bounded_ref: BoundedVec<u8, T::StringLimit> =
+			external_ref.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+		let burned = BurnCertificate::<T, I>::get(who, id).map_or(Zero::zero(), |c| c.amount);
+
+		RetirementConfirmation::<T, I>::try_mutate(who, id, |maybe_confirmation| -> DispatchResult {
+			let confirmed = maybe_confirmation.as_ref().map_or(Zero::zero(), |c| c.amount);
+			let total = confirmed.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+			ensure!(total <= burned, Error::<T, I>::ExceedsBurned);
+			*maybe_confirmation =
+				Some(RegistryConfirmation { amount: total, external_ref: bounded_ref });
+			Ok(())
+		})?;
+		Self::note_custodian_confirmation(amount);
+
+		Self::deposit_event(Event::RetirementRegistrationConfirmed {
+			account: who.clone(),
+			asset_id: id,
+			amount,
+			external_ref,
+		});
+		Ok(())
+	}
+
+	/// Create a new asset without taking a deposit.
+	///
+	/// * `id`: The `AssetId` you want the new asset to have. Must not already be in use.
+	/// * `owner`: The owner, issuer, admin, and freezer of this asset upon creation.
+	/// * `is_sufficient`: Whether this asset needs users to have an existential deposit to hold
+	///   this asset.
+	/// * `min_balance`: The minimum balance a user is allowed to have of this asset before they are
+	///   considered dust and cleaned up.
+	pub(super) fn do_force_create(
+		id: AssetId,
+		owner: T::AccountId,
+		is_sufficient: bool,
+		min_balance: T::Balance,
+	) -> DispatchResult {
+		ensure!(!Asset::<T, I>::contains_key(id), Error::<T, I>::InUse);
+		ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
+
+		Asset::<T, I>::insert(
+			id,
+			AssetDetails {
+				owner: owner.clone(),
+				issuer: owner.clone(),
+				admin: owner.clone(),
+				freezer: owner.clone(),
+				supply: Zero::zero(),
+				deposit: Zero::zero(),
+				min_balance,
+				is_sufficient,
+				accounts: 0,
+				sufficients: 0,
+				approvals: 0,
+				status: AssetStatus::Live,
+				whitelist_enabled: false,
+			},
+		);
+		Self::note_owner(id, None, Some(&owner));
+		Self::deposit_event_indexed(id, Event::ForceCreated { asset_id: id, owner });
+		Ok(())
+	}
+
+	/// Destroy an existing asset.
+	///
+	/// * `id`: The asset you want to destroy.
+	/// * `witness`: Witness data needed about the current state of the asset, used to confirm
+	///   complexity of the operation.
+	/// * `maybe_check_owner`: An optional check before destroying the asset, if the provided
+	///   account is the owner of that asset. Can be used for authorization checks.
+	pub(super) fn do_destroy(
+		id: AssetId,
+		witness: DestroyWitness,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> Result<DestroyWitness, DispatchError> {
+		let mut dead_accounts: Vec<T::AccountId> = vec![];
+
+		let result_witness: DestroyWitness = Asset::<T, I>::try_mutate_exists(
+			id,
+			|maybe_details| -> Result<DestroyWitness, DispatchError> {
+				let mut details = maybe_details.take().ok_or(Error::<T, I>::Unknown)?;
+				if let Some(check_owner) = maybe_check_owner {
+					ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
+				}
+				ensure!(details.accounts <= witness.accounts, Error::<T, I>::BadWitness);
+				ensure!(details.sufficients <= witness.sufficients, Error::<T, I>::BadWitness);
+				ensure!(details.approvals <= witness.approvals, Error::<T, I>::BadWitness);
+
+				for (who, v) in Account::<T, I>::drain_prefix(id) {
+					// We have to force this as it's destroying the entire asset class.
+					// This could mean that some accounts now have irreversibly reserved
+					// funds.
+					let _ = Self::dead_account(&who, &mut details, &v.reason, true);
+					Self::note_ledger_entry(LedgerOp::Burn, id, Some(&who), None, v.balance);
+					dead_accounts.push(who);
+				}
+				debug_assert_eq!(details.accounts, 0);
+				debug_assert_eq!(details.sufficients, 0);
+
+				for ((owner, _), approval) in Approvals::<T, I>::drain_prefix((&id,)) {
+					T::Deposits::release(&owner, approval.deposit);
+					Self::note_deposit(
+						Some(id),
+						DepositPurpose::Approval,
+						approval.deposit,
+						Zero::zero(),
+					);
+				}
+				for (_, attribute) in Attributes::<T, I>::drain_prefix(&id) {
+					T::Deposits::release(&attribute.depositor, attribute.deposit);
+					Self::note_deposit(
+						Some(id),
+						DepositPurpose::Attribute,
+						attribute.deposit,
+						Zero::zero(),
+					);
+				}
+				Self::clear_destroyed_asset(id, &details);
+
+				Ok(DestroyWitness {
+					accounts: details.accounts,
+					sufficients: details.sufficients,
+					approvals: details.approvals,
+				})
+			},
+		)?;
+
+		// Execute hooks outside of `mutate`.
+		for who in dead_accounts {
+			Self::account_died(id, &who);
+		}
+		Ok(result_witness)
+	}
+
+	/// Remove the metadata and the per-asset records of the destroyed asset `id`, returning the
+	/// asset and metadata deposits to the owner.
+	///
+	/// Emits `Destroyed`.
+	fn clear_destroyed_asset(
+		id: AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+	) {
+		let metadata = Metadata::<T, I>::take(&id);
+		LegacyDataIpfs::<T, I>::remove(&id);
+		Self::note_symbol(id, Some(&metadata.symbol), None);
+		ReservedSupply::<T, I>::remove(&id);
+		T::Deposits::release(&details.owner, details.deposit.saturating_add(metadata.deposit));
+		Self::note_deposit(Some(id), DepositPurpose::Asset, details.deposit, Zero::zero());
+		Self::note_deposit(Some(id), DepositPurpose::Metadata, metadata.deposit, Zero::zero());
+
+		Self::note_owner(id, Some(&details.owner), None);
+		CoOwner::<T, I>::remove(&id);
+		PendingOwnerActions::<T, I>::remove(&id);
+		PendingOwner::<T, I>::remove(&id);
+		AssetFreezeReason::<T, I>::remove(&id);
+		ProjectStatusOf::<T, I>::remove(&id);
+		SerialRanges::<T, I>::remove(&id);
+		AssetSplits::<T, I>::remove(&id);
+		AssetBadges::<T, I>::remove(&id);
+		AssetVintage::<T, I>::remove(&id);
+		Custodians::<T, I>::remove(&id);
+		PendingAssetCustodian::<T, I>::remove(&id);
+		for request_id in PendingMintRequests::<T, I>::get(&id) {
+			if let Some(request) = MintRequests::<T, I>::take(request_id) {
+				Self::settle_mint_request(request_id, &request);
+			}
+		}
+		if let Some(pot) = SponsorshipPots::<T, I>::take(&id) {
+			Self::release_sponsorship_pot(id, pot);
+		}
+		ProjectDetailsOf::<T, I>::remove(&id);
+		if let Some(methodology) = AssetMethodology::<T, I>::take(&id) {
+			MethodologyAssets::<T, I>::remove(methodology, &id);
+		}
+		Self::deposit_event(Event::Destroyed { asset_id: id });
+	}
+
+	/// Start the destruction of asset `id`: the asset can no longer be used and its accounts and
+	/// approvals can be removed with `do_destroy_accounts` and `do_destroy_approvals`.
+	///
+	/// Emits `DestructionStarted`.
+	pub(super) fn do_start_destroy(
+		id: AssetId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		Asset::<T, I>::try_mutate_exists(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
+			}
+			ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+			details.status = AssetStatus::Destroying;
+
+			Self::deposit_event(Event::DestructionStarted { asset_id: id });
+			Ok(())
+		})
+	}
+
+	/// Remove up to `max_items` accounts of asset `id` in destruction, returning the deposits
+	/// held for them.
+	///
+	/// Returns the number of removed accounts. Emits `AccountsDestroyed`.
+	pub(super) fn do_destroy_accounts(id: AssetId, max_items: u32) -> Result<u32, DispatchError> {
+		let mut dead_accounts: Vec<T::AccountId> = vec![];
+		let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+
+		for (who, v) in Account::<T, I>::iter_prefix(id).take(max_items as usize) {
+			// We have to force this as it's destroying the entire asset class.
+			let _ = Self::dead_account(&who, &mut details, &v.reason, true);
+			if let ExistenceReason::DepositHeld(deposit) = v.reason {
+				T::Deposits::release(&who, deposit);
+				Self::note_deposit(Some(id), DepositPurpose::Account, deposit, Zero::zero());
+			}
+			Self::note_ledger_entry(LedgerOp::Burn, id, Some(&who), None, v.balance);
+			dead_accounts.push(who);
+		}
+		for who in dead_accounts.iter() {
+			Account::<T, I>::remove(id, who);
+		}
+		let accounts_remaining = details.accounts;
+		Asset::<T, I>::insert(id, details);
+
+		// Execute hooks outside of the asset update.
+		for who in dead_accounts.iter() {
+			Self::account_died(id, who);
+		}
+		let accounts_destroyed = dead_accounts.len() as u32;
+		Self::deposit_event(Event::AccountsDestroyed {
+			asset_id: id,
+			accounts_destroyed,
+			accounts_remaining,
+		});
+		Ok(accounts_destroyed)
+	}
+
+	/// Remove up to `max_items` approvals and, once there are no approvals, attributes of asset
+	/// `id` in destruction, returning their deposits.
+	///
+	/// Returns the number of removed items. Emits `ApprovalsDestroyed`.
+	pub(super) fn do_destroy_approvals(id: AssetId, max_items: u32) -> Result<u32, DispatchError> {
+		let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+
+		let mut approvals_destroyed = 0u32;
+		let approvals: Vec<_> =
+			Approvals::<T, I>::iter_prefix((&id,)).take(max_items as usize).collect();
+		for ((owner, delegate), _) in approvals {
+			if Self::take_approval(id, &owner, &delegate, &mut details).is_some() {
+				approvals_destroyed.saturating_inc();
+			}
+			OrphanedApprovalsSince::<T, I>::remove(id, &owner);
+		}
+		let mut removed = approvals_destroyed;
+		if removed < max_items {
+			let attributes: Vec<_> =
+				Attributes::<T, I>::iter_prefix(&id).take((max_items - removed) as usize).collect();
+			for (key, attribute) in attributes {
+				Attributes::<T, I>::remove(&id, &key);
+				T::Deposits::release(&attribute.depositor, attribute.deposit);
+				Self::note_deposit(
+					Some(id),
+					DepositPurpose::Attribute,
+					attribute.deposit,
+					Zero::zero(),
+				);
+				removed.saturating_inc();
+			}
+		}
+		let approvals_remaining = details.approvals;
+		Asset::<T, I>::insert(id, details);
+
+		Self::deposit_event(Event::ApprovalsDestroyed {
+			asset_id: id,
+			approvals_destroyed,
+			approvals_remaining,
+		});
+		Ok(removed)
+	}
+
+	/// Pay `who` the `CleanupReward` for `items` removed from asset `id` in destruction, out of
+	/// the asset deposit held on the owner. The owner is not rewarded.
+	pub(super) fn reward_destroy_cleanup(id: AssetId, who: &T::AccountId, items: u32) {
+		let reward = T::CleanupReward::get().saturating_mul(items.into());
+		if reward.is_zero() {
+			return
+		}
+		Asset::<T, I>::mutate(id, |maybe_details| {
+			if let Some(details) = maybe_details {
+				if &details.owner == who {
+					return
+				}
+				let amount = reward.min(details.deposit);
+				if Self::pay_cleanup_reward(id, &details.owner, who, amount) {
+					let deposit = details.deposit - amount;
+					Self::note_deposit(Some(id), DepositPurpose::Asset, details.deposit, deposit);
+					details.deposit = deposit;
+				}
+			}
+		});
+	}
+
+	/// Pay `who` the `CleanupReward` for removing the approval of `owner` to `delegate` for
+	/// asset `id`, out of the approval deposit. Only an expired approval or an approval of a
+	/// reaped owner is rewarded, so the deposit of any other approval is returned to the owner
+	/// in full. The owner is not rewarded.
+	pub(super) fn reward_approval_cleanup(
+		id: AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		who: &T::AccountId,
+	) {
+		let reward = T::CleanupReward::get();
+		if reward.is_zero() || owner == who {
+			return
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let owner_reaped = frame_system::Pallet::<T>::providers(owner).is_zero();
+		Approvals::<T, I>::mutate((id, owner, delegate), |maybe_approval| {
+			if let Some(approval) = maybe_approval {
+				if !owner_reaped && !approval.is_expired(&now) {
+					return
+				}
+				let amount = reward.min(approval.deposit);
+				if Self::pay_cleanup_reward(id, owner, who, amount) {
+					let deposit = approval.deposit - amount;
+					Self::note_deposit(Some(id), DepositPurpose::Approval, approval.deposit, deposit);
+					approval.deposit = deposit;
+				}
+			}
+		});
+	}
+
+	/// Move `amount` held on `from` to the free balance of `who`.
+	///
+	/// Emits `CleanupRewarded` and returns whether the reward was paid.
+	fn pay_cleanup_reward(
+		id: AssetId,
+		from: &T::AccountId,
+		who: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> bool {
+		if amount.is_zero() || T::Deposits::transfer_held(from, who, amount).is_err() {
+			return false
+		}
+		T::Deposits::release(who, amount);
+		Self::deposit_event(Event::CleanupRewarded { asset_id: id, who: who.clone(), amount });
+		true
+	}
+
+	/// Complete the destruction of asset `id` once all its accounts, approvals and attributes
+	/// are removed.
+	///
+	/// Emits `Destroyed`.
+	pub(super) fn do_finish_destroy(id: AssetId) -> DispatchResult {
+		Asset::<T, I>::try_mutate_exists(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.take().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+			ensure!(details.accounts == 0, Error::<T, I>::InUse);
+			ensure!(details.approvals == 0, Error::<T, I>::InUse);
+			ensure!(Attributes::<T, I>::iter_prefix(&id).next().is_none(), Error::<T, I>::InUse);
+
+			Self::clear_destroyed_asset(id, &details);
+			Ok(())
+		})
+	}
+
+	/// Destroy asset `id` in one go through the staged destruction: start it unless it was
+	/// started already, remove the accounts, approvals and attributes and finish it.
+	///
+	/// Fails with `BadWitness` if the asset has more accounts or approvals than `witness`.
+	/// Returns the actual number of removed accounts and approvals.
+	pub(super) fn do_staged_destroy(
+		id: AssetId,
+		witness: DestroyWitness,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> Result<DestroyWitness, DispatchError> {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
+		}
+		ensure!(details.accounts <= witness.accounts, Error::<T, I>::BadWitness);
+		ensure!(details.sufficients <= witness.sufficients, Error::<T, I>::BadWitness);
+		ensure!(details.approvals <= witness.approvals, Error::<T, I>::BadWitness);
+
+		if details.status != AssetStatus::Destroying {
+			Self::do_start_destroy(id, None)?;
+		}
+		Self::do_destroy_accounts(id, details.accounts)?;
+		// The attributes are removed after the approvals within the same limit.
+		let attributes = Attributes::<T, I>::iter_prefix(&id).count() as u32;
+		Self::do_destroy_approvals(id, details.approvals.saturating_add(attributes))?;
+		Self::do_finish_destroy(id)?;
+
+		Ok(details.destroy_witness())
+	}
+
+	/// Fail with `Frozen` if the asset is frozen and with `AssetNotLive` if it is being
+	/// destroyed.
+	pub(super) fn ensure_live(
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+	) -> DispatchResult {
+		match details.status {
+			AssetStatus::Live => Ok(()),
+			AssetStatus::Frozen => Err(Error::<T, I>::Frozen.into()),
+			AssetStatus::Destroying => Err(Error::<T, I>::AssetNotLive.into()),
+		}
+	}
+
+	/// Creates an approval from `owner` to spend `amount` of asset `id` tokens by 'delegate'
+	/// while reserving `T::ApprovalDeposit` from owner
+	///
+	/// If an approval already exists, the new amount is added to such existing approval, unless
+	/// it has expired. The approval expires at block `expiry`, if any, which replaces the expiry
+	/// of the existing approval.
+	pub(super) fn do_approve_transfer(
+		id: AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
 		amount: T::Balance,
+		expiry: Option<T::BlockNumber>,
 	) -> DispatchResult {
 		let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
-		ensure!(!d.is_frozen, Error::<T, I>::Frozen);
-		Approvals::<T, I>::try_mutate(
-			(id, &owner, &delegate),
-			|maybe_approved| -> DispatchResult {
-				let mut approved = match maybe_approved.take() {
-					// an approval already exists and is being updated
-					Some(a) => a,
-					// a new approval is created
-					None => {
-						d.approvals.saturating_inc();
-						Default::default()
-					},
-				};
-				let deposit_required = T::ApprovalDeposit::get();
-				if approved.deposit < deposit_required {
-					T::Currency::reserve(owner, deposit_required - approved.deposit)?;
-					approved.deposit = deposit_required;
-				}
-				approved.amount = approved.amount.saturating_add(amount);
-				*maybe_approved = Some(approved);
-				Ok(())
-			},
-		)?;
+		Self::ensure_live(&d)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(expiry.map_or(true, |expiry| expiry > now), Error::<T, I>::ApprovalExpired);
+		Self::ensure_not_paused(PAUSE_APPROVALS)?;
+		let mut approved = Approvals::<T, I>::get((id, &owner, &delegate)).unwrap_or_default();
+		if approved.is_expired(&now) {
+			approved.amount = Zero::zero();
+		}
+		approved.expiry = expiry;
+		Self::hold_approval_deposit(id, owner, &mut approved)?;
+		approved.amount = approved.amount.saturating_add(amount);
+		Self::put_approval(id, owner, delegate, approved, &mut d);
+		Self::note_activity(owner, |a| a.approvals_granted.saturating_inc());
 		Asset::<T, I>::insert(id, d);
 		Self::deposit_event(Event::ApprovedTransfer {
 			asset_id: id,
@@ -766,6 +2872,82 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Top up the deposit of the `approved` approval of asset `id` given by `owner` to
+	/// `T::ApprovalDeposit`.
+	fn hold_approval_deposit(
+		id: AssetId,
+		owner: &T::AccountId,
+		approved: &mut Approval<T::Balance, DepositBalanceOf<T, I>, T::BlockNumber>,
+	) -> DispatchResult {
+		let deposit_required = T::ApprovalDeposit::get();
+		if approved.deposit < deposit_required {
+			T::Deposits::hold(owner, deposit_required - approved.deposit)?;
+			Self::note_deposit(
+				Some(id),
+				DepositPurpose::Approval,
+				approved.deposit,
+				deposit_required,
+			);
+			approved.deposit = deposit_required;
+		}
+		Ok(())
+	}
+
+	/// Increase or decrease by `delta` the amount of asset `id` which `delegate` may transfer on
+	/// behalf of `owner`, keeping the expiry of the approval.
+	///
+	/// An increase creates the approval, reserving `T::ApprovalDeposit` from `owner`, if there
+	/// is none. A decrease to zero removes the approval and unreserves its deposit. Fails with
+	/// `ApprovalExpired` if the approval has expired.
+	///
+	/// Emits `AllowanceChanged` with the new amount.
+	pub(super) fn do_change_allowance(
+		id: AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		delta: T::Balance,
+		increase: bool,
+	) -> DispatchResult {
+		let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		Self::ensure_live(&d)?;
+		Self::ensure_not_paused(PAUSE_APPROVALS)?;
+		let maybe_approved = Approvals::<T, I>::get((id, owner, delegate));
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(approved) = &maybe_approved {
+			ensure!(!approved.is_expired(&now), Error::<T, I>::ApprovalExpired);
+		}
+
+		let amount = if increase {
+			let mut approved = maybe_approved.unwrap_or_default();
+			Self::hold_approval_deposit(id, owner, &mut approved)?;
+			approved.amount =
+				approved.amount.checked_add(&delta).ok_or(ArithmeticError::Overflow)?;
+			let amount = approved.amount;
+			Self::put_approval(id, owner, delegate, approved, &mut d);
+			Self::note_activity(owner, |a| a.approvals_granted.saturating_inc());
+			amount
+		} else {
+			let mut approved = maybe_approved.ok_or(Error::<T, I>::Unapproved)?;
+			let remaining =
+				approved.amount.checked_sub(&delta).ok_or(Error::<T, I>::AllowanceUnderflow)?;
+			if remaining.is_zero() {
+				Self::take_approval(id, owner, delegate, &mut d);
+			} else {
+				approved.amount = remaining;
+				Approvals::<T, I>::insert((id, owner, delegate), approved);
+			}
+			remaining
+		};
+		Asset::<T, I>::insert(id, d);
+		Self::deposit_event(Event::AllowanceChanged {
+			asset_id: id,
+			owner: owner.clone(),
+			delegate: delegate.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
 	/// Reduces the asset `id` balance of `owner` by some `amount` and increases the balance of
 	/// `dest` by (similar) amount, checking that 'delegate' has an existing approval from `owner`
 	/// to spend`amount`.
@@ -780,40 +2962,105 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		destination: &T::AccountId,
 		amount: T::Balance,
 	) -> DispatchResult {
-		let mut owner_died: Option<DeadConsequence> = None;
-
-		Approvals::<T, I>::try_mutate_exists(
-			(id, &owner, delegate),
-			|maybe_approved| -> DispatchResult {
-				let mut approved = maybe_approved.take().ok_or(Error::<T, I>::Unapproved)?;
-				let remaining =
-					approved.amount.checked_sub(&amount).ok_or(Error::<T, I>::Unapproved)?;
+		let mut approved =
+			Approvals::<T, I>::get((id, &owner, delegate)).ok_or(Error::<T, I>::Unapproved)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(!approved.is_expired(&now), Error::<T, I>::ApprovalExpired);
+		let remaining = approved.amount.checked_sub(&amount).ok_or(Error::<T, I>::Unapproved)?;
 
-				let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
-				owner_died = Self::transfer_and_die(id, owner, destination, amount, None, f)?.1;
+		// The details are read and written once, also when the approval is used up.
+		let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+		let owner_died =
+			Self::transfer_and_die_with(id, &mut details, owner, destination, amount, None, f)?.1;
 
-				if remaining.is_zero() {
-					T::Currency::unreserve(owner, approved.deposit);
-					Asset::<T, I>::mutate(id, |maybe_details| {
-						if let Some(details) = maybe_details {
-							details.approvals.saturating_dec();
-						}
-					});
-				} else {
-					approved.amount = remaining;
-					*maybe_approved = Some(approved);
-				}
-				Ok(())
-			},
-		)?;
+		if remaining.is_zero() {
+			Self::take_approval(id, owner, delegate, &mut details);
+		} else {
+			approved.amount = remaining;
+			Approvals::<T, I>::insert((id, &owner, delegate), approved);
+		}
+		Asset::<T, I>::insert(id, details);
 
 		// Execute hook outside of `mutate`.
 		if let Some(Remove) = owner_died {
-			T::Freezer::died(id, owner);
+			Self::account_died(id, owner);
 		}
 		Ok(())
 	}
 
+	/// Remove the expired approval of `owner` to `delegate` for asset `id`, rewarding `who`
+	/// and unreserving the rest of its deposit.
+	///
+	/// Emits `ApprovalCancelled`.
+	pub(super) fn do_clean_expired_approval(
+		id: AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		let approval =
+			Approvals::<T, I>::get((id, owner, delegate)).ok_or(Error::<T, I>::Unapproved)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(approval.is_expired(&now), Error::<T, I>::ApprovalNotExpired);
+
+		let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		Self::reward_approval_cleanup(id, owner, delegate, who);
+		Self::take_approval(id, owner, delegate, &mut d);
+		Asset::<T, I>::insert(id, d);
+		Self::deposit_event(Event::ApprovalCancelled {
+			asset_id: id,
+			owner: owner.clone(),
+			delegate: delegate.clone(),
+		});
+		Ok(())
+	}
+
+	/// Cancel up to `limit` approvals of asset `id` given by `owner` who has no asset-account of
+	/// `id`, rewarding `who` and unreserving the rest of their deposits.
+	///
+	/// Returns the number of cancelled approvals. Fails with `Unapproved` if there is nothing to
+	/// cancel.
+	pub(super) fn do_gc_approvals(
+		id: AssetId,
+		owner: &T::AccountId,
+		limit: u32,
+		who: &T::AccountId,
+	) -> Result<u32, DispatchError> {
+		ensure!(!Account::<T, I>::contains_key(id, owner), Error::<T, I>::OwnerAlive);
+		if frame_system::Pallet::<T>::providers(owner) > 0 {
+			let since =
+				OrphanedApprovalsSince::<T, I>::get(id, owner).ok_or(Error::<T, I>::OwnerAlive)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= since.saturating_add(T::ApprovalGcGracePeriod::get()),
+				Error::<T, I>::GracePeriodNotPassed
+			);
+		}
+		let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+
+		let delegates: Vec<T::AccountId> = Approvals::<T, I>::iter_key_prefix((id, owner))
+			.take(limit as usize)
+			.collect();
+		ensure!(!delegates.is_empty(), Error::<T, I>::Unapproved);
+
+		for delegate in &delegates {
+			Self::reward_approval_cleanup(id, owner, delegate, who);
+			if Self::take_approval(id, owner, delegate, &mut d).is_some() {
+				Self::deposit_event(Event::ApprovalCancelled {
+					asset_id: id,
+					owner: owner.clone(),
+					delegate: delegate.clone(),
+				});
+			}
+		}
+		if Approvals::<T, I>::iter_key_prefix((id, owner)).next().is_none() {
+			OrphanedApprovalsSince::<T, I>::remove(id, owner);
+		}
+		Asset::<T, I>::insert(id, d);
+		Ok(delegates.len() as u32)
+	}
+
 	/// Do set metadata
 	pub(super) fn do_set_metadata(
 		id: AssetId,
@@ -828,8 +3075,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			symbol.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
 		let bounded_url: BoundedVec<u8, T::StringLimit> =
 			"".as_bytes().to_vec().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
-		let bounded_data_ipfs: BoundedVec<u8, T::StringLimit> =
-			"".as_bytes().to_vec().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+		T::MetadataValidator::validate_metadata(&name, &symbol)
+			.map_err(Error::<T, I>::MetadataRejected)?;
 
 		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(from == &d.owner, Error::<T, I>::NoPermission);
@@ -837,35 +3084,368 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Metadata::<T, I>::try_mutate_exists(id, |metadata| {
 			ensure!(metadata.as_ref().map_or(true, |m| !m.is_frozen), Error::<T, I>::NoPermission);
 
-			let old_deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
-			let new_deposit = T::MetadataDepositPerByte::get()
-				.saturating_mul(((name.len() + symbol.len()) as u32).into())
-				.saturating_add(T::MetadataDepositBase::get());
+			let old = metadata.take();
+			let old_deposit = old.as_ref().map_or(Zero::zero(), |m| m.deposit);
+			Self::note_symbol(id, old.as_ref().map(|m| &m.symbol[..]), Some(&symbol));
+			let new_deposit = Self::metadata_deposit((name.len() + symbol.len()) as u32);
+
+			if new_deposit > old_deposit {
+				T::Deposits::hold(from, new_deposit - old_deposit)?;
+			} else {
+				T::Deposits::release(from, old_deposit - new_deposit);
+			}
+			Self::note_deposit(Some(id), DepositPurpose::Metadata, old_deposit, new_deposit);
+			LegacyDataIpfs::<T, I>::remove(id);
+
+			*metadata = Some(AssetMetadata {
+				deposit: new_deposit,
+				url: bounded_url,
+				data_ipfs: None,
+				name: bounded_name,
+				symbol: bounded_symbol,
+				decimals,
+				is_frozen: false,
+			});
+
+			Self::deposit_event(Event::MetadataSet {
+				asset_id: id,
+				name,
+				symbol,
+				decimals,
+				is_frozen: false,
+			});
+			Ok(())
+		})
+	}
+
+	/// Ensure that `who` is the owner of the asset `id` with `details` or its custodian.
+	fn ensure_owner_or_custodian(
+		id: AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		if &details.owner == who {
+			return Ok(())
+		}
+		Self::ensure_asset_custodian(id, details, who)
+	}
+
+	/// Set the `splits` of every `transfer_with_splits` of asset `id` by its custodian `from`.
+	pub(super) fn do_set_asset_splits(
+		id: AssetId,
+		from: &T::AccountId,
+		splits: Vec<(T::AccountId, Perbill)>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		Self::ensure_asset_custodian(id, &details, from)?;
+
+		let bounded: BoundedVec<_, T::MaxSplits> =
+			splits.clone().try_into().map_err(|_| Error::<T, I>::BadSplits)?;
+		let total = splits
+			.iter()
+			.try_fold(0u32, |total, (_, share)| total.checked_add(share.deconstruct()));
+		ensure!(
+			total.map_or(false, |total| total <= Perbill::one().deconstruct()),
+			Error::<T, I>::BadSplits
+		);
+		for (i, (recipient, _)) in splits.iter().enumerate() {
+			ensure!(
+				!splits[..i].iter().any(|(other, _)| other == recipient),
+				Error::<T, I>::BadSplits
+			);
+		}
+
+		if bounded.is_empty() {
+			AssetSplits::<T, I>::remove(id);
+		} else {
+			AssetSplits::<T, I>::insert(id, bounded);
+		}
+		Self::deposit_event(Event::AssetSplitsSet { asset_id: id, splits });
+		Ok(())
+	}
+
+	/// Transfer `amount` of asset `id` from `source`, paying the `AssetSplits` of the asset and
+	/// the rest to `dest`. The `splits` agreed by `source` must match the `AssetSplits`.
+	pub(super) fn do_transfer_with_splits(
+		id: AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		splits: Vec<(T::AccountId, Perbill)>,
+	) -> DispatchResult {
+		let configured = AssetSplits::<T, I>::get(id);
+		ensure!(configured[..] == splits[..], Error::<T, I>::SplitsMismatch);
+
+		// The details are read and written once for all the payments.
+		let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+		let mut rest = amount;
+		let mut paid = Vec::with_capacity(configured.len());
+		let mut source_died = None;
+		for (recipient, share) in configured.into_iter() {
+			let part = share.mul_floor(amount);
+			if !part.is_zero() {
+				source_died = Self::transfer_and_die_with(
+					id,
+					&mut details,
+					source,
+					&recipient,
+					part,
+					None,
+					f,
+				)?
+				.1;
+				rest = rest.saturating_sub(part);
+			}
+			paid.push((recipient, part));
+		}
+		if !rest.is_zero() {
+			source_died =
+				Self::transfer_and_die_with(id, &mut details, source, dest, rest, None, f)?.1;
+		}
+		Asset::<T, I>::insert(id, details);
+		// Execute hook outside of the transfers.
+		if let Some(Remove) = source_died {
+			Self::account_died(id, source);
+		}
+
+		Self::deposit_event(Event::TransferredWithSplits {
+			asset_id: id,
+			from: source.clone(),
+			to: dest.clone(),
+			amount: rest,
+			splits: paid,
+		});
+		Ok(())
+	}
+
+	/// Make the asset `id` reference the registered `methodology`. Only possible before the
+	/// first mint.
+	pub(super) fn do_set_asset_methodology(
+		id: AssetId,
+		from: &T::AccountId,
+		methodology: u32,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		Self::ensure_owner_or_custodian(id, &details, from)?;
+		ensure!(details.supply.is_zero(), Error::<T, I>::CannotChangeAfterMint);
+		ensure!(
+			Methodologies::<T, I>::contains_key(methodology),
+			Error::<T, I>::UnknownMethodology
+		);
+
+		if let Some(old) = AssetMethodology::<T, I>::get(id) {
+			MethodologyAssets::<T, I>::remove(old, id);
+		}
+		AssetMethodology::<T, I>::insert(id, methodology);
+		MethodologyAssets::<T, I>::insert(methodology, id, ());
+		Self::deposit_event(Event::AssetMethodologySet { asset_id: id, methodology });
+		Ok(())
+	}
+
+	/// Attest the co-benefit `badges` of asset `id` by the `verifier`, with the optional IPFS
+	/// CID of the `evidence`.
+	///
+	/// Emits `AssetBadgesSet`.
+	pub(super) fn do_set_asset_badges(
+		id: AssetId,
+		verifier: T::AccountId,
+		badges: u32,
+		evidence: Option<Vec<u8>>,
+	) -> DispatchResult {
+		ensure!(T::Verifiers::contains(&verifier), Error::<T, I>::NotVerifier);
+		ensure!(Asset::<T, I>::contains_key(id), Error::<T, I>::Unknown);
+		ensure!(badges & !KNOWN_BADGES == 0, Error::<T, I>::BadBadges);
+		let evidence: Option<BoundedVec<u8, T::StringLimit>> = evidence
+			.map(|cid| {
+				ensure!(crate::cid::is_valid(&cid), Error::<T, I>::BadCid);
+				cid.try_into().map_err(|_| Error::<T, I>::BadMetadata)
+			})
+			.transpose()?;
+
+		if badges == 0 {
+			AssetBadges::<T, I>::remove(id);
+		} else {
+			let attested = CoBenefitBadges { badges, evidence, verifier: verifier.clone() };
+			AssetBadges::<T, I>::insert(id, attested);
+		}
+		Self::deposit_event(Event::AssetBadgesSet { asset_id: id, badges, verifier });
+		Ok(())
+	}
+
+	/// Get the co-benefit badges of asset `id` with the evidence CID as bytes.
+	pub fn attested_badges(id: AssetId) -> Option<CoBenefitBadges<T::AccountId, Vec<u8>>> {
+		AssetBadges::<T, I>::get(id).map(|attested| CoBenefitBadges {
+			badges: attested.badges,
+			evidence: attested.evidence.map(|cid| cid.into_inner()),
+			verifier: attested.verifier,
+		})
+	}
+
+	/// Get the assets having all the co-benefit `badges`.
+	///
+	/// Iterates over all assets with badges, so it is meant for runtime API queries only.
+	pub fn assets_with_badges(badges: u32) -> Vec<AssetId> {
+		AssetBadges::<T, I>::iter()
+			.filter(|(_, attested)| attested.badges & badges == badges)
+			.map(|(id, _)| id)
+			.collect()
+	}
+
+	/// Get the witness `destroy` of asset `id` would accept at the current block, with the weight
+	/// of the call and its weight fee under `W`.
+	///
+	/// Meant for runtime API queries: the witness goes stale as soon as the asset accounts or
+	/// approvals change, so freezing the asset before querying it is advised on busy assets.
+	pub fn destroy_preview<W: WeightToFee>(id: AssetId) -> Option<DestroyPreview<W::Balance>> {
+		let witness = Asset::<T, I>::get(id)?.destroy_witness();
+		let weight = T::WeightInfo::destroy(
+			witness.accounts.saturating_sub(witness.sufficients),
+			witness.sufficients,
+			witness.approvals,
+		);
+		Some(DestroyPreview { witness, weight, fee: W::weight_to_fee(&weight) })
+	}
+
+	/// Get the assets referencing the `methodology`.
+	pub fn methodology_assets(methodology: u32) -> Vec<AssetId> {
+		MethodologyAssets::<T, I>::iter_key_prefix(methodology).collect()
+	}
+
+	/// Set the attribute `key` of asset `id` to `value`, reserving the deposit from `from`.
+	///
+	/// The deposit of the previous value is returned to its depositor.
+	pub(super) fn do_set_attribute(
+		id: AssetId,
+		from: &T::AccountId,
+		key: Vec<u8>,
+		value: Vec<u8>,
+	) -> DispatchResult {
+		let bounded_key: BoundedVec<u8, T::AttributeKeyLimit> =
+			key.clone().try_into().map_err(|_| Error::<T, I>::BadAttribute)?;
+		let bounded_value: BoundedVec<u8, T::AttributeValueLimit> =
+			value.clone().try_into().map_err(|_| Error::<T, I>::BadAttribute)?;
+
+		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		Self::ensure_owner_or_custodian(id, &d, from)?;
+
+		let deposit = Self::attribute_deposit((key.len() + value.len()) as u32);
+
+		match Attributes::<T, I>::get(id, &bounded_key) {
+			Some(old) if &old.depositor == from => {
+				if deposit > old.deposit {
+					T::Deposits::hold(from, deposit - old.deposit)?;
+				} else {
+					T::Deposits::release(from, old.deposit - deposit);
+				}
+				Self::note_deposit(Some(id), DepositPurpose::Attribute, old.deposit, deposit);
+			},
+			maybe_old => {
+				T::Deposits::hold(from, deposit)?;
+				let old_deposit = maybe_old.map_or(Zero::zero(), |old| {
+					T::Deposits::release(&old.depositor, old.deposit);
+					old.deposit
+				});
+				Self::note_deposit(Some(id), DepositPurpose::Attribute, old_deposit, deposit);
+			},
+		}
+
+		Attributes::<T, I>::insert(
+			id,
+			&bounded_key,
+			AttributeValue { value: bounded_value, depositor: from.clone(), deposit },
+		);
+		Self::deposit_event(Event::AttributeSet { asset_id: id, key, value });
+		Ok(())
+	}
+
+	/// Clear the attribute `key` of asset `id`, returning the deposit to its depositor.
+	pub(super) fn do_clear_attribute(
+		id: AssetId,
+		from: &T::AccountId,
+		key: Vec<u8>,
+	) -> DispatchResult {
+		let bounded_key: BoundedVec<u8, T::AttributeKeyLimit> =
+			key.clone().try_into().map_err(|_| Error::<T, I>::BadAttribute)?;
+
+		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		Self::ensure_owner_or_custodian(id, &d, from)?;
+
+		let old = Attributes::<T, I>::take(id, &bounded_key).ok_or(Error::<T, I>::NoAttribute)?;
+		T::Deposits::release(&old.depositor, old.deposit);
+		Self::note_deposit(Some(id), DepositPurpose::Attribute, old.deposit, Zero::zero());
+		Self::deposit_event(Event::AttributeCleared { asset_id: id, key });
+		Ok(())
+	}
+
+	/// Set the project data and the project `details` of asset `id` sent by `from`, or queue
+	/// them if `from` is the owner under dual control. The details are queued as a separate
+	/// owner action.
+	pub(super) fn do_set_project_data(
+		id: AssetId,
+		from: &T::AccountId,
+		url: Vec<u8>,
+		data_ipfs: Vec<u8>,
+		details: Option<ProjectDetails<Vec<u8>>>,
+	) -> DispatchResult {
+		let details = details.map(Self::bound_project_details).transpose()?;
+		if Self::needs_co_signing(id, from) {
+			let url = url.try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+			let data_ipfs: BoundedVec<u8, T::StringLimit> =
+				data_ipfs.try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+			ensure!(cid::is_valid(&data_ipfs), Error::<T, I>::BadMetadata);
+			T::MetadataValidator::validate_project_data(&url, &data_ipfs)
+				.map_err(Error::<T, I>::MetadataRejected)?;
+			Self::queue_owner_action(id, OwnerAction::SetProjectData { url, data_ipfs })?;
+			if let Some(details) = details {
+				Self::queue_owner_action(id, OwnerAction::SetProjectDetails(details))?;
+			}
+			return Ok(())
+		}
+		Self::update_metadata(id, from, url, data_ipfs)?;
+		if let Some(details) = details {
+			Self::note_project_details(id, details)?;
+		}
+		Ok(())
+	}
+
+	/// Execute the owner-level `calls` of asset `id` sent by `from` in order, as if sent one by
+	/// one. The extrinsic is transactional, so if a call fails, none of them is applied.
+	pub(super) fn do_owner_batch(
+		id: AssetId,
+		from: &T::AccountId,
+		calls: BoundedVec<OwnerCall, T::MaxOwnerBatch>,
+	) -> DispatchResult {
+		ensure!(!calls.is_empty(), Error::<T, I>::BadOwnerBatch);
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		Self::ensure_owner_or_custodian(id, &details, from)?;
 
-			if new_deposit > old_deposit {
-				T::Currency::reserve(from, new_deposit - old_deposit)?;
-			} else {
-				T::Currency::unreserve(from, old_deposit - new_deposit);
+		let count = calls.len() as u32;
+		for call in calls {
+			match call {
+				OwnerCall::SetProjectData { url, data_ipfs, details } =>
+					Self::do_set_project_data(id, from, url, data_ipfs, details)?,
+				OwnerCall::SetAttribute { key, value } =>
+					Self::do_set_attribute(id, from, key, value)?,
+				OwnerCall::ClearAttribute { key } => Self::do_clear_attribute(id, from, key)?,
+				OwnerCall::SetMethodology(methodology) =>
+					Self::do_set_asset_methodology(id, from, methodology)?,
 			}
+		}
+		Self::deposit_event(Event::OwnerBatchExecuted { asset_id: id, calls: count });
+		Ok(())
+	}
 
-			*metadata = Some(AssetMetadata {
-				deposit: new_deposit,
-				url: bounded_url,
-				data_ipfs: bounded_data_ipfs,
-				name: bounded_name,
-				symbol: bounded_symbol,
-				decimals,
-				is_frozen: false,
-			});
-
-			Self::deposit_event(Event::MetadataSet {
-				asset_id: id,
-				name,
-				symbol,
-				decimals,
-				is_frozen: false,
-			});
-			Ok(())
+	/// The weight of the owner-level `calls` of an `owner_batch`, without the overhead of the
+	/// batch itself.
+	pub fn owner_calls_weight(calls: &[OwnerCall]) -> Weight {
+		calls.iter().fold(Weight::zero(), |total, call| {
+			total.saturating_add(match call {
+				OwnerCall::SetProjectData { .. } => T::WeightInfo::set_project_data(),
+				OwnerCall::SetAttribute { .. } => T::WeightInfo::set_attribute(),
+				OwnerCall::ClearAttribute { .. } => T::WeightInfo::clear_attribute(),
+				OwnerCall::SetMethodology(_) => T::WeightInfo::set_asset_methodology(),
+			})
 		})
 	}
 
@@ -878,8 +3458,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> DispatchResult {
 		let bounded_url: BoundedVec<u8, T::StringLimit> =
 			url.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
-		let bounded_data_ipfs: BoundedVec<u8, T::StringLimit> =
-			data_ipfs.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+		let cid = cid::Cid::parse(&data_ipfs).ok_or(Error::<T, I>::BadMetadata)?;
+		T::MetadataValidator::validate_project_data(&url, &data_ipfs)
+			.map_err(Error::<T, I>::MetadataRejected)?;
 
 		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(from == &d.owner || from == &d.admin, Error::<T, I>::NoPermission);
@@ -891,20 +3472,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 			let meta = metadata.take().unwrap_or_default();
 			let old_deposit = meta.deposit;
-			let new_deposit = T::MetadataDepositPerByte::get()
-				.saturating_mul(((url.len() + data_ipfs.len()) as u32).into())
-				.saturating_add(T::MetadataDepositBase::get());
+			let new_deposit = Self::metadata_deposit((url.len() + data_ipfs.len()) as u32);
 
 			if new_deposit > old_deposit {
-				T::Currency::reserve(from, new_deposit - old_deposit)?;
+				T::Deposits::hold(from, new_deposit - old_deposit)?;
 			} else {
-				T::Currency::unreserve(from, old_deposit - new_deposit);
+				T::Deposits::release(from, old_deposit - new_deposit);
 			}
+			Self::note_deposit(Some(id), DepositPurpose::Metadata, old_deposit, new_deposit);
 
+			if from != &d.owner {
+				Self::note_custodian_action(CustodianAction::MetadataUpdate, id, None, Zero::zero());
+			}
+			LegacyDataIpfs::<T, I>::remove(id);
 			*metadata = Some(AssetMetadata {
 					deposit: new_deposit,
 					url: bounded_url,
-					data_ipfs: bounded_data_ipfs,
+					data_ipfs: Some(cid),
 					name: meta.name.clone(),
 					symbol: meta.symbol.clone(),
 					decimals: meta.decimals,
@@ -920,8 +3504,678 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})	
 	}
 
+	/// Check the strings and the country code of the project `details` and bound them.
+	pub(super) fn bound_project_details(
+		details: ProjectDetails<Vec<u8>>,
+	) -> Result<ProjectDetails<BoundedVec<u8, T::StringLimit>>, DispatchError> {
+		ensure!(
+			details.country.iter().all(u8::is_ascii_uppercase) && !details.project_id.is_empty(),
+			Error::<T, I>::BadProjectDetails
+		);
+		Ok(ProjectDetails {
+			standard: details.standard,
+			methodology_code: details
+				.methodology_code
+				.try_into()
+				.map_err(|_| Error::<T, I>::BadMetadata)?,
+			vintage: details.vintage,
+			country: details.country,
+			project_id: details.project_id.try_into().map_err(|_| Error::<T, I>::BadMetadata)?,
+		})
+	}
+
+	/// Store the project `details` of asset `id`. Only possible before the first mint, and the
+	/// vintage has to match the vintage of the asset, if any.
+	///
+	/// Emits `ProjectDetailsSet`.
+	pub(super) fn note_project_details(
+		id: AssetId,
+		details: ProjectDetails<BoundedVec<u8, T::StringLimit>>,
+	) -> DispatchResult {
+		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(d.supply.is_zero(), Error::<T, I>::CannotChangeAfterMint);
+		ensure!(
+			AssetVintage::<T, I>::get(id).map_or(true, |vintage| vintage == details.vintage),
+			Error::<T, I>::BadProjectDetails
+		);
+		ProjectDetailsOf::<T, I>::insert(id, details);
+		Self::deposit_event(Event::ProjectDetailsSet { asset_id: id });
+		Ok(())
+	}
+
+	/// Change the owner of the asset `id` from `from` to `owner`, moving the deposits. Ends the
+	/// dual control of the previous owner.
+	pub(super) fn do_transfer_ownership(
+		id: AssetId,
+		from: &T::AccountId,
+		owner: T::AccountId,
+	) -> DispatchResult {
+		Asset::<T, I>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(from == &details.owner, Error::<T, I>::NoPermission);
+			if details.owner == owner {
+				return Ok(())
+			}
+
+			let metadata_deposit = Metadata::<T, I>::get(id).deposit;
+			let deposit = details.deposit + metadata_deposit;
+
+			// Move the deposit to the new owner.
+			T::Deposits::transfer_held(&details.owner, &owner, deposit)?;
+
+			Self::note_owner(id, Some(&details.owner), Some(&owner));
+			details.owner = owner.clone();
+			PendingOwner::<T, I>::remove(id);
+			if CoOwner::<T, I>::take(id).is_some() {
+				PendingOwnerActions::<T, I>::remove(id);
+				Self::deposit_event(Event::CoOwnerSet { asset_id: id, co_owner: None });
+			}
+
+			Self::deposit_event(Event::OwnerChanged { asset_id: id, owner });
+			Ok(())
+		})
+	}
+
+	/// Propose `owner` as the owner of the asset `id` owned by `from`, for
+	/// `OwnershipAcceptancePeriod` blocks. Proposing the current owner withdraws the proposal.
+	pub(super) fn do_propose_owner(
+		id: AssetId,
+		from: &T::AccountId,
+		owner: T::AccountId,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(from == &details.owner, Error::<T, I>::NoPermission);
+		if details.owner == owner {
+			if PendingOwner::<T, I>::take(id).is_some() {
+				Self::deposit_event(Event::OwnershipProposalCancelled { asset_id: id });
+			}
+			return Ok(())
+		}
+
+		let expires_at = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::OwnershipAcceptancePeriod::get());
+		PendingOwner::<T, I>::insert(id, (owner.clone(), expires_at));
+		Self::deposit_event(Event::OwnershipProposed { asset_id: id, owner, expires_at });
+		Ok(())
+	}
+
+	/// Make the proposed owner `who` the owner of the asset `id`, if the proposal has not
+	/// expired.
+	pub(super) fn do_accept_ownership(id: AssetId, who: T::AccountId) -> DispatchResult {
+		let (owner, expires_at) =
+			PendingOwner::<T, I>::get(id).ok_or(Error::<T, I>::NotProposedOwner)?;
+		ensure!(owner == who, Error::<T, I>::NotProposedOwner);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= expires_at,
+			Error::<T, I>::OwnershipProposalExpired
+		);
+		let from = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?.owner;
+		Self::do_transfer_ownership(id, &from, owner)
+	}
+
+	/// Whether the action of `who` on the asset `id` waits for the co-owner, i.e. the asset is
+	/// under dual control and `who` is its owner.
+	pub(super) fn needs_co_signing(id: AssetId, who: &T::AccountId) -> bool {
+		CoOwner::<T, I>::contains_key(id) &&
+			Asset::<T, I>::get(id).map_or(false, |details| &details.owner == who)
+	}
+
+	/// Set or remove the co-owner of the asset `id`. Pending owner actions are dropped when the
+	/// dual control ends.
+	pub(super) fn do_set_co_owner(id: AssetId, co_owner: Option<T::AccountId>) {
+		match &co_owner {
+			Some(co_owner) => CoOwner::<T, I>::insert(id, co_owner),
+			None => {
+				CoOwner::<T, I>::remove(id);
+				PendingOwnerActions::<T, I>::remove(id);
+			},
+		}
+		Self::deposit_event(Event::CoOwnerSet { asset_id: id, co_owner });
+	}
+
+	/// Queue the owner `action` of the asset `id` for the confirmation by the co-owner.
+	pub(super) fn queue_owner_action(
+		id: AssetId,
+		action: OwnerAction<T::AccountId, BoundedVec<u8, T::StringLimit>>,
+	) -> DispatchResult {
+		let action_id = NextOwnerActionId::<T, I>::get();
+		PendingOwnerActions::<T, I>::try_mutate(id, |pending| {
+			pending
+				.try_push(PendingOwnerAction { id: action_id, action })
+				.map_err(|_| Error::<T, I>::TooManyPendingActions)
+		})?;
+		NextOwnerActionId::<T, I>::put(action_id.wrapping_add(1));
+		Self::deposit_event(Event::OwnerActionProposed { asset_id: id, action_id });
+		Ok(())
+	}
+
+	/// Remove the pending owner action `action_id` of the asset `id` from the queue.
+	pub(super) fn take_owner_action(
+		id: AssetId,
+		action_id: u32,
+	) -> Result<OwnerAction<T::AccountId, BoundedVec<u8, T::StringLimit>>, DispatchError> {
+		PendingOwnerActions::<T, I>::try_mutate(id, |pending| {
+			let index = pending
+				.iter()
+				.position(|p| p.id == action_id)
+				.ok_or(Error::<T, I>::UnknownOwnerAction)?;
+			Ok(pending.remove(index).action)
+		})
+	}
+
+	/// Perform the pending owner action `action_id` of the asset `id` on behalf of its owner.
+	pub(super) fn do_confirm_owner_action(
+		id: AssetId,
+		action_id: u32,
+		witness: Option<DestroyWitness>,
+	) -> DispatchResult {
+		let action = Self::take_owner_action(id, action_id)?;
+		let owner = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?.owner;
+		match action {
+			OwnerAction::TransferOwnership(new_owner) =>
+				Self::do_propose_owner(id, &owner, new_owner)?,
+			OwnerAction::SetProjectData { url, data_ipfs } =>
+				Self::update_metadata(id, &owner, url.into_inner(), data_ipfs.into_inner())?,
+			OwnerAction::Destroy => {
+				let witness = witness.ok_or(Error::<T, I>::BadWitness)?;
+				Self::do_destroy(id, witness, Some(owner))?;
+			},
+			OwnerAction::SetCoOwner(co_owner) => Self::do_set_co_owner(id, co_owner),
+			OwnerAction::SetProjectDetails(details) => Self::note_project_details(id, details)?,
+			OwnerAction::StartDestroy => Self::do_start_destroy(id, Some(owner))?,
+		}
+		Self::deposit_event(Event::OwnerActionConfirmed { asset_id: id, action_id });
+		Ok(())
+	}
+
 	// Additional logic
 
+	/// Pass `amount` of asset `id`, already removed from the supply, to `OnDustRemoval` as a
+	/// credit. The credit is burned again if the handler drops it.
+	pub(super) fn handle_dust(id: AssetId, amount: T::Balance) {
+		if !amount.is_zero() {
+			let credit = <Self as fungibles::Balanced<T::AccountId>>::issue(id, amount);
+			T::OnDustRemoval::on_unbalanced(credit);
+		}
+	}
+
+	/// Update the operation counters of `who` with `f`, if `AccountStats` is enabled.
+	pub(super) fn note_activity(who: &T::AccountId, f: impl FnOnce(&mut AccountActivity)) {
+		if T::AccountStats::get() {
+			AccountActivityOf::<T, I>::mutate(who, f);
+		}
+	}
+
+	/// The event topic of asset `id`: `Hashing` of the SCALE-encoded id.
+	pub fn asset_topic(id: AssetId) -> T::Hash {
+		<T::Hashing as sp_runtime::traits::Hash>::hash_of(&id)
+	}
+
+	/// Deposit `event` of asset `id` with the `asset_topic` of the asset, so light clients and
+	/// explorers can filter the events of an asset by topic.
+	pub(super) fn deposit_event_indexed(id: AssetId, event: Event<T, I>) {
+		let event: <T as Config<I>>::RuntimeEvent = event.into();
+		frame_system::Pallet::<T>::deposit_event_indexed(&[Self::asset_topic(id)], event.into());
+	}
+
+	/// Report `amount` of asset `id` moved from `debit` to `credit` to `OnCarbonTransfer` and
+	/// emit a `LedgerEntry`, if `LedgerEvents` are enabled.
+	pub(super) fn note_ledger_entry(
+		op: LedgerOp,
+		id: AssetId,
+		debit: Option<&T::AccountId>,
+		credit: Option<&T::AccountId>,
+		amount: T::Balance,
+	) {
+		if amount.is_zero() {
+			return
+		}
+		T::OnCarbonTransfer::on_transfer(id, debit, credit, amount);
+		if T::LedgerEvents::get() {
+			Self::deposit_event(Event::LedgerEntry {
+				op,
+				asset_id: id,
+				debit: debit.cloned(),
+				credit: credit.cloned(),
+				amount,
+			});
+		}
+	}
+
+	/// Move the project of asset `id` from `Draft` to `Submitted` on behalf of its `owner`.
+	///
+	/// Emits `ProjectStatusChanged`.
+	pub(super) fn do_submit_project(id: AssetId, owner: &T::AccountId) -> DispatchResult {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(&details.owner == owner, Error::<T, I>::NoPermission);
+		ensure!(
+			ProjectStatusOf::<T, I>::get(id) == ProjectStatus::Draft,
+			Error::<T, I>::BadProjectStatus
+		);
+		Self::set_project_status(id, ProjectStatus::Submitted, owner);
+		Ok(())
+	}
+
+	/// Approve the project of asset `id` by `who`: `Submitted` projects by an auditor and
+	/// `AuditorApproved` ones by the custodian of the asset.
+	///
+	/// Emits `ProjectStatusChanged`.
+	pub(super) fn do_approve_project(id: AssetId, who: &T::AccountId) -> DispatchResult {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		let status = match ProjectStatusOf::<T, I>::get(id) {
+			ProjectStatus::Submitted => {
+				ensure!(T::Auditors::contains(who), Error::<T, I>::NoPermission);
+				ProjectStatus::AuditorApproved
+			},
+			ProjectStatus::AuditorApproved => {
+				Self::ensure_asset_custodian(id, &details, who)?;
+				ProjectStatus::CustodianApproved
+			},
+			_ => return Err(Error::<T, I>::BadProjectStatus.into()),
+		};
+		Self::set_project_status(id, status, who);
+		Ok(())
+	}
+
+	fn set_project_status(id: AssetId, status: ProjectStatus, by: &T::AccountId) {
+		ProjectStatusOf::<T, I>::insert(id, status);
+		Self::deposit_event(Event::ProjectStatusChanged { asset_id: id, status, by: by.clone() });
+	}
+
+	/// Ensure that `who` is the custodian.
+	pub(super) fn ensure_custodian(who: &T::AccountId) -> DispatchResult {
+		let custodian = Custodian::<T, I>::get().ok_or(Error::<T, I>::NoCustodian)?;
+		ensure!(who == &custodian, Error::<T, I>::NoPermission);
+		Ok(())
+	}
+
+	/// Ensure that `origin` is the ForceOrigin or Signed by the custodian.
+	pub(super) fn ensure_force_or_custodian(origin: T::RuntimeOrigin) -> DispatchResult {
+		T::ForceOrigin::try_origin(origin).map(|_| ()).or_else(|origin| -> DispatchResult {
+			Self::ensure_custodian(&frame_system::ensure_signed(origin)?)
+		})
+	}
+
+	/// Ensure that none of the operation classes `ops` is `Paused`.
+	pub(super) fn ensure_not_paused(ops: u8) -> DispatchResult {
+		ensure!(Paused::<T, I>::get() & ops == 0, Error::<T, I>::OperationPaused);
+		Ok(())
+	}
+
+	/// Get the custodian of the asset `id` with `details`: the custodian assigned to the asset,
+	/// if any, else the global custodian if `CustodianRequired` is set, otherwise the issuer
+	/// the asset was created with.
+	pub(super) fn custodian_of(
+		id: AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+	) -> Result<T::AccountId, DispatchError> {
+		if let Some(custodian) = Custodians::<T, I>::get(id) {
+			Ok(custodian)
+		} else if T::CustodianRequired::get() {
+			Ok(Custodian::<T, I>::get().ok_or(Error::<T, I>::NoCustodian)?)
+		} else {
+			Ok(details.issuer.clone())
+		}
+	}
+
+	/// Propose `custodian` as the new custodian from `CustodianChangeDelay` blocks on.
+	pub(super) fn do_propose_custodian(custodian: T::AccountId) {
+		let activates_at =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::CustodianChangeDelay::get());
+		PendingCustodian::<T, I>::put((custodian.clone(), activates_at));
+		Self::deposit_event(Event::CustodianProposed { custodian, activates_at });
+	}
+
+	/// Make the proposed custodian `who` the custodian, once the delay has passed.
+	pub(super) fn do_accept_custodian(who: T::AccountId) -> DispatchResult {
+		let (custodian, activates_at) =
+			PendingCustodian::<T, I>::get().ok_or(Error::<T, I>::NotProposedCustodian)?;
+		ensure!(custodian == who, Error::<T, I>::NotProposedCustodian);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= activates_at,
+			Error::<T, I>::CustodianChangeNotDue
+		);
+		PendingCustodian::<T, I>::kill();
+		Custodian::<T, I>::put(&custodian);
+		Self::deposit_event(Event::CustodianSet { custodian });
+		Ok(())
+	}
+
+	/// Record the custodian `action` on asset `id` in the `CustodianLog`, pruning the oldest
+	/// entry if the log is full. Does nothing if `MaxCustodianLog` is zero.
+	pub(super) fn note_custodian_action(
+		action: CustodianAction,
+		id: AssetId,
+		account: Option<&T::AccountId>,
+		amount: T::Balance,
+	) {
+		let max = T::MaxCustodianLog::get() as u64;
+		if max == 0 {
+			return
+		}
+		let head = CustodianLogHead::<T, I>::get();
+		let tail = CustodianLogTail::<T, I>::get();
+		if tail.saturating_sub(head) >= max {
+			CustodianLog::<T, I>::remove(head);
+			CustodianLogHead::<T, I>::put(head.saturating_add(1));
+		}
+		let entry = CustodianLogEntry {
+			action,
+			asset_id: id,
+			account: account.cloned(),
+			amount,
+			block_number: frame_system::Pallet::<T>::block_number(),
+		};
+		CustodianLog::<T, I>::insert(tail, entry);
+		CustodianLogTail::<T, I>::put(tail.saturating_add(1));
+	}
+
+	/// Get the entries of the `CustodianLog` from the oldest one, with their ids.
+	pub fn custodian_log() -> Vec<(u64, CustodianLogEntry<T::AccountId, T::Balance, T::BlockNumber>)>
+	{
+		let tail = CustodianLogTail::<T, I>::get();
+		(CustodianLogHead::<T, I>::get()..tail)
+			.filter_map(|id| CustodianLog::<T, I>::get(id).map(|entry| (id, entry)))
+			.collect()
+	}
+
+	/// Ensure that `who` is the custodian of the asset `id` with `details`.
+	pub(super) fn ensure_asset_custodian(
+		id: AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		ensure!(&Self::custodian_of(id, details)? == who, Error::<T, I>::NotAssetCustodian);
+		Ok(())
+	}
+
+	/// Ensure that `who` is the custodian of the asset `id`, or the global custodian if the
+	/// asset does not exist (anymore).
+	pub(super) fn ensure_custodian_of(id: AssetId, who: &T::AccountId) -> DispatchResult {
+		match Asset::<T, I>::get(id) {
+			Some(details) => Self::ensure_asset_custodian(id, &details, who),
+			None => Self::ensure_custodian(who),
+		}
+	}
+
+	/// Reserve `amount` from `who`, the owner or the custodian of asset `id`, into the
+	/// sponsorship pot of the asset.
+	///
+	/// Emits `SponsorshipPotFunded`.
+	pub(super) fn do_fund_sponsorship_pot(
+		id: AssetId,
+		who: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		Self::ensure_owner_or_custodian(id, &details, who)?;
+		SponsorshipPots::<T, I>::try_mutate(id, |maybe_pot| -> DispatchResult {
+			let pot = maybe_pot
+				.get_or_insert_with(|| SponsorshipPot { funder: who.clone(), amount: Zero::zero() });
+			ensure!(&pot.funder == who, Error::<T, I>::NotPotFunder);
+			T::Deposits::hold(who, amount)?;
+			Self::note_deposit(Some(id), DepositPurpose::SponsorshipPot, Zero::zero(), amount);
+			pot.amount.saturating_accrue(amount);
+			Ok(())
+		})?;
+		Self::deposit_event(Event::SponsorshipPotFunded { asset_id: id, funder: who.clone(), amount });
+		Ok(())
+	}
+
+	/// Close the sponsorship pot of asset `id` by its funder `who`, releasing what is left.
+	///
+	/// Emits `SponsorshipPotWithdrawn`.
+	pub(super) fn do_withdraw_sponsorship_pot(id: AssetId, who: &T::AccountId) -> DispatchResult {
+		let pot = SponsorshipPots::<T, I>::get(id).ok_or(Error::<T, I>::NoSponsorshipPot)?;
+		ensure!(&pot.funder == who, Error::<T, I>::NotPotFunder);
+		SponsorshipPots::<T, I>::remove(id);
+		Self::release_sponsorship_pot(id, pot);
+		Ok(())
+	}
+
+	/// Return what is left in the removed sponsorship pot `pot` of asset `id` to its funder.
+	///
+	/// Emits `SponsorshipPotWithdrawn`.
+	fn release_sponsorship_pot(
+		id: AssetId,
+		pot: SponsorshipPot<T::AccountId, DepositBalanceOf<T, I>>,
+	) {
+		T::Deposits::release(&pot.funder, pot.amount);
+		Self::note_deposit(Some(id), DepositPurpose::SponsorshipPot, pot.amount, Zero::zero());
+		Self::deposit_event(Event::SponsorshipPotWithdrawn {
+			asset_id: id,
+			funder: pot.funder,
+			amount: pot.amount,
+		});
+	}
+
+	/// Send `SponsoredRetirementFee` for the retirement of `burned` of asset `id` by `who` from
+	/// the sponsorship pot of the asset to `SponsoredFeeDestination` through `Deposits`, if the
+	/// pot covers it. Only a retirement of at least the minimum balance of the asset is
+	/// sponsored, and at most one retirement of `who` every `SponsoredRetirementInterval`
+	/// blocks. An emptied pot is closed.
+	///
+	/// Returns `Pays::No` if the fee was sponsored. Emits `RetirementSponsored`.
+	pub(super) fn sponsor_retirement_fee(
+		id: AssetId,
+		who: &T::AccountId,
+		burned: T::Balance,
+	) -> Result<Pays, DispatchError> {
+		let min_balance = Asset::<T, I>::get(id).map_or_else(Bounded::max_value, |d| d.min_balance);
+		if burned < min_balance {
+			return Ok(Pays::Yes)
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let interval = T::SponsoredRetirementInterval::get();
+		if LastSponsoredRetirement::<T, I>::get(who)
+			.map_or(false, |last| last.saturating_add(interval) > now)
+		{
+			return Ok(Pays::Yes)
+		}
+		let fee = T::SponsoredRetirementFee::get();
+		let sponsored = SponsorshipPots::<T, I>::try_mutate_exists(
+			id,
+			|maybe_pot| -> Result<bool, DispatchError> {
+				let pot = match maybe_pot {
+					Some(pot) if pot.amount >= fee => pot,
+					_ => return Ok(false),
+				};
+				let destination = T::SponsoredFeeDestination::get();
+				T::Deposits::transfer_held(&pot.funder, &destination, fee)?;
+				T::Deposits::release(&destination, fee);
+				pot.amount.saturating_reduce(fee);
+				if pot.amount.is_zero() {
+					*maybe_pot = None;
+				}
+				Ok(true)
+			},
+		)?;
+		if !sponsored {
+			return Ok(Pays::Yes)
+		}
+		Self::note_deposit(Some(id), DepositPurpose::SponsorshipPot, fee, Zero::zero());
+		LastSponsoredRetirement::<T, I>::insert(who, now);
+		Self::deposit_event(Event::RetirementSponsored { asset_id: id, who: who.clone(), fee });
+		Ok(Pays::No)
+	}
+
+	/// Propose `custodian` as the custodian of the asset `id` from `CustodianChangeDelay`
+	/// blocks on, or `None` to clear it.
+	///
+	/// Emits `AssetCustodianProposed`.
+	pub(super) fn do_propose_asset_custodian(
+		id: AssetId,
+		custodian: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(Asset::<T, I>::contains_key(id), Error::<T, I>::Unknown);
+		let activates_at =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::CustodianChangeDelay::get());
+		PendingAssetCustodian::<T, I>::insert(id, (custodian.clone(), activates_at));
+		Self::deposit_event(Event::AssetCustodianProposed { asset_id: id, custodian, activates_at });
+		Ok(())
+	}
+
+	/// Make the proposed change of the custodian of asset `id` accepted by `who`, the proposed
+	/// custodian or the global custodian taking the asset back, once the delay has passed.
+	pub(super) fn do_accept_asset_custodian(id: AssetId, who: T::AccountId) -> DispatchResult {
+		let (custodian, activates_at) =
+			PendingAssetCustodian::<T, I>::get(id).ok_or(Error::<T, I>::NotProposedCustodian)?;
+		let accepting = custodian.clone().or_else(Custodian::<T, I>::get);
+		ensure!(accepting.as_ref() == Some(&who), Error::<T, I>::NotProposedCustodian);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= activates_at,
+			Error::<T, I>::CustodianChangeNotDue
+		);
+		PendingAssetCustodian::<T, I>::remove(id);
+		Self::do_set_asset_custodian(id, custodian)
+	}
+
+	/// Assign `custodian` to the asset `id`, making it the issuer, admin and freezer, or clear
+	/// it and hand these roles back to the global custodian, if any.
+	///
+	/// Emits `AssetCustodianSet`.
+	pub(super) fn do_set_asset_custodian(
+		id: AssetId,
+		custodian: Option<T::AccountId>,
+	) -> DispatchResult {
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			let roles = match &custodian {
+				Some(custodian) => {
+					Custodians::<T, I>::insert(id, custodian);
+					Some(custodian.clone())
+				},
+				None => {
+					Custodians::<T, I>::remove(id);
+					Custodian::<T, I>::get()
+				},
+			};
+			if let Some(account) = roles {
+				details.issuer = account.clone();
+				details.admin = account.clone();
+				details.freezer = account;
+			}
+			Ok(())
+		})?;
+		Self::deposit_event(Event::AssetCustodianSet { asset_id: id, custodian });
+		Ok(())
+	}
+
+	/// Queue the request of the owner `who` of asset `id` to mint `amount` of it, backed by
+	/// the IPFS document `evidence_ipfs`, holding the metadata deposit of the evidence from
+	/// `who`.
+	///
+	/// Emits `MintRequested`.
+	pub(super) fn do_request_mint(
+		id: AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		evidence_ipfs: Vec<u8>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(&details.owner == who, Error::<T, I>::NoPermission);
+		ensure!(cid::is_valid(&evidence_ipfs), Error::<T, I>::BadCid);
+		let evidence: BoundedVec<u8, T::StringLimit> =
+			evidence_ipfs.try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+
+		let request_id = NextMintRequestId::<T, I>::get();
+		PendingMintRequests::<T, I>::try_mutate(id, |pending| {
+			pending.try_push(request_id).map_err(|_| Error::<T, I>::TooManyMintRequests)
+		})?;
+		let deposit = Self::metadata_deposit(evidence.len() as u32);
+		T::Deposits::hold(who, deposit)?;
+		Self::note_deposit(Some(id), DepositPurpose::MintRequest, Zero::zero(), deposit);
+		NextMintRequestId::<T, I>::put(request_id.saturating_add(1));
+		MintRequests::<T, I>::insert(
+			request_id,
+			MintRequest {
+				asset_id: id,
+				owner: who.clone(),
+				amount,
+				deposit,
+				evidence,
+				requested_at: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+		Self::note_mint_request();
+		Self::deposit_event(Event::MintRequested {
+			request_id,
+			asset_id: id,
+			owner: who.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Mint the amount of the mint request `request_id` to the owner of the asset, checking
+	/// that `who` is the custodian of the asset.
+	///
+	/// Emits `Issued` and `MintRequestApproved`.
+	pub(super) fn do_approve_mint(request_id: u64, who: T::AccountId) -> DispatchResult {
+		let request =
+			MintRequests::<T, I>::take(request_id).ok_or(Error::<T, I>::UnknownMintRequest)?;
+		Self::do_mint_to_owner(request.asset_id, request.amount, Some(who.clone()), None)?;
+		Self::settle_mint_request(request_id, &request);
+		Self::deposit_event(Event::MintRequestApproved {
+			request_id,
+			asset_id: request.asset_id,
+			custodian: who,
+		});
+		Ok(())
+	}
+
+	/// Remove the mint request `request_id`, checking that `who` is the custodian of the asset,
+	/// or the global custodian if the asset is gone.
+	///
+	/// Emits `MintRequestRejected`.
+	pub(super) fn do_reject_mint(
+		request_id: u64,
+		who: &T::AccountId,
+		reason: Vec<u8>,
+	) -> DispatchResult {
+		ensure!(reason.len() <= T::StringLimit::get() as usize, Error::<T, I>::BadMetadata);
+		let request =
+			MintRequests::<T, I>::take(request_id).ok_or(Error::<T, I>::UnknownMintRequest)?;
+		Self::ensure_custodian_of(request.asset_id, who)?;
+		Self::settle_mint_request(request_id, &request);
+		Self::deposit_event(Event::MintRequestRejected {
+			request_id,
+			asset_id: request.asset_id,
+			reason,
+		});
+		Ok(())
+	}
+
+	/// Remove the taken mint request `request_id` from the pending ones of its asset and return
+	/// its deposit to the requesting owner.
+	fn settle_mint_request(
+		request_id: u64,
+		request: &MintRequest<
+			T::AccountId,
+			T::Balance,
+			DepositBalanceOf<T, I>,
+			T::BlockNumber,
+			BoundedVec<u8, T::StringLimit>,
+		>,
+	) {
+		PendingMintRequests::<T, I>::mutate_exists(request.asset_id, |maybe_pending| {
+			if let Some(pending) = maybe_pending {
+				pending.retain(|id| *id != request_id);
+				if pending.is_empty() {
+					*maybe_pending = None;
+				}
+			}
+		});
+		T::Deposits::release(&request.owner, request.deposit);
+		Self::note_deposit(
+			Some(request.asset_id),
+			DepositPurpose::MintRequest,
+			request.deposit,
+			Zero::zero(),
+		);
+		Self::note_mint_request_settled();
+	}
+
 	pub(super) fn get_new_asset_id(account: &T::AccountId) -> Result<AssetId, DispatchError> {
 		let id = LastNonce::<T, I>::get();
 		let new_id = id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
@@ -934,7 +4188,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(res.is_ok(), Error::<T,I>::ErrorCreatingAssetId);
 		let result: [u8; 24] = res.unwrap();
 
-		Ok(result)
+		Ok(result.into())
 	}
 
 	#[cfg(any(test, feature = "runtime-benchmarks"))]
@@ -948,7 +4202,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(res.is_ok(), Error::<T,I>::ErrorCreatingAssetId);
 		let result: [u8; 24] = res.unwrap();
 
-		Ok(result)
+		Ok(result.into())
 	}
 
 	#[cfg(test)]
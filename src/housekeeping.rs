@@ -0,0 +1,187 @@
+// This file is part of Evercity.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Housekeeping of residual records in the idle time of blocks.
+//!
+//! `on_idle` scans `Metadata`, `Approvals` and `Account` in turn, one record per
+//! `on_idle_item` weight, and removes metadata of destroyed assets, approvals of zero amount
+//! and empty asset-accounts, as long as they hold no deposit of an unknown owner. The scan
+//! resumes from the `HousekeepingCursor` in the next block and starts over after the last
+//! stage.
+
+use super::*;
+use frame_support::{weights::Weight, BoundedVec};
+use functions::DeadConsequence;
+
+impl HousekeepingStage {
+	/// The stage scanned after this one, `None` after the last stage.
+	fn next(self) -> Option<Self> {
+		match self {
+			HousekeepingStage::Metadata => Some(HousekeepingStage::Approvals),
+			HousekeepingStage::Approvals => Some(HousekeepingStage::Accounts),
+			HousekeepingStage::Accounts => None,
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Scan and clean up records within the `remaining` weight, resuming from the
+	/// `HousekeepingCursor`.
+	///
+	/// Emits `HousekeepingDone` if any record was removed. Returns the weight used.
+	pub(super) fn do_housekeeping(remaining: Weight) -> Weight {
+		let base = T::WeightInfo::on_idle_base();
+		let item = T::WeightInfo::on_idle_item();
+		if remaining.ref_time() < base.saturating_add(item).ref_time() {
+			return Weight::zero()
+		}
+		let max_items = remaining.saturating_sub(base).ref_time() / item.ref_time().max(1);
+
+		let (mut stage, mut cursor) = match HousekeepingCursor::<T, I>::get() {
+			Some((stage, key)) => (stage, Some(key.into_inner())),
+			None => (HousekeepingStage::Metadata, None),
+		};
+		let mut report = HousekeepingReport::default();
+		let mut items = 0u64;
+		while items < max_items {
+			items.saturating_inc();
+			let last = match stage {
+				HousekeepingStage::Metadata => Self::housekeep_metadata(cursor.take(), &mut report),
+				HousekeepingStage::Approvals =>
+					Self::housekeep_approvals(cursor.take(), &mut report),
+				HousekeepingStage::Accounts => Self::housekeep_accounts(cursor.take(), &mut report),
+			};
+			match (last, stage.next()) {
+				(Some(key), _) => cursor = Some(key),
+				(None, Some(next)) => stage = next,
+				// The scan is complete, start over in the next block.
+				(None, None) => {
+					stage = HousekeepingStage::Metadata;
+					break
+				},
+			}
+		}
+
+		match cursor.and_then(|key| BoundedVec::try_from(key).ok()) {
+			Some(key) => HousekeepingCursor::<T, I>::put((stage, key)),
+			None => HousekeepingCursor::<T, I>::put((stage, BoundedVec::default())),
+		}
+		if report != HousekeepingReport::default() {
+			HousekeepingTotals::<T, I>::mutate(|totals| {
+				totals.metadata.saturating_accrue(report.metadata);
+				totals.approvals.saturating_accrue(report.approvals);
+				totals.accounts.saturating_accrue(report.accounts);
+			});
+			Self::deposit_event(Event::HousekeepingDone {
+				metadata: report.metadata,
+				approvals: report.approvals,
+				accounts: report.accounts,
+			});
+		}
+		base.saturating_add(item.saturating_mul(items))
+	}
+
+	/// Remove the next `Metadata` after the `cursor` if its asset does not exist and it holds
+	/// no deposit. The owner who held the deposit is unknown once the asset is gone, so such
+	/// metadata is kept rather than dropping the record of the held funds and their totals.
+	///
+	/// Returns the key of the scanned record, `None` at the end of the stage.
+	fn housekeep_metadata(
+		cursor: Option<Vec<u8>>,
+		report: &mut HousekeepingReport,
+	) -> Option<Vec<u8>> {
+		let id = match cursor {
+			Some(key) if !key.is_empty() => Metadata::<T, I>::iter_keys_from(key).next(),
+			_ => Metadata::<T, I>::iter_keys().next(),
+		}?;
+		if !Asset::<T, I>::contains_key(id) && Metadata::<T, I>::get(id).deposit.is_zero() {
+			let metadata = Metadata::<T, I>::take(id);
+			LegacyDataIpfs::<T, I>::remove(id);
+			Self::note_symbol(id, Some(&metadata.symbol), None);
+			report.metadata.saturating_inc();
+		}
+		Some(Metadata::<T, I>::hashed_key_for(id))
+	}
+
+	/// Remove the next approval after the `cursor` if its amount is zero or it has expired,
+	/// returning the deposit.
+	///
+	/// Returns the key of the scanned record, `None` at the end of the stage.
+	fn housekeep_approvals(
+		cursor: Option<Vec<u8>>,
+		report: &mut HousekeepingReport,
+	) -> Option<Vec<u8>> {
+		let ((id, owner, delegate), approval) = match cursor {
+			Some(key) if !key.is_empty() => Approvals::<T, I>::iter_from(key).next(),
+			_ => Approvals::<T, I>::iter().next(),
+		}?;
+		let now = frame_system::Pallet::<T>::block_number();
+		if approval.amount.is_zero() || approval.is_expired(&now) {
+			match Asset::<T, I>::get(id) {
+				Some(mut details) => {
+					Self::take_approval(id, &owner, &delegate, &mut details);
+					Asset::<T, I>::insert(id, details);
+				},
+				None => {
+					Approvals::<T, I>::remove((id, &owner, &delegate));
+					T::Deposits::release(&owner, approval.deposit);
+					Self::note_deposit(
+						Some(id),
+						DepositPurpose::Approval,
+						approval.deposit,
+						Zero::zero(),
+					);
+				},
+			}
+			report.approvals.saturating_inc();
+		}
+		Some(Approvals::<T, I>::hashed_key_for((id, &owner, &delegate)))
+	}
+
+	/// Remove the next asset-account after the `cursor` if it is empty, not frozen and holds
+	/// no deposit.
+	///
+	/// Returns the key of the scanned record, `None` at the end of the stage.
+	fn housekeep_accounts(
+		cursor: Option<Vec<u8>>,
+		report: &mut HousekeepingReport,
+	) -> Option<Vec<u8>> {
+		let (id, who, account) = match cursor {
+			Some(key) if !key.is_empty() => Account::<T, I>::iter_from(key).next(),
+			_ => Account::<T, I>::iter().next(),
+		}?;
+		if account.balance.is_zero() && !account.is_frozen {
+			let removed = match Asset::<T, I>::get(id) {
+				Some(mut details) => {
+					let dead = Self::dead_account(&who, &mut details, &account.reason, false);
+					if matches!(dead, DeadConsequence::Remove) {
+						Asset::<T, I>::insert(id, details);
+						true
+					} else {
+						false
+					}
+				},
+				None => !matches!(account.reason, ExistenceReason::DepositHeld(_)),
+			};
+			if removed {
+				Account::<T, I>::remove(id, &who);
+				Self::account_died(id, &who);
+				report.accounts.saturating_inc();
+			}
+		}
+		Some(Account::<T, I>::hashed_key_for(id, &who))
+	}
+}
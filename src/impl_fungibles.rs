@@ -18,6 +18,7 @@
 //! Implementations for fungibles trait.
 
 use super::*;
+use frame_support::storage::KeyPrefixIterator;
 
 impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId> for Pallet<T, I> {
 	type AssetId = types::AssetId;
@@ -80,6 +81,19 @@ impl<T: Config<I>, I: 'static> fungibles::InspectMetadata<<T as SystemConfig>::A
 	}
 }
 
+impl<T: Config<I>, I: 'static> fungibles::InspectEnumerable<<T as SystemConfig>::AccountId>
+	for Pallet<T, I>
+{
+	type AssetsIterator = KeyPrefixIterator<AssetId>;
+
+	/// Returns an iterator of the assets in existence.
+	///
+	/// NOTE: iterating this list invokes a storage read per item.
+	fn asset_ids() -> Self::AssetsIterator {
+		Asset::<T, I>::iter_keys()
+	}
+}
+
 impl<T: Config<I>, I: 'static> fungibles::Mutate<<T as SystemConfig>::AccountId> for Pallet<T, I> {
 	fn mint_into(
 		asset: AssetId,
@@ -131,6 +145,7 @@ impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T,
 				asset.supply = amount
 			}
 		});
+		Self::note_touched(id);
 	}
 	fn decrease_balance(
 		asset: AssetId,
@@ -153,7 +168,7 @@ impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T,
 		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> Result<Self::Balance, DispatchError> {
-		Self::increase_balance(asset, who, amount, |_| Ok(()))?;
+		Self::increase_balance(asset, who, amount, |_, _| Ok(()))?;
 		Ok(amount)
 	}
 	fn increase_balance_at_most(
@@ -161,7 +176,7 @@ impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T,
 		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> Self::Balance {
-		match Self::increase_balance(asset, who, amount, |_| Ok(())) {
+		match Self::increase_balance(asset, who, amount, |_, _| Ok(())) {
 			Ok(()) => amount,
 			Err(_) => Zero::zero(),
 		}
@@ -179,6 +194,9 @@ impl<T: Config<I>, I: 'static> fungibles::Create<T::AccountId> for Pallet<T, I>
 	}
 }
 
+/// Destroys the asset through the staged destruction (`start_destroy`, `destroy_accounts`,
+/// `destroy_approvals` and `finish_destroy`) in a single storage layer, so an asset whose
+/// destruction was started by its owner can be finished by another pallet as well.
 impl<T: Config<I>, I: 'static> fungibles::Destroy<T::AccountId> for Pallet<T, I> {
 	type DestroyWitness = DestroyWitness;
 
@@ -191,7 +209,13 @@ impl<T: Config<I>, I: 'static> fungibles::Destroy<T::AccountId> for Pallet<T, I>
 		witness: Self::DestroyWitness,
 		maybe_check_owner: Option<T::AccountId>,
 	) -> Result<Self::DestroyWitness, DispatchError> {
-		Self::do_destroy(id, witness, maybe_check_owner)
+		if let Some(owner) = &maybe_check_owner {
+			// Other pallets cannot collect the confirmation of the co-owner.
+			ensure!(!Self::needs_co_signing(id, owner), Error::<T, I>::NoPermission);
+		}
+		frame_support::storage::with_storage_layer(|| {
+			Self::do_staged_destroy(id, witness, maybe_check_owner)
+		})
 	}
 }
 
@@ -234,7 +258,9 @@ impl<T: Config<I>, I: 'static> fungibles::approvals::Inspect<<T as SystemConfig>
 		owner: &<T as SystemConfig>::AccountId,
 		delegate: &<T as SystemConfig>::AccountId,
 	) -> T::Balance {
+		let now = frame_system::Pallet::<T>::block_number();
 		Approvals::<T, I>::get((asset, &owner, &delegate))
+			.filter(|x| !x.is_expired(&now))
 			.map(|x| x.amount)
 			.unwrap_or_else(Zero::zero)
 	}
@@ -249,7 +275,7 @@ impl<T: Config<I>, I: 'static> fungibles::approvals::Mutate<<T as SystemConfig>:
 		delegate: &<T as SystemConfig>::AccountId,
 		amount: T::Balance,
 	) -> DispatchResult {
-		Self::do_approve_transfer(asset, owner, delegate, amount)
+		Self::do_approve_transfer(asset, owner, delegate, amount, None)
 	}
 
 	// Aprove spending tokens from a given account
@@ -263,3 +289,83 @@ impl<T: Config<I>, I: 'static> fungibles::approvals::Mutate<<T as SystemConfig>:
 		Self::do_transfer_approved(asset, owner, delegate, dest, amount)
 	}
 }
+
+impl<T: Config<I>, I: 'static> fungibles::InspectHold<T::AccountId> for Pallet<T, I> {
+	fn balance_on_hold(asset: AssetId, who: &T::AccountId) -> T::Balance {
+		Holds::<T, I>::get(asset, who)
+	}
+
+	fn can_hold(asset: AssetId, who: &T::AccountId, amount: T::Balance) -> bool {
+		Self::can_hold(asset, who, amount)
+	}
+}
+
+/// Held funds stay in the balance of the account, locked like a freeze, so the account is kept
+/// alive while anything is on hold.
+impl<T: Config<I>, I: 'static> fungibles::MutateHold<T::AccountId> for Pallet<T, I> {
+	fn hold(asset: AssetId, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Self::do_hold(asset, who, amount)
+	}
+
+	fn release(
+		asset: AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		best_effort: bool,
+	) -> Result<T::Balance, DispatchError> {
+		Self::do_release(asset, who, amount, best_effort)
+	}
+
+	fn transfer_held(
+		asset: AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		best_effort: bool,
+		on_hold: bool,
+	) -> Result<T::Balance, DispatchError> {
+		Self::do_transfer_held(asset, source, dest, amount, best_effort, on_hold)
+	}
+}
+
+impl<T: Config<I>, I: 'static> InspectFreeze<T::AccountId> for Pallet<T, I> {
+	type Id = T::FreezeIdentifier;
+
+	fn balance_frozen(asset: AssetId, id: &Self::Id, who: &T::AccountId) -> T::Balance {
+		Freezes::<T, I>::get(asset, who)
+			.iter()
+			.find(|f| &f.id == id)
+			.map_or_else(Zero::zero, |f| f.amount)
+	}
+
+	fn can_freeze(asset: AssetId, id: &Self::Id, who: &T::AccountId) -> bool {
+		let freezes = Freezes::<T, I>::get(asset, who);
+		Account::<T, I>::contains_key(asset, who) &&
+			(freezes.len() < T::MaxFreezes::get() as usize || freezes.iter().any(|f| &f.id == id))
+	}
+}
+
+impl<T: Config<I>, I: 'static> MutateFreeze<T::AccountId> for Pallet<T, I> {
+	fn set_freeze(
+		asset: AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Self::do_set_freeze(asset, id, who, amount)
+	}
+
+	fn extend_freeze(
+		asset: AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let amount = amount.max(Self::balance_frozen(asset, id, who));
+		Self::do_set_freeze(asset, id, who, amount)
+	}
+
+	fn thaw(asset: AssetId, id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+		Self::do_set_freeze(asset, id, who, Zero::zero())
+	}
+}
@@ -0,0 +1,147 @@
+// This file is part of Evercity.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Programmatic interface of the carbon assets pallet for other evercity pallets.
+
+use super::*;
+use codec::Encode;
+use frame_support::traits::ChangeMembers;
+
+/// Narrow interface used by other pallets (e.g. evercity bonds) to operate carbon assets
+/// without depending on the pallet storage layout.
+///
+/// No origin checks are done here: the calling pallet is responsible for its own
+/// authorization.
+pub trait CarbonAssetsInterface<AccountId, Balance> {
+	/// Create a new carbon asset owned by `owner`. Returns the generated asset id.
+	fn create_carbon_asset(
+		owner: &AccountId,
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+	) -> Result<AssetId, DispatchError>;
+
+	/// Mint `amount` of asset `id` to the owner of the asset.
+	fn mint_carbon_asset(id: AssetId, amount: Balance) -> DispatchResult;
+
+	/// Retire `amount` of asset `id` from `who`, recording it in the burn certificate of `who`.
+	/// Returns the actual amount burned.
+	fn burn_carbon_asset(
+		id: AssetId,
+		who: &AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError>;
+
+	/// Transfer `amount` of asset `id` from `source` to `dest`. Returns the actual amount
+	/// credited to `dest`.
+	fn transfer_carbon_asset(
+		id: AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError>;
+
+	/// Get the asset `id` balance of `who`.
+	fn carbon_asset_balance(id: AssetId, who: &AccountId) -> Balance;
+}
+
+/// Keeps the `ReservedSupply` in line with the `ReserveAccounts`: set the pallet as the
+/// `MembershipChanged` handler of the membership pallet providing them, so the reserved supply
+/// held by removed reserve accounts is released.
+impl<T: Config<I>, I: 'static> ChangeMembers<T::AccountId> for Pallet<T, I> {
+	fn change_members_sorted(
+		_incoming: &[T::AccountId],
+		outgoing: &[T::AccountId],
+		_sorted_new: &[T::AccountId],
+	) {
+		Self::release_reserve_accounts(outgoing);
+	}
+}
+
+impl<T: Config<I>, I: 'static> CarbonAssetsInterface<T::AccountId, T::Balance> for Pallet<T, I> {
+	fn create_carbon_asset(
+		owner: &T::AccountId,
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+	) -> Result<AssetId, DispatchError> {
+		Self::do_create(owner, name, symbol, None, DEFAULT_DECIMALS)
+	}
+
+	fn mint_carbon_asset(id: AssetId, amount: T::Balance) -> DispatchResult {
+		Self::do_mint_to_owner(id, amount, None, None)
+	}
+
+	fn burn_carbon_asset(
+		id: AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		Self::do_retire(id, who, amount, None, who).map(|(actual, _)| actual)
+	}
+
+	fn transfer_carbon_asset(
+		id: AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+		Self::do_transfer(id, source, dest, amount, None, f)
+	}
+
+	fn carbon_asset_balance(id: AssetId, who: &T::AccountId) -> T::Balance {
+		Self::balance(id, who)
+	}
+}
+
+/// Inspect the freezes of fungible assets, like `fungibles::InspectFreeze` of later
+/// frame-support releases, which the version used by the pallet does not have yet.
+///
+/// Freezes are identified by `Id`, e.g. the name of the pallet placing them. They overlap each
+/// other and the held amount: the frozen amount is the largest of them.
+pub trait InspectFreeze<AccountId>: fungibles::Inspect<AccountId> {
+	/// Identifier of a freeze.
+	type Id: Encode + TypeInfo + 'static;
+
+	/// The amount of asset `asset` of `who` frozen by the freeze `id`.
+	fn balance_frozen(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> Self::Balance;
+
+	/// Whether the freeze `id` can be placed on or changed for asset `asset` of `who`.
+	fn can_freeze(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> bool;
+}
+
+/// Place and change the freezes of fungible assets, like `fungibles::MutateFreeze` of later
+/// frame-support releases.
+pub trait MutateFreeze<AccountId>: InspectFreeze<AccountId> {
+	/// Set the freeze `id` of asset `asset` of `who` to `amount`, removing it if `amount` is
+	/// zero. The amount may exceed the balance.
+	fn set_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Set the freeze `id` of asset `asset` of `who` to `amount` unless it is larger already.
+	fn extend_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Remove the freeze `id` of asset `asset` of `who`.
+	fn thaw(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> DispatchResult;
+}
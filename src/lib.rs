@@ -98,8 +98,9 @@
 //! * `force_transfer`: Transfers between arbitrary accounts; called by the asset class's Admin.
 //! * `freeze`: Disallows further `transfer`s from an account; called by the asset class's Freezer.
 //! * `thaw`: Allows further `transfer`s from an account; called by the asset class's Admin.
-//! * `transfer_ownership`: Changes an asset class's Owner; called by the asset class's Owner.
+//! * `transfer_ownership`: Proposes a new Owner of an asset class; called by the asset class's
 //!   Owner.
+//! * `accept_ownership`: Accepts the proposed ownership; called by the proposed Owner.
 //!
 //! Please refer to the [`Call`] enum and its associated variants for documentation on each
 //! function.
@@ -125,23 +126,45 @@ mod benchmarking;
 #[cfg(test)]
 pub mod mock;
 #[cfg(test)]
+mod scenarios;
+#[cfg(test)]
 mod tests;
 pub mod weights;
 
+pub mod cid;
+#[cfg(feature = "compliance-freezer")]
+pub mod compliance_freezer;
+pub mod conservation;
+mod constants;
+#[cfg(feature = "example-runtime")]
+pub mod example_runtime;
+mod housekeeping;
+pub mod migration;
+pub mod outbox;
+pub mod primitives;
+pub use primitives::{CarbonAssetId, ParseAssetIdError};
+pub mod runtime_api;
+#[cfg(feature = "xcm-support")]
+pub mod xcm_support;
+mod sla;
 mod extra_mutator;
 pub use extra_mutator::*;
 mod functions;
 mod impl_fungibles;
+mod interface;
+pub use interface::*;
 mod impl_stored_map;
+mod storage;
 mod types;
 pub use types::*;
 
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
-		AtLeast32BitUnsigned, Bounded, CheckedAdd, CheckedSub, Saturating, StaticLookup, Zero, One,
+		AtLeast32BitUnsigned, Bounded, CheckedAdd, CheckedMul, CheckedSub, IdentifyAccount,
+		Saturating, StaticLookup, Verify, Zero, One,
 	},
-	ArithmeticError, TokenError,
+	ArithmeticError, Perbill, SaturatedConversion, TokenError,
 };
 use sp_std::{borrow::Borrow, prelude::*};
 
@@ -151,8 +174,8 @@ use frame_support::{
 	pallet_prelude::DispatchResultWithPostInfo,
 	traits::{
 		tokens::{fungibles, DepositConsequence, WithdrawConsequence},
-		BalanceStatus::Reserved,
-		Currency, ReservableCurrency, StoredMap,
+		Currency, OffchainWorker, OnUnbalanced, ReservableCurrency,
+		SortedMembers, StoredMap,
 	},
 };
 use frame_system::Config as SystemConfig;
@@ -166,8 +189,60 @@ pub mod pallet {
 	use frame_support::pallet_prelude::{*, StorageValue};
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
+
+	/// The current version of the Call/Event surface with the capabilities which do not
+	/// depend on the configuration.
+	pub const INTERFACE_VERSION: InterfaceVersion =
+		InterfaceVersion { major: 6, minor: 2, capabilities: 0 }
+			.with(Capability::MintBatch)
+			.with(Capability::ReserveBackedMint)
+			.with(Capability::Attributes)
+			.with(Capability::Organizations)
+			.with(Capability::RetirementAnchors)
+			.with(Capability::RetirementVouchers)
+			.with(Capability::DualControl)
+			.with(Capability::Methodologies)
+			.with(Capability::TransferSplits)
+			.with(Capability::AssetCustodians)
+			.with(Capability::MintRequests)
+			.with(Capability::Aliases)
+			.with(Capability::RetirementCertificates)
+			.with(Capability::ProjectDetails)
+			.with(Capability::StagedDestroy)
+			.with(Capability::RetirementSponsorship)
+			.with(Capability::ExpiringApprovals)
+			.with(Capability::CoBenefitBadges)
+			.with(Capability::TransferMemo)
+			.with(Capability::Vintages)
+			.with(Capability::RetirementQuotas)
+			.with(Capability::CustodianTimelock)
+			.with(Capability::OwnershipAcceptance)
+			.with(Capability::SignedBurns)
+			.with(Capability::FreezeReasons)
+			.with(Capability::DelegatedRetirement)
+			.with(Capability::EmergencyStop)
+			.with(Capability::ProjectVerification)
+			.with(Capability::SerialRanges)
+			.with(Capability::DeprecatedAliases)
+			.with(Capability::TransferAll)
+			.with(Capability::AllowanceAdjustment)
+			.with(Capability::ConfigurableDecimals)
+			.with(Capability::ApprovalPermits)
+			.with(Capability::PartialFreezes)
+			.with(Capability::SponsoredAccounts)
+			.with(Capability::HoldsAndFreezes)
+			.with(Capability::RegistryExports)
+			.with(Capability::TransferWhitelist)
+			.with(Capability::RetirementRegistry)
+			.with(Capability::OwnerBatch)
+			.with(Capability::ViewerKeys)
+			.with(Capability::ComplianceWindow);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
@@ -190,6 +265,10 @@ pub mod pallet {
 		/// The currency mechanism.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// Takes the deposits of the pallet: `NativeDeposits<Self::Currency>` to reserve them in
+		/// `Currency` or `FungiblesDeposits` to hold them in a sufficient asset.
+		type Deposits: DepositHandler<Self::AccountId, DepositBalanceOf<Self, I>>;
+
 		/// The origin which may forcibly create or destroy an asset or otherwise alter privileged
 		/// attributes.
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -212,6 +291,74 @@ pub mod pallet {
 		#[pallet::constant]
 		type MetadataDepositPerByte: Get<DepositBalanceOf<Self, I>>;
 
+		/// The basic amount of funds that must be reserved when adding an attribute to an asset.
+		/// `MetadataDepositPerByte` is added for every byte of the key and the value.
+		#[pallet::constant]
+		type AttributeDepositBase: Get<DepositBalanceOf<Self, I>>;
+
+		/// The maximum length of an attribute key.
+		#[pallet::constant]
+		type AttributeKeyLimit: Get<u32>;
+
+		/// The maximum length of an attribute value.
+		#[pallet::constant]
+		type AttributeValueLimit: Get<u32>;
+
+		/// The maximum number of mints in one `mint_batch` call.
+		#[pallet::constant]
+		type MaxMintBatch: Get<u32>;
+
+		/// The maximum number of assets an account may own when it calls `create`. The
+		/// Custodian is exempt, and assets created by `ForceOrigin` or received by an ownership
+		/// transfer are not limited.
+		#[pallet::constant]
+		type MaxAssetsPerAccount: Get<u32>;
+
+		/// The maximum number of assets retired in one `retire_co2e` call.
+		#[pallet::constant]
+		type MaxRetirementBasket: Get<u32>;
+
+		/// The maximum number of accounts, approvals or attributes removed in one
+		/// `destroy_accounts` or `destroy_approvals` call.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+
+		/// The reward per item removed by `destroy_accounts`, `destroy_approvals`,
+		/// `clean_expired_approval` and `gc_approvals`, paid to the sender out of the asset
+		/// deposit of the owner, or out of the deposit of the removed approval. Zero disables the
+		/// rewards.
+		#[pallet::constant]
+		type CleanupReward: Get<DepositBalanceOf<Self, I>>;
+
+		/// The number of blocks after the asset-account of an owner was removed from which
+		/// anyone may cancel its approvals via `gc_approvals`. Approvals of owners whose system
+		/// account was reaped can be cancelled at once.
+		#[pallet::constant]
+		type ApprovalGcGracePeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of recipients of the asset splits, see `set_asset_splits`.
+		#[pallet::constant]
+		type MaxSplits: Get<u32>;
+
+		/// The maximum length of the memo of `transfer_with_memo`.
+		#[pallet::constant]
+		type MemoLimit: Get<u32>;
+
+		/// The length of the periods of the retirement quotas, see `grant_retirement_quota`.
+		/// Zero makes every quota a lifetime one.
+		#[pallet::constant]
+		type QuotaPeriod: Get<Self::BlockNumber>;
+
+		/// The number of blocks after `propose_custodian` before the proposed custodian can
+		/// accept the role.
+		#[pallet::constant]
+		type CustodianChangeDelay: Get<Self::BlockNumber>;
+
+		/// The number of blocks the proposed owner of an asset has to accept the ownership, see
+		/// `transfer_ownership`.
+		#[pallet::constant]
+		type OwnershipAcceptancePeriod: Get<Self::BlockNumber>;
+
 		/// The amount of funds that must be reserved when creating a new approval.
 		#[pallet::constant]
 		type ApprovalDeposit: Get<DepositBalanceOf<Self, I>>;
@@ -221,9 +368,23 @@ pub mod pallet {
 		type StringLimit: Get<u32>;
 
 		/// A hook to allow a per-asset, per-account minimum balance to be enforced. This must be
-		/// respected in all permissionless operations.
+		/// respected in all permissionless operations. `()` freezes nothing; with the
+		/// `compliance-freezer` feature `compliance_freezer::Pallet` keeps the freezes on-chain.
 		type Freezer: FrozenBalance<AssetId, Self::AccountId, Self::Balance>;
 
+		/// A hook to screen the sender and the receiver of transfers and mints, e.g. against
+		/// sanctions lists. `()` allows everything.
+		type Screening: Screening<AssetId, Self::AccountId>;
+
+		/// A hook to check the structure of the metadata set by the owner, e.g.
+		/// `StrictMetadata`. `()` accepts any metadata within `StringLimit`.
+		type MetadataValidator: MetadataValidator;
+
+		/// A hook called after every transfer, mint and burn of carbon assets, e.g. to mirror the
+		/// balances in another pallet. Its weight is not accounted for, so it should be cheap.
+		/// `()` does nothing.
+		type OnCarbonTransfer: OnCarbonTransfer<AssetId, Self::AccountId, Self::Balance>;
+
 		/// Additional data to be stored with an account's asset balance.
 		type Extra: Member + Parameter + Default + MaxEncodedLen;
 
@@ -232,6 +393,134 @@ pub mod pallet {
 
 		/// Randomness for asssets name generation
         type Randomness: frame_support::traits::Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// Sovereign or bridge accounts which may receive reserve-backed mints from the
+		/// custodian.
+		type ReserveAccounts: SortedMembers<Self::AccountId>;
+
+		/// Accounts of the carbon credit standards which may attest the co-benefit badges of
+		/// assets, e.g. the accounts with the CC_STANDARD role.
+		type Verifiers: SortedMembers<Self::AccountId>;
+
+		/// Accounts which may approve the project data of assets before the custodian, e.g. the
+		/// accounts with the CC_AUDITOR role.
+		type Auditors: SortedMembers<Self::AccountId>;
+
+		/// Handler for the assets destroyed outside of explicit burns: dust of transfers and
+		/// balances burned on `refund`. The default `()` burns them from the supply.
+		type OnDustRemoval: OnUnbalanced<fungibles::CreditOf<Self::AccountId, Pallet<Self, I>>>;
+
+		/// Allow minting of a frozen asset or to a frozen asset-account. If `false`, such mints
+		/// fail with `AssetFrozen` or `AccountFrozen`.
+		#[pallet::constant]
+		type AllowMintWhenFrozen: Get<bool>;
+
+		/// Require the global custodian for all custodian operations. If `true`, assets cannot be
+		/// created without a custodian and every asset is governed by the current custodian, so
+		/// a custodian change hands over the existing assets too. If `false`, an asset is governed by
+		/// the custodian it was created with, and assets created without a custodian are governed
+		/// by their owner.
+		#[pallet::constant]
+		type CustodianRequired: Get<bool>;
+
+		/// Emit a `LedgerEntry` event for every balance mutation, giving accounting systems a
+		/// single double-entry feed.
+		#[pallet::constant]
+		type LedgerEvents: Get<bool>;
+
+		/// Count transfers, retirements and approvals of every account in `AccountActivityOf`
+		/// for lightweight analytics without an indexer.
+		#[pallet::constant]
+		type AccountStats: Get<bool>;
+
+		/// Require every asset to reference a methodology registered in `Methodologies` before
+		/// its carbon assets are minted.
+		#[pallet::constant]
+		type MethodologyRequired: Get<bool>;
+
+		/// Require the project of every asset to be approved by an auditor and then by the
+		/// custodian (`ProjectStatus::CustodianApproved`) before its carbon assets are minted.
+		#[pallet::constant]
+		type ProjectApprovalRequired: Get<bool>;
+
+		/// The maximum number of messages in the `Outbox`. The oldest message is dropped when
+		/// the outbox is full. Zero disables the outbox.
+		#[pallet::constant]
+		type MaxOutbox: Get<u32>;
+
+		/// The maximum number of entries in the `CustodianLog`. The oldest entry is pruned when
+		/// the log is full. Zero disables the log.
+		#[pallet::constant]
+		type MaxCustodianLog: Get<u32>;
+
+		/// The maximum number of serial ranges minted for an asset.
+		#[pallet::constant]
+		type MaxSerialRanges: Get<u32>;
+
+		/// The maximum number of owner actions of an asset under dual control waiting for the
+		/// confirmation by the co-owner.
+		#[pallet::constant]
+		type MaxPendingOwnerActions: Get<u32>;
+
+		/// The maximum number of mint requests of an asset waiting for the decision of its
+		/// custodian.
+		#[pallet::constant]
+		type MaxPendingMintRequests: Get<u32>;
+
+		/// The amount of `Currency` drawn from the sponsorship pot of an asset for a `self_burn`
+		/// of the asset, which is then free of fees. It should cover the fee of `self_burn`.
+		#[pallet::constant]
+		type SponsoredRetirementFee: Get<DepositBalanceOf<Self, I>>;
+
+		/// The account the fees drawn from sponsorship pots are sent to through `Deposits`, e.g.
+		/// the treasury.
+		type SponsoredFeeDestination: Get<Self::AccountId>;
+
+		/// The minimum number of blocks between two sponsored retirements of an account.
+		#[pallet::constant]
+		type SponsoredRetirementInterval: Get<Self::BlockNumber>;
+
+		/// The number of blocks between the custodian SLA reports submitted by the off-chain
+		/// worker. Zero disables the reports.
+		#[pallet::constant]
+		type SlaReportInterval: Get<Self::BlockNumber>;
+
+		/// The amount of `Currency` the custodian sends to a new account with `sponsor_account`,
+		/// e.g. the existential deposit and the fees of the first transactions.
+		#[pallet::constant]
+		type SponsorAmount: Get<DepositBalanceOf<Self, I>>;
+
+		/// The number of blocks after `sponsor_account` from which the custodian can claw the
+		/// sponsored amount back from an account which has not sent a transaction.
+		#[pallet::constant]
+		type SponsorshipClawbackPeriod: Get<Self::BlockNumber>;
+
+		/// Identifier of the freezes placed through `MutateFreeze`, e.g. a `[u8; 8]` name of the
+		/// pallet placing them.
+		type FreezeIdentifier: Parameter + MaxEncodedLen + Copy;
+
+		/// The maximum number of freezes on an asset-account placed through `MutateFreeze`.
+		#[pallet::constant]
+		type MaxFreezes: Get<u32>;
+
+		/// The maximum number of calls in one `owner_batch`.
+		#[pallet::constant]
+		type MaxOwnerBatch: Get<u32>;
+
+		/// The off-chain worker of the pallet, e.g. `outbox::OutboxWorker<Runtime>` to deliver
+		/// the outbox and report the custodian SLA. With `()` the pallet has no off-chain
+		/// worker and the runtime does not need to implement `CreateSignedTransaction`.
+		type OffchainWorker: OffchainWorker<Self::BlockNumber>;
+
+		/// Off-chain signature of retirement vouchers.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Off-chain public key of the voucher signer.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Helper to sign retirement vouchers in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::OffchainSignature>;
 	}
 
 	#[pallet::storage]
@@ -268,12 +557,24 @@ pub mod pallet {
 			NMapKey<Blake2_128Concat, T::AccountId>, // owner
 			NMapKey<Blake2_128Concat, T::AccountId>, // delegate
 		),
-		Approval<T::Balance, DepositBalanceOf<T, I>>,
+		Approval<T::Balance, DepositBalanceOf<T, I>, T::BlockNumber>,
 		OptionQuery,
 		GetDefault,
 		ConstU32<300_000>,
 	>;
 
+	#[pallet::storage]
+	/// The block at which the asset-account of an owner with approvals was removed, starting
+	/// the `ApprovalGcGracePeriod` of its approvals.
+	pub(super) type OrphanedApprovalsSince<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+	>;
+
 	#[pallet::storage]
 	/// Metadata of an asset.
 	pub(super) type Metadata<T: Config<I>, I: 'static = ()> = StorageMap<
@@ -286,6 +587,25 @@ pub mod pallet {
 		ConstU32<300_000>,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn legacy_data_ipfs)]
+	/// The `data_ipfs` text of the metadata of an asset which was not a well-formed CID when it
+	/// was migrated to `Cid`, kept until the metadata of the asset is changed.
+	pub(super) type LegacyDataIpfs<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, BoundedVec<u8, T::StringLimit>>;
+
+	#[pallet::storage]
+	/// Attributes of an asset: arbitrary structured facts such as methodology id, country code
+	/// or SDG tags.
+	pub(super) type Attributes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::AttributeKeyLimit>,
+		AttributeValue<T::AccountId, DepositBalanceOf<T, I>, BoundedVec<u8, T::AttributeValueLimit>>,
+	>;
+
 	#[pallet::storage]
 	/// Burn certificates for an AccountId.
 	pub(super) type BurnCertificate<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -294,376 +614,3428 @@ pub mod pallet {
 		T::AccountId,
 		Blake2_128Concat,
 		AssetId,
-		T::Balance
+		BurnCertificateEntry<T::Balance, T::BlockNumber>,
 	>;
 
 	#[pallet::storage]
-	/// Evercity custodian - only custodian can mint or burn assets
-	pub(super) type Custodian<T: Config<I>, I: 'static = ()> = StorageValue<
+	/// Reverse index of `BurnCertificate`: amount of an asset burned per AccountId.
+	pub(super) type AssetBurnCertificate<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
-		T::AccountId
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
 	>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn get_last_id)]
-	/// Last created AssetId
-	pub(super) type LastNonce<T: Config<I>, I: 'static = ()> = StorageValue<
+	#[pallet::getter(fn total_retired)]
+	/// Total amount of an asset burned by all accounts and stored in burn certificates.
+	pub(super) type TotalRetired<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Retirements by their content hash, see `RetirementAnchor`.
+	pub(super) type RetirementAnchors<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Identity,
+		T::Hash,
+		RetirementAnchor<T::AccountId, T::Balance, T::BlockNumber, BoundedVec<u8, T::StringLimit>>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn retirement_certificate)]
+	/// The beneficiary, reason and date of the retirements by `retire` by their certificate id.
+	pub(super) type RetirementCertificates<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
+		Twox64Concat,
 		u64,
+		RetirementCertificate<BoundedVec<u8, T::StringLimit>>,
+	>;
+
+	#[pallet::storage]
+	/// The certificate id of the next retirement or custodial burn, also the key of its
+	/// `RetirementRegistry` entry.
+	pub(super) type NextCertificateId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	/// Number of anchored retirements of an AccountId.
+	pub(super) type RetirementCount<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
 		ValueQuery,
-		InitialNonce
 	>;
 
-	#[pallet::type_value]
-	pub(super) fn InitialNonce() -> u64 { 100 }
+	#[pallet::storage]
+	#[pallet::getter(fn account_activity)]
+	/// Operation counters of an AccountId. Only maintained if `AccountStats` is enabled.
+	pub(super) type AccountActivityOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		AccountActivity,
+		ValueQuery,
+	>;
 
-	#[pallet::genesis_config]
-	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
-		/// Genesis custodian: custodian_address
-		pub custodian: Option<T::AccountId>,
-		/// Genesis assets: id, owner, is_sufficient, min_balance
-		pub assets: Vec<(AssetId, T::AccountId, bool, T::Balance)>,
-		/// Genesis metadata: id, name, symbol, decimals
-		pub metadata: Vec<(AssetId, Vec<u8>, Vec<u8>, u8)>,
-		/// Genesis accounts: id, account_id, balance
-		pub accounts: Vec<(AssetId, T::AccountId, T::Balance)>,
-	}
+	#[pallet::storage]
+	/// Notable events waiting for the delivery to webhooks by the off-chain worker.
+	pub(super) type Outbox<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		OutboxMessage<T::AccountId, T::Balance, T::BlockNumber>,
+	>;
 
-	#[cfg(feature = "std")]
-	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
-		fn default() -> Self {
-			Self {
-				custodian: None,
-				assets: Default::default(),
-				metadata: Default::default(),
-				accounts: Default::default(),
-			}
-		}
-	}
+	#[pallet::storage]
+	/// The id of the oldest message in the `Outbox`.
+	pub(super) type OutboxHead<T: Config<I>, I: 'static = ()> = StorageValue<_, u64, ValueQuery>;
 
+	#[pallet::storage]
+	/// The id of the next message in the `Outbox`.
+	pub(super) type OutboxTail<T: Config<I>, I: 'static = ()> = StorageValue<_, u64, ValueQuery>;
 
-	#[pallet::genesis_build]
-	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
-		fn build(&self) {
-			if let Some(custodian_account) = &self.custodian {
-				Custodian::<T, I>::put(custodian_account);
-			}
+	#[pallet::storage]
+	/// The accounts of the off-chain workers authorized by `ForceOrigin` to acknowledge the
+	/// delivered `Outbox` messages.
+	pub(super) type OutboxAuthorities<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
 
-			for (id, owner, is_sufficient, min_balance) in &self.assets {
-				assert!(!Asset::<T, I>::contains_key(id), "Asset id already in use");
-				assert!(!min_balance.is_zero(), "Min balance should not be zero");
-				Asset::<T, I>::insert(
-					id,
-					AssetDetails {
-						owner: owner.clone(),
-						issuer: owner.clone(),
-						admin: owner.clone(),
-						freezer: owner.clone(),
-						supply: Zero::zero(),
-						deposit: Zero::zero(),
-						min_balance: *min_balance,
-						is_sufficient: *is_sufficient,
-						accounts: 0,
-						sufficients: 0,
-						approvals: 0,
-						is_frozen: false,
-					},
-				);
-			}
+	#[pallet::storage]
+	#[pallet::getter(fn total_deposits)]
+	/// The total of the deposits currently reserved by the pallet for every purpose.
+	pub(super) type TotalDeposits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, DepositPurpose, DepositBalanceOf<T, I>, ValueQuery>;
 
-			for (id, name, symbol, decimals) in &self.metadata {
-				assert!(Asset::<T, I>::contains_key(id), "Asset does not exist");
-
-				let bounded_name: BoundedVec<u8, T::StringLimit> =
-					name.clone().try_into().expect("asset name is too long");
-				let bounded_symbol: BoundedVec<u8, T::StringLimit> =
-					symbol.clone().try_into().expect("asset symbol is too long");
-				let bounded_url: BoundedVec<u8, T::StringLimit> =
-					"".as_bytes().to_vec().clone().try_into().expect("wrong url");
-				let bounded_data_ipfs: BoundedVec<u8, T::StringLimit> =
-					"".as_bytes().to_vec().clone().try_into().expect("wrong data_ipfs");
-		
-				let metadata = AssetMetadata {
-					deposit: Zero::zero(),
-					url: bounded_url,
-					data_ipfs: bounded_data_ipfs,
-					name: bounded_name,
-					symbol: bounded_symbol,
-					decimals: *decimals,
-					is_frozen: false,
-				};
-				Metadata::<T, I>::insert(id, metadata);
-			}
+	#[pallet::storage]
+	#[pallet::getter(fn asset_deposits)]
+	/// The deposits currently reserved by the pallet for an asset, by purpose. Entries left after
+	/// the asset is destroyed are deposits the destroy did not return.
+	pub(super) type AssetDeposits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Twox64Concat,
+		DepositPurpose,
+		DepositBalanceOf<T, I>,
+		ValueQuery,
+	>;
 
-			for (id, account_id, amount) in &self.accounts {
-				let result = <Pallet<T, I>>::increase_balance(
-					*id,
-					account_id,
-					*amount,
-					|details| -> DispatchResult {
-						debug_assert!(
-							T::Balance::max_value() - details.supply >= *amount,
-							"checked in prep; qed"
-						);
-						details.supply = details.supply.saturating_add(*amount);
-						Ok(())
-					},
-				);
-				assert!(result.is_ok());
-			}
-		}
-	}
+	#[pallet::storage]
+	/// Index of the assets owned by an account.
+	pub(super) type OwnedAssets<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		AssetId,
+		(),
+	>;
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config<I>, I: 'static = ()> {
-		/// Some asset class was created.
-		Created { asset_id: AssetId, creator: T::AccountId },
-		/// Some assets were issued.
-		Issued { asset_id: AssetId, owner: T::AccountId, total_supply: T::Balance },
-		/// Some assets were transferred.
-		Transferred {
-			asset_id: AssetId,
-			from: T::AccountId,
-			to: T::AccountId,
-			amount: T::Balance,
-		},
-		/// Some assets were destroyed.
-		Burned { asset_id: AssetId, owner: T::AccountId, balance: T::Balance },
-		/// The management team changed.
-		TeamChanged {
-			asset_id: AssetId,
-			issuer: T::AccountId,
-			admin: T::AccountId,
-			freezer: T::AccountId,
-		},
-		/// The owner changed.
+	#[pallet::storage]
+	#[pallet::getter(fn owned_asset_count)]
+	/// The number of assets in `OwnedAssets` of an account.
+	pub(super) type OwnedAssetCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_splits)]
+	/// Recipients and their shares of every `transfer_with_splits` of an asset, e.g. the owner
+	/// royalty and the registry fee.
+	pub(super) type AssetSplits<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		BoundedVec<(T::AccountId, Perbill), T::MaxSplits>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn methodology)]
+	/// Carbon accounting methodologies approved by the `ForceOrigin`, by methodology id.
+	pub(super) type Methodologies<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, BoundedVec<u8, T::StringLimit>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_methodology)]
+	/// The methodology referenced by an asset.
+	pub(super) type AssetMethodology<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, u32>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_badges)]
+	/// Co-benefit badges of an asset attested by one of the `Verifiers`.
+	pub(super) type AssetBadges<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		CoBenefitBadges<T::AccountId, BoundedVec<u8, T::StringLimit>>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn retirement_quota)]
+	/// The retirement quota of a sub-account drawn from the balance of an organization account,
+	/// by organization account, sub-account and asset.
+	pub(super) type RetirementQuotas<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, AssetId>,
+		),
+		RetirementQuota<T::Balance, T::BlockNumber>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_vintage)]
+	/// The vintage year of the carbon credits of an asset, set on creation.
+	pub(super) type AssetVintage<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, u16>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn vintage_supply)]
+	/// The issued and retired totals of the assets of every vintage year.
+	pub(super) type VintageSupply<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u16, VintageTotals<T::Balance>, ValueQuery>;
+
+	#[pallet::storage]
+	/// Reverse index of `AssetMethodology`: the assets referencing a methodology.
+	pub(super) type MethodologyAssets<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, AssetId, ()>;
+
+	#[pallet::storage]
+	/// Where the housekeeping in `on_idle` continues: the stage and the last scanned storage
+	/// key, empty at the start of the stage.
+	pub(super) type HousekeepingCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (HousekeepingStage, BoundedVec<u8, ConstU32<256>>)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn housekeeping_totals)]
+	/// Total numbers of records removed by the housekeeping.
+	pub(super) type HousekeepingTotals<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, HousekeepingReport, ValueQuery>;
+
+	#[pallet::storage]
+	/// Co-owner of an asset under dual control, who confirms the sensitive owner actions.
+	pub(super) type CoOwner<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, T::AccountId>;
+
+	#[pallet::storage]
+	/// Owner actions of an asset under dual control waiting for the confirmation by the
+	/// co-owner.
+	pub(super) type PendingOwnerActions<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		BoundedVec<
+			PendingOwnerAction<T::AccountId, BoundedVec<u8, T::StringLimit>>,
+			T::MaxPendingOwnerActions,
+		>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The id of the next pending owner action.
+	pub(super) type NextOwnerActionId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// Organization identity (e.g. a company name or a DID) registered by an AccountId and the
+	/// deposit reserved for it.
+	pub(super) type AccountOrganization<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		(BoundedVec<u8, T::StringLimit>, DepositBalanceOf<T, I>),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn account_alias)]
+	/// Unique display name registered by an AccountId for certificate rendering, and the
+	/// deposit reserved for it.
+	pub(super) type AccountAlias<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		(BoundedVec<u8, T::StringLimit>, DepositBalanceOf<T, I>),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn alias_account)]
+	/// The account registered with a display alias.
+	pub(super) type AliasAccount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::StringLimit>, T::AccountId>;
+
+	#[pallet::storage]
+	/// The owner of an organization identity, registered by the custodian. The owner approves
+	/// the accounts registered with the organization.
+	pub(super) type OrganizationOwners<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::StringLimit>, T::AccountId>;
+
+	#[pallet::storage]
+	/// Accounts registered with an organization and approved by its owner or the custodian.
+	/// Only their retirements roll up in `OrganizationRetired`.
+	pub(super) type OrganizationMembers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::StringLimit>,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+
+	#[pallet::storage]
+	/// Amount of an asset burned by all the accounts of an organization while they were
+	/// registered with it and approved.
+	pub(super) type OrganizationRetired<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::StringLimit>,
+		Blake2_128Concat,
+		AssetId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Log of burns done by the custodian from an AccountId, kept apart from the voluntary
+	/// retirements in `BurnCertificate`. The second key is the index of the burn.
+	pub(super) type CustodialBurns<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		CustodialBurnRecord<T::Balance, T::BlockNumber, BoundedVec<u8, T::StringLimit>>,
+	>;
+
+	#[pallet::storage]
+	/// Number of burns done by the custodian from an AccountId.
+	pub(super) type CustodialBurnCount<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Consolidated retirements of baskets of carbon assets by an AccountId, normalized to
+	/// tonnes of CO2e. The second key is the index of the retirement.
+	pub(super) type Co2eRetirements<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		Co2eRetirement<
+			T::Balance,
+			T::BlockNumber,
+			BoundedVec<(AssetId, T::Balance), T::MaxRetirementBasket>,
+		>,
+	>;
+
+	#[pallet::storage]
+	/// Number of consolidated retirements of an AccountId.
+	pub(super) type Co2eRetirementCount<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Confirmations by the custodian that burns of an AccountId were mirrored in the external
+	/// registry.
+	pub(super) type RetirementConfirmation<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		AssetId,
+		RegistryConfirmation<T::Balance, BoundedVec<u8, T::StringLimit>>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn unconfirmed_retirements)]
+	/// Amount of carbon credits retired since the upgrade to SLA reports and not yet confirmed
+	/// by the custodian in the external registry.
+	pub(super) type UnconfirmedRetirements<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// The block since which `UnconfirmedRetirements` is not zero.
+	pub(super) type UnconfirmedSince<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn custodian_last_action)]
+	/// The block of the last retirement confirmed by the custodian.
+	pub(super) type CustodianLastAction<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn custodian_sla)]
+	/// The last SLA report of the custodian submitted by the off-chain worker.
+	pub(super) type CustodianSla<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, CustodianSlaReport<T::BlockNumber, T::Balance>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn reserved_supply)]
+	/// Part of the asset supply minted as reserve-backed to the `ReserveAccounts`. It is
+	/// included in the asset supply, but is not circulating.
+	pub(super) type ReservedSupply<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Nonces of the redeemed retirement vouchers of an AccountId.
+	pub(super) type RedeemedVouchers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u64,
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorship_pot)]
+	/// The pots paying the fees of the `self_burn` calls of an asset.
+	pub(super) type SponsorshipPots<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		SponsorshipPot<T::AccountId, DepositBalanceOf<T, I>>,
+	>;
+
+	#[pallet::storage]
+	/// The block of the last sponsored retirement of an account.
+	pub(super) type LastSponsoredRetirement<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn project_details)]
+	/// The registry standard, methodology code, vintage, country and registry id of the project
+	/// behind an asset, set with the project data before the first mint.
+	pub(super) type ProjectDetailsOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		ProjectDetails<BoundedVec<u8, T::StringLimit>>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn mint_request)]
+	/// The mint requests of asset owners awaiting the approval of the custodian of the asset.
+	pub(super) type MintRequests<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		MintRequest<
+			T::AccountId,
+			T::Balance,
+			DepositBalanceOf<T, I>,
+			T::BlockNumber,
+			BoundedVec<u8, T::StringLimit>,
+		>,
+	>;
+
+	#[pallet::storage]
+	/// The ids of the mint requests of an asset, at most `MaxPendingMintRequests`.
+	pub(super) type PendingMintRequests<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		BoundedVec<u64, T::MaxPendingMintRequests>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The number of the mint requests of all assets.
+	pub(super) type PendingMintRequestCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// The block since which the mint requests wait for a decision of the custodians: the
+	/// first pending request or the last decision, whichever is later.
+	pub(super) type MintRequestsSince<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::BlockNumber>;
+
+	#[pallet::storage]
+	/// The id of the next mint request.
+	pub(super) type NextMintRequestId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_custodian)]
+	/// The custodian of the asset, minting and burning it instead of the global `Custodian`.
+	pub(super) type Custodians<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn account_freeze_reason)]
+	/// The reason given by the freezer for a frozen account of an asset.
+	pub(super) type AccountFreezeReason<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u8, T::StringLimit>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_freeze_reason)]
+	/// The reason given by the freezer for a frozen asset.
+	pub(super) type AssetFreezeReason<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, BoundedVec<u8, T::StringLimit>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn signed_burn_nonce)]
+	/// The next nonce of the signed burn payloads of an AccountId, see `self_burn_signed`.
+	pub(super) type SignedBurnNonce<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	#[pallet::storage]
+	/// The reverse index of `Account`: the assets an AccountId has an account in.
+	pub(super) type AccountAssets<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, AssetId, ()>;
+
+	#[pallet::storage]
+	/// The assets per metadata symbol in ASCII upper case, see `symbol_exists`.
+	pub(super) type SymbolAssets<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::StringLimit>,
+		Blake2_128Concat,
+		AssetId,
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	/// The operation classes paused across all assets, a bitmask of `PAUSE_MINT`, `PAUSE_BURN`,
+	/// `PAUSE_TRANSFER` and `PAUSE_APPROVALS`.
+	pub(super) type Paused<T: Config<I>, I: 'static = ()> = StorageValue<_, u8, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn project_status)]
+	/// The verification stage of the project of an asset.
+	pub(super) type ProjectStatusOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, ProjectStatus, ValueQuery>;
+
+	#[pallet::storage]
+	/// Privileged actions of the custodian, kept on-chain for the audit of the custodian. At
+	/// most `MaxCustodianLog` entries are kept, the oldest entry is pruned first.
+	pub(super) type CustodianLog<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		CustodianLogEntry<T::AccountId, T::Balance, T::BlockNumber>,
+	>;
+
+	#[pallet::storage]
+	/// The id of the oldest entry in the `CustodianLog`.
+	pub(super) type CustodianLogHead<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	/// The id of the next entry in the `CustodianLog`.
+	pub(super) type CustodianLogTail<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	/// The serial ranges of the carbon credits minted for an asset, sorted by the first serial.
+	pub(super) type SerialRanges<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		BoundedVec<SerialRange<T::Balance>, T::MaxSerialRanges>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The serials consumed by a retirement, under the retirement anchor.
+	pub(super) type RetirementSerials<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Identity,
+		T::Hash,
+		BoundedVec<(u64, u64), T::MaxSerialRanges>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn permit_nonce)]
+	/// The next nonce of the approval permits of an AccountId, see `approve_with_signature`.
+	pub(super) type PermitNonce<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_account)]
+	/// The accounts funded by the custodian with `sponsor_account` and not yet settled by
+	/// `clawback_sponsorship`.
+	pub(super) type SponsoredAccounts<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Sponsorship<T::AccountId, DepositBalanceOf<T, I>, T::BlockNumber>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn held_balance)]
+	/// The amount of an asset put on hold in an asset-account through `fungibles::MutateHold`,
+	/// e.g. as loan collateral. It stays in the balance, but cannot be transferred or burned.
+	pub(super) type Holds<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The freezes of an asset-account placed through `MutateFreeze`. They overlap each other
+	/// and the held amount: the largest of them is locked.
+	pub(super) type Freezes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::FreezeIdentifier, T::Balance>, T::MaxFreezes>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn export_attestation)]
+	/// The exports of carbon assets to other registries by their id, see `export_retirement`.
+	pub(super) type ExportAttestations<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		ExportAttestation<
+			T::AccountId,
+			T::Balance,
+			T::BlockNumber,
+			BoundedVec<u8, T::StringLimit>,
+			BoundedVec<(u64, u64), T::MaxSerialRanges>,
+		>,
+	>;
+
+	#[pallet::storage]
+	/// The id of the next export attestation.
+	pub(super) type NextExportId<T: Config<I>, I: 'static = ()> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn total_exported)]
+	/// Total amount of an asset exported to other registries. It is not part of `TotalRetired`.
+	pub(super) type TotalExported<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, T::Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// The accounts which may transfer an asset whose `AssetDetails::whitelist_enabled` is set,
+	/// e.g. the approved counterparties of jurisdiction-restricted credits.
+	pub(super) type TransferWhitelist<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn retirement_record)]
+	/// The public registry of every retirement and custodial burn by its certificate id. The ids
+	/// are assigned in order from zero, so the registry can be enumerated by other registries.
+	pub(super) type RetirementRegistry<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		RetirementRecord<T::AccountId, T::Balance, T::BlockNumber>,
+	>;
+
+	#[pallet::storage]
+	/// The viewer keys authorized by `ForceOrigin` to read holder lists and account dumps via
+	/// `CarbonAssetsViewerApi`, e.g. the auditors of a consortium deployment.
+	pub(super) type ViewerKeys<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn compliance_window)]
+	/// The first and the last block of the current compliance true-up period, during which the
+	/// `ComplianceMarketAssets` cannot be transferred, only retired.
+	pub(super) type ComplianceWindow<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (T::BlockNumber, T::BlockNumber)>;
+
+	#[pallet::storage]
+	/// The assets of compliance markets, locked during the `ComplianceWindow`.
+	pub(super) type ComplianceMarketAssets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, ()>;
+
+	#[pallet::storage]
+	/// Evercity custodian - only custodian can mint or burn assets
+	pub(super) type Custodian<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		T::AccountId
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_custodian)]
+	/// The custodian proposed by the `ForceOrigin` and the block from which it can accept the
+	/// role.
+	pub(super) type PendingCustodian<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (T::AccountId, T::BlockNumber)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_asset_custodian)]
+	/// The custodian of an asset proposed by the `ForceOrigin`, `None` to fall back to the
+	/// global custodian, and the block from which the change can be accepted.
+	pub(super) type PendingAssetCustodian<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, (Option<T::AccountId>, T::BlockNumber)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_owner)]
+	/// The owner proposed by the owner of an asset and the last block to accept the ownership.
+	pub(super) type PendingOwner<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, (T::AccountId, T::BlockNumber)>;
+
+	#[pallet::storage]
+	#[pallet::unbounded]
+	/// The assets whose balances or supply changed since the last supply conservation check.
+	/// Only written with the `conservation-check` feature, see the `conservation` module.
+	pub(super) type ConservationTouched<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, Vec<AssetId>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_last_id)]
+	/// Last created AssetId
+	pub(super) type LastNonce<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		u64,
+		ValueQuery,
+		InitialNonce
+	>;
+
+	#[pallet::type_value]
+	pub(super) fn InitialNonce() -> u64 { 100 }
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		/// Genesis custodian: custodian_address
+		pub custodian: Option<T::AccountId>,
+		/// Genesis assets: id, owner, is_sufficient, min_balance
+		pub assets: Vec<(AssetId, T::AccountId, bool, T::Balance)>,
+		/// Genesis metadata: id, name, symbol, decimals
+		pub metadata: Vec<(AssetId, Vec<u8>, Vec<u8>, u8)>,
+		/// Genesis accounts: id, account_id, balance
+		pub accounts: Vec<(AssetId, T::AccountId, T::Balance)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
+		fn default() -> Self {
+			Self {
+				custodian: None,
+				assets: Default::default(),
+				metadata: Default::default(),
+				accounts: Default::default(),
+			}
+		}
+	}
+
+
+	#[cfg(feature = "std")]
+	impl<T: Config<I>, I: 'static> GenesisConfig<T, I> {
+		/// Check the genesis config for inconsistencies without touching the storage.
+		///
+		/// Returns all the problems found, so the chain spec can be fixed at once.
+		pub fn validate(&self) -> Result<(), Vec<String>> {
+			use sp_std::collections::btree_map::BTreeMap;
+
+			let mut errors = Vec::new();
+			let string_limit = T::StringLimit::get() as usize;
+
+			let mut assets = BTreeMap::new();
+			for (id, _, _, min_balance) in &self.assets {
+				if assets.insert(*id, *min_balance).is_some() {
+					errors.push(format!("asset {:?}: duplicate asset id", id));
+				}
+				if min_balance.is_zero() {
+					errors.push(format!("asset {:?}: min balance should not be zero", id));
+				}
+			}
+
+			let mut metadata = BTreeMap::new();
+			for (id, name, symbol, _) in &self.metadata {
+				if !assets.contains_key(id) {
+					errors.push(format!("metadata {:?}: asset does not exist", id));
+				}
+				if metadata.insert(*id, ()).is_some() {
+					errors.push(format!("metadata {:?}: duplicate metadata", id));
+				}
+				if name.len() > string_limit {
+					errors.push(format!(
+						"metadata {:?}: name is {} bytes long, limit is {}",
+						id,
+						name.len(),
+						string_limit
+					));
+				}
+				if symbol.len() > string_limit {
+					errors.push(format!(
+						"metadata {:?}: symbol is {} bytes long, limit is {}",
+						id,
+						symbol.len(),
+						string_limit
+					));
+				}
+			}
+
+			let mut accounts = BTreeMap::new();
+			let mut supplies = BTreeMap::<AssetId, T::Balance>::new();
+			for (id, account_id, amount) in &self.accounts {
+				match assets.get(id) {
+					None => errors.push(format!(
+						"account {:?} of asset {:?}: asset does not exist",
+						account_id, id
+					)),
+					Some(min_balance) if amount < min_balance => errors.push(format!(
+						"account {:?} of asset {:?}: balance {:?} is below min balance {:?}",
+						account_id, id, amount, min_balance
+					)),
+					Some(_) => (),
+				}
+				if accounts.insert((*id, account_id.clone()), ()).is_some() {
+					errors.push(format!(
+						"account {:?} of asset {:?}: duplicate account",
+						account_id, id
+					));
+				}
+				let supply = supplies.entry(*id).or_default();
+				match supply.checked_add(amount) {
+					Some(s) => *supply = s,
+					None => errors.push(format!("asset {:?}: total supply overflow", id)),
+				}
+			}
+
+			if errors.is_empty() {
+				Ok(())
+			} else {
+				Err(errors)
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
+		fn build(&self) {
+			if let Err(errors) = self.validate() {
+				panic!("Invalid carbon assets genesis config:\n{}", errors.join("\n"));
+			}
+
+			if let Some(custodian_account) = &self.custodian {
+				Custodian::<T, I>::put(custodian_account);
+			}
+
+			for (id, owner, is_sufficient, min_balance) in &self.assets {
+				Asset::<T, I>::insert(
+					id,
+					AssetDetails {
+						owner: owner.clone(),
+						issuer: owner.clone(),
+						admin: owner.clone(),
+						freezer: owner.clone(),
+						supply: Zero::zero(),
+						deposit: Zero::zero(),
+						min_balance: *min_balance,
+						is_sufficient: *is_sufficient,
+						accounts: 0,
+						sufficients: 0,
+						approvals: 0,
+						status: AssetStatus::Live,
+						whitelist_enabled: false,
+					},
+				);
+				Pallet::<T, I>::note_owner(*id, None, Some(owner));
+			}
+
+			for (id, name, symbol, decimals) in &self.metadata {
+				let metadata = AssetMetadata {
+					deposit: Zero::zero(),
+					url: Default::default(),
+					data_ipfs: Default::default(),
+					name: name.clone().try_into().expect("checked in validate; qed"),
+					symbol: symbol.clone().try_into().expect("checked in validate; qed"),
+					decimals: *decimals,
+					is_frozen: false,
+				};
+				Metadata::<T, I>::insert(id, metadata);
+				Pallet::<T, I>::note_symbol(*id, None, Some(symbol));
+			}
+
+			for (id, account_id, amount) in &self.accounts {
+				let result = <Pallet<T, I>>::increase_balance(
+					*id,
+					account_id,
+					*amount,
+					|details, _| -> DispatchResult {
+						debug_assert!(
+							T::Balance::max_value() - details.supply >= *amount,
+							"checked in prep; qed"
+						);
+						details.supply = details.supply.saturating_add(*amount);
+						Ok(())
+					},
+				);
+				if let Err(e) = result {
+					panic!(
+						"Invalid carbon assets genesis config:\naccount {:?} of asset {:?}: {:?}",
+						account_id, id, e
+					);
+				}
+			}
+			if let Err(e) = <Pallet<T, I>>::ensure_supply_conserved() {
+				panic!("Invalid carbon assets genesis config:\n{}", e);
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// Some asset class was created.
+		Created { asset_id: AssetId, creator: T::AccountId },
+		/// Some assets were issued.
+		Issued { asset_id: AssetId, owner: T::AccountId, total_supply: T::Balance },
+		/// Double-entry record of a balance mutation. `debit` is `None` for mints and `credit` is
+		/// `None` for burns. Only emitted if `LedgerEvents` is enabled.
+		LedgerEntry {
+			op: LedgerOp,
+			asset_id: AssetId,
+			debit: Option<T::AccountId>,
+			credit: Option<T::AccountId>,
+			amount: T::Balance,
+		},
+		/// A retirement voucher of `signer` was redeemed.
+		RetirementVoucherRedeemed {
+			signer: T::AccountId,
+			asset_id: AssetId,
+			amount: T::Balance,
+			nonce: u64,
+		},
+		/// Some reserve-backed assets were issued to a reserve account.
+		ReserveBackedIssued { asset_id: AssetId, owner: T::AccountId, amount: T::Balance },
+		/// Some assets were transferred.
+		Transferred {
+			asset_id: AssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Some assets were destroyed.
+		Burned { asset_id: AssetId, owner: T::AccountId, balance: T::Balance },
+		/// The management team changed.
+		TeamChanged {
+			asset_id: AssetId,
+			issuer: T::AccountId,
+			admin: T::AccountId,
+			freezer: T::AccountId,
+		},
+		/// The owner changed.
 		OwnerChanged { asset_id: AssetId, owner: T::AccountId },
-		/// Some account `who` was frozen.
-		Frozen { asset_id: AssetId, who: T::AccountId },
+		/// Some account `who` was frozen, with the reason given by the freezer, if any.
+		Frozen { asset_id: AssetId, who: T::AccountId, reason: Option<Vec<u8>> },
 		/// Some account `who` was thawed.
 		Thawed { asset_id: AssetId, who: T::AccountId },
-		/// Some asset `asset_id` was frozen.
-		AssetFrozen { asset_id: AssetId },
+		/// Some asset `asset_id` was frozen, with the reason given by the freezer, if any.
+		AssetFrozen { asset_id: AssetId, reason: Option<Vec<u8>> },
 		/// Some asset `asset_id` was thawed.
 		AssetThawed { asset_id: AssetId },
+		/// Some assets were issued to the owners of several assets in one batch: asset, owner
+		/// and amount.
+		BatchIssued { issued: Vec<(AssetId, T::AccountId, T::Balance)> },
 		/// An asset class was destroyed.
 		Destroyed { asset_id: AssetId },
+		/// The destruction of an asset class was started.
+		DestructionStarted { asset_id: AssetId },
+		/// Accounts were removed from an asset class in destruction.
+		AccountsDestroyed { asset_id: AssetId, accounts_destroyed: u32, accounts_remaining: u32 },
+		/// Approvals were removed from an asset class in destruction.
+		ApprovalsDestroyed { asset_id: AssetId, approvals_destroyed: u32, approvals_remaining: u32 },
 		/// Some asset class was force-created.
 		ForceCreated { asset_id: AssetId, owner: T::AccountId },
 		/// New metadata has been set for an asset.
 		MetadataSet {
 			asset_id: AssetId,
 			name: Vec<u8>,
-			symbol: Vec<u8>,
-			decimals: u8,
-			is_frozen: bool,
-		},
-		/// Metadata has been cleared for an asset.
-		MetadataCleared { asset_id: AssetId },
-		/// (Additional) funds have been approved for transfer to a destination account.
-		ApprovedTransfer {
-			asset_id: AssetId,
-			source: T::AccountId,
-			delegate: T::AccountId,
-			amount: T::Balance,
-		},
-		/// An approval for account `delegate` was cancelled by `owner`.
-		ApprovalCancelled { asset_id: AssetId, owner: T::AccountId, delegate: T::AccountId },
-		/// An `amount` was transferred in its entirety from `owner` to `destination` by
-		/// the approved `delegate`.
-		TransferredApproved {
-			asset_id: AssetId,
-			owner: T::AccountId,
-			delegate: T::AccountId,
-			destination: T::AccountId,
-			amount: T::Balance,
-		},
-		/// An asset has had its attributes changed by the `Force` origin.
-		AssetStatusChanged { asset_id: AssetId },
-		/// New custodian has been set by the `Force` origin.
-		CustodianSet { custodian: T::AccountId},
-		/// Metadata has been updated with `url` and `data_ipfs`.
-		MetadataUpdated { asset_id: AssetId, url: Vec<u8>, data_ipfs: Vec<u8>},
-		/// Carbon credites burned by `account`.
-		CarbonCreditsBurned { account: T::AccountId, asset_id: AssetId, amount: T::Balance },
-	}
+			symbol: Vec<u8>,
+			decimals: u8,
+			is_frozen: bool,
+		},
+		/// Metadata has been cleared for an asset.
+		MetadataCleared { asset_id: AssetId },
+		/// (Additional) funds have been approved for transfer to a destination account.
+		ApprovedTransfer {
+			asset_id: AssetId,
+			source: T::AccountId,
+			delegate: T::AccountId,
+			amount: T::Balance,
+		},
+		/// The approval of `owner` to `delegate` expires at block `expiry`.
+		ApprovalExpirySet {
+			asset_id: AssetId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			expiry: T::BlockNumber,
+		},
+		/// An approval for account `delegate` was cancelled by `owner`.
+		ApprovalCancelled { asset_id: AssetId, owner: T::AccountId, delegate: T::AccountId },
+		/// An `amount` was transferred in its entirety from `owner` to `destination` by
+		/// the approved `delegate`.
+		TransferredApproved {
+			asset_id: AssetId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			destination: T::AccountId,
+			amount: T::Balance,
+		},
+		/// An asset has had its attributes changed by the `Force` origin.
+		AssetStatusChanged { asset_id: AssetId },
+		/// New custodian has been set by the `Force` origin or accepted the proposed role.
+		CustodianSet { custodian: T::AccountId},
+		/// Metadata has been updated with `url` and `data_ipfs`.
+		MetadataUpdated { asset_id: AssetId, url: Vec<u8>, data_ipfs: Vec<u8>},
+		/// An attribute of an asset was set.
+		AttributeSet { asset_id: AssetId, key: Vec<u8>, value: Vec<u8> },
+		/// An attribute of an asset was cleared.
+		AttributeCleared { asset_id: AssetId, key: Vec<u8> },
+		/// Carbon credits of `account` were burned by the custodian for `reason`.
+		CustodialBurned {
+			account: T::AccountId,
+			asset_id: AssetId,
+			amount: T::Balance,
+			reason: CustodialBurnReason,
+		},
+		/// Carbon credits of `account` burned by `initiator`, either retired by the holder or
+		/// burned by the custodian, under the unique `certificate_id`.
+		CarbonCreditsBurned {
+			account: T::AccountId,
+			asset_id: AssetId,
+			amount: T::Balance,
+			kind: BurnKind,
+			initiator: T::AccountId,
+			alias: Option<BoundedVec<u8, T::StringLimit>>,
+			certificate_id: u64,
+		},
+		/// A basket of carbon credits was retired by `account` as `co2e` tonnes of CO2e, recorded
+		/// in `Co2eRetirements` under `index`.
+		Co2eRetired { account: T::AccountId, index: u32, co2e: T::Balance },
+		/// A retirement of `amount` of asset by `account` was stored under the `anchor` hash.
+		RetirementAnchored {
+			account: T::AccountId,
+			asset_id: AssetId,
+			amount: T::Balance,
+			anchor: T::Hash,
+		},
+		/// The `Outbox` messages up to `up_to` were delivered and removed.
+		OutboxAcknowledged { up_to: u64 },
+		/// `account` registered with the `organization`.
+		OrganizationSet { account: T::AccountId, organization: Vec<u8> },
+		/// `account` is no longer registered with an organization.
+		OrganizationCleared { account: T::AccountId },
+		/// `account` registered the display `alias`.
+		AliasSet { account: T::AccountId, alias: Vec<u8> },
+		/// `account` no longer has a display alias.
+		AliasCleared { account: T::AccountId },
+		/// Custodian confirmed that `amount` of carbon credits burned by `account` were retired
+		/// in the external registry under `external_ref`.
+		RetirementRegistrationConfirmed {
+			account: T::AccountId,
+			asset_id: AssetId,
+			amount: T::Balance,
+			external_ref: Vec<u8>,
+		},
+		/// The off-chain worker reported that `pending_amount` of retired carbon credits have
+		/// waited `waiting` blocks for the custodian at block `reported_at`.
+		CustodianSlaReported {
+			reported_at: T::BlockNumber,
+			waiting: T::BlockNumber,
+			pending_amount: T::Balance,
+		},
+		/// The asset is under dual control of its owner and `co_owner`, or under a single owner
+		/// if `co_owner` is `None`.
+		CoOwnerSet { asset_id: AssetId, co_owner: Option<T::AccountId> },
+		/// The owner action `action_id` waits for the confirmation by the co-owner.
+		OwnerActionProposed { asset_id: AssetId, action_id: u32 },
+		/// The co-owner confirmed and performed the owner action `action_id`.
+		OwnerActionConfirmed { asset_id: AssetId, action_id: u32 },
+		/// The owner action `action_id` was cancelled.
+		OwnerActionCancelled { asset_id: AssetId, action_id: u32 },
+		/// The methodology was approved under `name`.
+		MethodologyRegistered { methodology: u32, name: Vec<u8> },
+		/// The methodology is no longer approved.
+		MethodologyDeregistered { methodology: u32 },
+		/// The asset references the methodology.
+		AssetMethodologySet { asset_id: AssetId, methodology: u32 },
+		/// The `verifier` attested the co-benefit `badges` of the asset. Zero `badges` remove
+		/// them.
+		AssetBadgesSet { asset_id: AssetId, badges: u32, verifier: T::AccountId },
+		/// The housekeeping removed metadata of destroyed assets, approvals of zero amount and
+		/// empty asset-accounts.
+		HousekeepingDone { metadata: u32, approvals: u32, accounts: u32 },
+		/// The splits of every `transfer_with_splits` of the asset were set.
+		AssetSplitsSet { asset_id: AssetId, splits: Vec<(T::AccountId, Perbill)> },
+		/// `amount` was transferred to `to` after paying the `splits` of the asset.
+		TransferredWithSplits {
+			asset_id: AssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+			splits: Vec<(T::AccountId, T::Balance)>,
+		},
+		/// The custodian of the asset was set, or cleared in favour of the global custodian.
+		AssetCustodianSet { asset_id: AssetId, custodian: Option<T::AccountId> },
+		/// The owner of the asset requested to mint `amount` of it.
+		MintRequested { request_id: u64, asset_id: AssetId, owner: T::AccountId, amount: T::Balance },
+		/// The custodian approved the mint request and minted the requested amount.
+		MintRequestApproved { request_id: u64, asset_id: AssetId, custodian: T::AccountId },
+		/// The custodian rejected the mint request with the `reason`.
+		MintRequestRejected { request_id: u64, asset_id: AssetId, reason: Vec<u8> },
+		/// The project details of the asset were set.
+		ProjectDetailsSet { asset_id: AssetId },
+		/// `amount` was added to the sponsorship pot of the asset by `funder`.
+		SponsorshipPotFunded { asset_id: AssetId, funder: T::AccountId, amount: DepositBalanceOf<T, I> },
+		/// The sponsorship pot of the asset was closed and `amount` returned to `funder`.
+		SponsorshipPotWithdrawn {
+			asset_id: AssetId,
+			funder: T::AccountId,
+			amount: DepositBalanceOf<T, I>,
+		},
+		/// The `fee` of the retirement of the asset by `who` was drawn from its sponsorship pot.
+		RetirementSponsored { asset_id: AssetId, who: T::AccountId, fee: DepositBalanceOf<T, I> },
+		/// `amount` was transferred to `to` with the `memo` of the sender (e.g. a purchase-order
+		/// reference).
+		TransferredWithMemo {
+			asset_id: AssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+			memo: Vec<u8>,
+		},
+		/// The carbon credits of the asset are of the `vintage` year.
+		AssetVintageSet { asset_id: AssetId, vintage: u16 },
+		/// The `organization` account granted `sub` a retirement quota of `per_period` of the
+		/// asset per period. Zero `per_period` revokes the quota.
+		RetirementQuotaGranted {
+			organization: T::AccountId,
+			sub: T::AccountId,
+			asset_id: AssetId,
+			per_period: T::Balance,
+		},
+		/// The `custodian` was proposed and can accept the role from the `activates_at` block.
+		CustodianProposed { custodian: T::AccountId, activates_at: T::BlockNumber },
+		/// The `owner` was proposed as the owner of an asset and can accept the ownership until
+		/// the `expires_at` block.
+		OwnershipProposed { asset_id: AssetId, owner: T::AccountId, expires_at: T::BlockNumber },
+		/// The pending ownership proposal of an asset was withdrawn.
+		OwnershipProposalCancelled { asset_id: AssetId },
+		/// A signed burn payload of `signer` was submitted by the `relayer`.
+		SignedBurnExecuted {
+			signer: T::AccountId,
+			asset_id: AssetId,
+			amount: T::Balance,
+			nonce: u64,
+			relayer: T::AccountId,
+		},
+		/// The operation classes `ops` were paused across all assets; `paused` is the bitmask
+		/// of all the paused classes.
+		OperationsPaused { ops: u8, paused: u8 },
+		/// All the operation classes were resumed.
+		OperationsUnpaused,
+		/// The project of an asset moved to the verification stage `status`.
+		ProjectStatusChanged { asset_id: AssetId, status: ProjectStatus, by: T::AccountId },
+		/// The serial ranges `ranges` of an asset were consumed by a retirement or a custodial
+		/// burn of `account`.
+		SerialsConsumed { asset_id: AssetId, account: T::AccountId, ranges: Vec<(u64, u64)> },
+		/// The deprecated alias `call` was dispatched by `who`.
+		DeprecatedCallUsed { call: DeprecatedCall, who: T::AccountId },
+		/// `who` was paid `amount` for removing items of the asset by a cleanup call.
+		CleanupRewarded { asset_id: AssetId, who: T::AccountId, amount: DepositBalanceOf<T, I> },
+		/// The amount of asset `asset_id` which `delegate` may transfer on behalf of `owner` was
+		/// changed to `amount`. Zero means the approval was removed.
+		AllowanceChanged {
+			asset_id: AssetId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			amount: T::Balance,
+		},
+		/// The approval permit `nonce` of `owner` was submitted by `relayer`.
+		ApprovalPermitted { owner: T::AccountId, nonce: u64, relayer: T::AccountId },
+		/// `amount` of asset `asset_id` of `who` was frozen; `frozen` is the new frozen amount.
+		AmountFrozen { asset_id: AssetId, who: T::AccountId, amount: T::Balance, frozen: T::Balance },
+		/// `amount` of asset `asset_id` of `who` was thawed; `frozen` is the new frozen amount.
+		AmountThawed { asset_id: AssetId, who: T::AccountId, amount: T::Balance, frozen: T::Balance },
+		/// The new account `who` was sent `amount` by the custodian `sponsor` and got an
+		/// asset-account of `asset_id`, if any.
+		AccountSponsored {
+			who: T::AccountId,
+			sponsor: T::AccountId,
+			amount: DepositBalanceOf<T, I>,
+			asset_id: Option<AssetId>,
+		},
+		/// The sponsorship of `who` was settled and `clawed_back` was returned to `sponsor`. It
+		/// is zero if `who` has been active.
+		SponsorshipClosed {
+			who: T::AccountId,
+			sponsor: T::AccountId,
+			clawed_back: DepositBalanceOf<T, I>,
+		},
+		/// `amount` of the asset was put on hold in the account of `who`.
+		Held { asset_id: AssetId, who: T::AccountId, amount: T::Balance },
+		/// `amount` of the asset held in the account of `who` was released.
+		Released { asset_id: AssetId, who: T::AccountId, amount: T::Balance },
+		/// `amount` of the asset was burned by `who` to be moved to another registry and
+		/// recorded in `ExportAttestations` under `export_id`. It is not a retirement.
+		CreditsExported { export_id: u64, asset_id: AssetId, who: T::AccountId, amount: T::Balance },
+		/// `who` was added to the transfer whitelist of the asset.
+		AddedToWhitelist { asset_id: AssetId, who: T::AccountId },
+		/// `who` was removed from the transfer whitelist of the asset.
+		RemovedFromWhitelist { asset_id: AssetId, who: T::AccountId },
+		/// The transfer whitelist of the asset was enforced or lifted.
+		WhitelistEnforcementSet { asset_id: AssetId, enabled: bool },
+		/// `amount` of the asset retired from `account` was recorded in `RetirementRegistry`
+		/// under `certificate_id`.
+		RetirementRegistered {
+			certificate_id: u64,
+			account: T::AccountId,
+			asset_id: AssetId,
+			amount: T::Balance,
+		},
+		/// The owner-level `calls` of the asset were executed by `owner_batch`.
+		OwnerBatchExecuted { asset_id: AssetId, calls: u32 },
+		/// The viewer key was authorized for privileged reads.
+		ViewerKeyAdded { viewer: T::AccountId },
+		/// The viewer key was removed.
+		ViewerKeyRemoved { viewer: T::AccountId },
+		/// The compliance window was set or cleared.
+		ComplianceWindowSet { window: Option<(T::BlockNumber, T::BlockNumber)> },
+		/// The asset was designated as a compliance-market asset or released from it.
+		ComplianceMarketSet { asset_id: AssetId, enabled: bool },
+		/// The `organization` identity was registered with its `owner`.
+		OrganizationRegistered { organization: Vec<u8>, owner: T::AccountId },
+		/// `account` registered with the `organization` was approved as its member.
+		OrganizationMemberApproved { account: T::AccountId, organization: Vec<u8> },
+		/// The account was authorized to acknowledge the outbox.
+		OutboxAuthorityAdded { authority: T::AccountId },
+		/// The outbox authority was removed.
+		OutboxAuthorityRemoved { authority: T::AccountId },
+		/// The `custodian` of the asset, or `None` for the global custodian, was proposed and
+		/// can accept the custody from the `activates_at` block.
+		AssetCustodianProposed {
+			asset_id: AssetId,
+			custodian: Option<T::AccountId>,
+			activates_at: T::BlockNumber,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Account balance must be greater than or equal to the transfer amount.
+		BalanceLow,
+		/// The account to alter does not exist.
+		NoAccount,
+		/// The signing account has no permission to do the operation.
+		NoPermission,
+		/// The given asset ID is unknown.
+		Unknown,
+		/// The origin account is frozen.
+		Frozen,
+		/// The asset ID is already taken, or the asset in destruction still has accounts,
+		/// approvals or attributes.
+		InUse,
+		/// Invalid witness data given.
+		BadWitness,
+		/// Minimum balance should be non-zero.
+		MinBalanceZero,
+		/// Unable to increment the consumer reference counters on the account. Either no provider
+		/// reference exists to allow a non-zero balance of a non-self-sufficient asset, or the
+		/// maximum number of consumers has been reached.
+		NoProvider,
+		/// Invalid metadata given.
+		BadMetadata,
+		/// No approval exists that would allow the transfer.
+		Unapproved,
+		/// The source account would not survive the transfer and it needs to stay alive.
+		WouldDie,
+		/// The asset-account already exists.
+		AlreadyExists,
+		/// The asset-account doesn't have an associated deposit.
+		NoDeposit,
+		/// The operation would result in funds being burned.
+		WouldBurn,
+		/// Operation can not be done, custodian need to be set.
+		NoCustodian,
+		/// The signing account is not the custodian of the asset.
+		NotAssetCustodian,
+		/// Metadata for the asset does not exist.
+		NoMetadata,
+		/// Project data cannot be changed after minting.
+		CannotChangeAfterMint,
+		/// Error creating AssetId
+		ErrorCreatingAssetId,
+		/// The confirmed amount exceeds the amount burned by the account.
+		ExceedsBurned,
+		/// The account is not one of the `ReserveAccounts`.
+		NotReserveAccount,
+		/// The signature of the voucher is invalid.
+		BadSignature,
+		/// The voucher is expired.
+		VoucherExpired,
+		/// The voucher was already redeemed.
+		VoucherAlreadyRedeemed,
+		/// The asset is frozen and `AllowMintWhenFrozen` is not set.
+		AssetFrozen,
+		/// The asset-account is frozen and `AllowMintWhenFrozen` is not set.
+		AccountFrozen,
+		/// The owner of the approvals still holds the asset.
+		OwnerAlive,
+		/// The `ApprovalGcGracePeriod` of the approvals has not passed yet.
+		GracePeriodNotPassed,
+		/// The attribute key or value is too long.
+		BadAttribute,
+		/// The attribute does not exist.
+		NoAttribute,
+		/// The retirement basket is empty or has more than `MaxRetirementBasket` assets.
+		BadBasket,
+		/// The account is not registered with an organization.
+		NoOrganization,
+		/// The mint batch is empty or has more than `MaxMintBatch` entries.
+		BadBatch,
+		/// The `Outbox` message does not exist yet.
+		BadOutboxId,
+		/// The IPFS evidence is not a well-formed CID.
+		BadCid,
+		/// The sender is not the co-owner of the asset.
+		NotCoOwner,
+		/// The asset has `MaxPendingOwnerActions` owner actions already.
+		TooManyPendingActions,
+		/// The pending owner action does not exist.
+		UnknownOwnerAction,
+		/// The asset does not reference a methodology and `MethodologyRequired` is set.
+		NoMethodology,
+		/// The methodology is not registered.
+		UnknownMethodology,
+		/// The sender is not one of the `Verifiers`.
+		NotVerifier,
+		/// The badges include undefined bits.
+		BadBadges,
+		/// The splits have more than `MaxSplits` recipients, repeat a recipient or sum up to
+		/// more than the whole amount.
+		BadSplits,
+		/// The splits differ from the `AssetSplits` of the asset.
+		SplitsMismatch,
+		/// The mint request does not exist.
+		UnknownMintRequest,
+		/// The minimum balance cannot be raised while the asset has accounts.
+		LiveAccounts,
+		/// The asset is being destroyed.
+		AssetNotLive,
+		/// The asset is not being destroyed.
+		IncorrectStatus,
+		/// The alias is registered by another account.
+		AliasTaken,
+		/// The account has no alias.
+		NoAlias,
+		/// The project details have an invalid country code, an empty project id or a vintage
+		/// other than the one of the asset.
+		BadProjectDetails,
+		/// The sponsorship pot was funded by another account.
+		NotPotFunder,
+		/// The asset has no sponsorship pot.
+		NoSponsorshipPot,
+		/// The account owns `MaxAssetsPerAccount` assets already.
+		TooManyOwnedAssets,
+		/// The approval has expired, or the expiry is not in the future.
+		ApprovalExpired,
+		/// The approval has not expired yet.
+		ApprovalNotExpired,
+		/// The SLA report is not due, is older than the stored one or the reports are disabled.
+		BadSlaReport,
+		/// The `Screening` rejected the sender or the receiver for the given reason.
+		ScreeningRejected(ScreeningRejection),
+		/// The sub-account has no retirement quota of the organization account.
+		NoQuota,
+		/// The retirement exceeds the rest of the quota in the current period.
+		QuotaExceeded,
+		/// The custodian is set already, a change goes through `propose_custodian`.
+		CustodianAlreadySet,
+		/// The sender is not the proposed custodian.
+		NotProposedCustodian,
+		/// The `CustodianChangeDelay` of the proposed custodian has not passed yet.
+		CustodianChangeNotDue,
+		/// The sender is not the proposed owner of the asset.
+		NotProposedOwner,
+		/// The `OwnershipAcceptancePeriod` of the proposed owner has passed.
+		OwnershipProposalExpired,
+		/// The nonce of the signed burn payload is not the next nonce of the signer.
+		BadNonce,
+		/// The signed burn payload is for another chain.
+		WrongGenesisHash,
+		/// The signed burn payload is expired.
+		PayloadExpired,
+		/// The operation is paused across all assets, see `pause`.
+		OperationPaused,
+		/// The pause bitmask has no known operation class or an unknown one.
+		BadPauseMask,
+		/// The project of the asset is not approved by an auditor and the custodian.
+		ProjectNotApproved,
+		/// The project of the asset is not in the verification stage the call expects.
+		BadProjectStatus,
+		/// The serial range is empty, does not match the minted amount, or the asset was minted
+		/// without serial ranges before.
+		BadSerialRange,
+		/// The serial range overlaps a serial range minted before.
+		SerialRangeOverlap,
+		/// The asset was minted with serial ranges, so every mint needs one.
+		SerialRangeRequired,
+		/// The asset has the maximum number of serial ranges.
+		TooManySerialRanges,
+		/// The allowance is lower than the decrease.
+		AllowanceUnderflow,
+		/// The asset would have more decimals than `CO2E_DECIMALS`.
+		BadDecimals,
+		/// The frozen amount of the account is lower than the amount to thaw.
+		NotEnoughFrozen,
+		/// The account already exists or has already been sponsored.
+		NotNewAccount,
+		/// The account is not sponsored.
+		NotSponsored,
+		/// The clawback period of the sponsorship has not passed yet.
+		SponsorshipNotExpired,
+		/// Less than the amount is on hold.
+		NotEnoughHeld,
+		/// The asset-account has `MaxFreezes` freezes already.
+		TooManyFreezes,
+		/// The asset can only be transferred between whitelisted accounts.
+		NotWhitelisted,
+		/// The owner batch is empty or has more than `MaxOwnerBatch` calls.
+		BadOwnerBatch,
+		/// The metadata was rejected by the `MetadataValidator` of the runtime.
+		MetadataRejected(MetadataRejection),
+		/// The account is not a viewer key.
+		NotViewer,
+		/// The asset cannot be transferred during the compliance window, only retired.
+		ComplianceLocked,
+		/// The compliance window ends before it starts.
+		BadComplianceWindow,
+		/// The organization identity is not registered.
+		UnknownOrganization,
+		/// The account is not an outbox authority.
+		NotOutboxAuthority,
+		/// The asset has `MaxPendingMintRequests` mint requests already.
+		TooManyMintRequests,
+		/// The amount to retire is zero.
+		ZeroRetirement,
+	}
+
+	#[pallet::extra_constants]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The version of the Call/Event surface, see `InterfaceVersion`.
+		#[allow(non_snake_case)]
+		fn Interface() -> InterfaceVersion {
+			Self::interface_version()
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::do_housekeeping(remaining_weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+			Self::ensure_supply_conserved()
+		}
+
+		fn offchain_worker(now: T::BlockNumber) {
+			T::OffchainWorker::offchain_worker(now);
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I> {
+		type Call = Call<T, I>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::report_custodian_sla { report } => Self::validate_sla_report(report),
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
+
+		fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
+			match call {
+				Call::report_custodian_sla { report } => Self::check_sla_report(report),
+				_ => Err(InvalidTransaction::Call.into()),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		
+		/// Sets the first custodian.
+		/// 
+		/// The origin must conform to `ForceOrigin`. Fails with `CustodianAlreadySet` once a
+		/// custodian is set, the change goes through `propose_custodian` and `accept_custodian`.
+		/// 
+		/// - `custodian`: New custodian to be set. Only custodian can verify creation of carbon 
+		/// credit asset and mint created carbon credit asset.
+		/// 
+		/// Emits `CustodianSet` when successful.
+		/// 
+		#[pallet::weight(T::WeightInfo::set_custodian())]
+		pub fn set_custodian(
+			origin: OriginFor<T>,
+			custodian: T::AccountId
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(!Custodian::<T, I>::exists(), Error::<T, I>::CustodianAlreadySet);
+			Custodian::<T, I>::put(custodian.clone());
+			Self::deposit_event(Event::CustodianSet { custodian });
+			Ok(())
+		}
+
+		/// Issue a new class of fungible carbon assets from a public origin.
+		///
+		/// This new asset class has no assets initially and its owner is the origin.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		/// 
+		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
+		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
+		/// 
+		/// Funds of sender are reserved by `AssetDeposit`.
+		/// 
+		/// Admin of asset is the Custodian. Fails if no custodian are set.
+		/// Set asset metadata: generated `name` and `symbol`, decimals to 9.
+		/// 
+		/// Emits `Created` event when successful.
+		/// Emits `MetadataSet` with generated `name` and `symbol`.
+		///
+		#[pallet::weight(T::WeightInfo::create())]
+		pub fn create(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_create(&owner, name, symbol, None, DEFAULT_DECIMALS).map(|_| ())
+		}
+
+		/// Deprecated alias of `set_project_data` without the project details. It keeps the call
+		/// index and the arguments of the original `set_project_data`.
+		///
+		/// Emits `MetadataUpdated` and `DeprecatedCallUsed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_project_data())]
+		pub fn set_project_data_v1(
+			origin: OriginFor<T>,
+			id: AssetId,
+			url: Vec<u8>,
+			data_ipfs: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			Self::set_project_data(origin, id, url, data_ipfs, None)?;
+			Self::deposit_event(Event::DeprecatedCallUsed {
+				call: DeprecatedCall::SetProjectDataV1,
+				who,
+			});
+			Ok(())
+		}
+
+		/// Issue a new class of fungible assets from a privileged origin.
+		///
+		/// This new asset class has no assets initially.
+		///
+		/// The origin must conform to `ForceOrigin`.
+		///
+		/// Unlike `create`, no funds are reserved.
+		///
+		/// - `id`: The identifier of the new asset. This must not be currently in use to identify
+		/// an existing asset.
+		/// - `owner`: The owner of this class of assets. The owner has full superuser permissions
+		/// over this asset, but may later change and configure the permissions using
+		/// `transfer_ownership`.
+		/// - `min_balance`: The minimum balance of this new asset that any single account must
+		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		///
+		/// Emits `ForceCreated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_create())]
+		pub fn force_create(
+			origin: OriginFor<T>,
+			id: AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			is_sufficient: bool,
+			#[pallet::compact] min_balance: T::Balance,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			Self::do_force_create(id, owner, is_sufficient, min_balance)
+		}
+
+		/// Destroy a class of fungible assets.
+		///
+		/// The origin must conform to `ForceOrigin` or must be Signed and the sender must be the
+		/// owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
+		/// asset.
+		///
+		/// Emits `Destroyed` event when successful, or `OwnerActionProposed` if the owner sends it
+		/// under dual control.
+		///
+		/// NOTE: It can be helpful to first freeze an asset before destroying it so that you
+		/// can provide accurate witness information and prevent users from manipulating state
+		/// in a way that can make it harder to destroy.
+		///
+		/// Weight: `O(c + p + a)` where:
+		/// - `c = (witness.accounts - witness.sufficients)`
+		/// - `s = witness.sufficients`
+		/// - `a = witness.approvals`
+		#[pallet::weight(T::WeightInfo::destroy(
+			witness.accounts.saturating_sub(witness.sufficients),
+ 			witness.sufficients,
+ 			witness.approvals,
+ 		))]
+		pub fn destroy(
+			origin: OriginFor<T>,
+			id: AssetId,
+			witness: DestroyWitness,
+		) -> DispatchResultWithPostInfo {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			if let Some(owner) = &maybe_check_owner {
+				if Self::needs_co_signing(id, owner) {
+					Self::queue_owner_action(id, OwnerAction::Destroy)?;
+					return Ok(Some(T::WeightInfo::propose_owner_action()).into())
+				}
+			}
+			let details = Self::do_destroy(id, witness, maybe_check_owner)?;
+			Ok(Some(T::WeightInfo::destroy(
+				details.accounts.saturating_sub(details.sufficients),
+				details.sufficients,
+				details.approvals,
+			))
+			.into())
+		}
+
+		/// Deprecated alias of `mint` without the serial range. It keeps the call index and the
+		/// arguments of the original `mint`.
+		///
+		/// Emits `Issued` and `DeprecatedCallUsed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint_v1(
+			origin: OriginFor<T>,
+			id: AssetId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			Self::mint(origin, id, amount, None)?;
+			Self::deposit_event(Event::DeprecatedCallUsed { call: DeprecatedCall::MintV1, who });
+			Ok(())
+		}
+
+		/// Deprecated alias of `burn` without the reason and the reference. It keeps the call
+		/// index and the arguments of the original `burn`. The burn is recorded with the
+		/// `Unspecified` reason.
+		///
+		/// Emits `CustodialBurned` and `DeprecatedCallUsed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn_v1(
+			origin: OriginFor<T>,
+			id: AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin.clone())?;
+			Self::burn(origin, id, who, amount, CustodialBurnReason::Unspecified, None)?;
+			Self::deposit_event(Event::DeprecatedCallUsed { call: DeprecatedCall::BurnV1, who: caller });
+			Ok(())
+		}
+
+		/// Deprecated alias of `self_burn` without the note. It keeps the call index and the
+		/// arguments of the original `self_burn`.
+		///
+		/// Emits `CarbonCreditsBurned` and `DeprecatedCallUsed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::self_burn())]
+		pub fn self_burn_v1(
+			origin: OriginFor<T>,
+			id: AssetId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+			let post_info = Self::self_burn(origin, id, amount, None)?;
+			Self::deposit_event(Event::DeprecatedCallUsed { call: DeprecatedCall::SelfBurnV1, who });
+			Ok(post_info)
+		}
+
+		/// Move some assets from the sender account to another.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `amount`: The amount by which the sender's balance of assets should be reduced and
+		/// `target`'s balance increased. The amount actually transferred may be slightly greater in
+		/// the case that the transfer would otherwise take the sender balance above zero but below
+		/// the minimum balance. Must be greater than zero.
+		///
+		/// Emits `Transferred` with the actual amount transferred. If this takes the source balance
+		/// to below the minimum for the asset, then the amount transferred is increased to take it
+		/// to zero.
+		///
+		/// Weight: `O(1)`
+		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
+		/// `target`.
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			id: AssetId,
+			target: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(target)?;
+
+			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+			Self::do_transfer(id, &origin, &dest, amount, None, f).map(|_| ())
+		}
+
+		/// Move some assets from the sender account to another, keeping the sender account alive.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `amount`: The amount by which the sender's balance of assets should be reduced and
+		/// `target`'s balance increased. The amount actually transferred may be slightly greater in
+		/// the case that the transfer would otherwise take the sender balance above zero but below
+		/// the minimum balance. Must be greater than zero.
+		///
+		/// Emits `Transferred` with the actual amount transferred. If this takes the source balance
+		/// to below the minimum for the asset, then the amount transferred is increased to take it
+		/// to zero.
+		///
+		/// Weight: `O(1)`
+		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
+		/// `target`.
+		#[pallet::weight(T::WeightInfo::transfer_keep_alive())]
+		pub fn transfer_keep_alive(
+			origin: OriginFor<T>,
+			id: AssetId,
+			target: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(target)?;
+
+			let f = TransferFlags { keep_alive: true, best_effort: false, burn_dust: false };
+			Self::do_transfer(id, &source, &dest, amount, None, f).map(|_| ())
+		}
+
+		/// Move some assets from one account to another.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `source`: The account to be debited.
+		/// - `dest`: The account to be credited.
+		/// - `amount`: The amount by which the `source`'s balance of assets should be reduced and
+		/// `dest`'s balance increased. The amount actually transferred may be slightly greater in
+		/// the case that the transfer would otherwise take the `source` balance above zero but
+		/// below the minimum balance. Must be greater than zero.
+		///
+		/// Emits `Transferred` with the actual amount transferred. If this takes the source balance
+		/// to below the minimum for the asset, then the amount transferred is increased to take it
+		/// to zero.
+		///
+		/// Weight: `O(1)`
+		/// Modes: Pre-existence of `dest`; Post-existence of `source`; Account pre-existence of
+		/// `dest`.
+		#[pallet::weight(T::WeightInfo::force_transfer())]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			id: AssetId,
+			source: <T::Lookup as StaticLookup>::Source,
+			dest: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let source = T::Lookup::lookup(source)?;
+			let dest = T::Lookup::lookup(dest)?;
+
+			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+			Self::do_transfer(id, &source, &dest, amount, Some(origin), f).map(|_| ())
+		}
+
+		/// Deprecated alias of `freeze` without the reason. It keeps the call index and the
+		/// arguments of the original `freeze`.
+		///
+		/// Emits `Frozen` and `DeprecatedCallUsed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze_v1(
+			origin: OriginFor<T>,
+			id: AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin.clone())?;
+			Self::freeze(origin, id, who, None)?;
+			Self::deposit_event(Event::DeprecatedCallUsed {
+				call: DeprecatedCall::FreezeV1,
+				who: caller,
+			});
+			Ok(())
+		}
+
+		/// Allow unprivileged transfers from an account again.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be frozen.
+		/// - `who`: The account to be unfrozen.
+		///
+		/// Emits `Thawed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::thaw())]
+		pub fn thaw(
+			origin: OriginFor<T>,
+			id: AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(origin == details.admin, Error::<T, I>::NoPermission);
+			let who = T::Lookup::lookup(who)?;
+
+			Account::<T, I>::try_mutate(id, &who, |maybe_account| -> DispatchResult {
+				maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?.is_frozen = false;
+				Ok(())
+			})?;
+			AccountFreezeReason::<T, I>::remove(id, &who);
+			Self::note_custodian_action(CustodianAction::Thaw, id, Some(&who), Zero::zero());
+
+			Self::deposit_event(Event::<T, I>::Thawed { asset_id: id, who });
+			Ok(())
+		}
+
+		/// Deprecated alias of `freeze_asset` without the reason. It keeps the call index and the
+		/// arguments of the original `freeze_asset`.
+		///
+		/// Emits `AssetFrozen` and `DeprecatedCallUsed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::freeze_asset())]
+		pub fn freeze_asset_v1(origin: OriginFor<T>, id: AssetId) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			Self::freeze_asset(origin, id, None)?;
+			Self::deposit_event(Event::DeprecatedCallUsed { call: DeprecatedCall::FreezeAssetV1, who });
+			Ok(())
+		}
+
+		/// Allow unprivileged transfers for the asset again.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be thawed.
+		///
+		/// Emits `Thawed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::thaw_asset())]
+		pub fn thaw_asset(
+			origin: OriginFor<T>,
+			id: AssetId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(origin == d.admin, Error::<T, I>::NoPermission);
+				ensure!(d.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+
+				d.status = AssetStatus::Live;
+				AssetFreezeReason::<T, I>::remove(id);
+				Self::note_custodian_action(CustodianAction::Thaw, id, None, Zero::zero());
+
+				Self::deposit_event(Event::<T, I>::AssetThawed { asset_id: id });
+				Ok(())
+			})
+		}
+
+		/// Propose a new Owner of an asset. The proposed owner takes over the asset and its
+		/// deposits via `accept_ownership` within `OwnershipAcceptancePeriod` blocks. Replaces the
+		/// pending proposal, if any, and proposing the current Owner withdraws it.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The proposed Owner of this asset.
+		///
+		/// Emits `OwnershipProposed` or `OwnershipProposalCancelled`, or `OwnerActionProposed`
+		/// under dual control.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			id: AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			if Self::needs_co_signing(id, &origin) {
+				return Self::queue_owner_action(id, OwnerAction::TransferOwnership(owner))
+			}
+			Self::do_propose_owner(id, &origin, owner)
+		}
+
+		/// Force the metadata for an asset to some value.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// Any deposit is left alone.
+		///
+		/// - `id`: The identifier of the asset to update.
+		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
+		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
+		/// - `url`: The url.
+		/// - `data_ipfs`: The ipfs data link, a well-formed CID.
+		/// - `decimals`: The number of decimals this asset uses to represent one unit.
+		///
+		/// Emits `MetadataSet`.
+		///
+		/// Weight: `O(N + S)` where N and S are the length of the name and symbol respectively.
+		#[pallet::weight(T::WeightInfo::force_set_metadata(name.len() as u32, symbol.len() as u32))]
+		pub fn force_set_metadata(
+			origin: OriginFor<T>,
+			id: AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			url: Vec<u8>,
+			data_ipfs: Vec<u8>,
+			decimals: u8,
+			is_frozen: bool,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let bounded_name: BoundedVec<u8, T::StringLimit> =
+				name.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+
+			let bounded_symbol: BoundedVec<u8, T::StringLimit> =
+				symbol.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+
+			let bounded_url: BoundedVec<u8, T::StringLimit> =
+				url.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+			let cid = cid::Cid::parse(&data_ipfs).ok_or(Error::<T, I>::BadMetadata)?;
+
+			ensure!(Asset::<T, I>::contains_key(id), Error::<T, I>::Unknown);
+			Metadata::<T, I>::try_mutate_exists(id, |metadata| {
+				let old = metadata.take();
+				let deposit = old.as_ref().map_or(Zero::zero(), |m| m.deposit);
+				Self::note_symbol(id, old.as_ref().map(|m| &m.symbol[..]), Some(&symbol));
+				LegacyDataIpfs::<T, I>::remove(id);
+				*metadata = Some(AssetMetadata {
+					deposit,
+					url: bounded_url,
+					data_ipfs: Some(cid),
+					name: bounded_name,
+					symbol: bounded_symbol,
+					decimals,
+					is_frozen,
+				});
+
+				Self::deposit_event(Event::MetadataSet {
+					asset_id: id,
+					name,
+					symbol,
+					decimals,
+					is_frozen,
+				});
+				Self::deposit_event(Event::MetadataUpdated {
+					asset_id: id,
+					url,
+					data_ipfs,
+				});
+				Ok(())
+			})
+		}
+
+		/// Clear the metadata for an asset.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// Any deposit is returned.
+		///
+		/// - `id`: The identifier of the asset to clear.
+		///
+		/// Emits `MetadataCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_clear_metadata())]
+		pub fn force_clear_metadata(
+			origin: OriginFor<T>,
+			id: AssetId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			Metadata::<T, I>::try_mutate_exists(id, |metadata| {
+				let old = metadata.take().ok_or(Error::<T, I>::Unknown)?;
+				let deposit = old.deposit;
+				Self::note_symbol(id, Some(&old.symbol), None);
+				LegacyDataIpfs::<T, I>::remove(id);
+				T::Deposits::release(&d.owner, deposit);
+				Self::note_deposit(Some(id), DepositPurpose::Metadata, deposit, Zero::zero());
+				Self::deposit_event(Event::MetadataCleared { asset_id: id });
+				Ok(())
+			})
+		}
+
+		/// Alter the attributes of a given asset.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The new Owner of this asset.
+		/// - `issuer`: The new Issuer of this asset.
+		/// - `admin`: The new Admin of this asset.
+		/// - `freezer`: The new Freezer of this asset.
+		/// - `min_balance`: The minimum balance of this new asset that any single account must
+		/// have. If an account's balance is reduced below this, then it collapses to zero. It
+		/// must be non-zero and can only be raised while the asset has no accounts, so no
+		/// existing balance falls below it.
+		/// - `is_sufficient`: Whether a non-zero balance of this asset is deposit of sufficient
+		/// value to account for the state bloat associated with its balance storage. If set to
+		/// `true`, then non-zero balances may be stored without a `consumer` reference (and thus
+		/// an ED in the Balances pallet or whatever else is used to control user-account state
+		/// growth). Applies to the accounts created afterwards: the existing accounts keep
+		/// the reference recorded in their `ExistenceReason` and release it when they die.
+		/// - `is_frozen`: Whether this asset class is frozen except for permissioned/admin
+		/// instructions.
+		///
+		/// Emits `AssetStatusChanged` with the identity of the asset.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_asset_status())]
+		pub fn force_asset_status(
+			origin: OriginFor<T>,
+			id: AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			admin: <T::Lookup as StaticLookup>::Source,
+			freezer: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] min_balance: T::Balance,
+			is_sufficient: bool,
+			is_frozen: bool,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Asset::<T, I>::try_mutate(id, |maybe_asset| {
+				let mut asset = maybe_asset.take().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(asset.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+				ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
+				ensure!(
+					min_balance <= asset.min_balance || asset.accounts == 0,
+					Error::<T, I>::LiveAccounts
+				);
+				let owner = T::Lookup::lookup(owner)?;
+				asset.issuer = T::Lookup::lookup(issuer)?;
+				asset.admin = T::Lookup::lookup(admin)?;
+				asset.freezer = T::Lookup::lookup(freezer)?;
+				Self::note_owner(id, Some(&asset.owner), Some(&owner));
+				asset.owner = owner;
+				asset.min_balance = min_balance;
+				asset.is_sufficient = is_sufficient;
+				asset.status = if is_frozen { AssetStatus::Frozen } else { AssetStatus::Live };
+				*maybe_asset = Some(asset);
+
+				Self::deposit_event(Event::AssetStatusChanged { asset_id: id });
+				Ok(())
+			})
+		}
+
+		/// Approve an amount of asset for transfer by a delegated third-party account.
+		///
+		/// Origin must be Signed.
+		///
+		/// Ensures that `ApprovalDeposit` worth of `Currency` is reserved from signing account
+		/// for the purpose of holding the approval. If some non-zero amount of assets is already
+		/// approved from signing account to `delegate`, then it is topped up or unreserved to
+		/// meet the right value.
+		///
+		/// NOTE: The signing account does not need to own `amount` of assets at the point of
+		/// making this call.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account to delegate permission to transfer asset.
+		/// - `amount`: The amount of asset that may be transferred by `delegate`. If there is
+		/// already an approval in place, then this acts additively and removes its expiry.
+		///
+		/// Emits `ApprovedTransfer` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_transfer())]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			id: AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_approve_transfer(id, &owner, &delegate, amount, None)
+		}
+
+		/// Cancel all of some asset approved for delegated transfer by a third-party account.
+		///
+		/// Origin must be Signed and there must be an approval in place between signer and
+		/// `delegate`.
+		///
+		/// Unreserves any deposit previously reserved by `approve_transfer` for the approval.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account delegated permission to transfer asset.
+		///
+		/// Emits `ApprovalCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_approval())]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			id: AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			Self::take_approval(id, &owner, &delegate, &mut d).ok_or(Error::<T, I>::Unknown)?;
+			Asset::<T, I>::insert(id, d);
+
+			Self::deposit_event(Event::ApprovalCancelled { asset_id: id, owner, delegate });
+			Ok(())
+		}
+
+		/// Cancel all of some asset approved for delegated transfer by a third-party account.
+		///
+		/// Origin must be either ForceOrigin or Signed origin with the signer being the Admin
+		/// account of the asset `id`.
+		///
+		/// Unreserves any deposit previously reserved by `approve_transfer` for the approval.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account delegated permission to transfer asset.
+		///
+		/// Emits `ApprovalCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_cancel_approval())]
+		pub fn force_cancel_approval(
+			origin: OriginFor<T>,
+			id: AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			T::ForceOrigin::try_origin(origin)
+				.map(|_| ())
+				.or_else(|origin| -> DispatchResult {
+					let origin = ensure_signed(origin)?;
+					ensure!(origin == d.admin, Error::<T, I>::NoPermission);
+					Ok(())
+				})?;
+
+			let owner = T::Lookup::lookup(owner)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			Self::take_approval(id, &owner, &delegate, &mut d).ok_or(Error::<T, I>::Unknown)?;
+			Asset::<T, I>::insert(id, d);
+
+			Self::deposit_event(Event::ApprovalCancelled { asset_id: id, owner, delegate });
+			Ok(())
+		}
+
+		/// Transfer some asset balance from a previously delegated account to some third-party
+		/// account.
+		///
+		/// Origin must be Signed and there must be an approval in place by the `owner` to the
+		/// signer.
+		///
+		/// If the entire amount approved for transfer is transferred, then any deposit previously
+		/// reserved by `approve_transfer` is unreserved.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which previously approved for a transfer of at least `amount` and
+		/// from which the asset balance will be withdrawn.
+		/// - `destination`: The account to which the asset balance of `amount` will be transferred.
+		/// - `amount`: The amount of assets to transfer.
+		///
+		/// Emits `TransferredApproved` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_approved())]
+		pub fn transfer_approved(
+			origin: OriginFor<T>,
+			id: AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			destination: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let delegate = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let destination = T::Lookup::lookup(destination)?;
+			Self::do_transfer_approved(id, &owner, &delegate, &destination, amount)
+		}
+
+		/// Create an asset account for non-provider assets.
+		///
+		/// A deposit will be taken from the signer account.
+		///
+		/// - `origin`: Must be Signed; the signer account must have sufficient funds for a deposit
+		///   to be taken.
+		/// - `id`: The identifier of the asset for the account to be created.
+		///
+		/// Emits `Touched` event when successful.
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn touch(origin: OriginFor<T>, id: AssetId) -> DispatchResult {
+			Self::do_touch(id, ensure_signed(origin)?)
+		}
+
+		/// Return the deposit (if any) of an asset account.
+		///
+		/// The origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset for the account to be created.
+		/// - `allow_burn`: If `true` then assets may be destroyed in order to complete the refund.
+		///
+		/// Emits `Refunded` event when successful.
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn refund(
+			origin: OriginFor<T>,
+			id: AssetId,
+			allow_burn: bool,
+		) -> DispatchResult {
+			Self::do_refund(id, ensure_signed(origin)?, allow_burn)
+		}
+
+		/// Move the whole reducible balance of the sender account to another.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to be transferred.
+		/// - `target`: The account to be credited.
+		/// - `keep_alive`: If `true`, the minimum balance of the asset is left in the sender
+		/// account, so it is not reaped.
+		///
+		/// The amount held back by the freezer is never transferred and the account is then kept
+		/// alive whatever `keep_alive` is.
+		///
+		/// Emits `Transferred` with the amount transferred.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_all())]
+		pub fn transfer_all(
+			origin: OriginFor<T>,
+			id: AssetId,
+			target: <T::Lookup as StaticLookup>::Source,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(target)?;
+
+			let amount = Self::reducible_balance(id, &source, keep_alive)?;
+			let f = TransferFlags { keep_alive, best_effort: false, burn_dust: false };
+			Self::do_transfer(id, &source, &dest, amount, None, f).map(|_| ())
+		}
+
+		/// Increase the amount of asset which a delegated third-party account may transfer.
+		///
+		/// Origin must be Signed.
+		///
+		/// Unlike `approve_transfer`, the expiry of an existing approval is kept. If there is no
+		/// approval, one is created and `ApprovalDeposit` worth of `Currency` is reserved from
+		/// the signing account.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account delegated permission to transfer asset.
+		/// - `delta`: The amount by which the approved amount is increased.
+		///
+		/// Emits `AllowanceChanged` with the new approved amount.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::increase_allowance())]
+		pub fn increase_allowance(
+			origin: OriginFor<T>,
+			id: AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] delta: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_change_allowance(id, &owner, &delegate, delta, true)
+		}
+
+		/// Decrease the amount of asset which a delegated third-party account may transfer.
+		///
+		/// Origin must be Signed and there must be an unexpired approval in place between signer
+		/// and `delegate` of at least `delta`.
+		///
+		/// If the approved amount drops to zero, the approval is removed and its deposit is
+		/// unreserved.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account delegated permission to transfer asset.
+		/// - `delta`: The amount by which the approved amount is decreased.
+		///
+		/// Emits `AllowanceChanged` with the new approved amount.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::decrease_allowance())]
+		pub fn decrease_allowance(
+			origin: OriginFor<T>,
+			id: AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] delta: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_change_allowance(id, &owner, &delegate, delta, false)
+		}
+
+		/// Approve an amount of asset for transfer by a delegated third-party account with a
+		/// permit signed off-chain by the owner, e.g. for gasless delegations to custodial
+		/// marketplaces.
+		///
+		/// Origin must be Signed. Anyone may submit the permit and pays the fee, while
+		/// `ApprovalDeposit` is reserved from `owner` as by `approve_transfer`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which signed the permit.
+		/// - `delegate`: The account to delegate permission to transfer asset.
+		/// - `amount`: The amount of asset that may be transferred by `delegate`, added to an
+		/// existing approval.
+		/// - `deadline`: The last block number at which the permit can be submitted.
+		/// - `signature`: The signature of `approval_permit_message` of the `ApprovalPermit` with
+		/// the next `PermitNonce` of `owner`.
+		///
+		/// Emits `ApprovedTransfer` and `ApprovalPermitted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_with_signature())]
+		pub fn approve_with_signature(
+			origin: OriginFor<T>,
+			id: AssetId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+			deadline: T::BlockNumber,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			let permit = ApprovalPermit {
+				nonce: PermitNonce::<T, I>::get(&owner),
+				genesis_hash: frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()),
+				owner,
+				asset_id: id,
+				delegate,
+				amount,
+				deadline,
+			};
+			Self::do_approve_with_signature(relayer, permit, signature)
+		}
+
+		/// Freeze a part of the balance of an account, e.g. for an escrow, without freezing the
+		/// whole account.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account whose balance is frozen.
+		/// - `amount`: The amount added to the frozen amount of `who`. The frozen amount may not
+		/// exceed the balance.
+		///
+		/// The frozen amount cannot be transferred or burned by the holder, and the account is
+		/// kept alive while it is not zero.
+		///
+		/// Emits `AmountFrozen`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::freeze_amount())]
+		pub fn freeze_amount(
+			origin: OriginFor<T>,
+			id: AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_freeze_amount(id, &origin, &who, amount, true)
+		}
+
+		/// Thaw a part of the amount frozen by `freeze_amount`.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account whose balance is thawed.
+		/// - `amount`: The amount subtracted from the frozen amount of `who`.
+		///
+		/// Emits `AmountThawed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::unfreeze_amount())]
+		pub fn unfreeze_amount(
+			origin: OriginFor<T>,
+			id: AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_freeze_amount(id, &origin, &who, amount, false)
+		}
+
+		/// Fund a new account, e.g. of a new project owner without native tokens, with
+		/// `SponsorAmount` of `Currency` of the custodian.
+		///
+		/// Origin must be Signed by the Custodian, or by the Custodian of the asset `touch`.
+		///
+		/// - `new_owner`: The account to be funded. It must not exist yet.
+		/// - `touch`: The asset to create an asset-account of `new_owner` for, if any. The
+		/// `AssetAccountDeposit` is sent along and held from `new_owner`.
+		///
+		/// The sponsorship is stored in `SponsoredAccounts` until `clawback_sponsorship`.
+		///
+		/// Emits `AccountSponsored`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::sponsor_account())]
+		pub fn sponsor_account(
+			origin: OriginFor<T>,
+			new_owner: <T::Lookup as StaticLookup>::Source,
+			touch: Option<AssetId>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			let new_owner = T::Lookup::lookup(new_owner)?;
+			Self::do_sponsor_account(&sponsor, new_owner, touch)
+		}
+
+		/// Settle the sponsorship of an account after `SponsorshipClawbackPeriod`. If the
+		/// account has not sent a transaction since, the sponsored amount still free is returned
+		/// to the sponsor, together with the deposit of an empty pre-touched asset-account.
+		///
+		/// Origin must be Signed by the sponsor or the Custodian.
+		///
+		/// - `who`: The sponsored account.
+		///
+		/// Emits `SponsorshipClosed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::clawback_sponsorship())]
+		pub fn clawback_sponsorship(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_clawback_sponsorship(&origin, who)
+		}
+
+		/// Burn carbon assets of the sender to move them to another registry system and record
+		/// an export attestation. Unlike `self_burn`, the export is not a climate retirement: it
+		/// is kept out of `BurnCertificate`, `TotalRetired` and the retired vintage supply, so it
+		/// cannot be counted as an offset.
+		///
+		/// Origin must be Signed and the sender should have enough amount of asset.
+		///
+		/// - `id`: The identifier of the asset to export.
+		/// - `amount`: The amount to export.
+		/// - `dest_registry`: The name of the destination registry. Limited in length by
+		/// `StringLimit`.
+		/// - `dest_ref`: The reference of the credits in the destination registry, e.g. the
+		/// account or the issuance to credit. Limited in length by `StringLimit`.
+		///
+		/// Emits `Burned` and `CreditsExported`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::export_retirement())]
+		pub fn export_retirement(
+			origin: OriginFor<T>,
+			id: AssetId,
+			#[pallet::compact] amount: T::Balance,
+			dest_registry: Vec<u8>,
+			dest_ref: Vec<u8>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest_registry =
+				dest_registry.try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+			let dest_ref = dest_ref.try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+			Self::do_export_retirement(id, &origin, amount, dest_registry, dest_ref)
+		}
+
+		/// Add an account to the transfer whitelist of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account allowed to transfer the asset while the whitelist is enforced.
+		///
+		/// Emits `AddedToWhitelist`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::add_to_whitelist())]
+		pub fn add_to_whitelist(
+			origin: OriginFor<T>,
+			id: AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_set_whitelisted(id, &origin, who, true)
+		}
+
+		/// Remove an account from the transfer whitelist of an asset. Its balance stays, but
+		/// cannot be transferred while the whitelist is enforced.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to remove.
+		///
+		/// Emits `RemovedFromWhitelist`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::remove_from_whitelist())]
+		pub fn remove_from_whitelist(
+			origin: OriginFor<T>,
+			id: AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_set_whitelisted(id, &origin, who, false)
+		}
+
+		/// Enforce or lift the transfer whitelist of an asset. While it is enforced, both the
+		/// sender and the receiver of a transfer must be whitelisted; forced transfers of the
+		/// Admin are not restricted.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `enabled`: Whether to enforce the whitelist.
+		///
+		/// Emits `WhitelistEnforcementSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_whitelist_enforcement())]
+		pub fn set_whitelist_enforcement(
+			origin: OriginFor<T>,
+			id: AssetId,
+			enabled: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_whitelist_enforcement(id, &origin, enabled)
+		}
+
+		/// Configure an asset with several owner-level calls at once: either all of them are
+		/// applied or none, e.g. on runtimes which filter out `utility.batch`.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id` or the
+		/// Custodian. Every call is checked as if it was sent on its own, so under dual control
+		/// `SetProjectData` of the owner is queued for the co-owner.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `calls`: The `set_project_data`, `set_attribute`, `clear_attribute` and
+		/// `set_asset_methodology` calls to execute in order. At most `MaxOwnerBatch` calls.
+		///
+		/// Emits the events of every call and `OwnerBatchExecuted`.
+		///
+		/// Weight: `O(C)` where `C` is the length of `calls`
+		#[pallet::weight(
+			T::WeightInfo::owner_batch(calls.len() as u32)
+				.saturating_add(Pallet::<T, I>::owner_calls_weight(calls))
+		)]
+		pub fn owner_batch(
+			origin: OriginFor<T>,
+			id: AssetId,
+			calls: Vec<OwnerCall>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let calls: BoundedVec<_, T::MaxOwnerBatch> =
+				calls.try_into().map_err(|_| Error::<T, I>::BadOwnerBatch)?;
+			Self::do_owner_batch(id, &origin, calls)
+		}
+
+		/// Authorize a viewer key to read holder lists and account dumps via
+		/// `CarbonAssetsViewerApi`.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// - `viewer`: The account of the viewer public key.
+		///
+		/// Emits `ViewerKeyAdded`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::add_viewer_key())]
+		pub fn add_viewer_key(
+			origin: OriginFor<T>,
+			viewer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let viewer = T::Lookup::lookup(viewer)?;
+			ViewerKeys::<T, I>::insert(&viewer, ());
+			Self::deposit_event(Event::ViewerKeyAdded { viewer });
+			Ok(())
+		}
+
+		/// Revoke a viewer key. Requests signed with it are refused from the next block.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// - `viewer`: The account of the viewer public key.
+		///
+		/// Emits `ViewerKeyRemoved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::remove_viewer_key())]
+		pub fn remove_viewer_key(
+			origin: OriginFor<T>,
+			viewer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let viewer = T::Lookup::lookup(viewer)?;
+			ensure!(ViewerKeys::<T, I>::take(&viewer).is_some(), Error::<T, I>::NotViewer);
+			Self::deposit_event(Event::ViewerKeyRemoved { viewer });
+			Ok(())
+		}
+
+		/// Set the compliance window, e.g. the true-up period of a compliance scheme, during
+		/// which the compliance-market assets can be retired, but not transferred.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// - `window`: The first and the last block of the window, `None` to clear it.
+		///
+		/// Emits `ComplianceWindowSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_compliance_window())]
+		pub fn set_compliance_window(
+			origin: OriginFor<T>,
+			window: Option<(T::BlockNumber, T::BlockNumber)>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			match window {
+				Some((start, end)) => {
+					ensure!(start <= end, Error::<T, I>::BadComplianceWindow);
+					ComplianceWindow::<T, I>::put((start, end));
+				},
+				None => ComplianceWindow::<T, I>::kill(),
+			}
+			Self::deposit_event(Event::ComplianceWindowSet { window });
+			Ok(())
+		}
+
+		/// Designate an asset as a compliance-market asset, locked during the compliance window,
+		/// or release it.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `enabled`: Whether the asset is a compliance-market asset.
+		///
+		/// Emits `ComplianceMarketSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_compliance_market())]
+		pub fn set_compliance_market(
+			origin: OriginFor<T>,
+			id: AssetId,
+			enabled: bool,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Asset::<T, I>::contains_key(id), Error::<T, I>::Unknown);
+			if enabled {
+				ComplianceMarketAssets::<T, I>::insert(id, ());
+			} else {
+				ComplianceMarketAssets::<T, I>::remove(id);
+			}
+			Self::deposit_event(Event::ComplianceMarketSet { asset_id: id, enabled });
+			Ok(())
+		}
+
+		/// Confirm that carbon credits burned by `account` were retired in the external registry.
+		///
+		/// Origin must be Signed and the sender should be the Custodian of the asset `id`.
+		///
+		/// - `account`: The account which burned the carbon credits.
+		/// - `id`: The identifier of the burned asset.
+		/// - `amount`: The amount confirmed in the external registry. Confirmations sum up and
+		/// cannot exceed the amount in the `BurnCertificate` of `account`.
+		/// - `external_ref`: The retirement reference in the external registry. Limited in
+		/// length by `StringLimit`.
+		///
+		/// Emits `RetirementRegistrationConfirmed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::confirm_retirement_registration())]
+		pub fn confirm_retirement_registration(
+			origin: OriginFor<T>,
+			account: <T::Lookup as StaticLookup>::Source,
+			id: AssetId,
+			#[pallet::compact] amount: T::Balance,
+			external_ref: Vec<u8>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_custodian_of(id, &origin)?;
+			let account = T::Lookup::lookup(account)?;
+			Self::do_confirm_retirement_registration(&account, id, amount, external_ref)
+		}
+
+		/// Mint reserve-backed carbon assets to a sovereign or bridge account by Custodian.
+		///
+		/// The minted amount is a part of the asset supply, but is tracked apart in
+		/// `ReservedSupply` as it is backed by a reserve held on another chain.
+		///
+		/// The origin must be Signed and the sender must be the Custodian of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to have some amount minted.
+		/// - `beneficiary`: The reserve account to be credited, one of `ReserveAccounts`.
+		/// - `amount`: The amount of the asset to be minted.
+		///
+		/// Emits `ReserveBackedIssued` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::mint_reserve_backed())]
+		pub fn mint_reserve_backed(
+			origin: OriginFor<T>,
+			id: AssetId,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::ensure_custodian_of(id, &origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::do_mint_reserve_backed(id, &beneficiary, amount)
+		}
+
+		/// Redeem a retirement voucher signed off-chain by the holder of carbon assets.
+		/// Burn the voucher amount from the signer and store it in the signer's `BurnCertificate`.
+		///
+		/// Origin must be Signed. Anyone may submit the voucher.
+		///
+		/// - `voucher`: The voucher payload, see `RetirementVoucher`.
+		/// - `signature`: The signature of the SCALE-encoded `voucher` by its signer.
+		///
+		/// Emits `RetirementVoucherRedeemed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::redeem_retirement_voucher())]
+		pub fn redeem_retirement_voucher(
+			origin: OriginFor<T>,
+			voucher: RetirementVoucher<T::AccountId, T::Balance, T::BlockNumber>,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			let submitter = ensure_signed(origin)?;
+			Self::do_redeem_retirement_voucher(&submitter, voucher, signature)
+		}
+
+		/// Cancel up to `limit` approvals of asset `id` given by `owner` who no longer holds the
+		/// asset, unreserving their deposits back to `owner`. The system account of `owner` must
+		/// be reaped, or `ApprovalGcGracePeriod` must have passed since its asset-account was
+		/// removed.
+		///
+		/// Origin must be Signed. Anyone may call it; the fee is refunded if any approval was
+		/// cancelled and the sender is paid `CleanupReward` out of the deposit of every cancelled
+		/// approval which has expired or whose owner is reaped. The deposits of the other
+		/// approvals are returned to the owner in full.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which gave the approvals and has no asset-account of `id`.
+		/// - `limit`: The maximum number of approvals to cancel.
+		///
+		/// Emits `ApprovalCancelled` and `CleanupRewarded` for each cancelled approval.
+		///
+		/// Weight: `O(limit)`
+		#[pallet::weight(T::WeightInfo::gc_approvals(*limit))]
+		pub fn gc_approvals(
+			origin: OriginFor<T>,
+			id: AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let removed = Self::do_gc_approvals(id, &owner, limit, &who)?;
+			Ok((Some(T::WeightInfo::gc_approvals(removed)), Pays::No).into())
+		}
+
+		/// Set an attribute of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id` or the
+		/// Custodian.
+		///
+		/// Funds of sender are reserved according to the formula:
+		/// `AttributeDepositBase + MetadataDepositPerByte * (key.len + value.len)` taking into
+		/// account any already reserved funds.
+		///
+		/// - `id`: The identifier of the asset to update.
+		/// - `key`: The key of the attribute. Limited in length by `AttributeKeyLimit`.
+		/// - `value`: The value of the attribute. Limited in length by `AttributeValueLimit`.
+		///
+		/// Emits `AttributeSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			id: AssetId,
+			key: Vec<u8>,
+			value: Vec<u8>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_set_attribute(id, &caller, key, value)
+		}
+
+		/// Clear an attribute of an asset and return the deposit.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id` or the
+		/// Custodian.
+		///
+		/// - `id`: The identifier of the asset to update.
+		/// - `key`: The key of the attribute.
+		///
+		/// Emits `AttributeCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		pub fn clear_attribute(
+			origin: OriginFor<T>,
+			id: AssetId,
+			key: Vec<u8>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_clear_attribute(id, &caller, key)
+		}
+
+		/// Burn a basket of carbon assets owned by the sender in one call and record a single
+		/// retirement normalized to tonnes of CO2e using the decimals of each asset.
+		/// Every burned asset is also stored in the sender's `BurnCertificate`.
+		///
+		/// Origin must be Signed and the sender should have enough amount of every asset.
+		///
+		/// - `assets`: The identifiers of the assets and the amounts to burn. At most
+		/// `MaxRetirementBasket` entries.
+		///
+		/// Emits `CarbonCreditsBurned` for every asset and `Co2eRetired` with the total.
+		///
+		/// Weight: `O(A)` where `A` is the length of `assets`
+		#[pallet::weight(T::WeightInfo::retire_co2e(assets.len() as u32))]
+		pub fn retire_co2e(
+			origin: OriginFor<T>,
+			assets: Vec<(AssetId, T::Balance)>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let basket: BoundedVec<_, T::MaxRetirementBasket> =
+				assets.try_into().map_err(|_| Error::<T, I>::BadBasket)?;
+			Self::do_retire_co2e(&caller, basket)
+		}
+
+		/// Register the sender with an organization identity, so that its retirements roll up
+		/// with the retirements of the other wallets of the organization once the owner of the
+		/// organization or the custodian approves it via `approve_organization_member`. The owner
+		/// of the organization is approved at once.
+		///
+		/// Origin must be Signed.
+		///
+		/// Funds of sender are reserved according to the formula:
+		/// `MetadataDepositBase + MetadataDepositPerByte * organization.len` taking into
+		/// account any already reserved funds.
+		///
+		/// - `organization`: The organization identity, e.g. a company name or a DID. Limited in
+		/// length by `StringLimit`.
+		///
+		/// Only burns made while the sender is registered and approved count towards the
+		/// organization.
+		///
+		/// Emits `OrganizationSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_organization())]
+		pub fn set_organization(origin: OriginFor<T>, organization: Vec<u8>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_set_organization(&caller, organization)
+		}
+
+		/// Clear the organization identity of the sender and return the deposit.
+		///
+		/// Origin must be Signed and the sender should be registered with an organization.
+		///
+		/// Emits `OrganizationCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::clear_organization())]
+		pub fn clear_organization(origin: OriginFor<T>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let (organization, deposit) =
+				AccountOrganization::<T, I>::take(&caller).ok_or(Error::<T, I>::NoOrganization)?;
+			OrganizationMembers::<T, I>::remove(organization, &caller);
+			T::Deposits::release(&caller, deposit);
+			Self::note_deposit(None, DepositPurpose::Organization, deposit, Zero::zero());
+			Self::deposit_event(Event::OrganizationCleared { account: caller });
+			Ok(())
+		}
+
+		/// Register an organization identity with its owner, who approves the accounts
+		/// registered with the organization. Registering it again changes the owner.
+		///
+		/// Origin must be ForceOrigin or Signed by the custodian.
+		///
+		/// - `organization`: The organization identity, e.g. a company name or a DID. Limited in
+		/// length by `StringLimit`.
+		/// - `owner`: The account approving the members of the organization.
+		///
+		/// Emits `OrganizationRegistered`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::register_organization())]
+		pub fn register_organization(
+			origin: OriginFor<T>,
+			organization: Vec<u8>,
+			owner: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			Self::ensure_force_or_custodian(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let bounded: BoundedVec<u8, T::StringLimit> =
+				organization.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+			OrganizationOwners::<T, I>::insert(bounded, &owner);
+			Self::deposit_event(Event::OrganizationRegistered { organization, owner });
+			Ok(())
+		}
+
+		/// Approve the account `who` registered with an organization as a member, so that its
+		/// retirements roll up in the organization retirements.
+		///
+		/// Origin must be Signed by the owner of the organization or the custodian.
+		///
+		/// - `who`: The account registered with the organization via `set_organization`.
+		///
+		/// Emits `OrganizationMemberApproved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_organization_member())]
+		pub fn approve_organization_member(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_approve_organization_member(&caller, &who)
+		}
+
+		/// Mint carbon assets of several assets to their owners in one call by Custodian, e.g. at
+		/// the end of a verification cycle.
+		///
+		/// The origin must be Signed and the sender must be the Custodian of every asset.
+		///
+		/// - `mints`: The identifiers of the assets and the amounts to mint. At most
+		/// `MaxMintBatch` entries.
+		///
+		/// Emits a single `BatchIssued` event when successful.
+		///
+		/// Weight: `O(M)` where `M` is the length of `mints`
+		#[pallet::weight(T::WeightInfo::mint_batch(mints.len() as u32))]
+		pub fn mint_batch(
+			origin: OriginFor<T>,
+			mints: Vec<(AssetId, T::Balance)>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let batch: BoundedVec<_, T::MaxMintBatch> =
+				mints.try_into().map_err(|_| Error::<T, I>::BadBatch)?;
+			Self::do_mint_batch(origin, batch)
+		}
+
+		/// Remove the messages delivered to the webhooks from the `Outbox`.
+		///
+		/// Origin must be Signed and the sender should be an outbox authority. It is submitted by
+		/// the off-chain worker, signed with the outbox authority key of the node.
+		///
+		/// - `up_to`: The id of the last delivered message.
+		///
+		/// Emits `OutboxAcknowledged`.
+		///
+		/// Weight: `O(MaxOutbox)`
+		#[pallet::weight(T::WeightInfo::ack_outbox(T::MaxOutbox::get()))]
+		pub fn ack_outbox(origin: OriginFor<T>, up_to: u64) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			ensure!(OutboxAuthorities::<T, I>::contains_key(&origin), Error::<T, I>::NoPermission);
+			let removed = Self::do_ack_outbox(up_to)?;
+			Ok(Some(T::WeightInfo::ack_outbox(removed)).into())
+		}
+
+		/// Authorize an account to acknowledge the outbox, e.g. the key of type `crbn` in the
+		/// keystore of the node running the off-chain worker.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// - `authority`: The account of the off-chain worker key.
+		///
+		/// Emits `OutboxAuthorityAdded`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::add_outbox_authority())]
+		pub fn add_outbox_authority(
+			origin: OriginFor<T>,
+			authority: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let authority = T::Lookup::lookup(authority)?;
+			OutboxAuthorities::<T, I>::insert(&authority, ());
+			Self::deposit_event(Event::OutboxAuthorityAdded { authority });
+			Ok(())
+		}
+
+		/// Remove an outbox authority.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// - `authority`: The account of the off-chain worker key.
+		///
+		/// Emits `OutboxAuthorityRemoved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::remove_outbox_authority())]
+		pub fn remove_outbox_authority(
+			origin: OriginFor<T>,
+			authority: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let authority = T::Lookup::lookup(authority)?;
+			ensure!(
+				OutboxAuthorities::<T, I>::take(&authority).is_some(),
+				Error::<T, I>::NotOutboxAuthority
+			);
+			Self::deposit_event(Event::OutboxAuthorityRemoved { authority });
+			Ok(())
+		}
+
+		/// Put an asset under dual control, change its co-owner or return it to a single owner.
+		///
+		/// Under dual control `transfer_ownership`, `set_project_data` and `destroy` by the
+		/// owner only queue the action in `PendingOwnerActions`, and the co-owner performs it
+		/// via `confirm_owner_action`. A change of an existing co-owner is queued as well.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `co_owner`: The co-owner, or `None` to return the asset to a single owner.
+		///
+		/// Emits `CoOwnerSet`, or `OwnerActionProposed` if the asset has a co-owner already.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_co_owner())]
+		pub fn set_co_owner(
+			origin: OriginFor<T>,
+			id: AssetId,
+			co_owner: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let co_owner = co_owner.map(T::Lookup::lookup).transpose()?;
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(origin == details.owner, Error::<T, I>::NoPermission);
+			if CoOwner::<T, I>::contains_key(id) {
+				return Self::queue_owner_action(id, OwnerAction::SetCoOwner(co_owner))
+			}
+			Self::do_set_co_owner(id, co_owner);
+			Ok(())
+		}
+
+		/// Confirm and perform a pending owner action of an asset under dual control.
+		///
+		/// Origin must be Signed and the sender should be the co-owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `action_id`: The identifier of the pending action.
+		/// - `witness`: The destroy witness, required to confirm `destroy` only.
+		///
+		/// Emits the events of the action and `OwnerActionConfirmed`.
+		///
+		/// Weight: `O(1)`, plus the `destroy` weight of the `witness`.
+		#[pallet::weight(T::WeightInfo::confirm_owner_action().saturating_add(
+			witness.map_or(Weight::zero(), |w| T::WeightInfo::destroy(
+				w.accounts.saturating_sub(w.sufficients),
+				w.sufficients,
+				w.approvals,
+			))
+		))]
+		pub fn confirm_owner_action(
+			origin: OriginFor<T>,
+			id: AssetId,
+			action_id: u32,
+			witness: Option<DestroyWitness>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			ensure!(CoOwner::<T, I>::get(id).as_ref() == Some(&origin), Error::<T, I>::NotCoOwner);
+			Self::do_confirm_owner_action(id, action_id, witness)
+		}
+
+		/// Cancel a pending owner action of an asset under dual control.
+		///
+		/// Origin must be Signed and the sender should be the Owner or the co-owner of the asset
+		/// `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `action_id`: The identifier of the pending action.
+		///
+		/// Emits `OwnerActionCancelled`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_owner_action())]
+		pub fn cancel_owner_action(
+			origin: OriginFor<T>,
+			id: AssetId,
+			action_id: u32,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(
+				origin == details.owner || CoOwner::<T, I>::get(id).as_ref() == Some(&origin),
+				Error::<T, I>::NoPermission
+			);
+			Self::take_owner_action(id, action_id)?;
+			Self::deposit_event(Event::OwnerActionCancelled { asset_id: id, action_id });
+			Ok(())
+		}
+
+		/// Approve a carbon accounting methodology, or rename an approved one.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// - `methodology`: The identifier of the methodology.
+		/// - `name`: The name of the methodology, e.g. `VM0007`. Limited in length by
+		/// `StringLimit`.
+		///
+		/// Emits `MethodologyRegistered`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::register_methodology())]
+		pub fn register_methodology(
+			origin: OriginFor<T>,
+			methodology: u32,
+			name: Vec<u8>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let bounded_name: BoundedVec<u8, T::StringLimit> =
+				name.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
+			Methodologies::<T, I>::insert(methodology, bounded_name);
+			Self::deposit_event(Event::MethodologyRegistered { methodology, name });
+			Ok(())
+		}
 
-	#[pallet::error]
-	pub enum Error<T, I = ()> {
-		/// Account balance must be greater than or equal to the transfer amount.
-		BalanceLow,
-		/// The account to alter does not exist.
-		NoAccount,
-		/// The signing account has no permission to do the operation.
-		NoPermission,
-		/// The given asset ID is unknown.
-		Unknown,
-		/// The origin account is frozen.
-		Frozen,
-		/// The asset ID is already taken.
-		InUse,
-		/// Invalid witness data given.
-		BadWitness,
-		/// Minimum balance should be non-zero.
-		MinBalanceZero,
-		/// Unable to increment the consumer reference counters on the account. Either no provider
-		/// reference exists to allow a non-zero balance of a non-self-sufficient asset, or the
-		/// maximum number of consumers has been reached.
-		NoProvider,
-		/// Invalid metadata given.
-		BadMetadata,
-		/// No approval exists that would allow the transfer.
-		Unapproved,
-		/// The source account would not survive the transfer and it needs to stay alive.
-		WouldDie,
-		/// The asset-account already exists.
-		AlreadyExists,
-		/// The asset-account doesn't have an associated deposit.
-		NoDeposit,
-		/// The operation would result in funds being burned.
-		WouldBurn,
-		/// Operation can not be done, custodian need to be set.
-		NoCustodian,
-		/// Metadata for the asset does not exist.
-		NoMetadata,
-		/// Project data cannot be changed after minting.
-		CannotChangeAfterMint,
-		/// Error creating AssetId
-		ErrorCreatingAssetId,
-	}
+		/// Withdraw the approval of a carbon accounting methodology. Assets referencing it
+		/// cannot be minted while `MethodologyRequired` is set.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// - `methodology`: The identifier of the methodology.
+		///
+		/// Emits `MethodologyDeregistered`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::deregister_methodology())]
+		pub fn deregister_methodology(origin: OriginFor<T>, methodology: u32) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Methodologies::<T, I>::take(methodology).ok_or(Error::<T, I>::UnknownMethodology)?;
+			Self::deposit_event(Event::MethodologyDeregistered { methodology });
+			Ok(())
+		}
 
-	#[pallet::call]
-	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		
-		/// Sets new custodian.
-		/// 
-		/// The origin must conform to `ForceOrigin`.
-		/// 
-		/// - `custodian`: New custodian to be set. Only custodian can verify creation of carbon 
-		/// credit asset and mint created carbon credit asset.
-		/// 
-		/// Emits `CustodianSet` when successful.
-		/// 
-		#[pallet::weight(T::WeightInfo::set_custodian())]
-		pub fn set_custodian(
+		/// Reference an approved methodology from an asset before its first mint.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id` or the
+		/// Custodian.
+		///
+		/// - `id`: The identifier of the asset to update.
+		/// - `methodology`: The identifier of a registered methodology.
+		///
+		/// Emits `AssetMethodologySet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_asset_methodology())]
+		pub fn set_asset_methodology(
 			origin: OriginFor<T>,
-			custodian: T::AccountId
+			id: AssetId,
+			methodology: u32,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_set_asset_methodology(id, &caller, methodology)
+		}
+
+		/// Move some assets from the sender account to another, paying the splits of the asset
+		/// (e.g. the owner royalty and the registry fee) out of the amount in the same call.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited with the rest of the amount.
+		/// - `amount`: The amount by which the sender's balance of assets should be reduced.
+		/// - `splits`: The `AssetSplits` of the asset the sender agrees to pay.
+		///
+		/// Emits `Transferred` for every payment and `TransferredWithSplits`.
+		///
+		/// Weight: `O(S)` where `S` is the length of `splits`
+		#[pallet::weight(T::WeightInfo::transfer_with_splits(splits.len() as u32))]
+		pub fn transfer_with_splits(
+			origin: OriginFor<T>,
+			id: AssetId,
+			target: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+			splits: Vec<(T::AccountId, Perbill)>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(target)?;
+			Self::do_transfer_with_splits(id, &origin, &dest, amount, splits)
+		}
+
+		/// Set the recipients and their shares of every `transfer_with_splits` of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Custodian of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `splits`: The recipients and their shares of the transferred amount, at most
+		/// `MaxSplits` distinct recipients summing up to at most the whole amount. Empty to
+		/// remove the splits.
+		///
+		/// Emits `AssetSplitsSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_asset_splits())]
+		pub fn set_asset_splits(
+			origin: OriginFor<T>,
+			id: AssetId,
+			splits: Vec<(T::AccountId, Perbill)>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_asset_splits(id, &origin, splits)
+		}
+
+		/// Propose a custodian for the asset, e.g. the regional verification body of its
+		/// projects, or propose to clear it to fall back to the global custodian. The change is
+		/// accepted via `accept_asset_custodian` after `CustodianChangeDelay` blocks. Replaces the
+		/// pending proposal of the asset, if any.
+		///
+		/// The custodian of the asset mints and burns it and becomes its issuer, admin and
+		/// freezer. Clearing it hands these roles back to the global custodian, if any.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `custodian`: The custodian of the asset, or `None` to clear it.
+		///
+		/// Emits `AssetCustodianProposed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_asset_custodian())]
+		pub fn set_asset_custodian(
+			origin: OriginFor<T>,
+			id: AssetId,
+			custodian: Option<T::AccountId>,
 		) -> DispatchResult {
 			T::ForceOrigin::ensure_origin(origin)?;
-			Custodian::<T, I>::put(custodian.clone());
-			Self::deposit_event(Event::CustodianSet { custodian });
-			Ok(())
+			Self::do_propose_asset_custodian(id, custodian)
 		}
 
-		/// Issue a new class of fungible carbon assets from a public origin.
+		/// Accept the custody of the asset proposed by `set_asset_custodian`.
 		///
-		/// This new asset class has no assets initially and its owner is the origin.
+		/// Origin must be Signed and the sender should be the proposed custodian, or the global
+		/// custodian if the asset custodian is to be cleared, after the `CustodianChangeDelay`.
 		///
-		/// The origin must be Signed and the sender must have sufficient funds free.
-		/// 
-		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
-		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
-		/// 
-		/// Funds of sender are reserved by `AssetDeposit`.
-		/// 
-		/// Admin of asset is the Custodian. Fails if no custodian are set.
-		/// Set asset metadata: generated `name` and `symbol`, decimals to 9.
-		/// 
-		/// Emits `Created` event when successful.
-		/// Emits `MetadataSet` with generated `name` and `symbol`.
+		/// - `id`: The identifier of the asset.
 		///
-		#[pallet::weight(T::WeightInfo::create())]
-		pub fn create(
+		/// Emits `AssetCustodianSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::accept_asset_custodian())]
+		pub fn accept_asset_custodian(origin: OriginFor<T>, id: AssetId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_accept_asset_custodian(id, caller)
+		}
+
+		/// Request the custodian of the asset to mint `amount` of it to the owner.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `amount`: The amount to mint.
+		/// - `evidence_ipfs`: The CID of the IPFS document with the evidence of the credits.
+		///
+		/// Emits `MintRequested` with the id of the request.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::request_mint())]
+		pub fn request_mint(
 			origin: OriginFor<T>,
-			name: Vec<u8>,
-			symbol: Vec<u8>,
+			id: AssetId,
+			#[pallet::compact] amount: T::Balance,
+			evidence_ipfs: Vec<u8>,
 		) -> DispatchResult {
-			let owner = ensure_signed(origin)?;
-			let admin_option = Custodian::<T, I>::get();
-			ensure!(admin_option.is_some(), Error::<T, I>::NoCustodian);
-			let admin = admin_option.unwrap();
-			let id = Self::get_new_asset_id(&owner)?;
-
-			let deposit = T::AssetDeposit::get();
-			T::Currency::reserve(&owner, deposit)?;
-
-			Asset::<T, I>::insert(
-				id,
-				AssetDetails {
-					owner: owner.clone(),
-					issuer: admin.clone(),
-					admin: admin.clone(),
-					freezer: admin,
-					supply: Zero::zero(),
-					deposit,
-					min_balance: One::one(),
-					is_sufficient: false,
-					accounts: 0,
-					sufficients: 0,
-					approvals: 0,
-					is_frozen: false,
-				},
-			);
-			Self::deposit_event(Event::Created { asset_id: id, creator: owner.clone() });
+			let origin = ensure_signed(origin)?;
+			Self::do_request_mint(id, &origin, amount, evidence_ipfs)
+		}
 
-			Self::do_set_metadata(id, &owner, name, symbol, 9)
+		/// Approve the mint request, minting the requested amount to the owner of the asset.
+		///
+		/// Origin must be Signed and the sender should be the Custodian of the asset.
+		///
+		/// - `request_id`: The id of the mint request.
+		///
+		/// Emits `Issued` and `MintRequestApproved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_mint())]
+		pub fn approve_mint(origin: OriginFor<T>, request_id: u64) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_approve_mint(request_id, origin)
 		}
 
-		/// Set project data to metadata of an asset.
-		/// 
-		/// Origin must be Signed and the sender should be the Owner of the asset `id` or the Custodian.
-		/// 
-		/// - `id`: The identifier of the asset to update.
-		/// - `url`: The url.
-		/// - `data_ipfs`: The ipfs data link.
-		/// 
-		/// Emits `MetadataUpdated`.
-		/// 
-		#[pallet::weight(T::WeightInfo::set_project_data())]
-		pub fn set_project_data(
+		/// Reject the mint request.
+		///
+		/// Origin must be Signed and the sender should be the Custodian of the asset.
+		///
+		/// - `request_id`: The id of the mint request.
+		/// - `reason`: Why the request was rejected. Limited in length by `StringLimit`.
+		///
+		/// Emits `MintRequestRejected`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::reject_mint())]
+		pub fn reject_mint(
 			origin: OriginFor<T>,
-			id: AssetId,
-			url: Vec<u8>,
-			data_ipfs: Vec<u8>,
+			request_id: u64,
+			reason: Vec<u8>,
 		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_reject_mint(request_id, &origin, reason)
+		}
+
+		/// Register a display alias of the sender, e.g. for certificate-rendering frontends. The
+		/// alias is included in the `CarbonCreditsBurned` events of the sender.
+		///
+		/// Origin must be Signed.
+		///
+		/// Funds of sender are reserved according to the formula:
+		/// `MetadataDepositBase + MetadataDepositPerByte * alias.len` taking into
+		/// account any already reserved funds.
+		///
+		/// - `alias`: The display name, unique among the accounts. Limited in length by
+		/// `StringLimit`.
+		///
+		/// Emits `AliasSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_alias())]
+		pub fn set_alias(origin: OriginFor<T>, alias: Vec<u8>) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
-			Self::update_metadata(id, &caller, url, data_ipfs)
+			Self::do_set_alias(&caller, alias)
 		}
 
-		/// Issue a new class of fungible assets from a privileged origin.
+		/// Clear the display alias of the sender and return the deposit.
 		///
-		/// This new asset class has no assets initially.
+		/// Origin must be Signed and the sender should have an alias.
 		///
-		/// The origin must conform to `ForceOrigin`.
+		/// Emits `AliasCleared`.
 		///
-		/// Unlike `create`, no funds are reserved.
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::clear_alias())]
+		pub fn clear_alias(origin: OriginFor<T>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let (alias, deposit) =
+				AccountAlias::<T, I>::take(&caller).ok_or(Error::<T, I>::NoAlias)?;
+			AliasAccount::<T, I>::remove(&alias);
+			T::Deposits::release(&caller, deposit);
+			Self::note_deposit(None, DepositPurpose::Alias, deposit, Zero::zero());
+			Self::deposit_event(Event::AliasCleared { account: caller });
+			Ok(())
+		}
+
+		/// Retire carbon credits of the sender with a certificate for off-chain registries.
 		///
-		/// - `id`: The identifier of the new asset. This must not be currently in use to identify
-		/// an existing asset.
-		/// - `owner`: The owner of this class of assets. The owner has full superuser permissions
-		/// over this asset, but may later change and configure the permissions using
-		/// `transfer_ownership`.
-		/// - `min_balance`: The minimum balance of this new asset that any single account must
-		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		/// Origin must be Signed and the sender should have enough amount of the asset.
 		///
-		/// Emits `ForceCreated` event when successful.
+		/// - `id`: The identifier of the asset to retire.
+		/// - `amount`: The amount to retire.
+		/// - `beneficiary_name`: The name the retirement is claimed for, also the note of the
+		/// retirement anchor. Limited in length by `StringLimit`.
+		/// - `reason`: Why the credits are retired. Limited in length by `StringLimit`.
+		/// - `retirement_date`: The retirement date as a Unix timestamp in seconds.
+		///
+		/// Emits `CarbonCreditsBurned` with the certificate id of the stored
+		/// `RetirementCertificate`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::force_create())]
-		pub fn force_create(
+		#[pallet::weight(T::WeightInfo::retire())]
+		pub fn retire(
 			origin: OriginFor<T>,
 			id: AssetId,
-			owner: <T::Lookup as StaticLookup>::Source,
-			is_sufficient: bool,
-			#[pallet::compact] min_balance: T::Balance,
+			#[pallet::compact] amount: T::Balance,
+			beneficiary_name: Vec<u8>,
+			reason: Vec<u8>,
+			retirement_date: u64,
 		) -> DispatchResult {
-			T::ForceOrigin::ensure_origin(origin)?;
-			let owner = T::Lookup::lookup(owner)?;
-			Self::do_force_create(id, owner, is_sufficient, min_balance)
+			let caller = ensure_signed(origin)?;
+			let certificate = RetirementCertificate {
+				beneficiary_name: beneficiary_name
+					.try_into()
+					.map_err(|_| Error::<T, I>::BadMetadata)?,
+				reason: reason.try_into().map_err(|_| Error::<T, I>::BadMetadata)?,
+				retirement_date,
+			};
+			Self::do_retire_with_certificate(id, &caller, amount, certificate)
 		}
 
-		/// Destroy a class of fungible assets.
+		/// Start the destruction of an asset class with many accounts over several blocks.
 		///
 		/// The origin must conform to `ForceOrigin` or must be Signed and the sender must be the
 		/// owner of the asset `id`.
@@ -671,371 +4043,395 @@ pub mod pallet {
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		/// asset.
 		///
-		/// Emits `Destroyed` event when successful.
+		/// The asset can no longer be minted, transferred, burned or approved. Its accounts and
+		/// approvals are then removed by `destroy_accounts` and `destroy_approvals` and the
+		/// destruction is completed by `finish_destroy`.
 		///
-		/// NOTE: It can be helpful to first freeze an asset before destroying it so that you
-		/// can provide accurate witness information and prevent users from manipulating state
-		/// in a way that can make it harder to destroy.
+		/// Emits `DestructionStarted`, or `OwnerActionProposed` if the owner sends it under dual
+		/// control.
 		///
-		/// Weight: `O(c + p + a)` where:
-		/// - `c = (witness.accounts - witness.sufficients)`
-		/// - `s = witness.sufficients`
-		/// - `a = witness.approvals`
-		#[pallet::weight(T::WeightInfo::destroy(
-			witness.accounts.saturating_sub(witness.sufficients),
- 			witness.sufficients,
- 			witness.approvals,
- 		))]
-		pub fn destroy(
-			origin: OriginFor<T>,
-			id: AssetId,
-			witness: DestroyWitness,
-		) -> DispatchResultWithPostInfo {
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::start_destroy())]
+		pub fn start_destroy(origin: OriginFor<T>, id: AssetId) -> DispatchResultWithPostInfo {
 			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
 				Ok(_) => None,
 				Err(origin) => Some(ensure_signed(origin)?),
 			};
-			let details = Self::do_destroy(id, witness, maybe_check_owner)?;
-			Ok(Some(T::WeightInfo::destroy(
-				details.accounts.saturating_sub(details.sufficients),
-				details.sufficients,
-				details.approvals,
-			))
-			.into())
+			if let Some(owner) = &maybe_check_owner {
+				if Self::needs_co_signing(id, owner) {
+					Self::queue_owner_action(id, OwnerAction::StartDestroy)?;
+					return Ok(Some(T::WeightInfo::propose_owner_action()).into())
+				}
+			}
+			Self::do_start_destroy(id, maybe_check_owner)?;
+			Ok(().into())
 		}
 
-		/// Mint carbon assets of a particular class by Custodian. Benefitiary is the owner of the asset.
+		/// Remove up to `RemoveItemsLimit` accounts of an asset class in destruction, returning
+		/// the deposits held for them.
 		///
-		/// The origin must be Signed and the sender must be the Custodian == the Issuer of the asset `id`.
+		/// Origin must be Signed. The sender is paid `CleanupReward` per removed account out of
+		/// the asset deposit, unless it is the owner.
 		///
-		/// - `id`: The identifier of the asset to have some amount minted.
-		/// - `amount`: The amount of the asset to be minted.
+		/// - `id`: The identifier of the asset in destruction.
 		///
-		/// Emits `Issued` event when successful.
+		/// Emits `AccountsDestroyed` and `CleanupRewarded`.
+		///
+		/// Weight: `O(c)` where `c` is the number of removed accounts, at most
+		/// `RemoveItemsLimit`
+		#[pallet::weight(T::WeightInfo::destroy_accounts(T::RemoveItemsLimit::get()))]
+		pub fn destroy_accounts(origin: OriginFor<T>, id: AssetId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let removed = Self::do_destroy_accounts(id, T::RemoveItemsLimit::get())?;
+			Self::reward_destroy_cleanup(id, &who, removed);
+			Ok(Some(T::WeightInfo::destroy_accounts(removed)).into())
+		}
+
+		/// Remove up to `RemoveItemsLimit` approvals and, once there are no approvals,
+		/// attributes of an asset class in destruction, returning their deposits.
+		///
+		/// Origin must be Signed. The sender is paid `CleanupReward` per removed item out of the
+		/// asset deposit, unless it is the owner.
+		///
+		/// - `id`: The identifier of the asset in destruction.
+		///
+		/// Emits `ApprovalsDestroyed` and `CleanupRewarded`.
+		///
+		/// Weight: `O(a)` where `a` is the number of removed items, at most `RemoveItemsLimit`
+		#[pallet::weight(T::WeightInfo::destroy_approvals(T::RemoveItemsLimit::get()))]
+		pub fn destroy_approvals(origin: OriginFor<T>, id: AssetId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let removed = Self::do_destroy_approvals(id, T::RemoveItemsLimit::get())?;
+			Self::reward_destroy_cleanup(id, &who, removed);
+			Ok(Some(T::WeightInfo::destroy_approvals(removed)).into())
+		}
+
+		/// Complete the destruction of an asset class once all its accounts, approvals and
+		/// attributes are removed, returning the asset and metadata deposits to the owner.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset in destruction.
+		///
+		/// Emits `Destroyed`.
 		///
 		/// Weight: `O(1)`
-		/// 
-		#[pallet::weight(T::WeightInfo::mint())]
-		pub fn mint(
+		#[pallet::weight(T::WeightInfo::finish_destroy())]
+		pub fn finish_destroy(origin: OriginFor<T>, id: AssetId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_finish_destroy(id)
+		}
+
+		/// Add `amount` of `Currency` to the sponsorship pot of the asset, so the `self_burn`
+		/// calls of the asset are free of fees while the pot covers `SponsoredRetirementFee`.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id` or the
+		/// Custodian, and the funder of the pot if it exists. The amount is reserved from the
+		/// sender.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `amount`: The amount to add to the pot.
+		///
+		/// Emits `SponsorshipPotFunded`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::fund_sponsorship_pot())]
+		pub fn fund_sponsorship_pot(
 			origin: OriginFor<T>,
 			id: AssetId,
-			#[pallet::compact] amount: T::Balance,
+			amount: DepositBalanceOf<T, I>,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
-			let asset_details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
-			let beneficiary = asset_details.owner;
-			Self::do_mint(id, &beneficiary, amount, Some(origin))?;
-			Ok(())
+			Self::do_fund_sponsorship_pot(id, &origin, amount)
 		}
 
-		/// Burn of carbon credits assets by custodian. 
-		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
-		/// Store information about the burned carbon asset in `BurnCertificate`.
+		/// Close the sponsorship pot of the asset, returning what is left to the funder.
 		///
-		/// Origin must be Signed and the sender should be the Custodian.
+		/// Origin must be Signed and the sender should be the funder of the pot.
 		///
-		/// Bails with `NoAccount` if the `who` is already dead.
+		/// - `id`: The identifier of the asset, which may be destroyed already.
 		///
-		/// - `id`: The identifier of the asset to have some amount burned.
-		/// - `who`: The account to be debited from.
-		/// - `amount`: The maximum amount by which `who`'s balance should be reduced.
+		/// Emits `SponsorshipPotWithdrawn`.
 		///
-		/// Emits `Burned` with the actual amount burned. If this takes the balance to below the
-		/// minimum for the asset, then the amount burned is increased to take it to zero.
-		/// 
-		/// Emits `CarbonCreditsBurned`.
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::withdraw_sponsorship_pot())]
+		pub fn withdraw_sponsorship_pot(origin: OriginFor<T>, id: AssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_withdraw_sponsorship_pot(id, &origin)
+		}
+
+		/// Report the responsiveness of the custodian.
+		///
+		/// Origin must be None. It is submitted by the off-chain worker every
+		/// `SlaReportInterval` blocks and only accepted if it matches the chain state at
+		/// `reported_at`.
+		///
+		/// - `report`: The SLA report computed by `custodian_sla_report`.
+		///
+		/// Emits `CustodianSlaReported`.
 		///
 		/// Weight: `O(1)`
-		/// Modes: Post-existence of `who`; Pre & post Zombie-status of `who`.
-		#[pallet::weight(T::WeightInfo::burn())]
-		pub fn burn(
+		#[pallet::weight(T::WeightInfo::report_custodian_sla())]
+		pub fn report_custodian_sla(
 			origin: OriginFor<T>,
-			id: AssetId,
-			who: <T::Lookup as StaticLookup>::Source,
-			#[pallet::compact] amount: T::Balance,
+			report: CustodianSlaReport<T::BlockNumber, T::Balance>,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-			let who = T::Lookup::lookup(who)?;
-
-			let f = DebitFlags { keep_alive: false, best_effort: false };
-			let _ = Self::do_burn(id, &who, amount, Some(origin), f)?;
-
-			BurnCertificate::<T,I>::mutate(who.clone(), id, |burned| {
-				if let Some(b) = burned {
-					let result = b.saturating_add(amount);
-					*burned = Some(result);
-				} else {
-					*burned = Some(amount);
-				}
-			});
-			Self::deposit_event(Event::CarbonCreditsBurned {account: who, asset_id: id, amount});
-			Ok(())
+			ensure_none(origin)?;
+			Self::do_report_custodian_sla(report)
 		}
 
-		/// Burn of carbon credits assets by owner. 
-		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
-		/// Store information about the burned carbon asset in `BurnCertificate`.
+		/// Approve an amount of asset for transfer by a delegated third-party account until
+		/// block `expiry`.
 		///
-		/// Origin must be Signed and the sender should have enough amount of asset.
+		/// Origin must be Signed.
 		///
-		/// Bails with `NoAccount` if the `who` is already dead.
+		/// Works as `approve_transfer`, but `transfer_approved` fails with `ApprovalExpired`
+		/// from block `expiry` on, and anyone may then remove the approval with
+		/// `clean_expired_approval`. The expiry replaces the expiry of the existing approval,
+		/// and the amount of an expired approval is not added to.
 		///
-		/// - `id`: The identifier of the asset to have some amount burned.
-		/// - `amount`: The maximum amount by which `who`'s balance should be reduced.
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account to delegate permission to transfer asset.
+		/// - `amount`: The amount of asset that may be transferred by `delegate`.
+		/// - `expiry`: The block from which the approval can no longer be used. Must be in the
+		/// future.
 		///
-		/// Emits `Burned` with the actual amount burned. If this takes the balance to below the
-		/// minimum for the asset, then the amount burned is increased to take it to zero.
-		/// 
-		/// Emits `CarbonCreditsBurned`.
+		/// Emits `ApprovedTransfer` and `ApprovalExpirySet` on success.
 		///
 		/// Weight: `O(1)`
-		/// Modes: Post-existence of `who`; Pre & post Zombie-status of `who`.
-		#[pallet::weight(T::WeightInfo::burn())]
-		pub fn self_burn(
+		#[pallet::weight(T::WeightInfo::approve_transfer_with_expiry())]
+		pub fn approve_transfer_with_expiry(
 			origin: OriginFor<T>,
 			id: AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
 			#[pallet::compact] amount: T::Balance,
+			expiry: T::BlockNumber,
 		) -> DispatchResult {
-			let caller = ensure_signed(origin)?;
-
-			let f = DebitFlags { keep_alive: false, best_effort: false };
-			let actual = Self::decrease_balance(id, &caller, amount, f, |actual, details| {
-				details.supply = details.supply.saturating_sub(actual);
-
-				Ok(())
-			})?;
-			Self::deposit_event(Event::Burned { asset_id: id, owner: caller.clone(), balance: actual });
-		
-			BurnCertificate::<T,I>::mutate(caller.clone(), id, |burned| {
-				if let Some(b) = burned {
-					let result = b.saturating_add(amount);
-					*burned = Some(result);
-				} else {
-					*burned = Some(amount);
-				}
-			});
-			Self::deposit_event(Event::CarbonCreditsBurned {account: caller, asset_id: id, amount});
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_approve_transfer(id, &owner, &delegate, amount, Some(expiry))?;
+			Self::deposit_event(Event::ApprovalExpirySet { asset_id: id, owner, delegate, expiry });
 			Ok(())
 		}
 
-		/// Move some assets from the sender account to another.
+		/// Remove an expired approval, unreserving its deposit back to `owner`.
 		///
-		/// Origin must be Signed.
+		/// Origin must be Signed. Anyone may call it; the fee is refunded on success and the
+		/// sender is paid `CleanupReward` out of the approval deposit.
 		///
-		/// - `id`: The identifier of the asset to have some amount transferred.
-		/// - `target`: The account to be credited.
-		/// - `amount`: The amount by which the sender's balance of assets should be reduced and
-		/// `target`'s balance increased. The amount actually transferred may be slightly greater in
-		/// the case that the transfer would otherwise take the sender balance above zero but below
-		/// the minimum balance. Must be greater than zero.
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which gave the approval.
+		/// - `delegate`: The account delegated permission to transfer asset.
 		///
-		/// Emits `Transferred` with the actual amount transferred. If this takes the source balance
-		/// to below the minimum for the asset, then the amount transferred is increased to take it
-		/// to zero.
+		/// Emits `ApprovalCancelled` and `CleanupRewarded` on success.
 		///
 		/// Weight: `O(1)`
-		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
-		/// `target`.
-		#[pallet::weight(T::WeightInfo::transfer())]
-		pub fn transfer(
+		#[pallet::weight(T::WeightInfo::clean_expired_approval())]
+		pub fn clean_expired_approval(
+			origin: OriginFor<T>,
+			id: AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_clean_expired_approval(id, &owner, &delegate, &who)?;
+			Ok(Pays::No.into())
+		}
+
+		/// Attest the co-benefits of an asset.
+		///
+		/// Origin must be Signed and the sender should be one of the `Verifiers`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `badges`: Bitmask of the badges, `sdg_badge(sdg)` for every Sustainable Development
+		/// Goal and `BIODIVERSITY_BADGE`. Zero removes the badges of the asset.
+		/// - `evidence`: The IPFS CID of the verification evidence, a CIDv0 or a CIDv1 in base32,
+		/// base58btc or base16.
+		///
+		/// Emits `AssetBadgesSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_asset_badges())]
+		pub fn set_asset_badges(
 			origin: OriginFor<T>,
 			id: AssetId,
-			target: <T::Lookup as StaticLookup>::Source,
-			#[pallet::compact] amount: T::Balance,
+			badges: u32,
+			evidence: Option<Vec<u8>>,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-			let dest = T::Lookup::lookup(target)?;
-
-			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
-			Self::do_transfer(id, &origin, &dest, amount, None, f).map(|_| ())
+			let caller = ensure_signed(origin)?;
+			Self::do_set_asset_badges(id, caller, badges, evidence)
 		}
 
-		/// Move some assets from the sender account to another, keeping the sender account alive.
+		/// Move some assets from the sender account to another with a memo, e.g. the purchase-order
+		/// reference of a corporate buyer.
 		///
 		/// Origin must be Signed.
 		///
 		/// - `id`: The identifier of the asset to have some amount transferred.
 		/// - `target`: The account to be credited.
-		/// - `amount`: The amount by which the sender's balance of assets should be reduced and
-		/// `target`'s balance increased. The amount actually transferred may be slightly greater in
-		/// the case that the transfer would otherwise take the sender balance above zero but below
-		/// the minimum balance. Must be greater than zero.
+		/// - `amount`: The amount by which the sender's balance of assets should be reduced.
+		/// - `memo`: The memo of the transfer, at most `MemoLimit` bytes.
 		///
-		/// Emits `Transferred` with the actual amount transferred. If this takes the source balance
-		/// to below the minimum for the asset, then the amount transferred is increased to take it
-		/// to zero.
+		/// Emits `Transferred` with the actual amount transferred and `TransferredWithMemo`.
 		///
 		/// Weight: `O(1)`
-		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
-		/// `target`.
-		#[pallet::weight(T::WeightInfo::transfer_keep_alive())]
-		pub fn transfer_keep_alive(
+		#[pallet::weight(T::WeightInfo::transfer_with_memo())]
+		pub fn transfer_with_memo(
 			origin: OriginFor<T>,
 			id: AssetId,
 			target: <T::Lookup as StaticLookup>::Source,
 			#[pallet::compact] amount: T::Balance,
+			memo: BoundedVec<u8, T::MemoLimit>,
 		) -> DispatchResult {
-			let source = ensure_signed(origin)?;
+			let origin = ensure_signed(origin)?;
 			let dest = T::Lookup::lookup(target)?;
 
-			let f = TransferFlags { keep_alive: true, best_effort: false, burn_dust: false };
-			Self::do_transfer(id, &source, &dest, amount, None, f).map(|_| ())
+			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+			let amount = Self::do_transfer(id, &origin, &dest, amount, None, f)?;
+			Self::deposit_event(Event::TransferredWithMemo {
+				asset_id: id,
+				from: origin,
+				to: dest,
+				amount,
+				memo: memo.into_inner(),
+			});
+			Ok(())
 		}
 
-		/// Move some assets from one account to another.
+		/// Issue a new class of fungible carbon assets of the carbon credits of a vintage year.
 		///
-		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		/// Same as `create`, but the asset is bound to the `vintage` year, so its mints and
+		/// retirements are summed up in the `VintageSupply` of the year.
 		///
-		/// - `id`: The identifier of the asset to have some amount transferred.
-		/// - `source`: The account to be debited.
-		/// - `dest`: The account to be credited.
-		/// - `amount`: The amount by which the `source`'s balance of assets should be reduced and
-		/// `dest`'s balance increased. The amount actually transferred may be slightly greater in
-		/// the case that the transfer would otherwise take the `source` balance above zero but
-		/// below the minimum balance. Must be greater than zero.
+		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
+		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
+		/// - `vintage`: The vintage year of the carbon credits, e.g. `2021`.
 		///
-		/// Emits `Transferred` with the actual amount transferred. If this takes the source balance
-		/// to below the minimum for the asset, then the amount transferred is increased to take it
-		/// to zero.
+		/// Emits `Created`, `AssetVintageSet` and `MetadataSet`.
 		///
 		/// Weight: `O(1)`
-		/// Modes: Pre-existence of `dest`; Post-existence of `source`; Account pre-existence of
-		/// `dest`.
-		#[pallet::weight(T::WeightInfo::force_transfer())]
-		pub fn force_transfer(
+		#[pallet::weight(T::WeightInfo::create_with_vintage())]
+		pub fn create_with_vintage(
 			origin: OriginFor<T>,
-			id: AssetId,
-			source: <T::Lookup as StaticLookup>::Source,
-			dest: <T::Lookup as StaticLookup>::Source,
-			#[pallet::compact] amount: T::Balance,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			vintage: u16,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-			let source = T::Lookup::lookup(source)?;
-			let dest = T::Lookup::lookup(dest)?;
-
-			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
-			Self::do_transfer(id, &source, &dest, amount, Some(origin), f).map(|_| ())
+			let owner = ensure_signed(origin)?;
+			Self::do_create(&owner, name, symbol, Some(vintage), DEFAULT_DECIMALS).map(|_| ())
 		}
 
-		/// Disallow further unprivileged transfers from an account.
+		/// Grant a sub-account (e.g. a department wallet) a periodic quota to retire an asset
+		/// from the balance of the sender, without the right to transfer it.
 		///
-		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		/// Origin must be Signed and the sender should be registered with an organization.
 		///
-		/// - `id`: The identifier of the asset to be frozen.
-		/// - `who`: The account to be frozen.
+		/// - `sub`: The sub-account retiring via `self_burn_from_org`.
+		/// - `id`: The identifier of the asset.
+		/// - `amount_per_period`: The amount the sub-account may retire every `QuotaPeriod`
+		/// blocks. Zero revokes the quota.
 		///
-		/// Emits `Frozen`.
+		/// Emits `RetirementQuotaGranted`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::freeze())]
-		pub fn freeze(
+		#[pallet::weight(T::WeightInfo::grant_retirement_quota())]
+		pub fn grant_retirement_quota(
 			origin: OriginFor<T>,
+			sub: <T::Lookup as StaticLookup>::Source,
 			id: AssetId,
-			who: <T::Lookup as StaticLookup>::Source,
+			amount_per_period: T::Balance,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-
-			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
-			ensure!(origin == d.freezer, Error::<T, I>::NoPermission);
-			let who = T::Lookup::lookup(who)?;
-
-			Account::<T, I>::try_mutate(id, &who, |maybe_account| -> DispatchResult {
-				maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?.is_frozen = true;
-				Ok(())
-			})?;
-
-			Self::deposit_event(Event::<T, I>::Frozen { asset_id: id, who });
-			Ok(())
+			let caller = ensure_signed(origin)?;
+			let sub = T::Lookup::lookup(sub)?;
+			Self::do_grant_retirement_quota(&caller, sub, id, amount_per_period)
 		}
 
-		/// Allow unprivileged transfers from an account again.
+		/// Retire carbon credits from the balance of an organization account within the
+		/// retirement quota of the sender.
 		///
-		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		/// Origin must be Signed and the sender should have a quota of the organization account.
 		///
-		/// - `id`: The identifier of the asset to be frozen.
-		/// - `who`: The account to be unfrozen.
+		/// - `organization`: The organization account granting the quota.
+		/// - `id`: The identifier of the asset to retire.
+		/// - `amount`: The amount to retire, at most the rest of the quota in the current period.
+		/// - `note`: Optional note about the beneficiary of the retirement. Limited in length by
+		/// `StringLimit`.
 		///
-		/// Emits `Thawed`.
+		/// The retirement is recorded in the `BurnCertificate` of the organization account with
+		/// the sender as the initiator.
+		///
+		/// Emits `Burned`, `CarbonCreditsBurned` and `RetirementAnchored`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::thaw())]
-		pub fn thaw(
+		#[pallet::weight(T::WeightInfo::self_burn_from_org())]
+		pub fn self_burn_from_org(
 			origin: OriginFor<T>,
+			organization: <T::Lookup as StaticLookup>::Source,
 			id: AssetId,
-			who: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+			note: Option<Vec<u8>>,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-
-			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
-			ensure!(origin == details.admin, Error::<T, I>::NoPermission);
-			let who = T::Lookup::lookup(who)?;
-
-			Account::<T, I>::try_mutate(id, &who, |maybe_account| -> DispatchResult {
-				maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?.is_frozen = false;
-				Ok(())
-			})?;
-
-			Self::deposit_event(Event::<T, I>::Thawed { asset_id: id, who });
-			Ok(())
+			let caller = ensure_signed(origin)?;
+			let organization = T::Lookup::lookup(organization)?;
+			let note = note
+				.map(|n| n.try_into().map_err(|_| Error::<T, I>::BadMetadata))
+				.transpose()?;
+			Self::do_self_burn_from_org(&caller, &organization, id, amount, note)
 		}
 
-		/// Disallow further unprivileged transfers for the asset class.
+		/// Propose a new custodian, which can accept the role after `CustodianChangeDelay`
+		/// blocks. Replaces the pending proposal, if any.
 		///
-		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		/// The origin must conform to `ForceOrigin`.
 		///
-		/// - `id`: The identifier of the asset to be frozen.
+		/// - `custodian`: The proposed custodian.
 		///
-		/// Emits `Frozen`.
+		/// Emits `CustodianProposed`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::freeze_asset())]
-		pub fn freeze_asset(
-			origin: OriginFor<T>,
-			id: AssetId,
-		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-
-			Asset::<T, I>::try_mutate(id, |maybe_details| {
-				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
-				ensure!(origin == d.freezer, Error::<T, I>::NoPermission);
-
-				d.is_frozen = true;
+		#[pallet::weight(T::WeightInfo::propose_custodian())]
+		pub fn propose_custodian(origin: OriginFor<T>, custodian: T::AccountId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_propose_custodian(custodian);
+			Ok(())
+		}
 
-				Self::deposit_event(Event::<T, I>::AssetFrozen { asset_id: id });
-				Ok(())
-			})
+		/// Accept the custodian role proposed by `propose_custodian`.
+		///
+		/// Origin must be Signed and the sender should be the proposed custodian, after the
+		/// `CustodianChangeDelay`.
+		///
+		/// Emits `CustodianSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::accept_custodian())]
+		pub fn accept_custodian(origin: OriginFor<T>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_accept_custodian(caller)
 		}
 
-		/// Allow unprivileged transfers for the asset again.
+		/// Accept the ownership of an asset proposed by `transfer_ownership`, taking over the
+		/// deposits of the asset.
 		///
-		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		/// Origin must be Signed and the sender should be the proposed Owner of the asset `id`,
+		/// within the `OwnershipAcceptancePeriod`.
 		///
-		/// - `id`: The identifier of the asset to be thawed.
+		/// - `id`: The identifier of the asset.
 		///
-		/// Emits `Thawed`.
+		/// Emits `OwnerChanged`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::thaw_asset())]
-		pub fn thaw_asset(
-			origin: OriginFor<T>,
-			id: AssetId,
-		) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::accept_ownership())]
+		pub fn accept_ownership(origin: OriginFor<T>, id: AssetId) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
-
-			Asset::<T, I>::try_mutate(id, |maybe_details| {
-				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
-				ensure!(origin == d.admin, Error::<T, I>::NoPermission);
-
-				d.is_frozen = false;
-
-				Self::deposit_event(Event::<T, I>::AssetThawed { asset_id: id });
-				Ok(())
-			})
+			Self::do_accept_ownership(id, origin)
 		}
 
-		/// Change the Owner of an asset.
+		/// Change the Owner of an asset at once, without the acceptance of the new Owner.
 		///
-		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		/// The origin must conform to `ForceOrigin`.
 		///
 		/// - `id`: The identifier of the asset.
 		/// - `owner`: The new Owner of this asset.
@@ -1043,349 +4439,370 @@ pub mod pallet {
 		/// Emits `OwnerChanged`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::transfer_ownership())]
-		pub fn transfer_ownership(
+		#[pallet::weight(T::WeightInfo::force_transfer_ownership())]
+		pub fn force_transfer_ownership(
 			origin: OriginFor<T>,
 			id: AssetId,
 			owner: <T::Lookup as StaticLookup>::Source,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
+			T::ForceOrigin::ensure_origin(origin)?;
 			let owner = T::Lookup::lookup(owner)?;
-
-			Asset::<T, I>::try_mutate(id, |maybe_details| {
-				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
-				ensure!(origin == details.owner, Error::<T, I>::NoPermission);
-				if details.owner == owner {
-					return Ok(())
-				}
-
-				let metadata_deposit = Metadata::<T, I>::get(id).deposit;
-				let deposit = details.deposit + metadata_deposit;
-
-				// Move the deposit to the new owner.
-				T::Currency::repatriate_reserved(&details.owner, &owner, deposit, Reserved)?;
-
-				details.owner = owner.clone();
-
-				Self::deposit_event(Event::OwnerChanged { asset_id: id, owner });
-				Ok(())
-			})
+			let from = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?.owner;
+			Self::do_transfer_ownership(id, &from, owner)
 		}
 
-		/// Force the metadata for an asset to some value.
-		///
-		/// Origin must be ForceOrigin.
+		/// Burn carbon assets of the signer of an off-chain payload, e.g. a user without native
+		/// tokens whose retirement is sponsored by a custodial frontend. The amount is stored in
+		/// the signer's `BurnCertificate`.
 		///
-		/// Any deposit is left alone.
+		/// Origin must be Signed. Anyone may submit the payload and pays the fee.
 		///
-		/// - `id`: The identifier of the asset to update.
-		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
-		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
-		/// - `decimals`: The number of decimals this asset uses to represent one unit.
+		/// - `payload`: The burn payload, see `SignedBurnPayload`.
+		/// - `signature`: The signature of the SCALE-encoded `payload` by its signer.
 		///
-		/// Emits `MetadataSet`.
+		/// Emits `SignedBurnExecuted`.
 		///
-		/// Weight: `O(N + S)` where N and S are the length of the name and symbol respectively.
-		#[pallet::weight(T::WeightInfo::force_set_metadata(name.len() as u32, symbol.len() as u32))]
-		pub fn force_set_metadata(
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::self_burn_signed())]
+		pub fn self_burn_signed(
 			origin: OriginFor<T>,
-			id: AssetId,
-			name: Vec<u8>,
-			symbol: Vec<u8>,
-			url: Vec<u8>,
-			data_ipfs: Vec<u8>,
-			decimals: u8,
-			is_frozen: bool,
+			payload: SignedBurnPayload<T::AccountId, T::Balance, T::BlockNumber, T::Hash>,
+			signature: T::OffchainSignature,
 		) -> DispatchResult {
-			T::ForceOrigin::ensure_origin(origin)?;
-
-			let bounded_name: BoundedVec<u8, T::StringLimit> =
-				name.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
-
-			let bounded_symbol: BoundedVec<u8, T::StringLimit> =
-				symbol.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
-
-			let bounded_url: BoundedVec<u8, T::StringLimit> =
-				url.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
-			let bounded_data_ipfs: BoundedVec<u8, T::StringLimit> =
-				data_ipfs.clone().try_into().map_err(|_| Error::<T, I>::BadMetadata)?;
-
-			ensure!(Asset::<T, I>::contains_key(id), Error::<T, I>::Unknown);
-			Metadata::<T, I>::try_mutate_exists(id, |metadata| {
-				let deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
-				*metadata = Some(AssetMetadata {
-					deposit,
-					url: bounded_url,
-					data_ipfs: bounded_data_ipfs,
-					name: bounded_name,
-					symbol: bounded_symbol,
-					decimals,
-					is_frozen,
-				});
-
-				Self::deposit_event(Event::MetadataSet {
-					asset_id: id,
-					name,
-					symbol,
-					decimals,
-					is_frozen,
-				});
-				Self::deposit_event(Event::MetadataUpdated {
-					asset_id: id,
-					url,
-					data_ipfs,
-				});
-				Ok(())
-			})
+			let relayer = ensure_signed(origin)?;
+			Self::do_self_burn_signed(relayer, payload, signature)
 		}
 
-		/// Clear the metadata for an asset.
-		///
-		/// Origin must be ForceOrigin.
+		/// Burn carbon assets of a previously delegated account, e.g. by an offset service
+		/// provider on behalf of its client. The amount is stored in the `BurnCertificate` of the
+		/// `beneficiary`, or of the `owner` if no beneficiary is given.
 		///
-		/// Any deposit is returned.
+		/// Origin must be Signed and there must be an approval in place by the `owner` to the
+		/// signer. The approval is used up like by `transfer_approved`.
 		///
-		/// - `id`: The identifier of the asset to clear.
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which previously approved at least `amount` and from which the
+		/// asset balance will be burned.
+		/// - `amount`: The amount of assets to burn.
+		/// - `beneficiary`: The account the retirement is recorded for, if not the `owner`.
 		///
-		/// Emits `MetadataCleared`.
+		/// Emits `Burned` and `CarbonCreditsBurned` with the signer as the `initiator`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::force_clear_metadata())]
-		pub fn force_clear_metadata(
+		#[pallet::weight(T::WeightInfo::retire_from())]
+		pub fn retire_from(
 			origin: OriginFor<T>,
 			id: AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+			beneficiary: Option<<T::Lookup as StaticLookup>::Source>,
 		) -> DispatchResult {
-			T::ForceOrigin::ensure_origin(origin)?;
+			let delegate = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let beneficiary = beneficiary.map(T::Lookup::lookup).transpose()?;
+			let beneficiary = beneficiary.as_ref().unwrap_or(&owner);
+			Self::do_retire_from(id, &owner, &delegate, beneficiary, amount)
+		}
 
-			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
-			Metadata::<T, I>::try_mutate_exists(id, |metadata| {
-				let deposit = metadata.take().ok_or(Error::<T, I>::Unknown)?.deposit;
-				T::Currency::unreserve(&d.owner, deposit);
-				Self::deposit_event(Event::MetadataCleared { asset_id: id });
-				Ok(())
-			})
+		/// Pause operation classes across all assets, e.g. during a registry incident.
+		///
+		/// Origin must be ForceOrigin or Signed by the custodian.
+		///
+		/// - `ops`: Bitmask of the classes to pause in addition to the paused ones: `PAUSE_MINT`,
+		/// `PAUSE_BURN`, `PAUSE_TRANSFER` and `PAUSE_APPROVALS`.
+		///
+		/// Emits `OperationsPaused`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>, ops: u8) -> DispatchResult {
+			Self::ensure_force_or_custodian(origin)?;
+			ensure!(ops != 0 && ops & !PAUSE_ALL == 0, Error::<T, I>::BadPauseMask);
+			let paused = Paused::<T, I>::mutate(|paused| {
+				*paused |= ops;
+				*paused
+			});
+			Self::deposit_event(Event::OperationsPaused { ops, paused });
+			Ok(())
+		}
+
+		/// Resume all the paused operation classes.
+		///
+		/// Origin must be ForceOrigin or Signed by the custodian.
+		///
+		/// Emits `OperationsUnpaused`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+			Self::ensure_force_or_custodian(origin)?;
+			Paused::<T, I>::kill();
+			Self::deposit_event(Event::OperationsUnpaused);
+			Ok(())
 		}
 
-		/// Alter the attributes of a given asset.
+		/// Submit the project data of an asset for the audit.
 		///
-		/// Origin must be `ForceOrigin`.
+		/// Origin must be Signed by the owner of the asset and the project must be a `Draft`.
 		///
 		/// - `id`: The identifier of the asset.
-		/// - `owner`: The new Owner of this asset.
-		/// - `issuer`: The new Issuer of this asset.
-		/// - `admin`: The new Admin of this asset.
-		/// - `freezer`: The new Freezer of this asset.
-		/// - `min_balance`: The minimum balance of this new asset that any single account must
-		/// have. If an account's balance is reduced below this, then it collapses to zero.
-		/// - `is_sufficient`: Whether a non-zero balance of this asset is deposit of sufficient
-		/// value to account for the state bloat associated with its balance storage. If set to
-		/// `true`, then non-zero balances may be stored without a `consumer` reference (and thus
-		/// an ED in the Balances pallet or whatever else is used to control user-account state
-		/// growth).
-		/// - `is_frozen`: Whether this asset class is frozen except for permissioned/admin
-		/// instructions.
 		///
-		/// Emits `AssetStatusChanged` with the identity of the asset.
+		/// Emits `ProjectStatusChanged` with `Submitted`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::force_asset_status())]
-		pub fn force_asset_status(
-			origin: OriginFor<T>,
-			id: AssetId,
-			owner: <T::Lookup as StaticLookup>::Source,
-			issuer: <T::Lookup as StaticLookup>::Source,
-			admin: <T::Lookup as StaticLookup>::Source,
-			freezer: <T::Lookup as StaticLookup>::Source,
-			#[pallet::compact] min_balance: T::Balance,
-			is_sufficient: bool,
-			is_frozen: bool,
-		) -> DispatchResult {
-			T::ForceOrigin::ensure_origin(origin)?;
-
-			Asset::<T, I>::try_mutate(id, |maybe_asset| {
-				let mut asset = maybe_asset.take().ok_or(Error::<T, I>::Unknown)?;
-				asset.owner = T::Lookup::lookup(owner)?;
-				asset.issuer = T::Lookup::lookup(issuer)?;
-				asset.admin = T::Lookup::lookup(admin)?;
-				asset.freezer = T::Lookup::lookup(freezer)?;
-				asset.min_balance = min_balance;
-				asset.is_sufficient = is_sufficient;
-				asset.is_frozen = is_frozen;
-				*maybe_asset = Some(asset);
-
-				Self::deposit_event(Event::AssetStatusChanged { asset_id: id });
-				Ok(())
-			})
+		#[pallet::weight(T::WeightInfo::submit_project())]
+		pub fn submit_project(origin: OriginFor<T>, id: AssetId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_submit_project(id, &owner)
 		}
 
-		/// Approve an amount of asset for transfer by a delegated third-party account.
+		/// Approve the project of an asset: an auditor approves a `Submitted` project, then the
+		/// custodian of the asset approves the `AuditorApproved` one.
 		///
-		/// Origin must be Signed.
+		/// Origin must be Signed by one of the `Auditors` or by the custodian of the asset.
 		///
-		/// Ensures that `ApprovalDeposit` worth of `Currency` is reserved from signing account
-		/// for the purpose of holding the approval. If some non-zero amount of assets is already
-		/// approved from signing account to `delegate`, then it is topped up or unreserved to
-		/// meet the right value.
+		/// - `id`: The identifier of the asset.
 		///
-		/// NOTE: The signing account does not need to own `amount` of assets at the point of
-		/// making this call.
+		/// Emits `ProjectStatusChanged` with `AuditorApproved` or `CustodianApproved`.
 		///
-		/// - `id`: The identifier of the asset.
-		/// - `delegate`: The account to delegate permission to transfer asset.
-		/// - `amount`: The amount of asset that may be transferred by `delegate`. If there is
-		/// already an approval in place, then this acts additively.
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_project())]
+		pub fn approve_project(origin: OriginFor<T>, id: AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_approve_project(id, &who)
+		}
+
+		/// Issue a new class of fungible carbon assets with the given number of decimals.
 		///
-		/// Emits `ApprovedTransfer` on success.
+		/// Same as `create`, but the metadata is set with `decimals` instead of 9, e.g. 0 for the
+		/// credits of registries issuing whole tonnes only. The minimum balance of an asset
+		/// without decimals is one tonne.
+		///
+		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
+		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
+		/// - `decimals`: The number of decimals of the asset, at most `CO2E_DECIMALS`.
+		///
+		/// Emits `Created` and `MetadataSet`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::approve_transfer())]
-		pub fn approve_transfer(
+		#[pallet::weight(T::WeightInfo::create())]
+		pub fn create_with_decimals(
 			origin: OriginFor<T>,
-			id: AssetId,
-			delegate: <T::Lookup as StaticLookup>::Source,
-			#[pallet::compact] amount: T::Balance,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
-			let delegate = T::Lookup::lookup(delegate)?;
-			Self::do_approve_transfer(id, &owner, &delegate, amount)
+			Self::do_create(&owner, name, symbol, None, decimals).map(|_| ())
 		}
 
-		/// Cancel all of some asset approved for delegated transfer by a third-party account.
-		///
-		/// Origin must be Signed and there must be an approval in place between signer and
-		/// `delegate`.
+		/// Set project data to metadata of an asset.
+		/// 
+		/// Origin must be Signed and the sender should be the Owner of the asset `id` or the Custodian.
+		/// 
+		/// - `id`: The identifier of the asset to update.
+		/// - `url`: The url.
+		/// - `data_ipfs`: The ipfs data link, a CIDv0 or a CIDv1 in base32, base58btc or base16.
+		/// - `details`: The structured project details, if any. Strings are limited in length by
+		/// `StringLimit`.
+		/// 
+		/// Emits `MetadataUpdated` and `ProjectDetailsSet`, or `OwnerActionProposed` if the owner
+		/// sends it under dual control. The details are queued as a separate owner action.
+		/// 
+		#[pallet::weight(T::WeightInfo::set_project_data())]
+		pub fn set_project_data(
+			origin: OriginFor<T>,
+			id: AssetId,
+			url: Vec<u8>,
+			data_ipfs: Vec<u8>,
+			details: Option<ProjectDetails<Vec<u8>>>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_set_project_data(id, &caller, url, data_ipfs, details)
+		}
+
+		/// Disallow further unprivileged transfers from an account.
 		///
-		/// Unreserves any deposit previously reserved by `approve_transfer` for the approval.
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
 		///
-		/// - `id`: The identifier of the asset.
-		/// - `delegate`: The account delegated permission to transfer asset.
+		/// - `id`: The identifier of the asset to be frozen.
+		/// - `who`: The account to be frozen.
+		/// - `reason`: The reason of the freeze, e.g. a compliance or a fraud investigation code,
+		/// stored in `AccountFreezeReason` until the account is thawed.
 		///
-		/// Emits `ApprovalCancelled` on success.
+		/// Emits `Frozen`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::cancel_approval())]
-		pub fn cancel_approval(
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(
 			origin: OriginFor<T>,
 			id: AssetId,
-			delegate: <T::Lookup as StaticLookup>::Source,
+			who: <T::Lookup as StaticLookup>::Source,
+			reason: Option<BoundedVec<u8, T::StringLimit>>,
 		) -> DispatchResult {
-			let owner = ensure_signed(origin)?;
-			let delegate = T::Lookup::lookup(delegate)?;
-			let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
-			let approval =
-				Approvals::<T, I>::take((id, &owner, &delegate)).ok_or(Error::<T, I>::Unknown)?;
-			T::Currency::unreserve(&owner, approval.deposit);
+			let origin = ensure_signed(origin)?;
 
-			d.approvals.saturating_dec();
-			Asset::<T, I>::insert(id, d);
+			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(origin == d.freezer, Error::<T, I>::NoPermission);
+			let who = T::Lookup::lookup(who)?;
 
-			Self::deposit_event(Event::ApprovalCancelled { asset_id: id, owner, delegate });
+			Account::<T, I>::try_mutate(id, &who, |maybe_account| -> DispatchResult {
+				maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?.is_frozen = true;
+				Ok(())
+			})?;
+			AccountFreezeReason::<T, I>::set(id, &who, reason.clone());
+			Self::note_custodian_action(CustodianAction::Freeze, id, Some(&who), Zero::zero());
+
+			Self::deposit_event(Event::<T, I>::Frozen {
+				asset_id: id,
+				who,
+				reason: reason.map(|reason| reason.into_inner()),
+			});
 			Ok(())
 		}
 
-		/// Cancel all of some asset approved for delegated transfer by a third-party account.
-		///
-		/// Origin must be either ForceOrigin or Signed origin with the signer being the Admin
-		/// account of the asset `id`.
+		/// Disallow further unprivileged transfers for the asset class.
 		///
-		/// Unreserves any deposit previously reserved by `approve_transfer` for the approval.
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
 		///
-		/// - `id`: The identifier of the asset.
-		/// - `delegate`: The account delegated permission to transfer asset.
+		/// - `id`: The identifier of the asset to be frozen.
+		/// - `reason`: The reason of the freeze, e.g. a compliance or a fraud investigation code,
+		/// stored in `AssetFreezeReason` until the asset is thawed.
 		///
-		/// Emits `ApprovalCancelled` on success.
+		/// Emits `AssetFrozen`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::force_cancel_approval())]
-		pub fn force_cancel_approval(
+		#[pallet::weight(T::WeightInfo::freeze_asset())]
+		pub fn freeze_asset(
 			origin: OriginFor<T>,
 			id: AssetId,
-			owner: <T::Lookup as StaticLookup>::Source,
-			delegate: <T::Lookup as StaticLookup>::Source,
+			reason: Option<BoundedVec<u8, T::StringLimit>>,
 		) -> DispatchResult {
-			let mut d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
-			T::ForceOrigin::try_origin(origin)
-				.map(|_| ())
-				.or_else(|origin| -> DispatchResult {
-					let origin = ensure_signed(origin)?;
-					ensure!(origin == d.admin, Error::<T, I>::NoPermission);
-					Ok(())
-				})?;
+			let origin = ensure_signed(origin)?;
 
-			let owner = T::Lookup::lookup(owner)?;
-			let delegate = T::Lookup::lookup(delegate)?;
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(origin == d.freezer, Error::<T, I>::NoPermission);
+				ensure!(d.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 
-			let approval =
-				Approvals::<T, I>::take((id, &owner, &delegate)).ok_or(Error::<T, I>::Unknown)?;
-			T::Currency::unreserve(&owner, approval.deposit);
-			d.approvals.saturating_dec();
-			Asset::<T, I>::insert(id, d);
+				d.status = AssetStatus::Frozen;
+				AssetFreezeReason::<T, I>::set(id, reason.clone());
+				Self::note_custodian_action(CustodianAction::Freeze, id, None, Zero::zero());
 
-			Self::deposit_event(Event::ApprovalCancelled { asset_id: id, owner, delegate });
-			Ok(())
+				Self::deposit_event(Event::<T, I>::AssetFrozen {
+					asset_id: id,
+					reason: reason.map(|reason| reason.into_inner()),
+				});
+				Ok(())
+			})
 		}
 
-		/// Transfer some asset balance from a previously delegated account to some third-party
-		/// account.
-		///
-		/// Origin must be Signed and there must be an approval in place by the `owner` to the
-		/// signer.
+		/// Mint carbon assets of a particular class by Custodian. Benefitiary is the owner of the asset.
 		///
-		/// If the entire amount approved for transfer is transferred, then any deposit previously
-		/// reserved by `approve_transfer` is unreserved.
+		/// The origin must be Signed and the sender must be the Custodian == the Issuer of the asset `id`.
 		///
-		/// - `id`: The identifier of the asset.
-		/// - `owner`: The account which previously approved for a transfer of at least `amount` and
-		/// from which the asset balance will be withdrawn.
-		/// - `destination`: The account to which the asset balance of `amount` will be transferred.
-		/// - `amount`: The amount of assets to transfer.
+		/// - `id`: The identifier of the asset to have some amount minted.
+		/// - `amount`: The amount of the asset to be minted.
+		/// - `serials`: The registry serial numbers `(serial_start, serial_end)` of the minted
+		/// carbon credits, one serial per unit of `amount`. It must not overlap the serial
+		/// ranges minted before, and is required once the asset was minted with one.
 		///
-		/// Emits `TransferredApproved` on success.
+		/// Emits `Issued` event when successful.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::transfer_approved())]
-		pub fn transfer_approved(
+		/// 
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint(
 			origin: OriginFor<T>,
 			id: AssetId,
-			owner: <T::Lookup as StaticLookup>::Source,
-			destination: <T::Lookup as StaticLookup>::Source,
 			#[pallet::compact] amount: T::Balance,
+			serials: Option<(u64, u64)>,
 		) -> DispatchResult {
-			let delegate = ensure_signed(origin)?;
-			let owner = T::Lookup::lookup(owner)?;
-			let destination = T::Lookup::lookup(destination)?;
-			Self::do_transfer_approved(id, &owner, &delegate, &destination, amount)
+			let origin = ensure_signed(origin)?;
+			Self::do_mint_to_owner(id, amount, Some(origin), serials)
 		}
 
-		/// Create an asset account for non-provider assets.
+		/// Burn of carbon credits assets by custodian. 
+		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
+		/// Store information about the burn in `CustodialBurns`. It is not a voluntary retirement,
+		/// so the `BurnCertificate` of `who` is not changed.
 		///
-		/// A deposit will be taken from the signer account.
+		/// Origin must be Signed and the sender should be the Custodian.
 		///
-		/// - `origin`: Must be Signed; the signer account must have sufficient funds for a deposit
-		///   to be taken.
-		/// - `id`: The identifier of the asset for the account to be created.
+		/// Bails with `NoAccount` if the `who` is already dead.
 		///
-		/// Emits `Touched` event when successful.
-		#[pallet::weight(T::WeightInfo::mint())]
-		pub fn touch(origin: OriginFor<T>, id: AssetId) -> DispatchResult {
-			Self::do_touch(id, ensure_signed(origin)?)
+		/// - `id`: The identifier of the asset to have some amount burned.
+		/// - `who`: The account to be debited from.
+		/// - `amount`: The maximum amount by which `who`'s balance should be reduced.
+		/// - `reason`: Why the assets are burned.
+		/// - `reference`: Optional reference of the burn, e.g. the number of the court case.
+		/// Limited in length by `StringLimit`.
+		///
+		/// Emits `Burned` with the actual amount burned. If this takes the balance to below the
+		/// minimum for the asset, then the amount burned is increased to take it to zero.
+		/// 
+		/// Emits `CarbonCreditsBurned` of `CustodialBurn` kind and `CustodialBurned`.
+		///
+		/// Weight: `O(1)`
+		/// Modes: Post-existence of `who`; Pre & post Zombie-status of `who`.
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(
+			origin: OriginFor<T>,
+			id: AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+			reason: CustodialBurnReason,
+			reference: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let reference = reference
+				.map(|r| r.try_into().map_err(|_| Error::<T, I>::BadMetadata))
+				.transpose()?;
+
+			let f = DebitFlags { keep_alive: false, best_effort: false };
+			let actual = Self::do_burn(id, &who, amount, Some(origin), f)?;
+
+			Self::note_custodial_burn(&who, id, actual, reason, reference, &origin);
+			Ok(())
 		}
 
-		/// Return the deposit (if any) of an asset account.
+		/// Burn of carbon credits assets by owner. 
+		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
+		/// Store information about the burned carbon asset in `BurnCertificate`.
 		///
-		/// The origin must be Signed.
+		/// Origin must be Signed and the sender should have enough amount of asset.
 		///
-		/// - `id`: The identifier of the asset for the account to be created.
-		/// - `allow_burn`: If `true` then assets may be destroyed in order to complete the refund.
+		/// Bails with `NoAccount` if the `who` is already dead.
 		///
-		/// Emits `Refunded` event when successful.
-		#[pallet::weight(T::WeightInfo::mint())]
-		pub fn refund(
+		/// - `id`: The identifier of the asset to have some amount burned.
+		/// - `amount`: The maximum amount by which `who`'s balance should be reduced.
+		/// - `note`: Optional note about the beneficiary of the retirement, included in the
+		/// retirement anchor. Limited in length by `StringLimit`.
+		///
+		/// Emits `Burned` with the actual amount burned. If this takes the balance to below the
+		/// minimum for the asset, then the amount burned is increased to take it to zero.
+		/// 
+		/// Emits `CarbonCreditsBurned` and `RetirementAnchored`. The fee is refunded and
+		/// `RetirementSponsored` emitted if the sponsorship pot of the asset covers
+		/// `SponsoredRetirementFee`, at least the minimum balance of the asset is burned and the
+		/// sender had no sponsored retirement in the last `SponsoredRetirementInterval` blocks.
+		///
+		/// Weight: `O(1)`
+		/// Modes: Post-existence of `who`; Pre & post Zombie-status of `who`.
+		#[pallet::weight(T::WeightInfo::self_burn())]
+		pub fn self_burn(
 			origin: OriginFor<T>,
 			id: AssetId,
-			allow_burn: bool,
-		) -> DispatchResult {
-			Self::do_refund(id, ensure_signed(origin)?, allow_burn)
+			#[pallet::compact] amount: T::Balance,
+			note: Option<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let note = note
+				.map(|n| n.try_into().map_err(|_| Error::<T, I>::BadMetadata))
+				.transpose()?;
+			let (burned, _) = Self::do_retire(id, &caller, amount, note, &caller)?;
+			Ok(Self::sponsor_retirement_fee(id, &caller, burned)?.into())
 		}
 	}
 }
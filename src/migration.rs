@@ -0,0 +1,405 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations of the Carbon Assets pallet.
+
+use super::*;
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+	use super::*;
+	use frame_support::{pallet_prelude::StorageDoubleMap, Blake2_128Concat};
+
+	/// `BurnCertificate` before the last-updated block was added.
+	#[frame_support::storage_alias]
+	pub(crate) type BurnCertificate<T: Config<I>, I: 'static> = StorageDoubleMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		AssetId,
+		<T as Config<I>>::Balance,
+	>;
+
+	/// Build the `AssetBurnCertificate` reverse index and `TotalRetired` from the existing
+	/// `BurnCertificate` storage.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			for (who, id, amount) in BurnCertificate::<T, I>::iter() {
+				AssetBurnCertificate::<T, I>::insert(id, &who, amount);
+				TotalRetired::<T, I>::mutate(id, |total| total.saturating_accrue(amount));
+				count.saturating_inc();
+			}
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_mul(2) + 1)
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Build the `OwnedAssets` index and `OwnedAssetCount` from the existing assets.
+	pub struct MigrateToV2<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			for (id, details) in Asset::<T, I>::iter() {
+				Pallet::<T, I>::note_owner(id, None, Some(&details.owner));
+				count.saturating_inc();
+			}
+			StorageVersion::new(2).put::<Pallet<T, I>>();
+
+			T::DbWeight::get()
+				.reads_writes(count.saturating_mul(3).saturating_add(1), count.saturating_mul(2) + 1)
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use codec::Decode;
+
+	/// `Approval` before the expiry was added.
+	#[derive(Decode)]
+	struct OldApproval<Balance, DepositBalance> {
+		amount: Balance,
+		deposit: DepositBalance,
+	}
+
+	/// Add the `expiry` to the existing approvals. They never expire.
+	pub struct MigrateToV3<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV3<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 2 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			Approvals::<T, I>::translate::<OldApproval<T::Balance, DepositBalanceOf<T, I>>, _>(
+				|_, old| {
+					count.saturating_inc();
+					Some(Approval { amount: old.amount, deposit: old.deposit, expiry: None })
+				},
+			);
+			StorageVersion::new(3).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+	}
+}
+
+pub mod v4 {
+	use super::*;
+
+	/// Add the last-updated block to the existing burn certificates. It is set to the block of
+	/// the upgrade, as the block of the last retirement is not known.
+	pub struct MigrateToV4<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV4<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 3 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut count = 0u64;
+			BurnCertificate::<T, I>::translate::<T::Balance, _>(|_, _, amount| {
+				count.saturating_inc();
+				Some(BurnCertificateEntry { amount, last_block: now })
+			});
+			StorageVersion::new(4).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(count.saturating_add(2), count.saturating_add(1))
+		}
+	}
+}
+
+pub mod v5 {
+	use super::*;
+
+	/// Build the `AccountAssets` index from the existing asset accounts.
+	pub struct MigrateToV5<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV5<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 4 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			for (id, who) in Account::<T, I>::iter_keys() {
+				AccountAssets::<T, I>::insert(who, id, ());
+				count.saturating_inc();
+			}
+			StorageVersion::new(5).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+	}
+}
+
+pub mod v6 {
+	use super::*;
+
+	/// Build the `SymbolAssets` index from the existing asset metadata.
+	pub struct MigrateToV6<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV6<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 5 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			for (id, metadata) in Metadata::<T, I>::iter() {
+				Pallet::<T, I>::note_symbol(id, None, Some(&metadata.symbol));
+				count.saturating_inc();
+			}
+			StorageVersion::new(6).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+	}
+}
+
+pub mod v7 {
+	use super::*;
+	use codec::Decode;
+
+	/// `AssetAccount` before the frozen amount was added.
+	#[derive(Decode)]
+	struct OldAssetAccount<Balance, DepositBalance, Extra> {
+		balance: Balance,
+		is_frozen: bool,
+		reason: ExistenceReason<DepositBalance>,
+		extra: Extra,
+	}
+
+	/// Add the frozen amount to the existing asset accounts. Nothing is frozen.
+	pub struct MigrateToV7<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV7<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 6 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			Account::<T, I>::translate::<
+				OldAssetAccount<T::Balance, DepositBalanceOf<T, I>, T::Extra>,
+				_,
+			>(|_, _, old| {
+				count.saturating_inc();
+				Some(AssetAccount {
+					balance: old.balance,
+					is_frozen: old.is_frozen,
+					frozen: Zero::zero(),
+					reason: old.reason,
+					extra: old.extra,
+				})
+			});
+			StorageVersion::new(7).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+	}
+}
+
+pub mod v8 {
+	use super::*;
+	use codec::Decode;
+
+	/// `AssetDetails` before the transfer whitelist flag was added.
+	#[derive(Decode)]
+	struct OldAssetDetails<Balance, AccountId, DepositBalance> {
+		owner: AccountId,
+		issuer: AccountId,
+		admin: AccountId,
+		freezer: AccountId,
+		supply: Balance,
+		deposit: DepositBalance,
+		min_balance: Balance,
+		is_sufficient: bool,
+		accounts: u32,
+		sufficients: u32,
+		approvals: u32,
+		status: AssetStatus,
+	}
+
+	/// Add the transfer whitelist flag to the existing assets. No whitelist is enforced.
+	pub struct MigrateToV8<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV8<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 7 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			Asset::<T, I>::translate::<
+				OldAssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+				_,
+			>(|_, old| {
+				count.saturating_inc();
+				Some(AssetDetails {
+					owner: old.owner,
+					issuer: old.issuer,
+					admin: old.admin,
+					freezer: old.freezer,
+					supply: old.supply,
+					deposit: old.deposit,
+					min_balance: old.min_balance,
+					is_sufficient: old.is_sufficient,
+					accounts: old.accounts,
+					sufficients: old.sufficients,
+					approvals: old.approvals,
+					status: old.status,
+					whitelist_enabled: false,
+				})
+			});
+			StorageVersion::new(8).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+	}
+}
+
+pub mod v9 {
+	use super::*;
+	use codec::Decode;
+	use frame_support::BoundedVec;
+
+	/// `AssetMetadata` before `data_ipfs` was stored as a `Cid`.
+	#[derive(Decode)]
+	struct OldAssetMetadata<DepositBalance, BoundedString> {
+		deposit: DepositBalance,
+		url: BoundedString,
+		data_ipfs: BoundedString,
+		name: BoundedString,
+		symbol: BoundedString,
+		decimals: u8,
+		is_frozen: bool,
+	}
+
+	type OldMetadataOf<T, I> =
+		OldAssetMetadata<DepositBalanceOf<T, I>, BoundedVec<u8, <T as Config<I>>::StringLimit>>;
+
+	/// Whether the `data_ipfs` text is neither empty nor a well-formed CID.
+	fn is_malformed(data_ipfs: &[u8]) -> bool {
+		!data_ipfs.is_empty() && !cid::is_valid(data_ipfs)
+	}
+
+	/// Parse the `data_ipfs` text of the existing metadata into a `Cid`. Malformed links, e.g.
+	/// set before the CIDs were validated, are kept as text in `LegacyDataIpfs`.
+	pub struct MigrateToV9<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV9<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 8 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			let mut malformed = 0u64;
+			Metadata::<T, I>::translate::<OldMetadataOf<T, I>, _>(|id, old| {
+				count.saturating_inc();
+				if is_malformed(&old.data_ipfs) {
+					malformed.saturating_inc();
+					LegacyDataIpfs::<T, I>::insert(id, old.data_ipfs.clone());
+				}
+				Some(AssetMetadata {
+					deposit: old.deposit,
+					url: old.url,
+					data_ipfs: cid::Cid::parse(&old.data_ipfs),
+					name: old.name,
+					symbol: old.symbol,
+					decimals: old.decimals,
+					is_frozen: old.is_frozen,
+				})
+			});
+			StorageVersion::new(9).put::<Pallet<T, I>>();
+			frame_support::log::info!(
+				target: "runtime::carbon-assets",
+				"migrated the data_ipfs of {} metadata, {} malformed kept in LegacyDataIpfs",
+				count,
+				malformed,
+			);
+
+			T::DbWeight::get()
+				.reads_writes(count.saturating_add(1), count.saturating_add(malformed).saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			if Pallet::<T, I>::on_chain_storage_version() != 8 {
+				return Ok(Vec::new())
+			}
+			let (mut count, mut malformed) = (0u32, 0u32);
+			for id in Metadata::<T, I>::iter_keys() {
+				let old: OldMetadataOf<T, I> =
+					frame_support::storage::unhashed::get(&Metadata::<T, I>::hashed_key_for(id))
+						.ok_or("metadata is not decodable before the upgrade")?;
+				count.saturating_inc();
+				if is_malformed(&old.data_ipfs) {
+					malformed.saturating_inc();
+				}
+			}
+			Ok(codec::Encode::encode(&(count, malformed)))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			if state.is_empty() {
+				return Ok(())
+			}
+			let (count, malformed) = <(u32, u32)>::decode(&mut &state[..])
+				.map_err(|_| "the pre-upgrade state is not decodable")?;
+			frame_support::ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == 9,
+				"the storage version is not 9"
+			);
+			frame_support::ensure!(
+				Metadata::<T, I>::iter_values().count() as u32 == count,
+				"metadata was lost"
+			);
+			frame_support::ensure!(
+				LegacyDataIpfs::<T, I>::iter_keys().count() as u32 == malformed,
+				"malformed data_ipfs was lost"
+			);
+			Ok(())
+		}
+	}
+}
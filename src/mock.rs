@@ -21,20 +21,24 @@ use super::*;
 use crate as pallet_assets;
 
 use frame_support::{
-	construct_runtime,
+	construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64, GenesisBuild},
 };
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestSignature, TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentityLookup},
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 pub const CUSTODIAN: u64 = 1;
+pub const RESERVE_ACCOUNT: u64 = 7;
+pub const VERIFIER: u64 = 9;
+pub const AUDITOR: u64 = 11;
 
+#[cfg(not(feature = "compliance-freezer"))]
 construct_runtime!(
 	pub enum Test where
 		Block = Block,
@@ -44,7 +48,22 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage},
-		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
+	}
+);
+
+#[cfg(feature = "compliance-freezer")]
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
+		ComplianceFreezer: pallet_assets::compliance_freezer::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -89,21 +108,141 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+parameter_types! {
+	pub static LedgerEvents: bool = false;
+	pub static AllowMintWhenFrozen: bool = false;
+	pub static CustodianRequired: bool = false;
+	pub static AccountStats: bool = false;
+	pub static MethodologyRequired: bool = false;
+	pub static ProjectApprovalRequired: bool = false;
+	pub static SlaReportInterval: u64 = 10;
+	pub static MaxAssetsPerAccount: u32 = 100;
+	pub static CleanupReward: u64 = 0;
+	pub static StrictMetadataChecks: bool = false;
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u64;
 	type Currency = Balances;
+	type Deposits = NativeDeposits<Balances>;
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type AssetDeposit = ConstU64<1>;
 	type AssetAccountDeposit = ConstU64<10>;
 	type MetadataDepositBase = ConstU64<1>;
 	type MetadataDepositPerByte = ConstU64<1>;
+	type AttributeDepositBase = ConstU64<1>;
+	type AttributeKeyLimit = ConstU32<10>;
+	type AttributeValueLimit = ConstU32<20>;
+	type MaxMintBatch = ConstU32<5>;
+	type MaxAssetsPerAccount = MaxAssetsPerAccount;
+	type MaxSplits = ConstU32<2>;
+	type MemoLimit = ConstU32<32>;
+	type QuotaPeriod = ConstU64<10>;
+	type CustodianChangeDelay = ConstU64<5>;
+	type OwnershipAcceptancePeriod = ConstU64<10>;
+	type RemoveItemsLimit = ConstU32<2>;
+	type CleanupReward = CleanupReward;
+	type ApprovalGcGracePeriod = ConstU64<10>;
+	type MaxRetirementBasket = ConstU32<5>;
 	type ApprovalDeposit = ConstU64<1>;
 	type StringLimit = ConstU32<50>;
 	type Freezer = TestFreezer;
+	type Screening = TestScreening;
+	type MetadataValidator = TestMetadataValidator;
+	type OnCarbonTransfer = TestOnCarbonTransfer;
 	type WeightInfo = ();
 	type Extra = ();
 	type Randomness = RandomnessCollectiveFlip;
+	type ReserveAccounts = TestReserveAccounts;
+	type Verifiers = TestVerifiers;
+	type Auditors = TestAuditors;
+	type OnDustRemoval = TestDustRemoval;
+	type AllowMintWhenFrozen = AllowMintWhenFrozen;
+	type CustodianRequired = CustodianRequired;
+	type LedgerEvents = LedgerEvents;
+	type AccountStats = AccountStats;
+	type MethodologyRequired = MethodologyRequired;
+	type ProjectApprovalRequired = ProjectApprovalRequired;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type MaxOutbox = ConstU32<3>;
+	type MaxCustodianLog = ConstU32<3>;
+	type MaxSerialRanges = ConstU32<3>;
+	type SlaReportInterval = SlaReportInterval;
+	type SponsorAmount = ConstU64<5>;
+	type SponsorshipClawbackPeriod = ConstU64<10>;
+	type FreezeIdentifier = [u8; 8];
+	type MaxFreezes = ConstU32<2>;
+	type MaxOwnerBatch = ConstU32<3>;
+	type MaxPendingOwnerActions = ConstU32<2>;
+	type MaxPendingMintRequests = ConstU32<2>;
+	type SponsoredRetirementFee = ConstU64<2>;
+	type SponsoredFeeDestination = ConstU64<999>;
+	type SponsoredRetirementInterval = ConstU64<10>;
+	type OffchainWorker = pallet_assets::outbox::OutboxWorker<Test>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u64, TestSignature> for TestBenchmarkHelper {
+	fn voucher_signer() -> u64 {
+		100
+	}
+
+	fn sign_voucher(signer: &u64, message: &[u8]) -> TestSignature {
+		TestSignature(*signer, message.to_vec())
+	}
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: UintAuthorityId,
+		_account: u64,
+		nonce: u64,
+	) -> Option<(RuntimeCall, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+impl pallet_assets::outbox::OffchainConfig for Test {
+	type AuthorityId = TestAuthorityId;
+}
+
+pub struct TestAuthorityId;
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthorityId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+#[cfg(feature = "compliance-freezer")]
+impl compliance_freezer::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type FreezeOrigin = frame_system::EnsureRoot<u64>;
 }
 
 use std::{cell::RefCell, collections::HashMap};
@@ -115,6 +254,12 @@ pub(crate) enum Hook {
 thread_local! {
 	static FROZEN: RefCell<HashMap<(AssetId, u64), u64>> = RefCell::new(Default::default());
 	static HOOKS: RefCell<Vec<Hook>> = RefCell::new(Default::default());
+	static DUST: RefCell<Vec<(AssetId, u64)>> = RefCell::new(Default::default());
+	static RESERVE_ACCOUNTS: RefCell<Vec<u64>> = RefCell::new(vec![RESERVE_ACCOUNT]);
+	static VERIFIERS: RefCell<Vec<u64>> = RefCell::new(vec![VERIFIER]);
+	static SANCTIONED: RefCell<Vec<u64>> = RefCell::new(Default::default());
+	static TRANSFERS: RefCell<Vec<(AssetId, Option<u64>, Option<u64>, u64)>> =
+		RefCell::new(Default::default());
 }
 
 pub struct TestFreezer;
@@ -130,6 +275,106 @@ impl FrozenBalance<AssetId, u64, u64> for TestFreezer {
 	}
 }
 
+pub struct TestScreening;
+impl Screening<AssetId, u64> for TestScreening {
+	fn screen(_: AssetId, from: Option<&u64>, to: &u64) -> Result<(), ScreeningRejection> {
+		SANCTIONED.with(|s| {
+			let s = s.borrow();
+			if from.map_or(false, |from| s.contains(from)) {
+				return Err(ScreeningRejection::SanctionedSender)
+			}
+			if s.contains(to) {
+				return Err(ScreeningRejection::SanctionedReceiver)
+			}
+			Ok(())
+		})
+	}
+}
+
+pub struct TestMetadataValidator;
+impl MetadataValidator for TestMetadataValidator {
+	fn validate_metadata(name: &[u8], symbol: &[u8]) -> Result<(), MetadataRejection> {
+		if StrictMetadataChecks::get() {
+			return StrictMetadata::validate_metadata(name, symbol)
+		}
+		Ok(())
+	}
+
+	fn validate_project_data(url: &[u8], data_ipfs: &[u8]) -> Result<(), MetadataRejection> {
+		if StrictMetadataChecks::get() {
+			return StrictMetadata::validate_project_data(url, data_ipfs)
+		}
+		Ok(())
+	}
+}
+
+pub(crate) fn set_sanctioned(who: u64) {
+	SANCTIONED.with(|s| s.borrow_mut().push(who));
+}
+
+pub struct TestOnCarbonTransfer;
+impl OnCarbonTransfer<AssetId, u64, u64> for TestOnCarbonTransfer {
+	fn on_transfer(asset: AssetId, from: Option<&u64>, to: Option<&u64>, amount: u64) {
+		TRANSFERS.with(|t| t.borrow_mut().push((asset, from.cloned(), to.cloned(), amount)));
+	}
+}
+
+pub(crate) fn take_transfers() -> Vec<(AssetId, Option<u64>, Option<u64>, u64)> {
+	TRANSFERS.with(|t| t.take())
+}
+
+pub struct TestDustRemoval;
+impl OnUnbalanced<fungibles::CreditOf<u64, Assets>> for TestDustRemoval {
+	fn on_nonzero_unbalanced(amount: fungibles::CreditOf<u64, Assets>) {
+		DUST.with(|d| d.borrow_mut().push((amount.asset(), amount.peek())));
+	}
+}
+
+pub(crate) fn take_dust() -> Vec<(AssetId, u64)> {
+	DUST.with(|d| d.take())
+}
+
+pub struct TestReserveAccounts;
+impl SortedMembers<u64> for TestReserveAccounts {
+	fn sorted_members() -> Vec<u64> {
+		RESERVE_ACCOUNTS.with(|r| r.borrow().clone())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &u64) {
+		RESERVE_ACCOUNTS.with(|r| {
+			let mut accounts = r.borrow_mut();
+			if let Err(pos) = accounts.binary_search(who) {
+				accounts.insert(pos, *who);
+			}
+		});
+	}
+}
+
+pub struct TestAuditors;
+impl SortedMembers<u64> for TestAuditors {
+	fn sorted_members() -> Vec<u64> {
+		vec![AUDITOR]
+	}
+}
+
+pub struct TestVerifiers;
+impl SortedMembers<u64> for TestVerifiers {
+	fn sorted_members() -> Vec<u64> {
+		VERIFIERS.with(|v| v.borrow().clone())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &u64) {
+		VERIFIERS.with(|v| {
+			let mut verifiers = v.borrow_mut();
+			if let Err(pos) = verifiers.binary_search(who) {
+				verifiers.insert(pos, *who);
+			}
+		});
+	}
+}
+
 pub(crate) fn set_frozen_balance(asset: AssetId, who: u64, amount: u64) {
 	FROZEN.with(|f| f.borrow_mut().insert((asset, who), amount));
 }
@@ -146,7 +391,7 @@ pub(crate) fn take_hooks() -> Vec<Hook> {
 	HOOKS.with(|h| h.take())
 }
 
-pub const PREEXIST_ASSET: [u8; 24] = [99u8; 24];
+pub const PREEXIST_ASSET: AssetId = AssetId::new([99u8; 24]);
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
@@ -172,6 +417,7 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext: sp_io::TestExternalities = storage.into();
 	// Clear thread local vars for https://github.com/paritytech/substrate/issues/10479.
 	ext.execute_with(take_hooks);
+	ext.execute_with(take_dust);
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
@@ -188,6 +434,7 @@ pub(crate) fn test_ext_no_custodian() -> sp_io::TestExternalities {
 	let mut ext: sp_io::TestExternalities = storage.into();
 	// Clear thread local vars for https://github.com/paritytech/substrate/issues/10479.
 	ext.execute_with(take_hooks);
+	ext.execute_with(take_dust);
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
@@ -0,0 +1,255 @@
+// This file is part of Evercity.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Outbox of notable events delivered to webhooks by the off-chain worker.
+//!
+//! Created, minted and retired carbon assets are queued on-chain in `Outbox`. The off-chain
+//! worker POSTs every queued message, SCALE-encoded as `(id, OutboxMessage)`, to the webhook
+//! URL stored in the persistent off-chain storage under `WEBHOOK_KEY` (or under
+//! `WEBHOOK_KEY ++ b"::" ++ asset_id` for a single asset). Failed deliveries are retried with
+//! an exponential backoff. Delivered messages are removed from the queue by the `ack_outbox`
+//! transaction signed with a key of the node registered in `OutboxAuthorities`. A submitted
+//! acknowledgment is recorded under `ACK_KEY` and not sent again for `ACK_TIMEOUT` blocks.
+//!
+//! The worker is opt-in: runtimes that implement `CreateSignedTransaction` for the pallet
+//! calls implement `OffchainConfig` and set `Config::OffchainWorker` to `OutboxWorker`.
+
+use super::*;
+use codec::Encode;
+use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer};
+use sp_runtime::offchain::{
+	http,
+	storage::StorageValueRef,
+	storage_lock::{StorageLock, Time},
+	Duration,
+};
+use sp_std::marker::PhantomData;
+
+/// Key type of the outbox authority key which signs the outbox acknowledgments.
+pub const KEY_TYPE: sp_runtime::KeyTypeId = sp_runtime::KeyTypeId(*b"crbn");
+
+/// Off-chain storage key of the webhook URL.
+pub const WEBHOOK_KEY: &[u8] = b"carbon-assets::webhook";
+/// Off-chain storage key of the next message id to deliver.
+const CURSOR_KEY: &[u8] = b"carbon-assets::outbox-cursor";
+/// Off-chain storage key of the number of failed deliveries in a row and the block of the
+/// next attempt.
+const RETRY_KEY: &[u8] = b"carbon-assets::outbox-retry";
+/// Off-chain storage key of the lock against concurrent workers.
+const LOCK_KEY: &[u8] = b"carbon-assets::outbox-lock";
+/// Off-chain storage key of the last submitted acknowledgment and the block it was sent in.
+const ACK_KEY: &[u8] = b"carbon-assets::outbox-ack";
+
+/// The maximum number of messages delivered in one block.
+const DELIVERIES_PER_BLOCK: u64 = 10;
+/// The maximum backoff exponent: at most `2^8` blocks between attempts.
+const MAX_BACKOFF_EXP: u32 = 8;
+/// Timeout of a webhook request.
+const HTTP_TIMEOUT_MS: u64 = 3_000;
+/// Timeout of the lock against concurrent workers.
+const LOCK_TIMEOUT_MS: u64 = 20_000;
+/// The number of blocks to wait for a submitted acknowledgment before it is sent again.
+const ACK_TIMEOUT: u32 = 10;
+
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Outbox authority key used by the off-chain worker to sign `ack_outbox`.
+	pub struct OutboxAuthId;
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OutboxAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sr25519::Signature;
+		type GenericPublic = sr25519::Public;
+	}
+}
+
+/// Configuration of the off-chain worker delivering the outbox.
+pub trait OffchainConfig<I: 'static = ()>:
+	Config<I> + CreateSignedTransaction<Call<Self, I>>
+{
+	/// Key which the off-chain worker uses to sign `ack_outbox`, e.g. `crypto::OutboxAuthId`.
+	/// Its account should be added to `OutboxAuthorities`.
+	type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+}
+
+/// The off-chain worker delivering the outbox and reporting the custodian SLA.
+pub struct OutboxWorker<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: OffchainConfig<I>, I: 'static> OffchainWorker<T::BlockNumber> for OutboxWorker<T, I> {
+	fn offchain_worker(now: T::BlockNumber) {
+		Pallet::<T, I>::process_outbox(now);
+		Pallet::<T, I>::report_sla(now);
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Queue a message about asset `id` in the `Outbox`, dropping the oldest message if the
+	/// queue is full. Does nothing if `MaxOutbox` is zero.
+	pub(super) fn note_outbox(
+		kind: OutboxKind,
+		id: AssetId,
+		account: &T::AccountId,
+		amount: T::Balance,
+	) {
+		let max = T::MaxOutbox::get() as u64;
+		if max == 0 {
+			return
+		}
+		let head = OutboxHead::<T, I>::get();
+		let tail = OutboxTail::<T, I>::get();
+		if tail.saturating_sub(head) >= max {
+			Outbox::<T, I>::remove(head);
+			OutboxHead::<T, I>::put(head.saturating_add(1));
+		}
+		let message = OutboxMessage {
+			kind,
+			asset_id: id,
+			account: account.clone(),
+			amount,
+			block_number: frame_system::Pallet::<T>::block_number(),
+		};
+		Outbox::<T, I>::insert(tail, message);
+		OutboxTail::<T, I>::put(tail.saturating_add(1));
+	}
+
+	/// Remove the messages up to `up_to` from the `Outbox`.
+	///
+	/// Returns the number of removed messages.
+	pub(super) fn do_ack_outbox(up_to: u64) -> Result<u32, DispatchError> {
+		let head = OutboxHead::<T, I>::get();
+		let tail = OutboxTail::<T, I>::get();
+		ensure!(up_to < tail, Error::<T, I>::BadOutboxId);
+
+		let mut removed = 0u32;
+		for id in head..=up_to {
+			Outbox::<T, I>::remove(id);
+			removed.saturating_inc();
+		}
+		OutboxHead::<T, I>::put(head.max(up_to.saturating_add(1)));
+		Self::deposit_event(Event::OutboxAcknowledged { up_to });
+		Ok(removed)
+	}
+}
+
+impl<T: OffchainConfig<I>, I: 'static> Pallet<T, I> {
+	/// Deliver the queued messages to the webhooks and acknowledge the delivered ones.
+	pub(super) fn process_outbox(now: T::BlockNumber) {
+		let url = match StorageValueRef::persistent(WEBHOOK_KEY).get::<Vec<u8>>() {
+			Ok(Some(url)) => url,
+			_ => return,
+		};
+
+		let mut lock = StorageLock::<Time>::with_deadline(
+			LOCK_KEY,
+			Duration::from_millis(LOCK_TIMEOUT_MS),
+		);
+		let _guard = match lock.try_lock() {
+			Ok(guard) => guard,
+			Err(_) => return,
+		};
+
+		let mut retry = StorageValueRef::persistent(RETRY_KEY);
+		let (failures, next_attempt) =
+			retry.get::<(u32, T::BlockNumber)>().ok().flatten().unwrap_or_default();
+		if now < next_attempt {
+			return
+		}
+
+		let cursor_ref = StorageValueRef::persistent(CURSOR_KEY);
+		let head = OutboxHead::<T, I>::get();
+		let cursor = cursor_ref.get::<u64>().ok().flatten().unwrap_or_default().max(head);
+		let end = OutboxTail::<T, I>::get().min(cursor.saturating_add(DELIVERIES_PER_BLOCK));
+
+		let mut next = cursor;
+		for id in cursor..end {
+			if let Some(message) = Outbox::<T, I>::get(id) {
+				let mut asset_key = WEBHOOK_KEY.to_vec();
+				asset_key.extend_from_slice(b"::");
+				asset_key.extend_from_slice(&message.asset_id);
+				let asset_url = StorageValueRef::persistent(&asset_key)
+					.get::<Vec<u8>>()
+					.ok()
+					.flatten()
+					.unwrap_or_else(|| url.clone());
+
+				if Self::post_message(&asset_url, id, &message).is_err() {
+					let failures = failures.saturating_add(1);
+					let backoff = 2u32.saturating_pow(failures.min(MAX_BACKOFF_EXP));
+					retry.set(&(failures, now.saturating_add(backoff.into())));
+					break
+				}
+			}
+			next = id.saturating_add(1);
+		}
+
+		if next > cursor {
+			cursor_ref.set(&next);
+			if next == end {
+				retry.clear();
+			}
+		}
+		// Acknowledge again if the previous acknowledgment was not included.
+		if next > head {
+			Self::send_ack(now, next.saturating_sub(1));
+		}
+	}
+
+	/// POST the SCALE-encoded `(id, message)` to the webhook `url`.
+	fn post_message(
+		url: &[u8],
+		id: u64,
+		message: &OutboxMessage<T::AccountId, T::Balance, T::BlockNumber>,
+	) -> Result<(), http::Error> {
+		let url = sp_std::str::from_utf8(url).map_err(|_| http::Error::Unknown)?;
+		let deadline =
+			sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+		let body = (id, message).encode();
+		let pending = http::Request::post(url, vec![body])
+			.add_header("Content-Type", "application/octet-stream")
+			.deadline(deadline)
+			.send()
+			.map_err(|_| http::Error::IoError)?;
+		let response =
+			pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+		if !(200..300).contains(&response.code) {
+			return Err(http::Error::Unknown)
+		}
+		Ok(())
+	}
+
+	/// Submit the `ack_outbox` transaction signed with any outbox authority key of the node,
+	/// unless an acknowledgment up to `up_to` is already in flight.
+	fn send_ack(now: T::BlockNumber, up_to: u64) {
+		let pending = StorageValueRef::persistent(ACK_KEY);
+		if let Ok(Some((sent, at))) = pending.get::<(u64, T::BlockNumber)>() {
+			if sent >= up_to && now < at.saturating_add(ACK_TIMEOUT.into()) {
+				return
+			}
+		}
+		let signer = Signer::<T, T::AuthorityId>::any_account();
+		// A failed acknowledgment is sent again by the next worker run.
+		if let Some((_, Ok(()))) =
+			signer.send_signed_transaction(|_account| Call::ack_outbox { up_to })
+		{
+			pending.set(&(up_to, now));
+		}
+	}
+}
@@ -0,0 +1,151 @@
+// This file is part of Evercity.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Primitive types of carbon assets shared with off-chain tools.
+//!
+//! [`CarbonAssetId`] has one canonical text form, so explorers, registries and scripts render
+//! and parse asset ids the same way: `0x` followed by the 48 lowercase hex digits of the id.
+//! The shorter base58 form (bitcoin alphabet) is accepted as well.
+
+use crate::cid::{decode_base16, decode_base58, encode_base58};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_std::{fmt, prelude::*, str::FromStr};
+
+/// Identifier of a carbon asset: 16 random bytes followed by the big-endian creation nonce.
+///
+/// It is SCALE-encoded as the bare 24 bytes, so storage, calls and events decode as before
+/// the newtype.
+#[derive(
+	Copy, Clone, Default, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, Hash, MaxEncodedLen, TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(frame_support::Serialize, frame_support::Deserialize))]
+#[cfg_attr(feature = "std", serde(crate = "frame_support::serde", transparent))]
+pub struct CarbonAssetId([u8; 24]);
+
+/// Error of parsing a [`CarbonAssetId`] from text.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ParseAssetIdError {
+	/// The text is not valid hex or base58.
+	InvalidCharacter,
+	/// The text does not encode exactly 24 bytes.
+	InvalidLength,
+}
+
+impl CarbonAssetId {
+	/// The length of an asset id in bytes.
+	pub const LEN: usize = 24;
+
+	/// The asset id with the given bytes.
+	pub const fn new(bytes: [u8; 24]) -> Self {
+		Self(bytes)
+	}
+
+	/// The bytes of the asset id.
+	pub fn as_bytes(&self) -> &[u8; 24] {
+		&self.0
+	}
+
+	/// The nonce of `LastNonce` the asset id was generated with by `create`.
+	pub fn nonce(&self) -> u64 {
+		let mut nonce = [0u8; 8];
+		nonce.copy_from_slice(&self.0[16..]);
+		u64::from_be_bytes(nonce)
+	}
+
+	/// Parse the canonical hex form, with or without the `0x` prefix, e.g. as printed by
+	/// `Display`.
+	pub fn from_hex(s: &str) -> Result<Self, ParseAssetIdError> {
+		let digits: Vec<u8> =
+			s.strip_prefix("0x").unwrap_or(s).bytes().map(|c| c.to_ascii_lowercase()).collect();
+		let bytes = decode_base16(&digits).ok_or(ParseAssetIdError::InvalidCharacter)?;
+		Self::try_from(&bytes[..]).map_err(|_| ParseAssetIdError::InvalidLength)
+	}
+
+	/// Parse the base58 form, e.g. as printed by `base58`.
+	pub fn from_base58(s: &str) -> Result<Self, ParseAssetIdError> {
+		let bytes = decode_base58(s.as_bytes()).ok_or(ParseAssetIdError::InvalidCharacter)?;
+		Self::try_from(&bytes[..]).map_err(|_| ParseAssetIdError::InvalidLength)
+	}
+
+	/// The base58 form of the asset id, at most 33 characters.
+	pub fn base58(&self) -> Base58 {
+		Base58(*self)
+	}
+}
+
+impl From<[u8; 24]> for CarbonAssetId {
+	fn from(bytes: [u8; 24]) -> Self {
+		Self(bytes)
+	}
+}
+
+impl From<CarbonAssetId> for [u8; 24] {
+	fn from(id: CarbonAssetId) -> Self {
+		id.0
+	}
+}
+
+impl TryFrom<&[u8]> for CarbonAssetId {
+	type Error = ParseAssetIdError;
+
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		bytes.try_into().map(Self).map_err(|_| ParseAssetIdError::InvalidLength)
+	}
+}
+
+impl AsRef<[u8]> for CarbonAssetId {
+	fn as_ref(&self) -> &[u8] {
+		&self.0
+	}
+}
+
+/// Parses the hex form if the text starts with `0x` or has 48 characters, the base58 form
+/// otherwise.
+impl FromStr for CarbonAssetId {
+	type Err = ParseAssetIdError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s.starts_with("0x") || s.len() == 2 * Self::LEN {
+			Self::from_hex(s)
+		} else {
+			Self::from_base58(s)
+		}
+	}
+}
+
+/// The canonical hex form, e.g. `0x0000…0001`.
+impl fmt::Display for CarbonAssetId {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("0x")?;
+		self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+	}
+}
+
+impl fmt::Debug for CarbonAssetId {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+
+/// The base58 form of a [`CarbonAssetId`], see [`CarbonAssetId::base58`].
+pub struct Base58(CarbonAssetId);
+
+impl fmt::Display for Base58 {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		encode_base58(self.0.as_bytes()).iter().try_for_each(|c| write!(f, "{}", *c as char))
+	}
+}
@@ -0,0 +1,136 @@
+// This file is part of Evercity.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the carbon assets pallet.
+
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use crate::{
+	AccountDump, AmountDisplay, AssetDetails, AssetId, AssetPosition, CoBenefitBadges,
+	DestroyPreview, InterfaceVersion, ViewError, ViewRequest,
+};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Query the state of carbon assets without decoding the pallet storage. The data of
+	/// single accounts is served by `CarbonAssetsViewerApi` only.
+	pub trait CarbonAssetsApi<AccountId, Balance, DepositBalance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		DepositBalance: Codec,
+	{
+		/// Get the total supply of the asset `id`, if the asset exists.
+		fn total_supply(id: AssetId) -> Option<Balance>;
+
+		/// Get the number of accounts holding the asset `id`, if the asset exists.
+		fn asset_accounts(id: AssetId) -> Option<u32>;
+
+		/// Get the number of accounts holding the asset `id` with a self-sufficient reference, if
+		/// the asset exists.
+		fn asset_sufficients(id: AssetId) -> Option<u32>;
+
+		/// Get the number of transfer-approvals of the asset `id`, if the asset exists.
+		fn asset_approvals(id: AssetId) -> Option<u32>;
+
+		/// Get the version of the pallet Call/Event surface and its supported capabilities.
+		fn interface_version() -> InterfaceVersion;
+
+		/// Get the assets referencing the carbon accounting `methodology`.
+		fn methodology_assets(methodology: u32) -> Vec<AssetId>;
+
+		/// Get the witness `destroy` of the asset `id` would accept at this block, with the
+		/// expected weight and weight fee of the call, if the asset exists.
+		fn destroy_witness(id: AssetId) -> Option<DestroyPreview<Balance>>;
+
+		/// Get the co-benefit badges of the asset `id` attested by a verifier, if any.
+		fn asset_badges(id: AssetId) -> Option<CoBenefitBadges<AccountId, Vec<u8>>>;
+
+		/// Get the assets having all the co-benefit `badges`, e.g.
+		/// `sdg_badge(15) | BIODIVERSITY_BADGE`.
+		fn assets_with_badges(badges: u32) -> Vec<AssetId>;
+
+		/// Get the assets burned by the account `who` with the amounts stored in its burn
+		/// certificates.
+		fn burn_certificates(who: AccountId) -> Vec<(AssetId, Balance)>;
+
+		/// Get the details of the asset `id`, if the asset exists.
+		fn asset_details(id: AssetId) -> Option<AssetDetails<Balance, AccountId, DepositBalance>>;
+
+		/// Get the ids of all the existing assets.
+		fn asset_ids() -> Vec<AssetId>;
+
+		/// Get the ids of the assets the account `who` has an account in.
+		fn account_asset_ids(who: AccountId) -> Vec<AssetId>;
+
+		/// Get the assets whose symbol equals `symbol` ignoring ASCII case, e.g. to warn about a
+		/// duplicated ticker before creating an asset.
+		fn symbol_exists(symbol: Vec<u8>) -> Vec<AssetId>;
+
+		/// Get up to `limit` live approvals of the account `owner` for the asset `id` as
+		/// delegates and approved amounts, starting after the delegate `cursor`.
+		fn approvals_of(
+			owner: AccountId,
+			id: AssetId,
+			cursor: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, Balance)>;
+
+		/// Get `amount` of the asset `id` split into whole units and the rest at the decimals of
+		/// the asset, if the asset exists.
+		fn display_amount(id: AssetId, amount: Balance) -> Option<AmountDisplay<Balance>>;
+	}
+
+	/// Privileged reads for the viewer keys in `ViewerKeys`, e.g. for consortium deployments
+	/// which do not expose holder data publicly. Every request is signed with a viewer key, see
+	/// `ViewRequest`.
+	pub trait CarbonAssetsViewerApi<AccountId, Balance, DepositBalance, BlockNumber, Signature>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		DepositBalance: Codec,
+		BlockNumber: Codec,
+		Signature: Codec,
+	{
+		/// Get all the data stored about the account of the `ViewQuery::AccountDump` request.
+		fn account_dump(
+			request: ViewRequest<AccountId, BlockNumber>,
+			signature: Signature,
+		) -> Result<AccountDump<AccountId, Balance, DepositBalance>, ViewError>;
+
+		/// Get the holders of the asset of the `ViewQuery::AssetHolders` request with their
+		/// balances.
+		fn asset_holders(
+			request: ViewRequest<AccountId, BlockNumber>,
+			signature: Signature,
+		) -> Result<Vec<(AccountId, Balance)>, ViewError>;
+
+		/// Get the balances of all the assets held by the account of the
+		/// `ViewQuery::AccountBalances` request.
+		fn account_balances(
+			request: ViewRequest<AccountId, BlockNumber>,
+			signature: Signature,
+		) -> Result<Vec<(AssetId, Balance)>, ViewError>;
+
+		/// Get the position of the account of the `ViewQuery::PositionStatement` request in
+		/// every asset it holds, with the metadata of the asset, e.g. for portfolio views.
+		fn position_statement(
+			request: ViewRequest<AccountId, BlockNumber>,
+			signature: Signature,
+		) -> Result<Vec<AssetPosition<Balance>>, ViewError>;
+	}
+}
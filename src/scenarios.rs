@@ -0,0 +1,195 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Executable examples of the tokenization flow described in the README.
+//!
+//! Every scenario walks a carbon asset through create → document → mint → trade → retire from
+//! the point of view of one persona: the project owner, the Custodian or the investor. Each
+//! step is a function asserting the events a client of the persona watches for, so the steps
+//! read as the canonical sequence of calls and fail as soon as the flow changes.
+
+use super::*;
+use crate::{mock::*, tests::CID_V0};
+use frame_support::assert_ok;
+use sp_core::H256;
+
+/// The project owner (Issuer) of the tokenized carbon credits.
+const PROJECT_OWNER: u64 = 4;
+/// The investor buying and retiring the carbon credits.
+const INVESTOR: u64 = 5;
+
+fn assert_event(event: crate::Event<Test>) {
+	System::assert_has_event(RuntimeEvent::Assets(event));
+}
+
+/// Project owner: create the carbon asset of the project. The `AssetId` is generated.
+fn owner_creates_asset() -> AssetId {
+	Balances::make_free_balance_be(&PROJECT_OWNER, 1000);
+	assert_ok!(Assets::create(
+		RuntimeOrigin::signed(PROJECT_OWNER),
+		b"Mangrove Restoration 2022".to_vec(),
+		b"MNGR22".to_vec()
+	));
+	let id = Assets::get_current_asset_id(&PROJECT_OWNER).unwrap();
+	assert_event(crate::Event::Created { asset_id: id, creator: PROJECT_OWNER });
+	id
+}
+
+/// Project owner: document the retirement in the external registry on IPFS.
+fn owner_documents_project(id: AssetId) {
+	assert_ok!(Assets::set_project_data(
+		RuntimeOrigin::signed(PROJECT_OWNER),
+		id,
+		b"https://registry.example/projects/42".to_vec(),
+		CID_V0.as_bytes().to_vec(),
+		None
+	));
+	assert_event(crate::Event::MetadataUpdated {
+		asset_id: id,
+		url: b"https://registry.example/projects/42".to_vec(),
+		data_ipfs: CID_V0.as_bytes().to_vec(),
+	});
+}
+
+/// Custodian: verify the documented project and mint the carbon credits to its owner.
+fn custodian_mints(id: AssetId, amount: u64) {
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, amount, None));
+	assert_event(crate::Event::Issued { asset_id: id, owner: PROJECT_OWNER, total_supply: amount });
+}
+
+/// Project owner: sell carbon credits to the investor.
+fn owner_sells(id: AssetId, amount: u64) {
+	Balances::make_free_balance_be(&INVESTOR, 1000);
+	assert_ok!(Assets::transfer(RuntimeOrigin::signed(PROJECT_OWNER), id, INVESTOR, amount));
+	assert_event(crate::Event::Transferred { asset_id: id, from: PROJECT_OWNER, to: INVESTOR, amount });
+}
+
+/// Investor: retire carbon credits on behalf of a beneficiary and return the retirement anchor
+/// to embed in the off-chain certificate.
+fn investor_retires(id: AssetId, amount: u64) -> H256 {
+	let certificate_id = NextCertificateId::<Test>::get();
+	assert_ok!(Assets::self_burn(
+		RuntimeOrigin::signed(INVESTOR),
+		id,
+		amount,
+		Some(b"Beneficiary: ACME Corp".to_vec())
+	));
+	assert_event(crate::Event::Burned { asset_id: id, owner: INVESTOR, balance: amount });
+	assert_event(crate::Event::CarbonCreditsBurned {
+		account: INVESTOR,
+		asset_id: id,
+		amount,
+		kind: BurnKind::SelfRetirement,
+		initiator: INVESTOR,
+		alias: None,
+		certificate_id,
+	});
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::Assets(crate::Event::RetirementAnchored {
+				account,
+				asset_id,
+				amount: anchored,
+				anchor,
+			}) if account == INVESTOR && asset_id == id && anchored == amount => Some(anchor),
+			_ => None,
+		})
+		.expect("the retirement is anchored")
+}
+
+/// Custodian: record the registry reference of the retirement in the external registry.
+fn custodian_confirms_retirement(id: AssetId, amount: u64) {
+	assert_ok!(Assets::confirm_retirement_registration(
+		RuntimeOrigin::signed(CUSTODIAN),
+		INVESTOR,
+		id,
+		amount,
+		b"VCS-RET-0001".to_vec()
+	));
+	assert_event(crate::Event::RetirementRegistrationConfirmed {
+		account: INVESTOR,
+		asset_id: id,
+		amount,
+		external_ref: b"VCS-RET-0001".to_vec(),
+	});
+}
+
+#[test]
+fn project_owner_tokenizes_and_sells_carbon_credits() {
+	new_test_ext().execute_with(|| {
+		let id = owner_creates_asset();
+		assert_eq!(Asset::<Test>::get(id).unwrap().owner, PROJECT_OWNER);
+
+		owner_documents_project(id);
+		custodian_mints(id, 1000);
+		assert_eq!(Assets::balance(id, PROJECT_OWNER), 1000);
+
+		owner_sells(id, 300);
+		assert_eq!(Assets::balance(id, PROJECT_OWNER), 700);
+
+		// the owner sees who retired the credits of the project
+		investor_retires(id, 100);
+		assert_eq!(Assets::asset_burn_certificates(id), vec![(INVESTOR, 100)]);
+		assert_eq!(TotalRetired::<Test>::get(id), 100);
+	});
+}
+
+#[test]
+fn custodian_verifies_mints_and_confirms_retirements() {
+	new_test_ext().execute_with(|| {
+		let id = owner_creates_asset();
+		owner_documents_project(id);
+		assert_eq!(Metadata::<Test>::get(id).data_ipfs.map(|cid| cid.to_text()), Some(CID_V0.as_bytes().to_vec()));
+
+		custodian_mints(id, 1000);
+		assert_eq!(Assets::total_supply(id), 1000);
+
+		owner_sells(id, 300);
+		investor_retires(id, 200);
+		assert_eq!(Assets::unconfirmed_retirements(), 200);
+
+		custodian_confirms_retirement(id, 200);
+		assert_eq!(RetirementConfirmation::<Test>::get(INVESTOR, id).unwrap().amount, 200);
+		assert_eq!(Assets::unconfirmed_retirements(), 0);
+	});
+}
+
+#[test]
+fn investor_buys_and_retires_carbon_credits() {
+	new_test_ext().execute_with(|| {
+		let id = owner_creates_asset();
+		owner_documents_project(id);
+		custodian_mints(id, 1000);
+
+		owner_sells(id, 300);
+		assert_eq!(Assets::balance(id, INVESTOR), 300);
+
+		let anchor = investor_retires(id, 120);
+		assert_eq!(Assets::balance(id, INVESTOR), 180);
+		assert_eq!(Assets::total_supply(id), 880);
+		assert_eq!(BurnCertificate::<Test>::get(INVESTOR, id).map(|c| c.amount), Some(120));
+
+		// the anchor printed on the off-chain certificate resolves to the retirement
+		let retirement = Assets::retirement_anchor(anchor).unwrap();
+		assert_eq!(retirement.account, INVESTOR);
+		assert_eq!(retirement.asset_id, id);
+		assert_eq!(retirement.amount, 120);
+		assert_eq!(retirement.note.map(|note| note.to_vec()), Some(b"Beneficiary: ACME Corp".to_vec()));
+	});
+}
@@ -0,0 +1,186 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Custodian SLA heartbeat reported by the off-chain worker.
+//!
+//! Retired carbon credits wait in `UnconfirmedRetirements` until the custodian confirms them
+//! in the external registry with `confirm_retirement_registration`. Every `SlaReportInterval`
+//! blocks the off-chain worker submits the unsigned `report_custodian_sla` transaction with
+//! the amount still awaiting confirmation and the number of blocks it has waited since the
+//! last custodian action, as well as the number of mint requests awaiting `approve_mint` or
+//! `reject_mint` and the number of blocks they have waited since the first request or the
+//! last decision. The transaction pool only accepts a report matching the chain
+//! state at the reported block, so the `CustodianSla` storage can be trusted to monitor the
+//! responsiveness of the registry custodian without trusting the reporting node.
+
+use super::*;
+use frame_system::offchain::{SendTransactionTypes, SubmitTransaction};
+use sp_runtime::{
+	traits::UniqueSaturatedInto,
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError,
+		ValidTransaction,
+	},
+};
+
+/// Priority of the unsigned SLA reports in the transaction pool.
+const SLA_REPORT_PRIORITY: TransactionPriority = TransactionPriority::max_value() / 2;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Add `amount` retired carbon credits to the ones awaiting confirmation by the custodian.
+	pub(super) fn note_unconfirmed_retirement(amount: T::Balance) {
+		if amount.is_zero() {
+			return
+		}
+		UnconfirmedRetirements::<T, I>::mutate(|pending| {
+			if pending.is_zero() {
+				UnconfirmedSince::<T, I>::put(frame_system::Pallet::<T>::block_number());
+			}
+			pending.saturating_accrue(amount);
+		});
+	}
+
+	/// Note the custodian confirmed `amount` retired carbon credits in the external registry.
+	pub(super) fn note_custodian_confirmation(amount: T::Balance) {
+		CustodianLastAction::<T, I>::put(frame_system::Pallet::<T>::block_number());
+		UnconfirmedRetirements::<T, I>::mutate(|pending| {
+			pending.saturating_reduce(amount);
+			if pending.is_zero() {
+				UnconfirmedSince::<T, I>::kill();
+			}
+		});
+	}
+
+	/// Add a mint request to the ones awaiting the decision of the custodians.
+	pub(super) fn note_mint_request() {
+		PendingMintRequestCount::<T, I>::mutate(|pending| {
+			if pending.is_zero() {
+				MintRequestsSince::<T, I>::put(frame_system::Pallet::<T>::block_number());
+			}
+			pending.saturating_inc();
+		});
+	}
+
+	/// Note a mint request was approved, rejected or dropped with its asset.
+	pub(super) fn note_mint_request_settled() {
+		PendingMintRequestCount::<T, I>::mutate(|pending| {
+			pending.saturating_dec();
+			if pending.is_zero() {
+				MintRequestsSince::<T, I>::kill();
+			} else {
+				MintRequestsSince::<T, I>::put(frame_system::Pallet::<T>::block_number());
+			}
+		});
+	}
+
+	/// The SLA report of the custodian at block `now`, computed from the chain state.
+	pub fn custodian_sla_report(
+		now: T::BlockNumber,
+	) -> CustodianSlaReport<T::BlockNumber, T::Balance> {
+		let last_action = CustodianLastAction::<T, I>::get();
+		let pending_amount = UnconfirmedRetirements::<T, I>::get();
+		// The pending credits wait since they were retired or since the last custodian action,
+		// whichever is later.
+		let waiting = match UnconfirmedSince::<T, I>::get() {
+			Some(since) if !pending_amount.is_zero() =>
+				now.saturating_sub(since.max(last_action.unwrap_or_default())),
+			_ => Zero::zero(),
+		};
+		let pending_mint_requests = PendingMintRequestCount::<T, I>::get();
+		let mint_requests_waiting = MintRequestsSince::<T, I>::get()
+			.map_or_else(Zero::zero, |since| now.saturating_sub(since));
+		CustodianSlaReport {
+			reported_at: now,
+			last_action,
+			waiting,
+			pending_amount,
+			pending_mint_requests,
+			mint_requests_waiting,
+		}
+	}
+}
+
+impl<T: Config<I> + SendTransactionTypes<Call<T, I>>, I: 'static> Pallet<T, I> {
+	/// Submit the SLA report of the custodian every `SlaReportInterval` blocks.
+	pub(super) fn report_sla(now: T::BlockNumber) {
+		let interval = T::SlaReportInterval::get();
+		if interval.is_zero() || !(now % interval).is_zero() {
+			return
+		}
+		let report = Self::custodian_sla_report(now);
+		// A failed report is replaced by the one of the next interval.
+		let _ = SubmitTransaction::<T, Call<T, I>>::submit_unsigned_transaction(
+			Call::report_custodian_sla { report }.into(),
+		);
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Store the SLA report of the custodian.
+	pub(super) fn do_report_custodian_sla(
+		report: CustodianSlaReport<T::BlockNumber, T::Balance>,
+	) -> DispatchResult {
+		Self::check_sla_report(&report).map_err(|_| Error::<T, I>::BadSlaReport)?;
+		Self::deposit_event(Event::CustodianSlaReported {
+			reported_at: report.reported_at,
+			waiting: report.waiting,
+			pending_amount: report.pending_amount,
+		});
+		CustodianSla::<T, I>::put(report);
+		Ok(())
+	}
+
+	/// Check the report is due and newer than the stored one.
+	pub(super) fn check_sla_report(
+		report: &CustodianSlaReport<T::BlockNumber, T::Balance>,
+	) -> Result<(), TransactionValidityError> {
+		let interval = T::SlaReportInterval::get();
+		ensure!(!interval.is_zero(), InvalidTransaction::Call);
+		ensure!((report.reported_at % interval).is_zero(), InvalidTransaction::Call);
+		ensure!(
+			report.reported_at < frame_system::Pallet::<T>::block_number(),
+			InvalidTransaction::Future
+		);
+		if let Some(last) = CustodianSla::<T, I>::get() {
+			ensure!(report.reported_at > last.reported_at, InvalidTransaction::Stale);
+		}
+		Ok(())
+	}
+
+	/// Validate an unsigned SLA report in the transaction pool.
+	///
+	/// The pool validates transactions for the next block, so the report must have been made
+	/// at the best block and match its state.
+	pub(super) fn validate_sla_report(
+		report: &CustodianSlaReport<T::BlockNumber, T::Balance>,
+	) -> TransactionValidity {
+		Self::check_sla_report(report)?;
+		let next = frame_system::Pallet::<T>::block_number();
+		ensure!(report.reported_at.saturating_add(One::one()) == next, InvalidTransaction::Stale);
+		ensure!(
+			*report == Self::custodian_sla_report(report.reported_at),
+			InvalidTransaction::BadProof
+		);
+
+		ValidTransaction::with_tag_prefix("CarbonAssetsSla")
+			.priority(SLA_REPORT_PRIORITY)
+			.and_provides(report.reported_at)
+			.longevity(T::SlaReportInterval::get().unique_saturated_into())
+			.propagate(true)
+			.build()
+	}
+}
@@ -0,0 +1,206 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2017-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed accessors for the storage items whose changes must be accompanied by counter updates
+//! in `AssetDetails`.
+//!
+//! New code must not insert or remove asset-accounts and approvals directly, but go through
+//! these functions, so the `accounts`, `sufficients` and `approvals` counters (and the consumer,
+//! sufficient and reserve references they stand for) cannot drift from the stored entries.
+
+use super::*;
+use crate::functions::DeadConsequence::{self, *};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Account for a new asset-account of `who` in the counters of `d`, taking a consumer or
+	/// sufficient reference unless a deposit is held for it.
+	///
+	/// Returns the reason of existence to store in the new `AssetAccount`.
+	pub(super) fn new_account(
+		who: &T::AccountId,
+		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		maybe_deposit: Option<DepositBalanceOf<T, I>>,
+	) -> Result<ExistenceReason<DepositBalanceOf<T, I>>, DispatchError> {
+		let accounts = d.accounts.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+		let reason = if let Some(deposit) = maybe_deposit {
+			ExistenceReason::DepositHeld(deposit)
+		} else if d.is_sufficient {
+			frame_system::Pallet::<T>::inc_sufficients(who);
+			d.sufficients += 1;
+			ExistenceReason::Sufficient
+		} else {
+			frame_system::Pallet::<T>::inc_consumers(who).map_err(|_| Error::<T, I>::NoProvider)?;
+			ExistenceReason::Consumer
+		};
+		d.accounts = accounts;
+		Ok(reason)
+	}
+
+	/// Remove the asset-account of `who` with the given existence `reason` from the counters of
+	/// `d`, releasing its consumer or sufficient reference.
+	///
+	/// Accounts holding a deposit are kept unless `force` is set.
+	pub(super) fn dead_account(
+		who: &T::AccountId,
+		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		reason: &ExistenceReason<DepositBalanceOf<T, I>>,
+		force: bool,
+	) -> DeadConsequence {
+		match *reason {
+			ExistenceReason::Consumer => frame_system::Pallet::<T>::dec_consumers(who),
+			ExistenceReason::Sufficient => {
+				d.sufficients = d.sufficients.saturating_sub(1);
+				frame_system::Pallet::<T>::dec_sufficients(who);
+			},
+			ExistenceReason::DepositRefunded => {},
+			ExistenceReason::DepositHeld(_) if !force => return Keep,
+			ExistenceReason::DepositHeld(_) => {},
+		}
+		d.accounts = d.accounts.saturating_sub(1);
+		Remove
+	}
+
+	/// Clean up after the account of `who` for asset `id` was removed, dropping its holds and
+	/// freezes, and call the `Freezer::died` hook. Starts the `ApprovalGcGracePeriod` of the
+	/// approvals of `who`, if any.
+	pub(super) fn account_died(id: AssetId, who: &T::AccountId) {
+		if Approvals::<T, I>::iter_key_prefix((id, who)).next().is_some() {
+			OrphanedApprovalsSince::<T, I>::insert(
+				id,
+				who,
+				frame_system::Pallet::<T>::block_number(),
+			);
+		}
+		AccountAssets::<T, I>::remove(who, id);
+		AccountFreezeReason::<T, I>::remove(id, who);
+		Holds::<T, I>::remove(id, who);
+		Freezes::<T, I>::remove(id, who);
+		T::Freezer::died(id, who);
+	}
+
+	/// Store the `approval` of `owner` to `delegate` for asset `id`, counting it in `d` if it is
+	/// new. The approval deposit must already be reserved.
+	pub(super) fn put_approval(
+		id: AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		approval: Approval<T::Balance, DepositBalanceOf<T, I>, T::BlockNumber>,
+		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+	) {
+		Approvals::<T, I>::mutate((id, owner, delegate), |maybe_approval| {
+			if maybe_approval.is_none() {
+				d.approvals.saturating_inc();
+			}
+			*maybe_approval = Some(approval);
+		});
+	}
+
+	/// Remove the approval of `owner` to `delegate` for asset `id`, unreserving its deposit and
+	/// uncounting it in `d`.
+	///
+	/// Returns the removed approval, if any.
+	pub(super) fn take_approval(
+		id: AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+	) -> Option<Approval<T::Balance, DepositBalanceOf<T, I>, T::BlockNumber>> {
+		let approval = Approvals::<T, I>::take((id, owner, delegate))?;
+		T::Deposits::release(owner, approval.deposit);
+		Self::note_deposit(Some(id), DepositPurpose::Approval, approval.deposit, Zero::zero());
+		d.approvals.saturating_dec();
+		Some(approval)
+	}
+
+	/// Update the `VintageSupply` of the vintage year of asset `id`, if it has one.
+	pub(super) fn note_vintage(id: AssetId, f: impl FnOnce(&mut VintageTotals<T::Balance>)) {
+		if let Some(vintage) = AssetVintage::<T, I>::get(id) {
+			VintageSupply::<T, I>::mutate(vintage, f);
+		}
+	}
+
+	/// Move asset `id` from the `SymbolAssets` of the `old` symbol to the ones of `new`. Must
+	/// accompany every change of the asset metadata.
+	pub(super) fn note_symbol(id: AssetId, old: Option<&[u8]>, new: Option<&[u8]>) {
+		if old == new {
+			return
+		}
+		if let Some(old) = old.and_then(Self::symbol_key) {
+			SymbolAssets::<T, I>::remove(old, id);
+		}
+		if let Some(new) = new.and_then(Self::symbol_key) {
+			SymbolAssets::<T, I>::insert(new, id, ());
+		}
+	}
+
+	/// The `SymbolAssets` key of `symbol`: the symbol in ASCII upper case, so tickers differing
+	/// only in case are reported as duplicates.
+	pub(super) fn symbol_key(symbol: &[u8]) -> Option<BoundedVec<u8, T::StringLimit>> {
+		symbol.to_ascii_uppercase().try_into().ok()
+	}
+
+	/// Move asset `id` from the `OwnedAssets` of `old` to the ones of `new`, updating their
+	/// `OwnedAssetCount`. Must accompany every change of the asset owner, including the creation
+	/// (`old` is `None`) and the destruction (`new` is `None`) of the asset.
+	pub(super) fn note_owner(id: AssetId, old: Option<&T::AccountId>, new: Option<&T::AccountId>) {
+		if old == new {
+			return
+		}
+		if let Some(old) = old {
+			if OwnedAssets::<T, I>::take(old, id).is_some() {
+				OwnedAssetCount::<T, I>::mutate_exists(old, |maybe_count| {
+					let count = maybe_count.unwrap_or_default().saturating_sub(1);
+					*maybe_count = if count == 0 { None } else { Some(count) };
+				});
+			}
+		}
+		if let Some(new) = new {
+			if !OwnedAssets::<T, I>::contains_key(new, id) {
+				OwnedAssets::<T, I>::insert(new, id, ());
+				OwnedAssetCount::<T, I>::mutate(new, |count| count.saturating_inc());
+			}
+		}
+	}
+
+	/// Account for the deposit for `purpose` of asset `maybe_id` (or of an account, if `None`)
+	/// changing from `old` to `new` in `TotalDeposits` and `AssetDeposits`. Must accompany
+	/// every reserve and unreserve of a deposit.
+	pub(super) fn note_deposit(
+		maybe_id: Option<AssetId>,
+		purpose: DepositPurpose,
+		old: DepositBalanceOf<T, I>,
+		new: DepositBalanceOf<T, I>,
+	) {
+		if old == new {
+			return
+		}
+		let apply = |total: DepositBalanceOf<T, I>| {
+			if new > old {
+				total.saturating_add(new - old)
+			} else {
+				total.saturating_sub(old - new)
+			}
+		};
+		TotalDeposits::<T, I>::mutate(purpose, |total| *total = apply(*total));
+		if let Some(id) = maybe_id {
+			AssetDeposits::<T, I>::mutate_exists(id, purpose, |maybe_total| {
+				let total = apply(maybe_total.unwrap_or_default());
+				*maybe_total = if total.is_zero() { None } else { Some(total) };
+			});
+		}
+	}
+}
@@ -19,21 +19,24 @@
 
 use super::*;
 use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, traits::Currency, error::BadOrigin};
+use codec::{Decode, Encode};
+use frame_support::{assert_noop, assert_ok, traits::{Currency, GenesisBuild}, error::BadOrigin, BoundedVec};
 use pallet_balances::Error as BalancesError;
-use sp_runtime::{traits::ConvertInto, TokenError};
+use sp_runtime::{testing::TestSignature, traits::{BlakeTwo256, ConvertInto, Hash}, TokenError};
 
-pub const ZERO_ID: [u8;24] = [0; 24];
-pub const ONE_ID: [u8;24] = [1; 24];
-pub const TWO_ID: [u8;24] = [2; 24];
+pub const ZERO_ID: AssetId = AssetId::new([0; 24]);
+pub const ONE_ID: AssetId = AssetId::new([1; 24]);
+pub const TWO_ID: AssetId = AssetId::new([2; 24]);
+pub const CID_V0: &str = "QmR65i22uNRpz7gdLpmNSGGJHLxZfSNtEy37yMBxfUnuHx";
+pub const CID_V1: &str = "zdj7WYBL5T6kquRrpzZ5ZizJe6ruZA1Nyo27HSee9adUvMoTt";
 
 #[test]
 fn can_mint_only_to_owner() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 2), 0);
 		assert_eq!(Assets::balance(ZERO_ID, 1), 200);
 	});
@@ -46,13 +49,13 @@ fn minting_too_many_insufficient_assets_fails() {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ONE_ID, 1, false, 1));
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), TWO_ID, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ONE_ID, 100));
-		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), TWO_ID, 100), TokenError::CannotCreate);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ONE_ID, 100, None));
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), TWO_ID, 100, None), TokenError::CannotCreate);
 
 		Balances::make_free_balance_be(&2, 1);
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 100));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), TWO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), TWO_ID, 100, None));
 	});
 }
 
@@ -63,14 +66,14 @@ fn minting_insufficient_asset_with_deposit_should_work_when_consumers_exhausted(
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ONE_ID, 1, false, 1));
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), TWO_ID, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ONE_ID, 100));
-		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), TWO_ID, 100), TokenError::CannotCreate);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ONE_ID, 100, None));
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), TWO_ID, 100, None), TokenError::CannotCreate);
 
 		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), TWO_ID));
 		assert_eq!(Balances::reserved_balance(&1), 10);
 
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), TWO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), TWO_ID, 100, None));
 	});
 }
 
@@ -78,10 +81,10 @@ fn minting_insufficient_asset_with_deposit_should_work_when_consumers_exhausted(
 fn minting_insufficient_assets_with_deposit_without_consumer_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, false, 1));
-		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100), TokenError::CannotCreate);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None), TokenError::CannotCreate);
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), ZERO_ID));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Balances::reserved_balance(&1), 10);
 		assert_eq!(System::consumers(&1), 0);
 	});
@@ -93,7 +96,7 @@ fn refunding_asset_deposit_with_burn_should_work() {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), ZERO_ID));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_ok!(Assets::refund(RuntimeOrigin::signed(1), ZERO_ID, true));
 		assert_eq!(Balances::reserved_balance(&1), 0);
 		assert_eq!(Assets::balance(ONE_ID, 0), 0);
@@ -106,7 +109,7 @@ fn refunding_asset_deposit_with_burn_disallowed_should_fail() {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), ZERO_ID));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_noop!(Assets::refund(RuntimeOrigin::signed(1), ZERO_ID, false), Error::<Test>::WouldBurn);
 	});
 }
@@ -115,10 +118,10 @@ fn refunding_asset_deposit_with_burn_disallowed_should_fail() {
 fn refunding_asset_deposit_without_burn_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, false, 1));
-		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100), TokenError::CannotCreate);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None), TokenError::CannotCreate);
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), ZERO_ID));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		Balances::make_free_balance_be(&2, 100);
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 100));
 		assert_eq!(Assets::balance(ZERO_ID, 2), 100);
@@ -137,7 +140,7 @@ fn refunding_calls_died_hook() {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, false, 1));
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), ZERO_ID));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_ok!(Assets::refund(RuntimeOrigin::signed(1), ZERO_ID, true));
 
 		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().accounts, 0);
@@ -152,7 +155,7 @@ fn approval_lifecycle_works() {
 		assert_noop!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50), Error::<Test>::Unknown);
 		// so we create it :)
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().approvals, 1);
@@ -174,7 +177,7 @@ fn transfer_approved_all_funds() {
 		assert_noop!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50), Error::<Test>::Unknown);
 		// so we create it :)
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().approvals, 1);
@@ -193,7 +196,7 @@ fn transfer_approved_all_funds() {
 fn approval_deposits_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		let e = BalancesError::<Test>::InsufficientBalance;
 		assert_noop!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50), e);
 
@@ -214,7 +217,7 @@ fn approval_deposits_work() {
 fn cannot_transfer_more_than_approved() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 		let e = Error::<Test>::Unapproved;
@@ -226,7 +229,7 @@ fn cannot_transfer_more_than_approved() {
 fn cannot_transfer_more_than_exists() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 101));
 		let e = Error::<Test>::BalanceLow;
@@ -238,7 +241,7 @@ fn cannot_transfer_more_than_exists() {
 fn cancel_approval_works() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().approvals, 1);
@@ -256,7 +259,7 @@ fn cancel_approval_works() {
 fn force_cancel_approval_works() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().approvals, 1);
@@ -298,9 +301,9 @@ fn lifecycle_should_work() {
 		assert!(Metadata::<Test>::contains_key(id));
 
 		Balances::make_free_balance_be(&10, 100);
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 100, None));
 		Balances::make_free_balance_be(&20, 100);
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 100, None));
 		assert_eq!(Account::<Test>::iter_prefix(id).count(), 1);
 
 		let w = Asset::<Test>::get(id).unwrap().destroy_witness();
@@ -320,8 +323,8 @@ fn lifecycle_should_work() {
 		assert_eq!(Balances::reserved_balance(&1), 12);
 		assert!(Metadata::<Test>::contains_key(second_id));
 
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), second_id, 100));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), second_id, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), second_id, 100, None));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), second_id, 100, None));
 		assert_eq!(Account::<Test>::iter_prefix(second_id).count(), 1);
 
 		let w = Asset::<Test>::get(second_id).unwrap().destroy_witness();
@@ -340,7 +343,7 @@ fn destroy_with_bad_witness_should_not_work() {
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
 		let mut w = Asset::<Test>::get(ZERO_ID).unwrap().destroy_witness();
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		// witness too low
 		assert_noop!(Assets::destroy(RuntimeOrigin::signed(1), ZERO_ID, w), Error::<Test>::BadWitness);
 		// witness too high is okay though
@@ -355,7 +358,7 @@ fn destroy_should_refund_approvals() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 3, 50));
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 4, 50));
@@ -376,7 +379,7 @@ fn non_providing_should_not_work() {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 0, false, 1));
 
 		Balances::make_free_balance_be(&0, 100);
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(0), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(0), ZERO_ID, 100, None));
 
 		// Cannot transfer into account 1 since it doesn't (yet) exist.
 		assert_noop!(Assets::transfer(RuntimeOrigin::signed(0), ZERO_ID, 1, 50), TokenError::CannotCreate);
@@ -399,8 +402,8 @@ fn min_balance_should_work() {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 10));
 		
 		// Cannot create a new account with a balance that is below minimum...
-		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 9), TokenError::BelowMinimum);
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 9, None), TokenError::BelowMinimum);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().accounts, 1);
 		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 9), TokenError::BelowMinimum);
 		assert_noop!(
@@ -424,13 +427,13 @@ fn min_balance_should_work() {
 		assert_eq!(take_hooks(), vec![Hook::Died(ZERO_ID, 2)]);
 
 		// Death by `burn`.
-		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), ZERO_ID, 1, 91));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), ZERO_ID, 1, 91, CustodialBurnReason::Invalidation, None));
 		assert!(Assets::maybe_balance(ZERO_ID, 1).is_none());
 		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().accounts, 0);
 		assert_eq!(take_hooks(), vec![Hook::Died(ZERO_ID, 1)]);
 
 		// Death by `transfer_approved`.
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 100));
 		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), ZERO_ID, 1, 3, 91));
@@ -442,7 +445,7 @@ fn min_balance_should_work() {
 fn querying_total_supply_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 50);
@@ -451,7 +454,7 @@ fn querying_total_supply_should_work() {
 		assert_eq!(Assets::balance(ZERO_ID, 1), 50);
 		assert_eq!(Assets::balance(ZERO_ID, 2), 19);
 		assert_eq!(Assets::balance(ZERO_ID, 3), 31);
-		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), ZERO_ID, 3, 31));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), ZERO_ID, 3, 31, CustodialBurnReason::Invalidation, None));
 		assert_eq!(Assets::total_supply(ZERO_ID), 69);
 	});
 }
@@ -460,7 +463,7 @@ fn querying_total_supply_should_work() {
 fn transferring_amount_below_available_balance_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 50);
@@ -472,7 +475,7 @@ fn transferring_amount_below_available_balance_should_work() {
 fn transferring_enough_to_kill_source_when_keep_alive_should_fail() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 10));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
 		assert_noop!(
 			Assets::transfer_keep_alive(RuntimeOrigin::signed(1), ZERO_ID, 2, 91),
@@ -489,9 +492,9 @@ fn transferring_enough_to_kill_source_when_keep_alive_should_fail() {
 fn transferring_frozen_user_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
-		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), ZERO_ID, 1));
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), ZERO_ID, 1, None));
 		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50), Error::<Test>::Frozen);
 		assert_ok!(Assets::thaw(RuntimeOrigin::signed(1), ZERO_ID, 1));
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
@@ -502,23 +505,51 @@ fn transferring_frozen_user_should_not_work() {
 fn transferring_frozen_asset_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
-		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), ZERO_ID));
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), ZERO_ID, None));
 		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50), Error::<Test>::Frozen);
 		assert_ok!(Assets::thaw_asset(RuntimeOrigin::signed(1), ZERO_ID));
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 	});
 }
 
+#[test]
+fn freeze_reasons_are_kept_until_thaw() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		let reason: BoundedVec<u8, <Test as Config>::StringLimit> = b"AML-2023-17".to_vec().try_into().unwrap();
+
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), ZERO_ID, 1, Some(reason.clone())));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Frozen {
+			asset_id: ZERO_ID,
+			who: 1,
+			reason: Some(b"AML-2023-17".to_vec()),
+		}));
+		assert_eq!(Assets::account_freeze_reason(ZERO_ID, 1), Some(reason.clone()));
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(1), ZERO_ID, 1));
+		assert_eq!(Assets::account_freeze_reason(ZERO_ID, 1), None);
+
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), ZERO_ID, Some(reason.clone())));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AssetFrozen {
+			asset_id: ZERO_ID,
+			reason: Some(b"AML-2023-17".to_vec()),
+		}));
+		assert_eq!(Assets::asset_freeze_reason(ZERO_ID), Some(reason));
+		assert_ok!(Assets::thaw_asset(RuntimeOrigin::signed(1), ZERO_ID));
+		assert_eq!(Assets::asset_freeze_reason(ZERO_ID), None);
+	});
+}
+
 #[test]
 fn approve_transfer_frozen_asset_should_not_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
-		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), ZERO_ID));
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), ZERO_ID, None));
 		assert_noop!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50), Error::<Test>::Frozen);
 		assert_ok!(Assets::thaw_asset(RuntimeOrigin::signed(1), ZERO_ID));
 		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
@@ -529,15 +560,15 @@ fn approve_transfer_frozen_asset_should_not_work() {
 fn origin_guards_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_noop!(
 			Assets::transfer_ownership(RuntimeOrigin::signed(2), ZERO_ID, 2),
 			Error::<Test>::NoPermission
 		);
-		assert_noop!(Assets::freeze(RuntimeOrigin::signed(2), ZERO_ID, 1), Error::<Test>::NoPermission);
+		assert_noop!(Assets::freeze(RuntimeOrigin::signed(2), ZERO_ID, 1, None), Error::<Test>::NoPermission);
 		assert_noop!(Assets::thaw(RuntimeOrigin::signed(2), ZERO_ID, 2), Error::<Test>::NoPermission);
-		assert_noop!(Assets::mint(RuntimeOrigin::signed(2), ZERO_ID, 100), Error::<Test>::NoPermission);
-		assert_noop!(Assets::burn(RuntimeOrigin::signed(2), ZERO_ID, 1, 100), Error::<Test>::NoPermission);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(2), ZERO_ID, 100, None), Error::<Test>::NotAssetCustodian);
+		assert_noop!(Assets::burn(RuntimeOrigin::signed(2), ZERO_ID, 1, 100, CustodialBurnReason::Invalidation, None), Error::<Test>::NotAssetCustodian);
 		assert_noop!(
 			Assets::force_transfer(RuntimeOrigin::signed(2), ZERO_ID, 1, 2, 100),
 			Error::<Test>::NoPermission
@@ -557,6 +588,8 @@ fn transfer_owner_should_work() {
 		assert_eq!(Balances::reserved_balance(&1), 12);
 
 		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(1), id, 2));
+		assert_eq!(Balances::reserved_balance(&1), 12);
+		assert_ok!(Assets::accept_ownership(RuntimeOrigin::signed(2), id));
 		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(2), id, 2));
 		assert_eq!(Balances::reserved_balance(&2), 12);
 		assert_eq!(Balances::reserved_balance(&1), 0);
@@ -568,6 +601,7 @@ fn transfer_owner_should_work() {
 
 		// Set metadata now and make sure that deposit gets transferred back.
 		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(2), id, 1));
+		assert_ok!(Assets::accept_ownership(RuntimeOrigin::signed(1), id));
 		assert_eq!(Balances::reserved_balance(&1), 12);
 		assert_eq!(Balances::reserved_balance(&2), 0);
 	});
@@ -577,11 +611,11 @@ fn transfer_owner_should_work() {
 fn transferring_to_frozen_account_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 200);
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 5));
-		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), ZERO_ID, 2));
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), ZERO_ID, 2, None));
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 		assert_eq!(Assets::balance(ZERO_ID, 2), 55);
 	});
@@ -591,12 +625,12 @@ fn transferring_to_frozen_account_should_work() {
 fn transferring_amount_more_than_available_balance_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 50);
 		assert_eq!(Assets::balance(ZERO_ID, 2), 50);
-		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), ZERO_ID, 1, 50));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), ZERO_ID, 1, 50, CustodialBurnReason::Invalidation, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 0);
 		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 1, 50), Error::<Test>::NoAccount);
 		assert_noop!(Assets::transfer(RuntimeOrigin::signed(2), ZERO_ID, 1, 51), Error::<Test>::BalanceLow);
@@ -607,7 +641,7 @@ fn transferring_amount_more_than_available_balance_should_not_work() {
 fn transferring_less_than_one_unit_is_fine() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 0));
 		// `ForceCreated` and `Issued` but no `Transferred` event.
@@ -619,7 +653,7 @@ fn transferring_less_than_one_unit_is_fine() {
 fn transferring_more_units_than_total_supply_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
 		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 101), Error::<Test>::BalanceLow);
 	});
@@ -629,9 +663,9 @@ fn transferring_more_units_than_total_supply_should_not_work() {
 fn burning_asset_balance_with_zero_balance_does_nothing() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 2), 0);
-		assert_noop!(Assets::burn(RuntimeOrigin::signed(1), ZERO_ID, 2, u64::MAX), Error::<Test>::NoAccount);
+		assert_noop!(Assets::burn(RuntimeOrigin::signed(1), ZERO_ID, 2, u64::MAX, CustodialBurnReason::Invalidation, None), Error::<Test>::NoAccount);
 		assert_eq!(Assets::balance(ZERO_ID, 2), 0);
 		assert_eq!(Assets::total_supply(ZERO_ID), 100);
 	});
@@ -643,8 +677,8 @@ fn destroy_calls_died_hooks() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 50));
 		// Create account 1 and 2.
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		// Destroy the asset.
 		let w = Asset::<Test>::get(ZERO_ID).unwrap().destroy_witness();
 		assert_ok!(Assets::destroy(RuntimeOrigin::signed(1), ZERO_ID, w));
@@ -659,7 +693,7 @@ fn destroy_calls_died_hooks() {
 fn freezer_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 10));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		assert_eq!(Assets::balance(ZERO_ID, 1), 100);
 
 		// freeze 50 of it.
@@ -727,7 +761,7 @@ fn force_metadata_should_work() {
 			vec![0u8; 10],
 			vec![0u8; 10],
 			vec![0u8; 10],
-			vec![0u8; 10],
+			CID_V0.as_bytes().to_vec(),
 			8,
 			false
 		));
@@ -741,7 +775,7 @@ fn force_metadata_should_work() {
 			vec![1u8; 10],
 			vec![1u8; 10],
 			vec![0u8; 10],
-			vec![0u8; 10],
+			CID_V0.as_bytes().to_vec(),
 			8,
 			false
 		));
@@ -750,7 +784,7 @@ fn force_metadata_should_work() {
 		// attempt to set metadata for non-existent asset class
 		assert_noop!(
 			Assets::force_set_metadata(RuntimeOrigin::root(), ONE_ID, vec![0u8; 10], vec![0u8; 10], vec![0u8; 10],
-			vec![0u8; 10], 8, false),
+			CID_V0.as_bytes().to_vec(), 8, false),
 			Error::<Test>::Unknown
 		);
 
@@ -824,25 +858,24 @@ fn force_asset_status_should_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
 		Balances::make_free_balance_be(&2, 10);
+		Balances::make_free_balance_be(&3, 10);
 		assert_ok!(Assets::create(RuntimeOrigin::signed(1), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
 		let id = Assets::get_current_asset_id(&1).unwrap();
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 200));
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), id, 2, 150));
 
-		// force asset status to change min_balance > balance
-		assert_ok!(Assets::force_asset_status(RuntimeOrigin::root(), id, 1, 1, 1, 1, 100, true, false));
-		assert_eq!(Assets::balance(id, 1), 50);
-
-		// account can recieve assets for balance < min_balance
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), id, 1, 1));
-		assert_eq!(Assets::balance(id, 1), 51);
-
-		// account on outbound transfer will cleanup for balance < min_balance
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), id, 2, 1));
-		assert_eq!(Assets::balance(id, 1), 0);
+		// min_balance can be raised before the asset has accounts
+		assert_ok!(Assets::force_asset_status(RuntimeOrigin::root(), id, 1, 1, 1, 1, 10, false, false));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 200, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), id, 2, 150));
 
-		// won't create new account with balance below min_balance
-		assert_noop!(Assets::transfer(RuntimeOrigin::signed(2), id, 3, 50), TokenError::BelowMinimum);
+		// but not above the balances of the existing accounts
+		assert_noop!(
+			Assets::force_asset_status(RuntimeOrigin::root(), id, 1, 1, 1, 1, 100, false, false),
+			Error::<Test>::LiveAccounts
+		);
+		assert_noop!(
+			Assets::force_asset_status(RuntimeOrigin::root(), id, 1, 1, 1, 1, 0, false, false),
+			Error::<Test>::MinBalanceZero
+		);
 
 		// force asset status will not execute for non-existent class
 		assert_noop!(
@@ -850,11 +883,22 @@ fn force_asset_status_should_work() {
 			Error::<Test>::Unknown
 		);
 
-		// account drains to completion when funds dip below min_balance
-		assert_ok!(Assets::force_asset_status(RuntimeOrigin::root(), id, 1, 1, 1, 1, 110, true, false));
-		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), id, 1, 110));
+		// lowering min_balance and flipping sufficiency are fine
+		assert_ok!(Assets::force_asset_status(RuntimeOrigin::root(), id, 1, 1, 1, 1, 5, true, false));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), id, 3, 20));
+		let details = Asset::<Test>::get(id).unwrap();
+		assert_eq!((details.accounts, details.sufficients), (3, 1));
+
+		// the existing accounts keep their consumer references
+		assert_eq!(System::consumers(&2), 1);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), id, 1, 150));
+		assert_eq!(System::consumers(&2), 0);
+		assert_eq!(System::sufficients(&3), 1);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), id, 1, 20));
+		assert_eq!(System::sufficients(&3), 0);
+		let details = Asset::<Test>::get(id).unwrap();
+		assert_eq!((details.accounts, details.sufficients), (1, 0));
 		assert_eq!(Assets::balance(id, 1), 200);
-		assert_eq!(Assets::balance(id, 2), 0);
 		assert_eq!(Assets::total_supply(id), 200);
 	});
 }
@@ -864,13 +908,13 @@ fn balance_conversion_should_work() {
 	new_test_ext().execute_with(|| {
 		use frame_support::traits::tokens::BalanceConversion;
 
-		let id = [42u8; 24];
+		let id = AssetId::new([42u8; 24]);
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, 1, true, 10));
-		let not_sufficient = [23u8; 24];
+		let not_sufficient = AssetId::new([23u8; 24]);
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), not_sufficient, 1, false, 10));
 
 		assert_eq!(
-			BalanceToAssetBalance::<Balances, Test, ConvertInto>::to_asset_balance(100, [123u8; 24]),
+			BalanceToAssetBalance::<Balances, Test, ConvertInto>::to_asset_balance(100, AssetId::new([123u8; 24])),
 			Err(ConversionError::AssetMissing)
 		);
 		assert_eq!(
@@ -909,7 +953,7 @@ fn querying_name_symbol_and_decimals_should_work() {
 			vec![0u8; 10],
 			vec![1u8; 10],
 			vec![0u8; 10],
-			vec![0u8; 10],
+			CID_V0.as_bytes().to_vec(),
 			12,
 			false
 		));
@@ -924,7 +968,7 @@ fn querying_allowance_should_work() {
 	new_test_ext().execute_with(|| {
 		use frame_support::traits::tokens::fungibles::approvals::{Inspect, Mutate};
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve(ZERO_ID, &1, &2, 50));
 		assert_eq!(Assets::allowance(ZERO_ID, &1, &2), 50);
@@ -939,7 +983,7 @@ fn transfer_large_asset() {
 	new_test_ext().execute_with(|| {
 		let amount = u64::pow(2, 63) + 2;
 		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, amount));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, amount, None));
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, amount - 1));
 	})
 }
@@ -948,11 +992,17 @@ fn transfer_large_asset() {
 
 #[test]
 fn set_custodian_ok() {
-	new_test_ext().execute_with(|| {
+	test_ext_no_custodian().execute_with(|| {
 		let custodian = 10;
 		assert_ok!(Assets::set_custodian(RuntimeOrigin::root(), custodian));
 		
 		assert_eq!(Assets::get_custodian(), Some(custodian));
+
+		// a change goes through the time-locked proposal
+		assert_noop!(
+			Assets::set_custodian(RuntimeOrigin::root(), 11),
+			Error::<Test>::CustodianAlreadySet
+		);
 	})
 }
 
@@ -987,7 +1037,7 @@ fn create_asset_with_generated_name() {
 		assert_eq!(aseet_details.accounts, 0);
 		assert_eq!(aseet_details.sufficients, 0);
 		assert_eq!(aseet_details.approvals, 0);
-		assert!(!aseet_details.is_frozen);
+		assert_eq!(aseet_details.status, AssetStatus::Live);
 	})
 }
 
@@ -999,14 +1049,15 @@ fn create_asset_ensure_user_cannot_mint() {
 		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
 		let id = Assets::get_current_asset_id(&user).unwrap();
 		
-		assert_noop!(Assets::mint(RuntimeOrigin::signed(user), id, 500), 
-			Error::<Test>::NoPermission);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(user), id, 500, None), 
+			Error::<Test>::NotAssetCustodian);
 	})
 }
 
 #[test]
 fn create_asset_failed_no_custodian() {
 	test_ext_no_custodian().execute_with(|| {
+		CustodianRequired::set(true);
 		let user = 4;
 		Balances::make_free_balance_be(&user, 1000);
 		assert_noop!(
@@ -1037,12 +1088,12 @@ fn set_project_data_by_user() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 		let metadata = Metadata::<Test>::get(id);
 		assert!(metadata.name.len() > 0);
 		assert!(metadata.symbol.len() > 0);
 		assert!(metadata.url.len() == 4);
-		assert!(metadata.data_ipfs.len() == 4);
+		assert_eq!(metadata.data_ipfs.map(|cid| cid.to_text()), Some(CID_V0.as_bytes().to_vec()));
 	})
 }
 
@@ -1056,12 +1107,12 @@ fn set_project_data_by_custodian() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(CUSTODIAN), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 		let metadata = Metadata::<Test>::get(id);
 		assert!(metadata.name.len() > 0);
 		assert!(metadata.symbol.len() > 0);
 		assert!(metadata.url.len() == 4);
-		assert!(metadata.data_ipfs.len() == 4);
+		assert_eq!(metadata.data_ipfs.map(|cid| cid.to_text()), Some(CID_V0.as_bytes().to_vec()));
 	})
 }
 
@@ -1075,21 +1126,21 @@ fn set_project_data_second_time() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 		let metadata = Metadata::<Test>::get(id);
 		assert!(metadata.name.len() > 0);
 		assert!(metadata.symbol.len() > 0);
 		assert!(metadata.url.len() == 4);
-		assert!(metadata.data_ipfs.len() == 4);
+		assert_eq!(metadata.data_ipfs.map(|cid| cid.to_text()), Some(CID_V0.as_bytes().to_vec()));
 
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g', b'f']));
+			 CID_V1.as_bytes().to_vec(), None));
 		let metadata = Metadata::<Test>::get(id);
 		assert!(metadata.name.len() > 0);
 		assert!(metadata.symbol.len() > 0);
 		assert!(metadata.url.len() == 4);
-		assert!(metadata.data_ipfs.len() == 5);
+		assert_eq!(metadata.data_ipfs, crate::cid::Cid::parse(CID_V1.as_bytes()));
 	})
 }
 
@@ -1103,23 +1154,23 @@ fn set_project_data_after_mint_fail() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 		let metadata = Metadata::<Test>::get(id);
 		assert!(metadata.name.len() > 0);
 		assert!(metadata.symbol.len() > 0);
 		assert!(metadata.url.len() == 4);
-		assert!(metadata.data_ipfs.len() == 4);
+		assert_eq!(metadata.data_ipfs.map(|cid| cid.to_text()), Some(CID_V0.as_bytes().to_vec()));
 
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
 		assert_noop!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g', b'f']), 
+			 CID_V1.as_bytes().to_vec(), None), 
 			Error::<Test>::CannotChangeAfterMint);
 		let metadata = Metadata::<Test>::get(id);
 		assert!(metadata.name.len() > 0);
 		assert!(metadata.symbol.len() > 0);
 		assert!(metadata.url.len() == 4);
-		assert!(metadata.data_ipfs.len() == 4);
+		assert_eq!(metadata.data_ipfs.map(|cid| cid.to_text()), Some(CID_V0.as_bytes().to_vec()));
 	})
 }
 
@@ -1133,12 +1184,12 @@ fn set_project_data_failed() {
 		
 		assert_noop!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 "123456789012345678901234567890123456789012345678901234567".as_bytes().to_vec()),
+			 "123456789012345678901234567890123456789012345678901234567".as_bytes().to_vec(), None),
 			Error::<Test>::BadMetadata);
 
 		assert_noop!(Assets::set_project_data(
 			RuntimeOrigin::signed(5), id, vec![b'h',b't',b't' ,b'p'],
-				"1234".as_bytes().to_vec()),
+				CID_V0.as_bytes().to_vec(), None),
 			Error::<Test>::NoPermission);
 	})
 }
@@ -1153,9 +1204,9 @@ fn custodian_mint() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 			
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
 		assert_eq!(500, Assets::balance(id, user));
 	})
 }
@@ -1170,10 +1221,10 @@ fn not_custodian_cannot_mint() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 			
-		assert_noop!(Assets::mint(RuntimeOrigin::signed(3), id, 500),
-			Error::<Test>::NoPermission);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(3), id, 500, None),
+			Error::<Test>::NotAssetCustodian);
 	})
 }
 
@@ -1190,21 +1241,21 @@ fn custodian_full_circle() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(CUSTODIAN), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 			
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 1500));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 1500, None));
 		assert_eq!(1500, Assets::balance(id, CUSTODIAN));
 
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(CUSTODIAN), id, user1, 500));
 		assert_ok!(Assets::transfer(RuntimeOrigin::signed(CUSTODIAN), id, user2, 700));
 
-		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user1, 100));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user1, 100, CustodialBurnReason::Invalidation, None));
 		assert_eq!(400, Assets::balance(id, user1));
-		assert_eq!(Some(100), BurnCertificate::<Test>::get(user1, id));
+		assert_eq!(1, CustodialBurnCount::<Test>::get(user1));
 
-		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user2, 100));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user2, 100, CustodialBurnReason::Invalidation, None));
 		assert_eq!(600, Assets::balance(id, user2));
-		assert_eq!(Some(100), BurnCertificate::<Test>::get(user2, id));
+		assert_eq!(1, CustodialBurnCount::<Test>::get(user2));
 	})
 }
 
@@ -1218,14 +1269,37 @@ fn custodian_burn() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 			
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
 		assert_eq!(500, Assets::balance(id, user));
 
-		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 100));
+		assert_ok!(Assets::burn(
+			RuntimeOrigin::signed(CUSTODIAN), id, user, 100, CustodialBurnReason::CourtOrder, Some(b"case-42".to_vec())));
 		assert_eq!(400, Assets::balance(id, user));
-		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::CustodialBurned {
+			account: user,
+			asset_id: id,
+			amount: 100,
+			reason: CustodialBurnReason::CourtOrder,
+		}));
+		// involuntary burns are not retirements
+		assert_eq!(None, BurnCertificate::<Test>::get(user, id).map(|c| c.amount));
+		assert_eq!(
+			CustodialBurns::<Test>::get(user, 0),
+			Some(CustodialBurnRecord {
+				asset_id: id,
+				amount: 100,
+				reason: CustodialBurnReason::CourtOrder,
+				reference: Some(b"case-42".to_vec().try_into().unwrap()),
+				block_number: 1,
+			})
+		);
+
+		assert_noop!(
+			Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 100, CustodialBurnReason::CourtOrder, Some(vec![0u8; 51])),
+			Error::<Test>::BadMetadata
+		);
 	})
 }
 
@@ -1239,18 +1313,20 @@ fn custodian_burn_several_times() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 			
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
 		assert_eq!(500, Assets::balance(id, user));
 
-		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 100));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 100, CustodialBurnReason::Invalidation, None));
 		assert_eq!(400, Assets::balance(id, user));
-		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id));
+		assert_eq!(1, CustodialBurnCount::<Test>::get(user));
 
-		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 111));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 111, CustodialBurnReason::UserRequest, None));
 		assert_eq!(289, Assets::balance(id, user));
-		assert_eq!(Some(211), BurnCertificate::<Test>::get(user, id));
+		assert_eq!(2, CustodialBurnCount::<Test>::get(user));
+		assert_eq!(CustodialBurns::<Test>::get(user, 1).unwrap().amount, 111);
+		assert_eq!(None, BurnCertificate::<Test>::get(user, id).map(|c| c.amount));
 	})
 }
 
@@ -1264,19 +1340,19 @@ fn user_self_burn() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 			
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
 		assert_eq!(500, Assets::balance(id, user));
 
-		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
 		assert_eq!(400, Assets::balance(id, user));
-		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id));
+		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id).map(|c| c.amount));
 
 		// burn second time
-		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
 		assert_eq!(300, Assets::balance(id, user));
-		assert_eq!(Some(200), BurnCertificate::<Test>::get(user, id));
+		assert_eq!(Some(200), BurnCertificate::<Test>::get(user, id).map(|c| c.amount));
 	})
 }
 
@@ -1290,20 +1366,23 @@ fn user_cannot_self_burn_more() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 			
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
 		assert_eq!(500, Assets::balance(id, user));
 
-		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
 		assert_eq!(400, Assets::balance(id, user));
-		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id));
+		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id).map(|c| c.amount));
 
 		// burn more than owned
-		assert_noop!(Assets::self_burn(RuntimeOrigin::signed(user), id, 500),
+		assert_noop!(Assets::self_burn(RuntimeOrigin::signed(user), id, 500, None),
 			Error::<Test>::BalanceLow);
+		// burn nothing
+		assert_noop!(Assets::self_burn(RuntimeOrigin::signed(user), id, 0, None),
+			Error::<Test>::ZeroRetirement);
 		assert_eq!(400, Assets::balance(id, user));
-		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id));
+		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id).map(|c| c.amount));
 	})
 }
 
@@ -1317,19 +1396,4113 @@ fn custodian_cannot_burn_more() {
 		
 		assert_ok!(Assets::set_project_data(
 			RuntimeOrigin::signed(user), id, vec![b'h',b't',b't' ,b'p'],
-			 vec![b'4',b'h',b'6',b'g']));
+			 CID_V0.as_bytes().to_vec(), None));
 			
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
 		assert_eq!(500, Assets::balance(id, user));
 
-		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
 		assert_eq!(400, Assets::balance(id, user));
-		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id));
+		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id).map(|c| c.amount));
 
 		// burn more than owned
-		assert_noop!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 500),
+		assert_noop!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 500, CustodialBurnReason::Invalidation, None),
 			Error::<Test>::BalanceLow);
 		assert_eq!(400, Assets::balance(id, user));
-		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id));
+		assert_eq!(Some(100), BurnCertificate::<Test>::get(user, id).map(|c| c.amount));
 	})
-}
\ No newline at end of file
+}
+#[test]
+fn carbon_assets_interface_works() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let investor = 5;
+		Balances::make_free_balance_be(&user, 1000);
+		let id = <Assets as CarbonAssetsInterface<u64, u64>>::create_carbon_asset(
+			&user, "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()).unwrap();
+		assert_eq!(Asset::<Test>::get(id).unwrap().owner, user);
+
+		assert_ok!(<Assets as CarbonAssetsInterface<u64, u64>>::mint_carbon_asset(id, 500));
+		assert_eq!(500, Assets::balance(id, user));
+
+		Balances::make_free_balance_be(&investor, 1000);
+		assert_eq!(
+			<Assets as CarbonAssetsInterface<u64, u64>>::transfer_carbon_asset(id, &user, &investor, 200),
+			Ok(200)
+		);
+		assert_eq!(
+			<Assets as CarbonAssetsInterface<u64, u64>>::burn_carbon_asset(id, &investor, 50),
+			Ok(50)
+		);
+		assert_eq!(150, <Assets as CarbonAssetsInterface<u64, u64>>::carbon_asset_balance(id, &investor));
+		assert_eq!(Some(50), BurnCertificate::<Test>::get(investor, id).map(|c| c.amount));
+	});
+}
+
+#[test]
+fn interface_create_with_bad_metadata_leaves_no_asset() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_noop!(
+			<Assets as CarbonAssetsInterface<u64, u64>>::create_carbon_asset(&user, vec![b'a'; 51], b"Token".to_vec()),
+			Error::<Test>::BadMetadata
+		);
+		assert_eq!(Balances::reserved_balance(&user), 0);
+	});
+}
+
+#[test]
+fn burn_certificate_records_the_burned_dust() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::force_asset_status(RuntimeOrigin::root(), id, user, CUSTODIAN, CUSTODIAN, CUSTODIAN, 10, false, false));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+
+		// the 5 units left are below the minimum balance and burned with the retirement
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 95, None));
+		assert_eq!(Assets::balance(id, user), 0);
+		assert_eq!(BurnCertificate::<Test>::get(user, id).map(|c| c.amount), Some(100));
+		assert_eq!(Assets::asset_burn_certificates(id), vec![(user, 100)]);
+		let anchored = System::events().into_iter().find_map(|r| match r.event {
+			RuntimeEvent::Assets(crate::Event::RetirementAnchored { amount, anchor, .. }) =>
+				Some((amount, anchor)),
+			_ => None,
+		});
+		let (amount, anchor) = anchored.unwrap();
+		assert_eq!(amount, 100);
+		assert_eq!(RetirementAnchors::<Test>::get(anchor).map(|a| a.amount), Some(100));
+	});
+}
+
+#[test]
+fn custodian_confirms_retirement_registration() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
+
+		assert_noop!(
+			Assets::confirm_retirement_registration(RuntimeOrigin::signed(user), user, id, 60, b"VCS-1".to_vec()),
+			Error::<Test>::NotAssetCustodian
+		);
+		assert_ok!(Assets::confirm_retirement_registration(
+			RuntimeOrigin::signed(CUSTODIAN), user, id, 60, b"VCS-1".to_vec()));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RetirementRegistrationConfirmed {
+			account: user,
+			asset_id: id,
+			amount: 60,
+			external_ref: b"VCS-1".to_vec(),
+		}));
+
+		// confirmations sum up and cannot exceed the burned amount
+		assert_noop!(
+			Assets::confirm_retirement_registration(RuntimeOrigin::signed(CUSTODIAN), user, id, 41, b"VCS-2".to_vec()),
+			Error::<Test>::ExceedsBurned
+		);
+		assert_ok!(Assets::confirm_retirement_registration(
+			RuntimeOrigin::signed(CUSTODIAN), user, id, 40, b"VCS-2".to_vec()));
+		let confirmation = RetirementConfirmation::<Test>::get(user, id).unwrap();
+		assert_eq!(confirmation.amount, 100);
+		assert_eq!(confirmation.external_ref.to_vec(), b"VCS-2".to_vec());
+	});
+}
+
+#[cfg(feature = "compliance-freezer")]
+#[test]
+fn compliance_freezer_works() {
+	new_test_ext().execute_with(|| {
+		type Freezer = compliance_freezer::Pallet<Test>;
+		assert_noop!(Freezer::set_freeze(RuntimeOrigin::signed(1), ZERO_ID, 2, 50), BadOrigin);
+		assert_ok!(Freezer::set_freeze(RuntimeOrigin::root(), ZERO_ID, 2, 50));
+		assert_eq!(<Freezer as FrozenBalance<AssetId, u64, u64>>::frozen_balance(ZERO_ID, &2), Some(50));
+		assert_eq!(<Freezer as FrozenBalance<AssetId, u64, u64>>::frozen_balance(ONE_ID, &2), None);
+
+		assert_ok!(Freezer::clear_freeze(RuntimeOrigin::root(), ZERO_ID, 2));
+		assert_eq!(Freezer::frozen(ZERO_ID, &2), None);
+		assert_noop!(
+			Freezer::clear_freeze(RuntimeOrigin::root(), ZERO_ID, 2),
+			compliance_freezer::Error::<Test>::NotFrozen
+		);
+
+		// the freeze is removed when the asset account dies
+		assert_ok!(Freezer::set_freeze(RuntimeOrigin::root(), ZERO_ID, 2, 50));
+		<Freezer as FrozenBalance<AssetId, u64, u64>>::died(ZERO_ID, &2);
+		assert_eq!(Freezer::frozen(ZERO_ID, &2), None);
+	});
+}
+
+#[cfg(feature = "example-runtime")]
+#[test]
+fn example_runtime_creates_carbon_assets() {
+	use crate::example_runtime::{testnet_genesis, CarbonAssets, Runtime, RuntimeOrigin, System};
+	use sp_runtime::{AccountId32, BuildStorage};
+
+	let custodian = AccountId32::new([1; 32]);
+	let owner = AccountId32::new([2; 32]);
+	let storage = testnet_genesis(&[], custodian.clone(), vec![custodian.clone(), owner.clone()])
+		.build_storage()
+		.unwrap();
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Custodian::<Runtime>::get(), Some(custodian));
+		assert_ok!(CarbonAssets::create(
+			RuntimeOrigin::signed(owner.clone()),
+			b"Forest".to_vec(),
+			b"FRST".to_vec()
+		));
+		assert_eq!(CarbonAssets::owned_asset_count(&owner), 1);
+	});
+}
+
+#[test]
+fn custodian_mints_reserve_backed_assets() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&RESERVE_ACCOUNT, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+
+		assert_noop!(
+			Assets::mint_reserve_backed(RuntimeOrigin::signed(user), id, RESERVE_ACCOUNT, 300),
+			Error::<Test>::NotAssetCustodian
+		);
+		assert_noop!(
+			Assets::mint_reserve_backed(RuntimeOrigin::signed(CUSTODIAN), id, user, 300),
+			Error::<Test>::NotReserveAccount
+		);
+		assert_ok!(Assets::mint_reserve_backed(RuntimeOrigin::signed(CUSTODIAN), id, RESERVE_ACCOUNT, 300));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ReserveBackedIssued {
+			asset_id: id,
+			owner: RESERVE_ACCOUNT,
+			amount: 300,
+		}));
+		assert_eq!(Assets::balance(id, RESERVE_ACCOUNT), 300);
+		assert_eq!(Assets::total_supply(id), 800);
+		assert_eq!(Assets::reserved_supply(id), 300);
+		assert_eq!(Assets::circulating_supply(id), 500);
+
+		// burning from the reserve account releases the reserved supply
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, RESERVE_ACCOUNT, 100, CustodialBurnReason::Invalidation, None));
+		assert_eq!(Assets::total_supply(id), 700);
+		assert_eq!(Assets::reserved_supply(id), 200);
+		assert_eq!(Assets::circulating_supply(id), 500);
+
+		// burning from a regular account does not
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 100, CustodialBurnReason::Invalidation, None));
+		assert_eq!(Assets::reserved_supply(id), 200);
+		assert_eq!(Assets::circulating_supply(id), 400);
+	});
+}
+
+#[test]
+fn transfer_out_of_reserve_account_releases_reserved_supply() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&RESERVE_ACCOUNT, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::mint_reserve_backed(RuntimeOrigin::signed(CUSTODIAN), id, RESERVE_ACCOUNT, 300));
+
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(RESERVE_ACCOUNT), id, user, 100));
+		assert_eq!(Assets::total_supply(id), 800);
+		assert_eq!(Assets::reserved_supply(id), 200);
+		assert_eq!(Assets::circulating_supply(id), 600);
+
+		// transfers into the reserve account do not change the reserved supply
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, RESERVE_ACCOUNT, 50));
+		assert_eq!(Assets::reserved_supply(id), 200);
+		assert_eq!(Assets::circulating_supply(id), 600);
+	});
+}
+
+#[test]
+fn removing_reserve_account_releases_reserved_supply() {
+	use frame_support::traits::ChangeMembers;
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&RESERVE_ACCOUNT, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::mint_reserve_backed(RuntimeOrigin::signed(CUSTODIAN), id, RESERVE_ACCOUNT, 300));
+		assert_eq!(Assets::circulating_supply(id), 500);
+
+		<Assets as ChangeMembers<u64>>::change_members_sorted(&[], &[RESERVE_ACCOUNT], &[]);
+		assert_eq!(Assets::reserved_supply(id), 0);
+		assert_eq!(Assets::circulating_supply(id), 800);
+	});
+}
+
+#[test]
+fn retirement_voucher_redeem_works() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let relayer = 5;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+
+		let voucher = RetirementVoucher { signer: user, asset_id: id, amount: 100, nonce: 1, expiry: 10 };
+		let signature = TestSignature(user, Assets::retirement_voucher_message(&voucher));
+
+		// signed by another account
+		assert_noop!(
+			Assets::redeem_retirement_voucher(
+				RuntimeOrigin::signed(relayer), voucher.clone(), TestSignature(relayer, Assets::retirement_voucher_message(&voucher))),
+			Error::<Test>::BadSignature
+		);
+		// payload does not match the signature
+		let forged = RetirementVoucher { amount: 400, ..voucher.clone() };
+		assert_noop!(
+			Assets::redeem_retirement_voucher(RuntimeOrigin::signed(relayer), forged, signature.clone()),
+			Error::<Test>::BadSignature
+		);
+
+		assert_ok!(Assets::redeem_retirement_voucher(RuntimeOrigin::signed(relayer), voucher.clone(), signature.clone()));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RetirementVoucherRedeemed {
+			signer: user,
+			asset_id: id,
+			amount: 100,
+			nonce: 1,
+		}));
+		assert_eq!(Assets::balance(id, user), 400);
+		assert_eq!(BurnCertificate::<Test>::get(user, id).map(|c| c.amount), Some(100));
+
+		// a signature of the raw voucher is not accepted
+		let voucher = RetirementVoucher { nonce: 3, ..voucher };
+		assert_noop!(
+			Assets::redeem_retirement_voucher(
+				RuntimeOrigin::signed(relayer), voucher.clone(), TestSignature(user, voucher.encode())),
+			Error::<Test>::BadSignature
+		);
+		let voucher = RetirementVoucher { nonce: 1, ..voucher };
+
+		// no replays
+		assert_noop!(
+			Assets::redeem_retirement_voucher(RuntimeOrigin::signed(relayer), voucher, signature),
+			Error::<Test>::VoucherAlreadyRedeemed
+		);
+
+		// expired
+		System::set_block_number(11);
+		let voucher = RetirementVoucher { signer: user, asset_id: id, amount: 100, nonce: 2, expiry: 10 };
+		let signature = TestSignature(user, Assets::retirement_voucher_message(&voucher));
+		assert_noop!(
+			Assets::redeem_retirement_voucher(RuntimeOrigin::signed(relayer), voucher, signature),
+			Error::<Test>::VoucherExpired
+		);
+	});
+}
+
+#[test]
+fn ledger_entries_are_emitted_when_enabled() {
+	new_test_ext().execute_with(|| {
+		let ledger_entries = || {
+			System::events()
+				.into_iter()
+				.filter_map(|r| match r.event {
+					RuntimeEvent::Assets(e @ crate::Event::LedgerEntry { .. }) => Some(e),
+					_ => None,
+				})
+				.collect::<Vec<_>>()
+		};
+		let entry = |op, debit, credit, amount| crate::Event::<Test>::LedgerEntry {
+			op,
+			asset_id: ZERO_ID,
+			debit,
+			credit,
+			amount,
+		};
+
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		assert!(ledger_entries().is_empty());
+
+		LedgerEvents::set(true);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), ZERO_ID, 2, 20, CustodialBurnReason::Invalidation, None));
+		// dust of 5 is burned when the source account dies
+		let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: true };
+		assert_ok!(Assets::do_transfer(ZERO_ID, &1, &2, 145, None, f));
+		LedgerEvents::set(false);
+
+		assert_eq!(
+			ledger_entries(),
+			vec![
+				entry(LedgerOp::Mint, None, Some(1), 100),
+				entry(LedgerOp::Transfer, Some(1), Some(2), 50),
+				entry(LedgerOp::Burn, Some(2), None, 20),
+				entry(LedgerOp::Transfer, Some(1), Some(2), 145),
+				entry(LedgerOp::Dust, Some(1), None, 5),
+			]
+		);
+	});
+}
+
+#[test]
+fn genesis_config_validation_reports_all_errors() {
+	let config: GenesisConfig<Test> = GenesisConfig {
+		custodian: Some(CUSTODIAN),
+		assets: vec![(ZERO_ID, 0, true, 10), (ZERO_ID, 0, true, 10), (ONE_ID, 0, true, 0)],
+		metadata: vec![(ZERO_ID, vec![0u8; 51], "TOKEN".into(), 10), (TWO_ID, "Name".into(), "TOKEN".into(), 10)],
+		accounts: vec![(ZERO_ID, 1, 5), (TWO_ID, 1, 100)],
+	};
+	let errors = config.validate().unwrap_err();
+	assert_eq!(errors.len(), 6);
+	assert!(errors[0].contains("duplicate asset id"));
+	assert!(errors[1].contains("min balance should not be zero"));
+	assert!(errors[2].contains("name is 51 bytes long, limit is 50"));
+	assert!(errors[3].contains("asset does not exist"));
+	assert!(errors[4].contains("below min balance"));
+	assert!(errors[5].contains("asset does not exist"));
+
+	let config: GenesisConfig<Test> = GenesisConfig {
+		custodian: Some(CUSTODIAN),
+		assets: vec![(ZERO_ID, 0, true, 10)],
+		metadata: vec![(ZERO_ID, "Name".into(), "TOKEN".into(), 10)],
+		accounts: vec![(ZERO_ID, 1, 10)],
+	};
+	assert_eq!(config.validate(), Ok(()));
+}
+
+#[test]
+#[should_panic(expected = "Invalid carbon assets genesis config")]
+fn genesis_build_rejects_invalid_config() {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let config: GenesisConfig<Test> = GenesisConfig {
+		custodian: None,
+		assets: vec![],
+		metadata: vec![(ZERO_ID, "Name".into(), "TOKEN".into(), 10)],
+		accounts: vec![],
+	};
+	config.assimilate_storage(&mut storage).unwrap();
+}
+
+#[test]
+fn asset_counters_getters_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::asset_accounts(ZERO_ID), None);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
+		Balances::make_free_balance_be(&1, 1);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 3, 10));
+
+		assert_eq!(Assets::asset_accounts(ZERO_ID), Some(2));
+		assert_eq!(Assets::asset_sufficients(ZERO_ID), Some(2));
+		assert_eq!(Assets::asset_approvals(ZERO_ID), Some(1));
+	});
+}
+
+#[test]
+fn dust_is_passed_to_on_dust_removal() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, false, 10));
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+
+		let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: true };
+		assert_ok!(Assets::do_transfer(ZERO_ID, &1, &2, 95, None, f));
+		assert_eq!(Assets::balance(ZERO_ID, 2), 95);
+		assert_eq!(Assets::total_supply(ZERO_ID), 95);
+		assert_eq!(take_dust(), vec![(ZERO_ID, 5)]);
+
+		// balance burned on refund
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), ZERO_ID));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), ZERO_ID, 1, 20));
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(1), ZERO_ID, true));
+		assert_eq!(Assets::total_supply(ZERO_ID), 75);
+		assert_eq!(take_dust(), vec![(ZERO_ID, 20)]);
+	});
+}
+
+#[test]
+fn account_dump_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ONE_ID, 2, false, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), ONE_ID));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(1), ZERO_ID, 30, None));
+		set_frozen_balance(ZERO_ID, 1, 20);
+
+		let mut dump = Assets::account_dump(&1);
+		dump.assets.sort_by_key(|a| a.asset_id);
+		assert_eq!(
+			dump.assets,
+			vec![
+				AccountAssetDump {
+					asset_id: ZERO_ID,
+					balance: 70,
+					is_frozen: false,
+					frozen_balance: Some(20),
+					deposit: None,
+				},
+				AccountAssetDump {
+					asset_id: ONE_ID,
+					balance: 0,
+					is_frozen: false,
+					frozen_balance: None,
+					deposit: Some(10),
+				},
+			]
+		);
+		assert_eq!(dump.owned_assets, vec![(ZERO_ID, 0)]);
+		assert_eq!(dump.approvals_given, vec![(ZERO_ID, 2, 50, 1)]);
+		assert!(dump.approvals_received.is_empty());
+		assert_eq!(dump.burn_certificates, vec![(ZERO_ID, 30)]);
+
+		let dump = Assets::account_dump(&2);
+		assert_eq!(dump.owned_assets, vec![(ONE_ID, 0)]);
+		assert_eq!(dump.approvals_received, vec![(ZERO_ID, 1, 50)]);
+		clear_frozen_balance(ZERO_ID, 1);
+	});
+}
+
+#[test]
+fn minting_frozen_asset_or_account_follows_policy() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), ZERO_ID, 1, None));
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None), Error::<Test>::AccountFrozen);
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(1), ZERO_ID, 1));
+
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), ZERO_ID, None));
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None), Error::<Test>::AssetFrozen);
+		// permission is checked first
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(2), ZERO_ID, 100, None), Error::<Test>::NotAssetCustodian);
+
+		AllowMintWhenFrozen::set(true);
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), ZERO_ID, 1, None));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		AllowMintWhenFrozen::set(false);
+		assert_eq!(Assets::balance(ZERO_ID, 1), 200);
+	});
+}
+
+#[test]
+fn gc_approvals_of_dead_owner_works() {
+	new_test_ext().execute_with(|| {
+		CleanupReward::set(1);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 3, 50));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 4, 50));
+		assert_eq!(Balances::reserved_balance(&1), 3);
+
+		assert_noop!(Assets::gc_approvals(RuntimeOrigin::signed(5), ZERO_ID, 1, 10), Error::<Test>::OwnerAlive);
+
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 5, 100));
+		assert_eq!(Assets::maybe_balance(ZERO_ID, 1), None);
+		assert_eq!(OrphanedApprovalsSince::<Test>::get(ZERO_ID, 1), Some(1));
+
+		// the owner keeps its system account, so its approvals are kept for the grace period
+		assert_noop!(
+			Assets::gc_approvals(RuntimeOrigin::signed(5), ZERO_ID, 1, 2),
+			Error::<Test>::GracePeriodNotPassed
+		);
+		System::set_block_number(11);
+
+		let info = Assets::gc_approvals(RuntimeOrigin::signed(5), ZERO_ID, 1, 2).unwrap();
+		assert_eq!(info.pays_fee, frame_support::dispatch::Pays::No);
+		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().approvals, 1);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		// the approvals of a living owner have not expired, so the deposits are not rewarded
+		assert_eq!(Balances::free_balance(&1), 9);
+		assert_eq!(Balances::free_balance(&5), 0);
+
+		assert_ok!(Assets::gc_approvals(RuntimeOrigin::signed(5), ZERO_ID, 1, 2));
+		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().approvals, 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(OrphanedApprovalsSince::<Test>::get(ZERO_ID, 1), None);
+
+		assert_noop!(Assets::gc_approvals(RuntimeOrigin::signed(5), ZERO_ID, 1, 2), Error::<Test>::Unapproved);
+		CleanupReward::set(0);
+	});
+}
+
+#[test]
+fn asset_attributes_work() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&CUSTODIAN, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		let reserved = Balances::reserved_balance(&user);
+
+		assert_noop!(
+			Assets::set_attribute(RuntimeOrigin::signed(5), id, b"country".to_vec(), b"KZ".to_vec()),
+			Error::<Test>::NotAssetCustodian
+		);
+		assert_noop!(
+			Assets::set_attribute(RuntimeOrigin::signed(user), id, vec![0u8; 11], b"KZ".to_vec()),
+			Error::<Test>::BadAttribute
+		);
+
+		assert_ok!(Assets::set_attribute(RuntimeOrigin::signed(user), id, b"country".to_vec(), b"KZ".to_vec()));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AttributeSet {
+			asset_id: id,
+			key: b"country".to_vec(),
+			value: b"KZ".to_vec(),
+		}));
+		// base 1 + 9 bytes
+		assert_eq!(Balances::reserved_balance(&user), reserved + 10);
+		let key = frame_support::BoundedVec::<u8, <Test as Config>::AttributeKeyLimit>::try_from(b"country".to_vec()).unwrap();
+		assert_eq!(Attributes::<Test>::get(id, &key).unwrap().value.to_vec(), b"KZ".to_vec());
+
+		// the custodian overrides the value and takes over the deposit
+		assert_ok!(Assets::set_attribute(RuntimeOrigin::signed(CUSTODIAN), id, b"country".to_vec(), b"KAZ".to_vec()));
+		assert_eq!(Balances::reserved_balance(&user), reserved);
+		assert_eq!(Balances::reserved_balance(&CUSTODIAN), 11);
+
+		assert_noop!(
+			Assets::clear_attribute(RuntimeOrigin::signed(user), id, b"sdg".to_vec()),
+			Error::<Test>::NoAttribute
+		);
+		assert_ok!(Assets::clear_attribute(RuntimeOrigin::signed(user), id, b"country".to_vec()));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AttributeCleared {
+			asset_id: id,
+			key: b"country".to_vec(),
+		}));
+		assert_eq!(Balances::reserved_balance(&CUSTODIAN), 0);
+	});
+}
+
+#[test]
+fn retire_co2e_basket_works() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		// PREEXIST_ASSET has 10 decimals
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(CUSTODIAN), PREEXIST_ASSET, user, 50));
+
+		assert_noop!(Assets::retire_co2e(RuntimeOrigin::signed(user), vec![]), Error::<Test>::BadBasket);
+		assert_noop!(
+			Assets::retire_co2e(RuntimeOrigin::signed(user), vec![(id, 1); 6]),
+			Error::<Test>::BadBasket
+		);
+		assert_noop!(
+			Assets::retire_co2e(RuntimeOrigin::signed(user), vec![(id, 200), (PREEXIST_ASSET, 60)]),
+			Error::<Test>::BalanceLow
+		);
+
+		assert_ok!(Assets::retire_co2e(RuntimeOrigin::signed(user), vec![(id, 200), (PREEXIST_ASSET, 30)]));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Co2eRetired {
+			account: user,
+			index: 0,
+			co2e: 203,
+		}));
+		assert_eq!(Assets::balance(id, user), 300);
+		assert_eq!(Assets::balance(PREEXIST_ASSET, user), 20);
+		assert_eq!(BurnCertificate::<Test>::get(user, id).map(|c| c.amount), Some(200));
+		assert_eq!(BurnCertificate::<Test>::get(user, PREEXIST_ASSET).map(|c| c.amount), Some(30));
+		assert_eq!(Co2eRetirementCount::<Test>::get(user), 1);
+		assert_eq!(Co2eRetirements::<Test>::get(user, 0).unwrap().co2e, 203);
+	});
+}
+
+#[test]
+fn retire_co2e_records_the_burned_dust() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::force_asset_status(RuntimeOrigin::root(), id, user, CUSTODIAN, CUSTODIAN, CUSTODIAN, 10, false, false));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+
+		// the 5 units left are below the minimum balance and burned with the retirement
+		assert_ok!(Assets::retire_co2e(RuntimeOrigin::signed(user), vec![(id, 95)]));
+		assert_eq!(Assets::balance(id, user), 0);
+		let record = Co2eRetirements::<Test>::get(user, 0).unwrap();
+		assert_eq!(record.co2e, 100);
+		assert_eq!(record.basket.into_inner(), vec![(id, 100)]);
+	});
+}
+
+#[test]
+fn burn_certificates_are_indexed_by_asset() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		Balances::make_free_balance_be(&5, 1000);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 5, 200));
+
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 50, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(5), id, 70, None));
+
+		assert_eq!(Assets::total_retired(id), 220);
+		let mut burned = Assets::asset_burn_certificates(id);
+		burned.sort();
+		assert_eq!(burned, vec![(user, 150), (5, 70)]);
+	});
+}
+
+#[test]
+fn migration_v1_indexes_burn_certificates() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Assets>();
+		crate::migration::v1::BurnCertificate::<Test>::insert(4, ONE_ID, 100);
+		crate::migration::v1::BurnCertificate::<Test>::insert(5, ONE_ID, 30);
+		crate::migration::v1::BurnCertificate::<Test>::insert(5, TWO_ID, 10);
+
+		crate::migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Assets>(), 1);
+		assert_eq!(Assets::total_retired(ONE_ID), 130);
+		assert_eq!(Assets::total_retired(TWO_ID), 10);
+		assert_eq!(AssetBurnCertificate::<Test>::get(ONE_ID, 5), Some(30));
+	});
+}
+
+#[test]
+fn migration_v3_adds_approval_expiry() {
+	use frame_support::{storage::unhashed, traits::{OnRuntimeUpgrade, StorageVersion}};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<Assets>();
+		let key = Approvals::<Test>::hashed_key_for((ZERO_ID, 1, 2));
+		unhashed::put_raw(&key, &(50u64, 1u64).encode());
+
+		crate::migration::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Assets>(), 3);
+		assert_eq!(
+			Approvals::<Test>::get((ZERO_ID, 1, 2)),
+			Some(Approval { amount: 50, deposit: 1, expiry: None })
+		);
+	});
+}
+
+#[test]
+fn migration_v4_adds_last_block_to_burn_certificates() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<Assets>();
+		crate::migration::v1::BurnCertificate::<Test>::insert(4, ONE_ID, 100);
+		System::set_block_number(7);
+
+		crate::migration::v4::MigrateToV4::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Assets>(), 4);
+		assert_eq!(
+			BurnCertificate::<Test>::get(4, ONE_ID),
+			Some(BurnCertificateEntry { amount: 100, last_block: 7 })
+		);
+
+		// later retirements move the last-updated block
+		System::set_block_number(9);
+		Assets::note_burn_certificate(&4, ONE_ID, 20, &4, 0);
+		assert_eq!(
+			BurnCertificate::<Test>::get(4, ONE_ID),
+			Some(BurnCertificateEntry { amount: 120, last_block: 9 })
+		);
+	});
+}
+
+#[test]
+fn migration_v2_indexes_owned_assets() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<Assets>();
+		OwnedAssets::<Test>::remove(0, PREEXIST_ASSET);
+		OwnedAssetCount::<Test>::remove(0);
+
+		crate::migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Assets>(), 2);
+		assert_eq!(Assets::owned_asset_count(0), 1);
+		assert!(OwnedAssets::<Test>::contains_key(0, PREEXIST_ASSET));
+	});
+}
+
+fn change_custodian(custodian: u64) {
+	assert_ok!(Assets::propose_custodian(RuntimeOrigin::root(), custodian));
+	System::set_block_number(System::block_number() + 5);
+	assert_ok!(Assets::accept_custodian(RuntimeOrigin::signed(custodian)));
+}
+
+#[test]
+fn custodian_policy_works() {
+	// assets keep the custodian they were created with
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		change_custodian(2);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(2), id, 100, None), Error::<Test>::NotAssetCustodian);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+	});
+
+	// the current custodian governs all the assets
+	new_test_ext().execute_with(|| {
+		CustodianRequired::set(true);
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		change_custodian(2);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None), Error::<Test>::NotAssetCustodian);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(2), id, 100, None));
+		CustodianRequired::set(false);
+	});
+
+	// without a custodian the owner governs the asset
+	test_ext_no_custodian().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), id, 100, None));
+		assert_eq!(Assets::balance(id, user), 100);
+	});
+}
+
+#[test]
+fn retirements_roll_up_per_organization() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&5, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 5, 200));
+
+		// burns before the registration do not count
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 10, None));
+
+		assert_ok!(Assets::set_organization(RuntimeOrigin::signed(user), b"did:evr:acme".to_vec()));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OrganizationSet {
+			account: user,
+			organization: b"did:evr:acme".to_vec(),
+		}));
+		assert_ok!(Assets::set_organization(RuntimeOrigin::signed(5), b"did:evr:acme".to_vec()));
+		// base 1 + 12 bytes
+		assert_eq!(Balances::reserved_balance(&5), 13);
+
+		// burns of unapproved accounts do not count
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(5), id, 10, None));
+		assert_eq!(Assets::organization_retired(b"did:evr:acme".to_vec()), vec![]);
+		assert_noop!(
+			Assets::approve_organization_member(RuntimeOrigin::signed(user), 5),
+			Error::<Test>::UnknownOrganization
+		);
+		assert_noop!(
+			Assets::register_organization(RuntimeOrigin::signed(user), b"did:evr:acme".to_vec(), user),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::register_organization(RuntimeOrigin::signed(CUSTODIAN), b"did:evr:acme".to_vec(), user));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OrganizationRegistered {
+			organization: b"did:evr:acme".to_vec(),
+			owner: user,
+		}));
+		// the owner registers again to be approved
+		assert_ok!(Assets::set_organization(RuntimeOrigin::signed(user), b"did:evr:acme".to_vec()));
+		assert_noop!(
+			Assets::approve_organization_member(RuntimeOrigin::signed(6), 5),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::approve_organization_member(RuntimeOrigin::signed(user), 5));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OrganizationMemberApproved {
+			account: 5,
+			organization: b"did:evr:acme".to_vec(),
+		}));
+
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(5), id, 50, None));
+		assert_eq!(Assets::organization_retired(b"did:evr:acme".to_vec()), vec![(id, 150)]);
+
+		assert_noop!(Assets::clear_organization(RuntimeOrigin::signed(6)), Error::<Test>::NoOrganization);
+		assert_ok!(Assets::clear_organization(RuntimeOrigin::signed(5)));
+		assert_eq!(Balances::reserved_balance(&5), 0);
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(5), id, 50, None));
+		assert_eq!(Assets::organization_retired(b"did:evr:acme".to_vec()), vec![(id, 150)]);
+	});
+}
+
+#[test]
+fn mint_batch_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&4, 1000);
+		Balances::make_free_balance_be(&5, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(4), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let first = Assets::get_current_asset_id(&4).unwrap();
+		assert_ok!(Assets::create(RuntimeOrigin::signed(5), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let second = Assets::get_current_asset_id(&5).unwrap();
+
+		assert_noop!(Assets::mint_batch(RuntimeOrigin::signed(CUSTODIAN), vec![]), Error::<Test>::BadBatch);
+		assert_noop!(
+			Assets::mint_batch(RuntimeOrigin::signed(CUSTODIAN), vec![(first, 1); 6]),
+			Error::<Test>::BadBatch
+		);
+		assert_noop!(
+			Assets::mint_batch(RuntimeOrigin::signed(4), vec![(first, 100), (second, 200)]),
+			Error::<Test>::NotAssetCustodian
+		);
+
+		assert_ok!(Assets::mint_batch(RuntimeOrigin::signed(CUSTODIAN), vec![(first, 100), (second, 200)]));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::BatchIssued {
+			issued: vec![(first, 4, 100), (second, 5, 200)],
+		}));
+		assert_eq!(Assets::balance(first, 4), 100);
+		assert_eq!(Assets::balance(second, 5), 200);
+	});
+}
+
+#[test]
+fn retirements_are_anchored_by_hash() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+
+		assert_noop!(
+			Assets::self_burn(RuntimeOrigin::signed(user), id, 100, Some(vec![0u8; 51])),
+			Error::<Test>::BadMetadata
+		);
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, Some(b"ACME Corp".to_vec())));
+
+		let note: Option<BoundedVec<u8, <Test as Config>::StringLimit>> =
+			Some(b"ACME Corp".to_vec().try_into().unwrap());
+		let anchor = BlakeTwo256::hash_of(&(user, id, 100u64, 1u64, &note, 0u32));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RetirementAnchored {
+			account: user,
+			asset_id: id,
+			amount: 100,
+			anchor,
+		}));
+		let record = Assets::retirement_anchor(anchor).unwrap();
+		assert_eq!(record.account, user);
+		assert_eq!(record.amount, 100);
+		assert_eq!(record.note, note);
+
+		// the same retirement gets a new anchor
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, Some(b"ACME Corp".to_vec())));
+		let second = BlakeTwo256::hash_of(&(user, id, 100u64, 1u64, &note, 1u32));
+		assert_ne!(anchor, second);
+		assert!(Assets::retirement_anchor(second).is_some());
+	});
+}
+
+#[test]
+fn account_activity_is_counted() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+
+		// not counted while disabled
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 10));
+		assert_eq!(Assets::account_activity(1), AccountActivity::default());
+
+		AccountStats::set(true);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 10));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), ZERO_ID, 1, 5));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 3, 10));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(1), ZERO_ID, 10, None));
+
+		assert_eq!(
+			Assets::account_activity(1),
+			AccountActivity { transfers_out: 1, transfers_in: 1, retirements: 1, approvals_granted: 1 }
+		);
+		assert_eq!(
+			Assets::account_activity(2),
+			AccountActivity { transfers_out: 1, transfers_in: 1, retirements: 0, approvals_granted: 0 }
+		);
+		AccountStats::set(false);
+	});
+}
+
+#[test]
+fn outbox_queues_and_acknowledges_messages() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
+
+		assert_eq!((OutboxHead::<Test>::get(), OutboxTail::<Test>::get()), (0, 3));
+		let kinds: Vec<_> = (0..3).map(|i| Outbox::<Test>::get(i).unwrap().kind).collect();
+		assert_eq!(kinds, vec![OutboxKind::Created, OutboxKind::Minted, OutboxKind::Retired]);
+		let retired = Outbox::<Test>::get(2).unwrap();
+		assert_eq!((retired.asset_id, retired.account, retired.amount), (id, user, 100));
+
+		// the oldest message is dropped when the queue is full
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 50, None));
+		assert_eq!((OutboxHead::<Test>::get(), OutboxTail::<Test>::get()), (1, 4));
+		assert!(Outbox::<Test>::get(0).is_none());
+
+		// only the outbox authorities acknowledge, not the custodian
+		let authority = 9;
+		assert_noop!(Assets::ack_outbox(RuntimeOrigin::signed(CUSTODIAN), 2), Error::<Test>::NoPermission);
+		assert_noop!(
+			Assets::remove_outbox_authority(RuntimeOrigin::root(), authority),
+			Error::<Test>::NotOutboxAuthority
+		);
+		assert_noop!(Assets::add_outbox_authority(RuntimeOrigin::signed(CUSTODIAN), authority), BadOrigin);
+		assert_ok!(Assets::add_outbox_authority(RuntimeOrigin::root(), authority));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OutboxAuthorityAdded { authority }));
+		assert_noop!(Assets::ack_outbox(RuntimeOrigin::signed(authority), 4), Error::<Test>::BadOutboxId);
+		assert_ok!(Assets::ack_outbox(RuntimeOrigin::signed(authority), 2));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OutboxAcknowledged { up_to: 2 }));
+		assert_eq!(OutboxHead::<Test>::get(), 3);
+		assert!(Outbox::<Test>::get(2).is_none());
+		assert!(Outbox::<Test>::get(3).is_some());
+
+		assert_ok!(Assets::remove_outbox_authority(RuntimeOrigin::root(), authority));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OutboxAuthorityRemoved { authority }));
+		assert_noop!(Assets::ack_outbox(RuntimeOrigin::signed(authority), 3), Error::<Test>::NoPermission);
+	});
+}
+
+#[test]
+fn offchain_worker_delivers_outbox_to_webhook() {
+	use frame_support::traits::Hooks;
+	use sp_core::offchain::{
+		testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt,
+	};
+	use sp_runtime::{testing::UintAuthorityId, traits::Extrinsic as _};
+
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let authority = 9;
+		UintAuthorityId::set_all_keys(vec![authority]);
+		assert_ok!(Assets::add_outbox_authority(RuntimeOrigin::root(), authority));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		let message = Outbox::<Test>::get(0).unwrap();
+
+		// nothing is delivered without a webhook
+		<Assets as Hooks<u64>>::offchain_worker(1);
+		assert!(pool_state.read().transactions.is_empty());
+
+		let url = "http://localhost/carbon".as_bytes().to_vec();
+		sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, outbox::WEBHOOK_KEY, &url.encode());
+		offchain_state.write().expect_request(PendingRequest {
+			method: "POST".into(),
+			uri: "http://localhost/carbon".into(),
+			body: (0u64, message).encode(),
+			headers: vec![("Content-Type".into(), "application/octet-stream".into())],
+			response: Some(vec![]),
+			sent: true,
+			..Default::default()
+		});
+		<Assets as Hooks<u64>>::offchain_worker(1);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert!(tx.is_signed().unwrap());
+		assert_eq!(tx.call, RuntimeCall::Assets(crate::Call::ack_outbox { up_to: 0 }));
+
+		// the acknowledgment in flight is not sent again until it times out
+		<Assets as Hooks<u64>>::offchain_worker(2);
+		assert!(pool_state.read().transactions.is_empty());
+		<Assets as Hooks<u64>>::offchain_worker(11);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.call, RuntimeCall::Assets(crate::Call::ack_outbox { up_to: 0 }));
+	});
+}
+
+#[test]
+fn offchain_worker_reports_custodian_sla() {
+	use frame_support::{traits::Hooks, unsigned::ValidateUnsigned};
+	use sp_core::offchain::{testing::TestTransactionPoolExt, TransactionPoolExt};
+	use sp_runtime::{
+		traits::Extrinsic as _,
+		transaction_validity::{InvalidTransaction, TransactionSource},
+	};
+
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
+		assert_eq!(Assets::unconfirmed_retirements(), 100);
+		assert_ok!(Assets::request_mint(RuntimeOrigin::signed(user), id, 10, CID_V0.as_bytes().to_vec()));
+
+		System::set_block_number(5);
+		assert_ok!(Assets::confirm_retirement_registration(
+			RuntimeOrigin::signed(CUSTODIAN), user, id, 60, b"VCS-1".to_vec()));
+		assert_eq!(Assets::custodian_last_action(), Some(5));
+
+		// no report between the intervals
+		<Assets as Hooks<u64>>::offchain_worker(9);
+		assert!(pool_state.read().transactions.is_empty());
+
+		System::set_block_number(10);
+		<Assets as Hooks<u64>>::offchain_worker(10);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert!(!tx.is_signed().unwrap());
+		let report = CustodianSlaReport {
+			reported_at: 10,
+			last_action: Some(5),
+			waiting: 5,
+			pending_amount: 40,
+			pending_mint_requests: 1,
+			mint_requests_waiting: 9,
+		};
+		let call = crate::Call::report_custodian_sla { report: report.clone() };
+		assert_eq!(tx.call, RuntimeCall::Assets(call.clone()));
+
+		// the pool validates the report for the next block against the reported state
+		System::set_block_number(11);
+		assert_ok!(Assets::validate_unsigned(TransactionSource::External, &call));
+		let forged = CustodianSlaReport { waiting: 0, ..report.clone() };
+		assert_eq!(
+			Assets::validate_unsigned(
+				TransactionSource::External,
+				&crate::Call::report_custodian_sla { report: forged }
+			),
+			InvalidTransaction::BadProof.into()
+		);
+
+		assert_ok!(Assets::report_custodian_sla(RuntimeOrigin::none(), report.clone()));
+		assert_eq!(Assets::custodian_sla(), Some(report.clone()));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::CustodianSlaReported {
+			reported_at: 10,
+			waiting: 5,
+			pending_amount: 40,
+		}));
+		// every report is stored once
+		assert_noop!(
+			Assets::report_custodian_sla(RuntimeOrigin::none(), report),
+			Error::<Test>::BadSlaReport
+		);
+		assert_noop!(
+			Assets::report_custodian_sla(RuntimeOrigin::signed(CUSTODIAN), Assets::custodian_sla_report(20)),
+			BadOrigin
+		);
+
+		// the waiting time is reset once all retirements are confirmed and all mint requests are
+		// decided
+		assert_ok!(Assets::confirm_retirement_registration(
+			RuntimeOrigin::signed(CUSTODIAN), user, id, 40, b"VCS-2".to_vec()));
+		assert_ok!(Assets::reject_mint(RuntimeOrigin::signed(CUSTODIAN), 0, b"no evidence".to_vec()));
+		assert_eq!(
+			Assets::custodian_sla_report(20),
+			CustodianSlaReport {
+				reported_at: 20,
+				last_action: Some(11),
+				waiting: 0,
+				pending_amount: 0,
+				pending_mint_requests: 0,
+				mint_requests_waiting: 0,
+			}
+		);
+	});
+}
+
+#[test]
+fn interface_version_reports_capabilities() {
+	new_test_ext().execute_with(|| {
+		let version = Assets::interface_version();
+		assert_eq!((version.major, version.minor), (6, 2));
+		for capability in [
+			Capability::MintBatch,
+			Capability::ReserveBackedMint,
+			Capability::Attributes,
+			Capability::Organizations,
+			Capability::RetirementAnchors,
+			Capability::RetirementVouchers,
+			Capability::Outbox,
+			Capability::DualControl,
+			Capability::Methodologies,
+			Capability::TransferSplits,
+			Capability::AssetCustodians,
+			Capability::MintRequests,
+			Capability::Aliases,
+			Capability::RetirementCertificates,
+			Capability::ProjectDetails,
+			Capability::StagedDestroy,
+			Capability::RetirementSponsorship,
+			Capability::CustodianSla,
+			Capability::ExpiringApprovals,
+			Capability::CoBenefitBadges,
+			Capability::TransferMemo,
+			Capability::Vintages,
+			Capability::RetirementQuotas,
+			Capability::CustodianTimelock,
+			Capability::OwnershipAcceptance,
+			Capability::SignedBurns,
+			Capability::FreezeReasons,
+			Capability::DelegatedRetirement,
+			Capability::EmergencyStop,
+			Capability::ProjectVerification,
+			Capability::SerialRanges,
+			Capability::DeprecatedAliases,
+			Capability::TransferAll,
+			Capability::AllowanceAdjustment,
+			Capability::ConfigurableDecimals,
+			Capability::ApprovalPermits,
+			Capability::PartialFreezes,
+			Capability::SponsoredAccounts,
+			Capability::HoldsAndFreezes,
+			Capability::RegistryExports,
+			Capability::TransferWhitelist,
+			Capability::RetirementRegistry,
+			Capability::OwnerBatch,
+			Capability::ViewerKeys,
+			Capability::ComplianceWindow,
+		] {
+			assert!(version.supports(capability));
+		}
+
+		// (LedgerEvents, AccountStats) => expected capabilities
+		for (ledger, stats) in [(false, false), (true, false), (false, true), (true, true)] {
+			LedgerEvents::set(ledger);
+			AccountStats::set(stats);
+			let version = Assets::interface_version();
+			assert_eq!(version.supports(Capability::LedgerEvents), ledger);
+			assert_eq!(version.supports(Capability::AccountStats), stats);
+			assert_eq!(
+				version.capabilities & INTERFACE_VERSION.capabilities,
+				INTERFACE_VERSION.capabilities
+			);
+		}
+		LedgerEvents::set(false);
+		AccountStats::set(false);
+	});
+}
+
+#[test]
+fn cid_validation_works() {
+	assert!(crate::cid::is_valid(CID_V0.as_bytes()));
+	assert!(crate::cid::is_valid(CID_V1.as_bytes()));
+	assert!(crate::cid::is_valid(b"bafybeibi26ovb72rlj4xqa6e2uuxxd7lclqlxw3a7u4eigv2n75pikqll4"));
+	assert!(crate::cid::is_valid(b"bafkreibi26ovb72rlj4xqa6e2uuxxd7lclqlxw3a7u4eigv2n75pikqll4"));
+	assert!(crate::cid::is_valid(
+		b"f0155122028d79d50ff515a797803c4d5297b8feb12e0bbdb60fd38441aba6ffaf42a0b5f"
+	));
+
+	// truncated digest
+	assert!(!crate::cid::is_valid(b"QmR65i22uNRpz7gdLpmNSGGJHLxZfSNtEy37yMBxfUnuH"));
+	// not a base58 character
+	assert!(!crate::cid::is_valid(b"QmR65i22uNRpz7gdLpmNSGGJHLxZfSNtEy37yMBxfUnu0x"));
+	// unknown multibase
+	assert!(!crate::cid::is_valid(b"Bafybeibi26ovb72rlj4xqa6e2uuxxd7lclqlxw3a7u4eigv2n75pikqll4"));
+	// unknown multicodec
+	assert!(!crate::cid::is_valid(
+		b"f0100122028d79d50ff515a797803c4d5297b8feb12e0bbdb60fd38441aba6ffaf42a0b5f"
+	));
+	// digest length does not match the hash function
+	assert!(!crate::cid::is_valid(b"f01551220"));
+	assert!(!crate::cid::is_valid(b""));
+	assert!(!crate::cid::is_valid(b"4h6g"));
+
+	// the longest well-formed CID is accepted, anything longer is rejected before decoding
+	let mut longest = b"f01a9021340".to_vec();
+	longest.extend(vec![b'a'; 128]);
+	assert_eq!(longest.len(), crate::cid::MAX_TEXT_LEN);
+	assert!(crate::cid::is_valid(&longest));
+	longest.push(b'a');
+	assert!(!crate::cid::is_valid(&longest));
+	assert!(!crate::cid::is_valid(&vec![b'z'; 100_000]));
+}
+
+#[test]
+fn malformed_data_ipfs_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		assert_noop!(
+			Assets::set_project_data(RuntimeOrigin::signed(user), id, b"http".to_vec(), b"4h6g".to_vec(), None),
+			Error::<Test>::BadMetadata
+		);
+		assert_noop!(
+			Assets::set_project_data(RuntimeOrigin::signed(user), id, b"http".to_vec(), vec![], None),
+			Error::<Test>::BadMetadata
+		);
+		assert_noop!(
+			Assets::force_set_metadata(RuntimeOrigin::root(), id, vec![0u8; 10], vec![0u8; 10], vec![],
+			b"4h6g".to_vec(), 8, false),
+			Error::<Test>::BadMetadata
+		);
+		assert_noop!(
+			Assets::force_set_metadata(RuntimeOrigin::root(), id, vec![0u8; 10], vec![0u8; 10], vec![],
+			vec![], 8, false),
+			Error::<Test>::BadMetadata
+		);
+	});
+}
+
+#[test]
+fn dual_control_queues_owner_actions() {
+	new_test_ext().execute_with(|| {
+		let (user, co_owner) = (4, 5);
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&6, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		assert_noop!(Assets::set_co_owner(RuntimeOrigin::signed(co_owner), id, Some(co_owner)), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_co_owner(RuntimeOrigin::signed(user), id, Some(co_owner)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::CoOwnerSet { asset_id: id, co_owner: Some(co_owner) }));
+
+		// the owner only proposes
+		assert_ok!(Assets::set_project_data(RuntimeOrigin::signed(user), id, b"http".to_vec(), CID_V0.as_bytes().to_vec(), None));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OwnerActionProposed { asset_id: id, action_id: 0 }));
+		assert!(Metadata::<Test>::get(id).data_ipfs.is_none());
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(user), id, 6));
+		assert_noop!(Assets::destroy(RuntimeOrigin::signed(user), id, Asset::<Test>::get(id).unwrap().destroy_witness()), Error::<Test>::TooManyPendingActions);
+		assert_eq!(Asset::<Test>::get(id).unwrap().owner, user);
+
+		assert_noop!(Assets::confirm_owner_action(RuntimeOrigin::signed(user), id, 0, None), Error::<Test>::NotCoOwner);
+		assert_ok!(Assets::confirm_owner_action(RuntimeOrigin::signed(co_owner), id, 0, None));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OwnerActionConfirmed { asset_id: id, action_id: 0 }));
+		assert_eq!(Metadata::<Test>::get(id).data_ipfs.map(|cid| cid.to_text()), Some(CID_V0.as_bytes().to_vec()));
+		assert_noop!(Assets::confirm_owner_action(RuntimeOrigin::signed(co_owner), id, 0, None), Error::<Test>::UnknownOwnerAction);
+
+		assert_noop!(Assets::cancel_owner_action(RuntimeOrigin::signed(6), id, 1), Error::<Test>::NoPermission);
+		assert_ok!(Assets::cancel_owner_action(RuntimeOrigin::signed(co_owner), id, 1));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OwnerActionCancelled { asset_id: id, action_id: 1 }));
+
+		// leaving dual control needs the co-owner too
+		assert_ok!(Assets::set_co_owner(RuntimeOrigin::signed(user), id, None));
+		assert!(CoOwner::<Test>::contains_key(id));
+		assert_ok!(Assets::confirm_owner_action(RuntimeOrigin::signed(co_owner), id, 2, None));
+		assert!(!CoOwner::<Test>::contains_key(id));
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(user), id, 6));
+		assert_ok!(Assets::accept_ownership(RuntimeOrigin::signed(6), id));
+		assert_eq!(Asset::<Test>::get(id).unwrap().owner, 6);
+	});
+}
+
+#[test]
+fn dual_control_destroy_needs_witness() {
+	new_test_ext().execute_with(|| {
+		let (user, co_owner) = (4, 5);
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::set_co_owner(RuntimeOrigin::signed(user), id, Some(co_owner)));
+
+		let witness = Asset::<Test>::get(id).unwrap().destroy_witness();
+		assert_ok!(Assets::destroy(RuntimeOrigin::signed(user), id, witness));
+		assert!(Asset::<Test>::contains_key(id));
+
+		assert_noop!(Assets::confirm_owner_action(RuntimeOrigin::signed(co_owner), id, 0, None), Error::<Test>::BadWitness);
+		assert_ok!(Assets::confirm_owner_action(RuntimeOrigin::signed(co_owner), id, 0, Some(witness)));
+		assert!(!Asset::<Test>::contains_key(id));
+		assert!(!CoOwner::<Test>::contains_key(id));
+		assert!(PendingOwnerActions::<Test>::get(id).is_empty());
+	});
+}
+
+#[test]
+fn carbon_credits_burned_tells_retirement_from_custodial_burn() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::CarbonCreditsBurned {
+			account: user,
+			asset_id: id,
+			amount: 100,
+			kind: BurnKind::SelfRetirement,
+			initiator: user,
+			alias: None,
+			certificate_id: 0,
+		}));
+
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 50, CustodialBurnReason::Invalidation, None));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::CarbonCreditsBurned {
+			account: user,
+			asset_id: id,
+			amount: 50,
+			kind: BurnKind::CustodialBurn,
+			initiator: CUSTODIAN,
+			alias: None,
+			certificate_id: 1,
+		}));
+		// custodial burns are not retirements
+		assert_eq!(Assets::asset_burn_certificates(id), vec![(user, 100)]);
+	});
+}
+
+#[test]
+fn mint_requires_registered_methodology() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		assert_noop!(Assets::register_methodology(RuntimeOrigin::signed(user), 1, b"VM0007".to_vec()), BadOrigin);
+		assert_noop!(Assets::register_methodology(RuntimeOrigin::root(), 1, vec![0u8; 51]), Error::<Test>::BadMetadata);
+		assert_ok!(Assets::register_methodology(RuntimeOrigin::root(), 1, b"VM0007".to_vec()));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::MethodologyRegistered { methodology: 1, name: b"VM0007".to_vec() }));
+		assert_eq!(Assets::methodology(1).unwrap().into_inner(), b"VM0007".to_vec());
+
+		MethodologyRequired::set(true);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None), Error::<Test>::NoMethodology);
+
+		assert_noop!(Assets::set_asset_methodology(RuntimeOrigin::signed(5), id, 1), Error::<Test>::NotAssetCustodian);
+		assert_noop!(Assets::set_asset_methodology(RuntimeOrigin::signed(user), id, 2), Error::<Test>::UnknownMethodology);
+		assert_ok!(Assets::set_asset_methodology(RuntimeOrigin::signed(user), id, 1));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AssetMethodologySet { asset_id: id, methodology: 1 }));
+		assert_eq!(Assets::asset_methodology(id), Some(1));
+		assert_eq!(Assets::methodology_assets(1), vec![id]);
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+		assert_noop!(Assets::set_asset_methodology(RuntimeOrigin::signed(user), id, 1), Error::<Test>::CannotChangeAfterMint);
+
+		// a withdrawn methodology stops the issuance
+		assert_ok!(Assets::deregister_methodology(RuntimeOrigin::root(), 1));
+		assert_noop!(Assets::deregister_methodology(RuntimeOrigin::root(), 1), Error::<Test>::UnknownMethodology);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None), Error::<Test>::UnknownMethodology);
+		MethodologyRequired::set(false);
+	});
+}
+
+#[test]
+fn on_idle_removes_residual_records() {
+	new_test_ext().execute_with(|| {
+		use frame_support::{traits::{Hooks, ReservableCurrency}, weights::Weight};
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		// metadata of a destroyed asset, without and with a deposit
+		Metadata::<Test>::insert(ONE_ID, AssetMetadata::default());
+		let held = AssetId::new([2; 24]);
+		Metadata::<Test>::insert(held, AssetMetadata { deposit: 3, ..Default::default() });
+		// approval of zero amount
+		assert_ok!(Balances::reserve(&1, 1));
+		Approvals::<Test>::insert((ZERO_ID, 1, 2), Approval { amount: 0, deposit: 1, expiry: None });
+		// empty asset-accounts without and with a deposit
+		Account::<Test>::insert(ZERO_ID, 3, AssetAccount {
+			balance: 0,
+			is_frozen: false,
+			frozen: 0,
+			reason: ExistenceReason::DepositRefunded,
+			extra: (),
+		});
+		AccountFreezeReason::<Test>::insert(ZERO_ID, 3, BoundedVec::try_from(b"audit".to_vec()).unwrap());
+		Account::<Test>::insert(ZERO_ID, 4, AssetAccount {
+			balance: 0,
+			is_frozen: false,
+			frozen: 0,
+			reason: ExistenceReason::DepositHeld(1),
+			extra: (),
+		});
+		Asset::<Test>::mutate(ZERO_ID, |details| {
+			let details = details.as_mut().unwrap();
+			details.approvals += 1;
+			details.accounts += 2;
+		});
+
+		// not enough weight for a single record
+		assert_eq!(<Assets as Hooks<u64>>::on_idle(1, Weight::from_ref_time(1)), Weight::zero());
+
+		assert!(<Assets as Hooks<u64>>::on_idle(1, Weight::from_ref_time(u64::MAX)).ref_time() > 0);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::HousekeepingDone {
+			metadata: 1,
+			approvals: 1,
+			accounts: 1,
+		}));
+		assert!(!Metadata::<Test>::contains_key(ONE_ID));
+		assert_eq!(Metadata::<Test>::get(held).deposit, 3);
+		assert!(Approvals::<Test>::get((ZERO_ID, 1, 2)).is_none());
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert!(Account::<Test>::get(ZERO_ID, 3).is_none());
+		assert!(AccountFreezeReason::<Test>::get(ZERO_ID, 3).is_none());
+		assert!(Account::<Test>::get(ZERO_ID, 4).is_some());
+		let details = Asset::<Test>::get(ZERO_ID).unwrap();
+		assert_eq!((details.approvals, details.accounts), (0, 1));
+		assert_eq!(
+			Assets::housekeeping_totals(),
+			HousekeepingReport { metadata: 1, approvals: 1, accounts: 1 }
+		);
+	});
+}
+
+#[test]
+fn transfer_with_splits_pays_asset_splits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 1000, None));
+
+		// only the custodian sets the splits
+		let splits = vec![(2, Perbill::from_percent(5)), (3, Perbill::from_percent(1))];
+		assert_noop!(
+			Assets::set_asset_splits(RuntimeOrigin::signed(4), ZERO_ID, splits.clone()),
+			Error::<Test>::NotAssetCustodian
+		);
+		assert_noop!(
+			Assets::set_asset_splits(
+				RuntimeOrigin::signed(1),
+				ZERO_ID,
+				vec![(2, Perbill::from_percent(60)), (3, Perbill::from_percent(50))]
+			),
+			Error::<Test>::BadSplits
+		);
+		assert_noop!(
+			Assets::set_asset_splits(
+				RuntimeOrigin::signed(1),
+				ZERO_ID,
+				vec![(2, Perbill::from_percent(5)), (2, Perbill::from_percent(1))]
+			),
+			Error::<Test>::BadSplits
+		);
+		assert_noop!(
+			Assets::set_asset_splits(
+				RuntimeOrigin::signed(1),
+				ZERO_ID,
+				vec![(2, Perbill::zero()), (3, Perbill::zero()), (5, Perbill::zero())]
+			),
+			Error::<Test>::BadSplits
+		);
+		assert_ok!(Assets::set_asset_splits(RuntimeOrigin::signed(1), ZERO_ID, splits.clone()));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AssetSplitsSet {
+			asset_id: ZERO_ID,
+			splits: splits.clone(),
+		}));
+
+		// the sender must agree to the configured splits
+		assert_noop!(
+			Assets::transfer_with_splits(RuntimeOrigin::signed(1), ZERO_ID, 4, 100, vec![]),
+			Error::<Test>::SplitsMismatch
+		);
+		assert_ok!(Assets::transfer_with_splits(
+			RuntimeOrigin::signed(1),
+			ZERO_ID,
+			4,
+			100,
+			splits.clone()
+		));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::TransferredWithSplits {
+			asset_id: ZERO_ID,
+			from: 1,
+			to: 4,
+			amount: 94,
+			splits: vec![(2, 5), (3, 1)],
+		}));
+		assert_eq!(Assets::balance(ZERO_ID, 1), 900);
+		assert_eq!(Assets::balance(ZERO_ID, 2), 5);
+		assert_eq!(Assets::balance(ZERO_ID, 3), 1);
+		assert_eq!(Assets::balance(ZERO_ID, 4), 94);
+
+		// the whole transfer fails if any payment fails
+		assert_noop!(
+			Assets::transfer_with_splits(RuntimeOrigin::signed(1), ZERO_ID, 4, 1000, splits),
+			Error::<Test>::BalanceLow
+		);
+
+		// empty splits remove the config
+		assert_ok!(Assets::set_asset_splits(RuntimeOrigin::signed(1), ZERO_ID, vec![]));
+		assert!(Assets::asset_splits(ZERO_ID).is_empty());
+		assert_ok!(Assets::transfer_with_splits(RuntimeOrigin::signed(1), ZERO_ID, 4, 10, vec![]));
+		assert_eq!(Assets::balance(ZERO_ID, 4), 104);
+	});
+}
+
+#[test]
+fn destroy_preview_matches_accepted_witness() {
+	use frame_support::weights::IdentityFee;
+
+	new_test_ext().execute_with(|| {
+		assert!(Assets::destroy_preview::<IdentityFee<u64>>(ZERO_ID).is_none());
+
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 10));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 3, 5));
+
+		let preview = Assets::destroy_preview::<IdentityFee<u64>>(ZERO_ID).unwrap();
+		let details = Asset::<Test>::get(ZERO_ID).unwrap();
+		assert_eq!(preview.witness, details.destroy_witness());
+		assert_eq!(preview.weight, <() as WeightInfo>::destroy(0, 2, 1));
+		assert_eq!(preview.fee, preview.weight.ref_time());
+
+		assert_ok!(Assets::destroy(RuntimeOrigin::signed(1), ZERO_ID, preview.witness));
+		assert!(Asset::<Test>::get(ZERO_ID).is_none());
+	});
+}
+
+#[test]
+fn asset_custodian_overrides_global_custodian() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let regional = 7;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		assert_noop!(Assets::set_asset_custodian(RuntimeOrigin::signed(CUSTODIAN), id, Some(regional)), BadOrigin);
+		assert_noop!(
+			Assets::set_asset_custodian(RuntimeOrigin::root(), ONE_ID, Some(regional)),
+			Error::<Test>::Unknown
+		);
+		assert_ok!(Assets::set_asset_custodian(RuntimeOrigin::root(), id, Some(regional)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AssetCustodianProposed {
+			asset_id: id,
+			custodian: Some(regional),
+			activates_at: 6,
+		}));
+		// the custody changes only once the proposed custodian accepts it after the delay
+		assert_eq!(Assets::asset_custodian(id), None);
+		assert_noop!(
+			Assets::accept_asset_custodian(RuntimeOrigin::signed(regional), id),
+			Error::<Test>::CustodianChangeNotDue
+		);
+		System::set_block_number(6);
+		assert_noop!(
+			Assets::accept_asset_custodian(RuntimeOrigin::signed(CUSTODIAN), id),
+			Error::<Test>::NotProposedCustodian
+		);
+		assert_ok!(Assets::accept_asset_custodian(RuntimeOrigin::signed(regional), id));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AssetCustodianSet {
+			asset_id: id,
+			custodian: Some(regional),
+		}));
+		assert_eq!(Assets::pending_asset_custodian(id), None);
+		assert_eq!(Assets::asset_custodian(id), Some(regional));
+		let details = Asset::<Test>::get(id).unwrap();
+		assert_eq!((details.issuer, details.admin, details.freezer), (regional, regional, regional));
+
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 10, None), Error::<Test>::NotAssetCustodian);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(regional), id, 10, None));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(regional), id, user, 1, CustodialBurnReason::UserRequest, None));
+		assert_eq!(Assets::balance(id, user), 9);
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 2, None));
+		assert_noop!(
+			Assets::confirm_retirement_registration(RuntimeOrigin::signed(CUSTODIAN), user, id, 2, b"VCS-1".to_vec()),
+			Error::<Test>::NotAssetCustodian
+		);
+		assert_ok!(Assets::confirm_retirement_registration(
+			RuntimeOrigin::signed(regional), user, id, 2, b"VCS-1".to_vec()));
+
+		// the global custodian accepts the asset back
+		assert_ok!(Assets::set_asset_custodian(RuntimeOrigin::root(), id, None));
+		System::set_block_number(11);
+		assert_noop!(
+			Assets::accept_asset_custodian(RuntimeOrigin::signed(regional), id),
+			Error::<Test>::NotProposedCustodian
+		);
+		assert_ok!(Assets::accept_asset_custodian(RuntimeOrigin::signed(CUSTODIAN), id));
+		assert_eq!(Assets::asset_custodian(id), None);
+		assert_eq!(Asset::<Test>::get(id).unwrap().issuer, CUSTODIAN);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(regional), id, 10, None), Error::<Test>::NotAssetCustodian);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 10, None));
+	});
+}
+
+#[test]
+fn deposit_totals_follow_reserves() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&1).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 100, None));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), id, 3, 50));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), id));
+		assert_ok!(Assets::set_attribute(RuntimeOrigin::signed(1), id, b"vintage".to_vec(), b"2022".to_vec()));
+
+		for (purpose, amount) in [
+			(DepositPurpose::Asset, 1),
+			(DepositPurpose::Metadata, 11),
+			(DepositPurpose::Approval, 1),
+			(DepositPurpose::Account, 10),
+			(DepositPurpose::Attribute, 12),
+		] {
+			assert_eq!(Assets::total_deposits(purpose), amount);
+			assert_eq!(Assets::asset_deposits(id, purpose), amount);
+		}
+		assert_eq!(Balances::reserved_balance(&1) + Balances::reserved_balance(&2), 35);
+
+		assert_ok!(Assets::cancel_approval(RuntimeOrigin::signed(1), id, 3));
+		assert_eq!(Assets::total_deposits(DepositPurpose::Approval), 0);
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(2), id, false));
+		assert_eq!(Assets::total_deposits(DepositPurpose::Account), 0);
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), id));
+
+		// destroy returns every deposit but the one of the touched account
+		let w = Asset::<Test>::get(id).unwrap().destroy_witness();
+		assert_ok!(Assets::destroy(RuntimeOrigin::signed(1), id, w));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 10);
+		assert_eq!(
+			AssetDeposits::<Test>::iter_prefix(id).collect::<Vec<_>>(),
+			vec![(DepositPurpose::Account, 10)]
+		);
+		assert_eq!(Assets::total_deposits(DepositPurpose::Asset), 0);
+		assert_eq!(Assets::total_deposits(DepositPurpose::Account), 10);
+
+		assert_ok!(Assets::set_organization(RuntimeOrigin::signed(1), b"Registry".to_vec()));
+		assert_eq!(Assets::total_deposits(DepositPurpose::Organization), 9);
+		assert_ok!(Assets::clear_organization(RuntimeOrigin::signed(1)));
+		assert_eq!(Assets::total_deposits(DepositPurpose::Organization), 0);
+	});
+}
+
+#[test]
+fn mint_requests_are_approved_or_rejected_by_custodian() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		assert_noop!(
+			Assets::request_mint(RuntimeOrigin::signed(3), id, 10, CID_V0.as_bytes().to_vec()),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::request_mint(RuntimeOrigin::signed(user), id, 10, b"4h6g".to_vec()),
+			Error::<Test>::BadCid
+		);
+		let reserved = Balances::reserved_balance(&user);
+		assert_ok!(Assets::request_mint(RuntimeOrigin::signed(user), id, 10, CID_V0.as_bytes().to_vec()));
+		assert_eq!(Balances::reserved_balance(&user), reserved + 47);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::MintRequested {
+			request_id: 0,
+			asset_id: id,
+			owner: user,
+			amount: 10,
+		}));
+		assert_ok!(Assets::request_mint(RuntimeOrigin::signed(user), id, 20, CID_V1.as_bytes().to_vec()));
+		let request = Assets::mint_request(1).unwrap();
+		assert_eq!((request.asset_id, request.amount, request.requested_at), (id, 20, 1));
+		assert_eq!(request.evidence.to_vec(), CID_V1.as_bytes().to_vec());
+		assert_eq!(request.deposit, 50);
+		assert_eq!(Assets::total_deposits(DepositPurpose::MintRequest), 97);
+		assert_noop!(
+			Assets::request_mint(RuntimeOrigin::signed(user), id, 30, CID_V0.as_bytes().to_vec()),
+			Error::<Test>::TooManyMintRequests
+		);
+
+		assert_noop!(Assets::approve_mint(RuntimeOrigin::signed(user), 0), Error::<Test>::NotAssetCustodian);
+		assert_ok!(Assets::approve_mint(RuntimeOrigin::signed(CUSTODIAN), 0));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::MintRequestApproved {
+			request_id: 0,
+			asset_id: id,
+			custodian: CUSTODIAN,
+		}));
+		assert_eq!(Assets::balance(id, user), 10);
+		assert_eq!(Balances::reserved_balance(&user), reserved + 50);
+		assert_noop!(Assets::approve_mint(RuntimeOrigin::signed(CUSTODIAN), 0), Error::<Test>::UnknownMintRequest);
+
+		assert_noop!(
+			Assets::reject_mint(RuntimeOrigin::signed(user), 1, b"no evidence".to_vec()),
+			Error::<Test>::NotAssetCustodian
+		);
+		assert_ok!(Assets::reject_mint(RuntimeOrigin::signed(CUSTODIAN), 1, b"no evidence".to_vec()));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::MintRequestRejected {
+			request_id: 1,
+			asset_id: id,
+			reason: b"no evidence".to_vec(),
+		}));
+		assert_eq!(Assets::mint_request(1), None);
+		assert_eq!(Assets::balance(id, user), 10);
+		assert_eq!(Balances::reserved_balance(&user), reserved);
+
+		assert_ok!(Assets::request_mint(RuntimeOrigin::signed(user), id, 30, CID_V0.as_bytes().to_vec()));
+		let w = Asset::<Test>::get(id).unwrap().destroy_witness();
+		assert_ok!(Assets::destroy(RuntimeOrigin::root(), id, w));
+		assert_eq!(Assets::mint_request(2), None);
+		assert_eq!(Assets::total_deposits(DepositPurpose::MintRequest), 0);
+	});
+}
+
+#[test]
+fn aliases_are_unique_and_named_in_retirements() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&5, 1000);
+		assert_noop!(Assets::clear_alias(RuntimeOrigin::signed(user)), Error::<Test>::NoAlias);
+		assert_noop!(Assets::set_alias(RuntimeOrigin::signed(user), vec![]), Error::<Test>::BadMetadata);
+
+		assert_ok!(Assets::set_alias(RuntimeOrigin::signed(user), b"Acme".to_vec()));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AliasSet {
+			account: user,
+			alias: b"Acme".to_vec(),
+		}));
+		assert_eq!(Balances::reserved_balance(&user), 5);
+		let alias: BoundedVec<u8, <Test as Config>::StringLimit> = b"Acme".to_vec().try_into().unwrap();
+		assert_eq!(Assets::alias_account(alias.clone()), Some(user));
+		assert_noop!(
+			Assets::set_alias(RuntimeOrigin::signed(5), b"Acme".to_vec()),
+			Error::<Test>::AliasTaken
+		);
+
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::CarbonCreditsBurned {
+			account: user,
+			asset_id: id,
+			amount: 100,
+			kind: BurnKind::SelfRetirement,
+			initiator: user,
+			alias: Some(alias),
+			certificate_id: 0,
+		}));
+
+		// renaming releases the old alias
+		assert_ok!(Assets::set_alias(RuntimeOrigin::signed(user), b"Acme Ltd".to_vec()));
+		assert_eq!(Balances::reserved_balance(&user), 9 + 12);
+		assert_ok!(Assets::set_alias(RuntimeOrigin::signed(5), b"Acme".to_vec()));
+
+		assert_ok!(Assets::clear_alias(RuntimeOrigin::signed(user)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AliasCleared { account: user }));
+		assert_eq!(Balances::reserved_balance(&user), 12);
+		assert!(Assets::account_alias(user).is_none());
+		assert_eq!(Assets::total_deposits(DepositPurpose::Alias), 5);
+	});
+}
+
+#[test]
+fn retire_stores_retirement_certificate() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 10, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 1, None));
+
+		assert_noop!(
+			Assets::retire(RuntimeOrigin::signed(user), id, 2, vec![b'a'; 51], b"Offset".to_vec(), 1_700_000_000),
+			Error::<Test>::BadMetadata
+		);
+		assert_ok!(Assets::retire(
+			RuntimeOrigin::signed(user),
+			id,
+			2,
+			b"ACME Corp".to_vec(),
+			b"2026 flights".to_vec(),
+			1_700_000_000
+		));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::CarbonCreditsBurned {
+			account: user,
+			asset_id: id,
+			amount: 2,
+			kind: BurnKind::SelfRetirement,
+			initiator: user,
+			alias: None,
+			certificate_id: 1,
+		}));
+		assert_eq!(Assets::balance(id, user), 7);
+		assert_eq!(BurnCertificate::<Test>::get(user, id).map(|c| c.amount), Some(3));
+		assert_eq!(Assets::retirement_record(1).map(|r| r.amount), Some(2));
+		let certificate = Assets::retirement_certificate(1).unwrap();
+		assert_eq!(certificate.beneficiary_name.to_vec(), b"ACME Corp".to_vec());
+		assert_eq!(certificate.reason.to_vec(), b"2026 flights".to_vec());
+		assert_eq!(certificate.retirement_date, 1_700_000_000);
+		assert_eq!(Assets::retirement_certificate(0), None);
+	});
+}
+
+#[test]
+fn project_details_are_set_with_project_data() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		assert_ok!(Assets::create_with_vintage(
+			RuntimeOrigin::signed(user),
+			b"Token".to_vec(),
+			b"TKN".to_vec(),
+			2021
+		));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		let details = ProjectDetails {
+			standard: RegistryStandard::Verra,
+			methodology_code: b"VM0007".to_vec(),
+			vintage: 2021,
+			country: *b"KZ",
+			project_id: b"VCS-1234".to_vec(),
+		};
+
+		for bad in [
+			ProjectDetails { country: *b"kz", ..details.clone() },
+			ProjectDetails { project_id: vec![], ..details.clone() },
+			ProjectDetails { vintage: 2020, ..details.clone() },
+		] {
+			assert_noop!(
+				Assets::set_project_data(RuntimeOrigin::signed(user), id, b"http".to_vec(), CID_V0.as_bytes().to_vec(), Some(bad)),
+				Error::<Test>::BadProjectDetails
+			);
+		}
+		assert_ok!(Assets::set_project_data(
+			RuntimeOrigin::signed(user),
+			id,
+			b"http".to_vec(),
+			CID_V0.as_bytes().to_vec(),
+			Some(details)
+		));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ProjectDetailsSet { asset_id: id }));
+		let stored = Assets::project_details(id).unwrap();
+		assert_eq!((stored.standard, stored.vintage, stored.country), (RegistryStandard::Verra, 2021, *b"KZ"));
+		assert_eq!(stored.methodology_code.to_vec(), b"VM0007".to_vec());
+		assert_eq!(stored.project_id.to_vec(), b"VCS-1234".to_vec());
+
+		// project data without details keeps them
+		assert_ok!(Assets::set_project_data(RuntimeOrigin::signed(user), id, b"https".to_vec(), CID_V0.as_bytes().to_vec(), None));
+		assert!(Assets::project_details(id).is_some());
+	});
+}
+
+#[test]
+fn metadata_types_are_stable() {
+	use frame_support::metadata::{RuntimeMetadata, StorageEntryType};
+	use scale_info::{TypeDef, TypeDefPrimitive};
+
+	let metadata = match Test::metadata().1 {
+		RuntimeMetadata::V14(metadata) => metadata,
+		_ => panic!("unexpected metadata version"),
+	};
+	let pallet = metadata.pallets.iter().find(|p| p.name == "Assets").unwrap();
+
+	// `AssetId` is the `CarbonAssetId` newtype of a plain `[u8; 24]` in every storage key, call
+	// and event, so it decodes as the bare bytes
+	let asset = pallet.storage.as_ref().unwrap().entries.iter().find(|e| e.name == "Asset").unwrap();
+	let key = match &asset.ty {
+		StorageEntryType::Map { key, .. } => metadata.types.resolve(key.id()).unwrap(),
+		_ => panic!("`Asset` is not a map"),
+	};
+	assert_eq!(key.path().segments(), ["pallet_carbon_assets", "primitives", "CarbonAssetId"]);
+	let bytes = match key.type_def() {
+		TypeDef::Composite(composite) if composite.fields().len() == 1 =>
+			metadata.types.resolve(composite.fields()[0].ty().id()).unwrap(),
+		_ => panic!("`AssetId` is not a newtype"),
+	};
+	match bytes.type_def() {
+		TypeDef::Array(array) => {
+			assert_eq!(array.len(), 24);
+			assert!(matches!(
+				metadata.types.resolve(array.type_param().id()).unwrap().type_def(),
+				TypeDef::Primitive(TypeDefPrimitive::U8)
+			));
+		},
+		_ => panic!("`AssetId` is not an array"),
+	}
+
+	// the pallet types keep their names, so clients decode them as named composites
+	for name in ["DestroyWitness", "BurnKind", "CustodialBurnReason", "DepositPurpose", "InterfaceVersion"] {
+		assert!(
+			metadata.types.types().iter().any(|t| t.ty().path().segments() ==
+				["pallet_carbon_assets", "types", name]),
+			"{} is not registered as pallet_carbon_assets::types::{}",
+			name,
+			name
+		);
+	}
+
+	for constant in ["Interface", "StringLimit", "MaxMintBatch", "MaxSplits"] {
+		assert!(pallet.constants.iter().any(|c| c.name == constant), "no {} constant", constant);
+	}
+}
+
+#[test]
+fn staged_destroy_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&4, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&1).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 100, None));
+		for who in 2..=3 {
+			Balances::make_free_balance_be(&who, 10);
+			assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), id, who, 10));
+		}
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(4), id));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), id, 2, 5));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), id, 3, 5));
+		assert_ok!(Assets::set_attribute(RuntimeOrigin::signed(1), id, b"vintage".to_vec(), b"2022".to_vec()));
+
+		assert_noop!(Assets::destroy_accounts(RuntimeOrigin::signed(5), id), Error::<Test>::IncorrectStatus);
+		assert_noop!(Assets::start_destroy(RuntimeOrigin::signed(2), id), Error::<Test>::NoPermission);
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), id));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DestructionStarted { asset_id: id }));
+		assert_noop!(Assets::start_destroy(RuntimeOrigin::signed(1), id), Error::<Test>::AssetNotLive);
+
+		// the asset can no longer be used
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), id, 100, None), Error::<Test>::AssetNotLive);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(2), id, 3, 5), Error::<Test>::AssetNotLive);
+		assert_noop!(Assets::approve_transfer(RuntimeOrigin::signed(1), id, 5, 5), Error::<Test>::AssetNotLive);
+		assert_noop!(Assets::thaw_asset(RuntimeOrigin::signed(1), id), Error::<Test>::AssetNotLive);
+		assert_noop!(Assets::finish_destroy(RuntimeOrigin::signed(5), id), Error::<Test>::InUse);
+
+		// at most `RemoveItemsLimit` accounts per call
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(5), id));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AccountsDestroyed {
+			asset_id: id,
+			accounts_destroyed: 2,
+			accounts_remaining: 2,
+		}));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(5), id));
+		assert_eq!(Account::<Test>::iter_prefix(id).count(), 0);
+		// the deposit of the touched account is returned
+		assert_eq!(Balances::reserved_balance(&4), 0);
+
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(5), id));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovalsDestroyed {
+			asset_id: id,
+			approvals_destroyed: 2,
+			approvals_remaining: 0,
+		}));
+		// attributes go after the approvals
+		assert_noop!(Assets::finish_destroy(RuntimeOrigin::signed(5), id), Error::<Test>::InUse);
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(5), id));
+
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(5), id));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Destroyed { asset_id: id }));
+		assert!(!Asset::<Test>::contains_key(id));
+		assert!(!Metadata::<Test>::contains_key(id));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(AssetDeposits::<Test>::iter_prefix(id).count(), 0);
+	});
+}
+
+#[test]
+fn sponsorship_pot_pays_retirement_fees() {
+	use frame_support::dispatch::Pays;
+
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), b"Token".to_vec(), b"TKN".to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 10, None));
+		assert_eq!(Assets::self_burn(RuntimeOrigin::signed(user), id, 1, None).unwrap().pays_fee, Pays::Yes);
+
+		Balances::make_free_balance_be(&CUSTODIAN, 100);
+		let (reserved, total) = (Balances::reserved_balance(&CUSTODIAN), Balances::total_balance(&CUSTODIAN));
+		assert_noop!(Assets::fund_sponsorship_pot(RuntimeOrigin::signed(3), id, 3), Error::<Test>::NotAssetCustodian);
+		assert_ok!(Assets::fund_sponsorship_pot(RuntimeOrigin::signed(CUSTODIAN), id, 3));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::SponsorshipPotFunded {
+			asset_id: id,
+			funder: CUSTODIAN,
+			amount: 3,
+		}));
+		assert_eq!(Balances::reserved_balance(&CUSTODIAN), reserved + 3);
+		assert_noop!(Assets::fund_sponsorship_pot(RuntimeOrigin::signed(user), id, 3), Error::<Test>::NotPotFunder);
+
+		// the pot pays the fee of 2 once
+		assert_eq!(Assets::self_burn(RuntimeOrigin::signed(user), id, 1, None).unwrap().pays_fee, Pays::No);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RetirementSponsored { asset_id: id, who: user, fee: 2 }));
+		assert_eq!(Assets::sponsorship_pot(id).map(|pot| pot.amount), Some(1));
+		assert_eq!(Balances::reserved_balance(&CUSTODIAN), reserved + 1);
+		assert_eq!(Balances::total_balance(&CUSTODIAN), total - 2);
+		assert_eq!(Balances::free_balance(&999), 2);
+		assert_eq!(Assets::total_deposits(DepositPurpose::SponsorshipPot), 1);
+		assert_eq!(Assets::self_burn(RuntimeOrigin::signed(user), id, 1, None).unwrap().pays_fee, Pays::Yes);
+
+		// one sponsored retirement of an account every `SponsoredRetirementInterval` blocks
+		assert_ok!(Assets::fund_sponsorship_pot(RuntimeOrigin::signed(CUSTODIAN), id, 4));
+		assert_eq!(Assets::self_burn(RuntimeOrigin::signed(user), id, 1, None).unwrap().pays_fee, Pays::Yes);
+		System::set_block_number(11);
+		assert_eq!(Assets::self_burn(RuntimeOrigin::signed(user), id, 1, None).unwrap().pays_fee, Pays::No);
+		assert_eq!(Assets::sponsorship_pot(id).map(|pot| pot.amount), Some(3));
+
+		assert_noop!(Assets::withdraw_sponsorship_pot(RuntimeOrigin::signed(user), id), Error::<Test>::NotPotFunder);
+		assert_ok!(Assets::withdraw_sponsorship_pot(RuntimeOrigin::signed(CUSTODIAN), id));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::SponsorshipPotWithdrawn {
+			asset_id: id,
+			funder: CUSTODIAN,
+			amount: 3,
+		}));
+		assert_eq!(Balances::reserved_balance(&CUSTODIAN), reserved);
+		assert_eq!(Assets::total_deposits(DepositPurpose::SponsorshipPot), 0);
+		assert_noop!(
+			Assets::withdraw_sponsorship_pot(RuntimeOrigin::signed(CUSTODIAN), id),
+			Error::<Test>::NoSponsorshipPot
+		);
+	});
+}
+
+#[test]
+fn sponsorship_pot_skips_dust_retirements_and_is_returned_on_destroy() {
+	use frame_support::dispatch::Pays;
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 50));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 200, None));
+		let reserved = Balances::reserved_balance(&1);
+		assert_ok!(Assets::fund_sponsorship_pot(RuntimeOrigin::signed(1), ZERO_ID, 10));
+
+		// less than the minimum balance of the asset is not sponsored
+		assert_eq!(Assets::self_burn(RuntimeOrigin::signed(1), ZERO_ID, 10, None).unwrap().pays_fee, Pays::Yes);
+		assert_eq!(Assets::sponsorship_pot(ZERO_ID).map(|pot| pot.amount), Some(10));
+		assert_eq!(Assets::self_burn(RuntimeOrigin::signed(1), ZERO_ID, 50, None).unwrap().pays_fee, Pays::No);
+		assert_eq!(Assets::sponsorship_pot(ZERO_ID).map(|pot| pot.amount), Some(8));
+
+		let w = Asset::<Test>::get(ZERO_ID).unwrap().destroy_witness();
+		assert_ok!(Assets::destroy(RuntimeOrigin::signed(1), ZERO_ID, w));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::SponsorshipPotWithdrawn {
+			asset_id: ZERO_ID,
+			funder: 1,
+			amount: 8,
+		}));
+		assert_eq!(Assets::sponsorship_pot(ZERO_ID), None);
+		assert_eq!(Balances::reserved_balance(&1), reserved);
+		assert_eq!(Assets::total_deposits(DepositPurpose::SponsorshipPot), 0);
+	});
+}
+
+#[test]
+fn fungibles_destroy_goes_through_staged_destroy() {
+	use frame_support::traits::tokens::fungibles::Destroy;
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&4, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&1).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, 100, None));
+		Balances::make_free_balance_be(&2, 10);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), id, 2, 10));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(4), id));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), id, 2, 5));
+		assert_ok!(Assets::set_attribute(RuntimeOrigin::signed(1), id, b"vintage".to_vec(), b"2022".to_vec()));
+
+		let witness = <Assets as Destroy<u64>>::get_destroy_witness(&id).unwrap();
+		assert_eq!((witness.accounts, witness.approvals), (3, 1));
+		assert_noop!(
+			<Assets as Destroy<u64>>::destroy(id, DestroyWitness { accounts: 2, ..witness }, Some(1)),
+			Error::<Test>::BadWitness
+		);
+		assert_noop!(<Assets as Destroy<u64>>::destroy(id, witness, Some(2)), Error::<Test>::NoPermission);
+
+		// the co-owner cannot confirm a destruction by another pallet
+		assert_ok!(Assets::set_co_owner(RuntimeOrigin::signed(1), id, Some(5)));
+		assert_noop!(<Assets as Destroy<u64>>::destroy(id, witness, Some(1)), Error::<Test>::NoPermission);
+
+		assert_eq!(<Assets as Destroy<u64>>::destroy(id, witness, None), Ok(witness));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::DestructionStarted { asset_id: id }));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::AccountsDestroyed {
+			asset_id: id,
+			accounts_destroyed: 3,
+			accounts_remaining: 0,
+		}));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Destroyed { asset_id: id }));
+		assert!(!Asset::<Test>::contains_key(id));
+		assert_eq!(Account::<Test>::iter_prefix(id).count(), 0);
+		assert_eq!(Attributes::<Test>::iter_prefix(id).count(), 0);
+		// all the deposits are returned
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&4), 0);
+	});
+}
+
+#[test]
+fn max_assets_per_account_limits_create() {
+	new_test_ext().execute_with(|| {
+		MaxAssetsPerAccount::set(2);
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&CUSTODIAN, 1000);
+		for _ in 0..2 {
+			assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		}
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_eq!(Assets::owned_asset_count(user), 2);
+		assert_noop!(
+			Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()),
+			Error::<Test>::TooManyOwnedAssets
+		);
+
+		// the custodian is exempt
+		for _ in 0..3 {
+			assert_ok!(Assets::create(RuntimeOrigin::signed(CUSTODIAN), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		}
+		assert_eq!(Assets::owned_asset_count(CUSTODIAN), 3);
+
+		// the index follows the ownership transfers
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(user), id, 5));
+		assert_ok!(Assets::accept_ownership(RuntimeOrigin::signed(5), id));
+		assert_eq!(Assets::owned_asset_count(user), 1);
+		assert!(OwnedAssets::<Test>::contains_key(5, id));
+		assert!(!OwnedAssets::<Test>::contains_key(user, id));
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+
+		// and the destruction of the asset
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(5), id));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(5), id));
+		assert_eq!(Assets::owned_asset_count(5), 0);
+		assert!(!OwnedAssets::<Test>::contains_key(5, id));
+		MaxAssetsPerAccount::set(100);
+	});
+}
+
+#[test]
+fn expiring_approvals_should_work() {
+	use frame_support::traits::tokens::fungibles::approvals::Inspect;
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+
+		assert_noop!(
+			Assets::approve_transfer_with_expiry(RuntimeOrigin::signed(1), ZERO_ID, 2, 50, 1),
+			Error::<Test>::ApprovalExpired
+		);
+		assert_ok!(Assets::approve_transfer_with_expiry(RuntimeOrigin::signed(1), ZERO_ID, 2, 50, 10));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovalExpirySet {
+			asset_id: ZERO_ID,
+			owner: 1,
+			delegate: 2,
+			expiry: 10,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_noop!(
+			Assets::clean_expired_approval(RuntimeOrigin::signed(3), ZERO_ID, 1, 2),
+			Error::<Test>::ApprovalNotExpired
+		);
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), ZERO_ID, 1, 3, 20));
+		assert_eq!(Assets::allowance(ZERO_ID, &1, &2), 30);
+
+		// the approval can no longer be used from the expiry block on
+		System::set_block_number(10);
+		assert_eq!(Assets::allowance(ZERO_ID, &1, &2), 0);
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(2), ZERO_ID, 1, 3, 20),
+			Error::<Test>::ApprovalExpired
+		);
+
+		// anyone reclaims the deposit for the owner
+		assert_ok!(Assets::clean_expired_approval(RuntimeOrigin::signed(3), ZERO_ID, 1, 2));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovalCancelled {
+			asset_id: ZERO_ID,
+			owner: 1,
+			delegate: 2,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().approvals, 0);
+		assert_noop!(
+			Assets::clean_expired_approval(RuntimeOrigin::signed(3), ZERO_ID, 1, 2),
+			Error::<Test>::Unapproved
+		);
+
+		// a plain approval never expires, and an expired amount is not topped up
+		assert_ok!(Assets::approve_transfer_with_expiry(RuntimeOrigin::signed(1), ZERO_ID, 2, 50, 20));
+		System::set_block_number(20);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 10));
+		System::set_block_number(1000);
+		assert_eq!(Assets::allowance(ZERO_ID, &1, &2), 10);
+	});
+}
+
+#[test]
+fn verifiers_attest_co_benefit_badges() {
+	new_test_ext().execute_with(|| {
+		let badges = sdg_badge(13) | sdg_badge(15) | BIODIVERSITY_BADGE;
+		assert_noop!(
+			Assets::set_asset_badges(RuntimeOrigin::signed(1), PREEXIST_ASSET, badges, None),
+			Error::<Test>::NotVerifier
+		);
+		assert_noop!(
+			Assets::set_asset_badges(RuntimeOrigin::signed(VERIFIER), ZERO_ID, badges, None),
+			Error::<Test>::Unknown
+		);
+		assert_noop!(
+			Assets::set_asset_badges(RuntimeOrigin::signed(VERIFIER), PREEXIST_ASSET, 1 << 20, None),
+			Error::<Test>::BadBadges
+		);
+		assert_noop!(
+			Assets::set_asset_badges(
+				RuntimeOrigin::signed(VERIFIER),
+				PREEXIST_ASSET,
+				badges,
+				Some(b"not a cid".to_vec())
+			),
+			Error::<Test>::BadCid
+		);
+
+		assert_ok!(Assets::set_asset_badges(
+			RuntimeOrigin::signed(VERIFIER),
+			PREEXIST_ASSET,
+			badges,
+			Some(CID_V0.as_bytes().to_vec())
+		));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AssetBadgesSet {
+			asset_id: PREEXIST_ASSET,
+			badges,
+			verifier: VERIFIER,
+		}));
+		assert_eq!(
+			Assets::attested_badges(PREEXIST_ASSET),
+			Some(CoBenefitBadges {
+				badges,
+				evidence: Some(CID_V0.as_bytes().to_vec()),
+				verifier: VERIFIER,
+			})
+		);
+		assert_eq!(Assets::assets_with_badges(sdg_badge(15) | BIODIVERSITY_BADGE), vec![PREEXIST_ASSET]);
+		assert!(Assets::assets_with_badges(sdg_badge(1)).is_empty());
+
+		// zero badges remove them
+		assert_ok!(Assets::set_asset_badges(RuntimeOrigin::signed(VERIFIER), PREEXIST_ASSET, 0, None));
+		assert_eq!(Assets::asset_badges(PREEXIST_ASSET), None);
+		assert_eq!(sdg_badge(0), 0);
+		assert_eq!(sdg_badge(18), 0);
+	});
+}
+
+#[test]
+fn transfer_with_memo_emits_memo() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+
+		let memo: BoundedVec<u8, <Test as Config>::MemoLimit> = b"PO-2024-0042".to_vec().try_into().unwrap();
+		assert_ok!(Assets::transfer_with_memo(RuntimeOrigin::signed(1), ZERO_ID, 2, 40, memo));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::Transferred {
+			asset_id: ZERO_ID,
+			from: 1,
+			to: 2,
+			amount: 40,
+		}));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::TransferredWithMemo {
+			asset_id: ZERO_ID,
+			from: 1,
+			to: 2,
+			amount: 40,
+			memo: b"PO-2024-0042".to_vec(),
+		}));
+		assert_eq!(Assets::balance(ZERO_ID, 1), 60);
+		assert_eq!(Assets::balance(ZERO_ID, 2), 40);
+
+		// the transfer fails as a whole without the memo event
+		assert_noop!(
+			Assets::transfer_with_memo(RuntimeOrigin::signed(2), ZERO_ID, 3, 50, Default::default()),
+			Error::<Test>::BalanceLow
+		);
+	});
+}
+
+#[test]
+fn multi_step_transfers_keep_asset_details_consistent() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 1000, None));
+		let splits = vec![(2, Perbill::from_percent(5)), (3, Perbill::from_percent(1))];
+		assert_ok!(Assets::set_asset_splits(RuntimeOrigin::signed(1), ZERO_ID, splits.clone()));
+
+		// all the payments of the splits update the same details, the sender dies
+		assert_ok!(Assets::transfer_with_splits(RuntimeOrigin::signed(1), ZERO_ID, 4, 1000, splits));
+		let details = Asset::<Test>::get(ZERO_ID).unwrap();
+		assert_eq!((details.accounts, details.sufficients, details.supply), (3, 3, 1000));
+		assert_eq!(take_hooks(), vec![Hook::Died(ZERO_ID, 1)]);
+
+		// the used up approval is removed along with the transfer
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(2), ZERO_ID, 3, 50));
+		assert_eq!(Asset::<Test>::get(ZERO_ID).unwrap().approvals, 1);
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(3), ZERO_ID, 2, 4, 50));
+		let details = Asset::<Test>::get(ZERO_ID).unwrap();
+		assert_eq!((details.accounts, details.approvals), (2, 0));
+		assert!(Approvals::<Test>::get((ZERO_ID, 2, 3)).is_none());
+		assert_eq!(take_hooks(), vec![Hook::Died(ZERO_ID, 2)]);
+		assert_eq!(Assets::balance(ZERO_ID, 4), 990);
+	});
+}
+
+#[test]
+fn screening_rejects_sanctioned_parties() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), ZERO_ID, 100, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 50));
+		set_sanctioned(2);
+
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 10),
+			Error::<Test>::ScreeningRejected(ScreeningRejection::SanctionedReceiver)
+		);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(2), ZERO_ID, 3, 10),
+			Error::<Test>::ScreeningRejected(ScreeningRejection::SanctionedSender)
+		);
+
+		// approved transfers screen the owner of the assets
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(2), ZERO_ID, 3, 10));
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(3), ZERO_ID, 2, 3, 10),
+			Error::<Test>::ScreeningRejected(ScreeningRejection::SanctionedSender)
+		);
+
+		// mints screen the receiver
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ONE_ID, 2, true, 1));
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(2), ONE_ID, 100, None),
+			Error::<Test>::ScreeningRejected(ScreeningRejection::SanctionedReceiver)
+		);
+
+		// the admin can still move the assets out of the sanctioned account
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), ZERO_ID, 2, 1, 50));
+		assert_eq!(Assets::balance(ZERO_ID, 2), 0);
+	});
+}
+
+#[test]
+fn vintage_supply_is_tracked_per_year() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create_with_vintage(
+			RuntimeOrigin::signed(user),
+			"Token".as_bytes().to_vec(),
+			"Token".as_bytes().to_vec(),
+			2021
+		));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::AssetVintageSet {
+			asset_id: id,
+			vintage: 2021,
+		}));
+		assert_eq!(Assets::asset_vintage(id), Some(2021));
+
+		// an asset without a vintage does not count
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let other = Assets::get_current_asset_id(&user).unwrap();
+		assert_eq!(Assets::asset_vintage(other), None);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), other, 300, None));
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), other, 100, None));
+		assert_eq!(Assets::vintage_supply(2021), VintageTotals { issued: 500, retired: 100 });
+		assert_eq!(Assets::vintage_supply(2022), VintageTotals::default());
+	});
+}
+
+#[test]
+fn sub_accounts_retire_within_quota_of_organization() {
+	new_test_ext().execute_with(|| {
+		let (org, sub) = (4, 5);
+		Balances::make_free_balance_be(&org, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(org), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&org).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+
+		// only organization accounts grant quotas
+		assert_noop!(
+			Assets::grant_retirement_quota(RuntimeOrigin::signed(org), sub, id, 30),
+			Error::<Test>::NoOrganization
+		);
+		assert_ok!(Assets::set_organization(RuntimeOrigin::signed(org), b"ACME".to_vec()));
+		assert_ok!(Assets::grant_retirement_quota(RuntimeOrigin::signed(org), sub, id, 30));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RetirementQuotaGranted {
+			organization: org,
+			sub,
+			asset_id: id,
+			per_period: 30,
+		}));
+
+		assert_noop!(
+			Assets::self_burn_from_org(RuntimeOrigin::signed(6), org, id, 10, None),
+			Error::<Test>::NoQuota
+		);
+		assert_ok!(Assets::self_burn_from_org(RuntimeOrigin::signed(sub), org, id, 20, None));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::CarbonCreditsBurned {
+			account: org,
+			asset_id: id,
+			amount: 20,
+			kind: BurnKind::SelfRetirement,
+			initiator: sub,
+			alias: None,
+			certificate_id: 0,
+		}));
+		assert_eq!(Assets::balance(id, org), 480);
+		assert_eq!(BurnCertificate::<Test>::get(org, id).map(|c| c.amount), Some(20));
+		assert_noop!(
+			Assets::self_burn_from_org(RuntimeOrigin::signed(sub), org, id, 11, None),
+			Error::<Test>::QuotaExceeded
+		);
+
+		// the quota renews every period
+		System::set_block_number(10);
+		assert_ok!(Assets::self_burn_from_org(RuntimeOrigin::signed(sub), org, id, 30, None));
+		let quota = Assets::retirement_quota((org, sub, id)).unwrap();
+		assert_eq!((quota.period_start, quota.used), (10, 30));
+
+		// zero revokes the quota
+		assert_ok!(Assets::grant_retirement_quota(RuntimeOrigin::signed(org), sub, id, 0));
+		assert!(Assets::retirement_quota((org, sub, id)).is_none());
+
+		// the dust burned with the amount is charged too
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ZERO_ID, org, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), ZERO_ID, 25, None));
+		assert_ok!(Assets::grant_retirement_quota(RuntimeOrigin::signed(org), sub, ZERO_ID, 24));
+		assert_noop!(
+			Assets::self_burn_from_org(RuntimeOrigin::signed(sub), org, ZERO_ID, 20, None),
+			Error::<Test>::QuotaExceeded
+		);
+		assert_ok!(Assets::grant_retirement_quota(RuntimeOrigin::signed(org), sub, ZERO_ID, 30));
+		assert_ok!(Assets::self_burn_from_org(RuntimeOrigin::signed(sub), org, ZERO_ID, 20, None));
+		assert_eq!(Assets::balance(ZERO_ID, org), 0);
+		assert_eq!(Assets::retirement_quota((org, sub, ZERO_ID)).map(|quota| quota.used), Some(25));
+	});
+}
+
+#[test]
+fn runtime_api_queries_account_holdings() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
+
+		assert_eq!(Assets::account_balances(&user), vec![(id, 400)]);
+		assert_eq!(Assets::account_balances(&1), vec![(PREEXIST_ASSET, 100)]);
+		assert_eq!(Assets::burn_certificates(&user), vec![(id, 100)]);
+		assert!(Assets::burn_certificates(&1).is_empty());
+
+		let details = Assets::asset_details(id).unwrap();
+		assert_eq!((details.owner, details.supply, details.accounts), (user, 400, 1));
+		assert_eq!(Assets::asset_details(ZERO_ID), None);
+	});
+}
+
+#[test]
+fn position_statement_reports_holdings_with_metadata() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&5, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "TKN".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 100, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 5, 50));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(user), id, 5, 30));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(5), id, user, 20));
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(CUSTODIAN), id, 5, None));
+
+		assert_eq!(
+			Assets::position_statement(&user),
+			vec![AssetPosition {
+				asset_id: id,
+				balance: 350,
+				frozen: 0,
+				approvals_out: 30,
+				approvals_in: 20,
+				retired: 100,
+				name: b"Token".to_vec(),
+				symbol: b"TKN".to_vec(),
+				decimals: 9,
+			}]
+		);
+		let position = Assets::position_statement(&5).pop().unwrap();
+		assert_eq!((position.balance, position.frozen, position.retired), (50, 50, 0));
+		assert!(Assets::position_statement(&6).is_empty());
+	});
+}
+
+#[test]
+fn custodian_change_is_time_locked_and_accepted() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Assets::propose_custodian(RuntimeOrigin::signed(CUSTODIAN), 2), BadOrigin);
+		assert_ok!(Assets::propose_custodian(RuntimeOrigin::root(), 2));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::CustodianProposed {
+			custodian: 2,
+			activates_at: 6,
+		}));
+		assert_eq!(Assets::pending_custodian(), Some((2, 6)));
+
+		// the current custodian stays until the proposed one accepts after the delay
+		assert_noop!(
+			Assets::accept_custodian(RuntimeOrigin::signed(3)),
+			Error::<Test>::NotProposedCustodian
+		);
+		assert_noop!(
+			Assets::accept_custodian(RuntimeOrigin::signed(2)),
+			Error::<Test>::CustodianChangeNotDue
+		);
+		System::set_block_number(6);
+		assert_eq!(Assets::get_custodian(), Some(CUSTODIAN));
+		assert_ok!(Assets::accept_custodian(RuntimeOrigin::signed(2)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::CustodianSet { custodian: 2 }));
+		assert_eq!(Assets::get_custodian(), Some(2));
+		assert_eq!(Assets::pending_custodian(), None);
+		assert_noop!(
+			Assets::accept_custodian(RuntimeOrigin::signed(2)),
+			Error::<Test>::NotProposedCustodian
+		);
+	});
+}
+
+#[test]
+fn conservation_check_pinpoints_broken_supply() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		assert_ok!(Assets::create(
+			RuntimeOrigin::signed(user),
+			"Token".as_bytes().to_vec(),
+			"Token".as_bytes().to_vec()
+		));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 5, 30));
+		assert_ok!(Assets::check_supply_conservation());
+
+		// a faulty balance change which forgets the supply
+		Account::<Test>::mutate(id, 5, |account| account.as_mut().unwrap().balance += 5);
+		Assets::note_touched(id);
+		assert_eq!(
+			Assets::check_supply_conservation(),
+			Err(crate::conservation::SupplyMismatch { asset_id: id, supply: 100, accounted: 105 })
+		);
+		// the touched assets are checked once
+		assert_ok!(Assets::check_supply_conservation());
+	});
+}
+
+#[test]
+fn conservation_extension_rejects_faulty_extrinsic() {
+	use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
+
+	new_test_ext().execute_with(|| {
+		Account::<Test>::mutate(PREEXIST_ASSET, 1, |account| account.as_mut().unwrap().balance += 5);
+		Assets::note_touched(PREEXIST_ASSET);
+		assert_eq!(
+			crate::conservation::CheckSupplyConservation::<Test>::post_dispatch(
+				None,
+				&Default::default(),
+				&Default::default(),
+				0,
+				&Ok(()),
+			),
+			Err(InvalidTransaction::Custom(crate::conservation::SUPPLY_NOT_CONSERVED).into())
+		);
+	});
+}
+
+#[test]
+fn ownership_transfer_needs_acceptance() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		Balances::make_free_balance_be(&5, 100);
+		Balances::make_free_balance_be(&6, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(user), id, 5));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OwnershipProposed {
+			asset_id: id,
+			owner: 5,
+			expires_at: 11,
+		}));
+		assert_eq!(Asset::<Test>::get(id).unwrap().owner, user);
+		assert_noop!(Assets::accept_ownership(RuntimeOrigin::signed(6), id), Error::<Test>::NotProposedOwner);
+
+		// the owner can withdraw the proposal
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(user), id, user));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OwnershipProposalCancelled { asset_id: id }));
+		assert_noop!(Assets::accept_ownership(RuntimeOrigin::signed(5), id), Error::<Test>::NotProposedOwner);
+
+		// the proposal lapses after the acceptance period
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(user), id, 5));
+		System::set_block_number(12);
+		assert_noop!(
+			Assets::accept_ownership(RuntimeOrigin::signed(5), id),
+			Error::<Test>::OwnershipProposalExpired
+		);
+
+		assert_ok!(Assets::transfer_ownership(RuntimeOrigin::signed(user), id, 5));
+		assert_ok!(Assets::accept_ownership(RuntimeOrigin::signed(5), id));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OwnerChanged { asset_id: id, owner: 5 }));
+		assert_eq!(Asset::<Test>::get(id).unwrap().owner, 5);
+		assert_eq!(Assets::pending_owner(id), None);
+
+		// the force origin changes the owner at once
+		assert_noop!(Assets::force_transfer_ownership(RuntimeOrigin::signed(5), id, 6), BadOrigin);
+		assert_ok!(Assets::force_transfer_ownership(RuntimeOrigin::root(), id, 6));
+		assert_eq!(Asset::<Test>::get(id).unwrap().owner, 6);
+	});
+}
+
+#[test]
+fn carbon_transfer_hook_mirrors_balance_movements() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		take_transfers();
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 5, 30));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(5), id, 10, None));
+		assert_eq!(
+			take_transfers(),
+			vec![
+				(id, None, Some(user), 100),
+				(id, Some(user), Some(5), 30),
+				(id, Some(5), None, 10),
+			]
+		);
+	});
+}
+
+#[test]
+fn self_burn_signed_is_relayed_once() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let relayer = 5;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+
+		let genesis_hash = System::block_hash(0);
+		let payload = SignedBurnPayload { signer: user, asset_id: id, amount: 100, nonce: 0, genesis_hash, expiry: 10 };
+		let sign = |payload: &SignedBurnPayload<u64, u64, u64, sp_core::H256>| {
+			TestSignature(user, Assets::signed_burn_message(payload))
+		};
+
+		// the bare payload is not the signed message
+		assert_noop!(
+			Assets::self_burn_signed(RuntimeOrigin::signed(relayer), payload.clone(), TestSignature(user, payload.encode())),
+			Error::<Test>::BadSignature
+		);
+
+		let other_chain = SignedBurnPayload { genesis_hash: sp_core::H256::repeat_byte(1), ..payload.clone() };
+		assert_noop!(
+			Assets::self_burn_signed(RuntimeOrigin::signed(relayer), other_chain.clone(), sign(&other_chain)),
+			Error::<Test>::WrongGenesisHash
+		);
+		let skipped = SignedBurnPayload { nonce: 1, ..payload.clone() };
+		assert_noop!(
+			Assets::self_burn_signed(RuntimeOrigin::signed(relayer), skipped.clone(), sign(&skipped)),
+			Error::<Test>::BadNonce
+		);
+
+		assert_ok!(Assets::self_burn_signed(RuntimeOrigin::signed(relayer), payload.clone(), sign(&payload)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::SignedBurnExecuted {
+			signer: user,
+			asset_id: id,
+			amount: 100,
+			nonce: 0,
+			relayer,
+		}));
+		assert_eq!(Assets::balance(id, user), 400);
+		assert_eq!(BurnCertificate::<Test>::get(user, id).map(|c| c.amount), Some(100));
+		assert_eq!(Assets::signed_burn_nonce(user), 1);
+
+		// no replays
+		assert_noop!(
+			Assets::self_burn_signed(RuntimeOrigin::signed(relayer), payload.clone(), sign(&payload)),
+			Error::<Test>::BadNonce
+		);
+		System::set_block_number(11);
+		let expired = SignedBurnPayload { nonce: 1, ..payload };
+		assert_noop!(
+			Assets::self_burn_signed(RuntimeOrigin::signed(relayer), expired.clone(), sign(&expired)),
+			Error::<Test>::PayloadExpired
+		);
+	});
+}
+
+#[test]
+fn asset_ids_enumerate_classes_and_holdings() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::tokens::fungibles::InspectEnumerable;
+
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		let mut all: Vec<AssetId> = Assets::asset_ids().collect();
+		all.sort();
+		let mut expected = vec![PREEXIST_ASSET, id];
+		expected.sort();
+		assert_eq!(all, expected);
+		let mut enumerated: Vec<AssetId> = <Assets as InspectEnumerable<u64>>::asset_ids().collect();
+		enumerated.sort();
+		assert_eq!(enumerated, expected);
+
+		assert_eq!(Assets::account_asset_ids(&user).count(), 0);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_eq!(Assets::account_asset_ids(&user).collect::<Vec<_>>(), vec![id]);
+		assert_eq!(Assets::account_asset_ids(&1).collect::<Vec<_>>(), vec![PREEXIST_ASSET]);
+	});
+}
+
+#[test]
+fn asset_events_are_indexed_by_asset_topic() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&5, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 5, 100));
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 50, None));
+
+		let topic = Assets::asset_topic(id);
+		let indexed: Vec<_> = System::events()
+			.into_iter()
+			.filter(|record| record.topics.contains(&topic))
+			.map(|record| record.event)
+			.collect();
+		assert_eq!(
+			indexed,
+			vec![
+				RuntimeEvent::Assets(crate::Event::Created { asset_id: id, creator: user }),
+				RuntimeEvent::Assets(crate::Event::Issued { asset_id: id, owner: user, total_supply: 500 }),
+				RuntimeEvent::Assets(crate::Event::Transferred { asset_id: id, from: user, to: 5, amount: 100 }),
+				RuntimeEvent::Assets(crate::Event::Burned { asset_id: id, owner: user, balance: 50 }),
+			]
+		);
+		assert_eq!(System::event_topics(topic).len(), 4);
+	});
+}
+
+#[test]
+fn account_assets_index_follows_accounts() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		Balances::make_free_balance_be(&5, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert!(AccountAssets::<Test>::contains_key(user, id));
+
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 5, 500));
+		assert!(!AccountAssets::<Test>::contains_key(user, id));
+		assert_eq!(Assets::account_asset_ids(&5).collect::<Vec<_>>(), vec![id]);
+		assert_eq!(Assets::account_balances(&5), vec![(id, 500)]);
+
+		// the migration rebuilds the index from `Account`
+		AccountAssets::<Test>::remove(5, id);
+		AccountAssets::<Test>::remove(1, PREEXIST_ASSET);
+		StorageVersion::new(4).put::<Assets>();
+		crate::migration::v5::MigrateToV5::<Test>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Assets>(), 5);
+		assert!(AccountAssets::<Test>::contains_key(5, id));
+		assert!(AccountAssets::<Test>::contains_key(1, PREEXIST_ASSET));
+	});
+}
+
+#[test]
+fn retire_from_uses_approval_and_records_beneficiary() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let provider = 5;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(user), id, provider, 60));
+
+		assert_noop!(
+			Assets::retire_from(RuntimeOrigin::signed(provider), id, user, 70, None),
+			Error::<Test>::Unapproved
+		);
+		assert_ok!(Assets::retire_from(RuntimeOrigin::signed(provider), id, user, 40, Some(6)));
+		assert_eq!(Assets::balance(id, user), 460);
+		assert_eq!(Assets::burn_certificates(&6), vec![(id, 40)]);
+		assert!(Assets::burn_certificates(&user).is_empty());
+		assert_eq!(Approvals::<Test>::get((id, user, provider)).map(|a| a.amount), Some(20));
+
+		assert_ok!(Assets::retire_from(RuntimeOrigin::signed(provider), id, user, 20, None));
+		assert_eq!(Assets::burn_certificates(&user), vec![(id, 20)]);
+		assert!(Approvals::<Test>::get((id, user, provider)).is_none());
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::CarbonCreditsBurned {
+			account: user,
+			asset_id: id,
+			amount: 20,
+			kind: BurnKind::SelfRetirement,
+			initiator: provider,
+			alias: None,
+			certificate_id: 1,
+		}));
+	});
+}
+
+#[test]
+fn symbol_exists_reports_duplicated_tickers() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&4, 1000);
+		Balances::make_free_balance_be(&5, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(4), "Token".as_bytes().to_vec(), "TCO2".as_bytes().to_vec()));
+		let first = Assets::get_current_asset_id(&4).unwrap();
+		assert_ok!(Assets::create(RuntimeOrigin::signed(5), "Token".as_bytes().to_vec(), "tco2".as_bytes().to_vec()));
+		let second = Assets::get_current_asset_id(&5).unwrap();
+
+		let mut found = Assets::symbol_exists(b"Tco2");
+		found.sort();
+		let mut expected = vec![first, second];
+		expected.sort();
+		assert_eq!(found, expected);
+		assert_eq!(Assets::symbol_exists(b"TOKEN"), vec![PREEXIST_ASSET]);
+		assert!(Assets::symbol_exists(b"NONE").is_empty());
+
+		assert_ok!(Assets::force_clear_metadata(RuntimeOrigin::root(), second));
+		assert_eq!(Assets::symbol_exists(b"TCO2"), vec![first]);
+
+		// the migration rebuilds the index from `Metadata`
+		let _ = SymbolAssets::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(5).put::<Assets>();
+		crate::migration::v6::MigrateToV6::<Test>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Assets>(), 6);
+		assert_eq!(Assets::symbol_exists(b"tco2"), vec![first]);
+	});
+}
+
+#[test]
+fn kyc_expiry_screening_rejects_lapsed_receivers() {
+	use frame_support::traits::Time;
+
+	struct Now;
+	impl Time for Now {
+		type Moment = u64;
+		fn now() -> u64 {
+			1_000
+		}
+	}
+	struct Kyc;
+	impl KycExpiry<u64, u64> for Kyc {
+		fn kyc_expires_at(who: &u64) -> Option<u64> {
+			match who {
+				4 => Some(1_000),
+				5 => Some(2_000),
+				_ => None,
+			}
+		}
+	}
+	type Screen = KycExpiryScreening<Kyc, Now>;
+
+	assert_eq!(
+		<Screen as Screening<AssetId, u64>>::screen(ZERO_ID, Some(&5), &4),
+		Err(ScreeningRejection::KycExpired)
+	);
+	assert_eq!(<Screen as Screening<AssetId, u64>>::screen(ZERO_ID, None, &4), Err(ScreeningRejection::KycExpired));
+	// lapsed holders can still send
+	assert_eq!(<Screen as Screening<AssetId, u64>>::screen(ZERO_ID, Some(&4), &5), Ok(()));
+	assert_eq!(<Screen as Screening<AssetId, u64>>::screen(ZERO_ID, Some(&4), &6), Ok(()));
+}
+
+#[test]
+fn pause_halts_operation_classes_across_assets() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 1000);
+		assert_noop!(Assets::pause(RuntimeOrigin::signed(2), PAUSE_MINT), Error::<Test>::NoPermission);
+		assert_noop!(Assets::pause(RuntimeOrigin::root(), 1 << 7), Error::<Test>::BadPauseMask);
+
+		assert_ok!(Assets::pause(RuntimeOrigin::signed(CUSTODIAN), PAUSE_TRANSFER));
+		assert_ok!(Assets::pause(RuntimeOrigin::root(), PAUSE_APPROVALS));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OperationsPaused {
+			ops: PAUSE_APPROVALS,
+			paused: PAUSE_TRANSFER | PAUSE_APPROVALS,
+		}));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 10),
+			Error::<Test>::OperationPaused
+		);
+		assert_noop!(
+			Assets::approve_transfer(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 10),
+			Error::<Test>::OperationPaused
+		);
+		// burns are not paused
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(1), PREEXIST_ASSET, 10, None));
+
+		assert_ok!(Assets::unpause(RuntimeOrigin::signed(CUSTODIAN)));
+		assert_eq!(Assets::paused(), 0);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 10));
+	});
+}
+
+#[test]
+fn approvals_of_pages_through_live_approvals() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 1000);
+		for delegate in 10..15 {
+			assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), PREEXIST_ASSET, delegate, delegate));
+		}
+		assert_ok!(Assets::approve_transfer_with_expiry(RuntimeOrigin::signed(1), PREEXIST_ASSET, 15, 15, 3));
+		System::set_block_number(3);
+
+		let mut pages = Vec::new();
+		let mut cursor = None;
+		loop {
+			let page = Assets::approvals_of(&1, PREEXIST_ASSET, cursor.as_ref(), 2);
+			if page.is_empty() {
+				break
+			}
+			cursor = page.last().map(|(delegate, _)| *delegate);
+			pages.extend(page);
+		}
+		pages.sort();
+		assert_eq!(pages, (10..15).map(|delegate| (delegate, delegate)).collect::<Vec<_>>());
+		assert!(Assets::approvals_of(&2, PREEXIST_ASSET, None, 10).is_empty());
+	});
+}
+
+#[test]
+fn project_must_be_approved_before_mint() {
+	new_test_ext().execute_with(|| {
+		ProjectApprovalRequired::set(true);
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_eq!(Assets::project_status(id), ProjectStatus::Draft);
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None),
+			Error::<Test>::ProjectNotApproved
+		);
+
+		// the custodian cannot skip the audit
+		assert_noop!(Assets::approve_project(RuntimeOrigin::signed(CUSTODIAN), id), Error::<Test>::BadProjectStatus);
+		assert_noop!(Assets::submit_project(RuntimeOrigin::signed(5), id), Error::<Test>::NoPermission);
+		assert_ok!(Assets::submit_project(RuntimeOrigin::signed(user), id));
+		assert_noop!(Assets::approve_project(RuntimeOrigin::signed(CUSTODIAN), id), Error::<Test>::NoPermission);
+		assert_ok!(Assets::approve_project(RuntimeOrigin::signed(AUDITOR), id));
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None),
+			Error::<Test>::ProjectNotApproved
+		);
+		assert_noop!(Assets::approve_project(RuntimeOrigin::signed(AUDITOR), id), Error::<Test>::NotAssetCustodian);
+		assert_ok!(Assets::approve_project(RuntimeOrigin::signed(CUSTODIAN), id));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ProjectStatusChanged {
+			asset_id: id,
+			status: ProjectStatus::CustodianApproved,
+			by: CUSTODIAN,
+		}));
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		assert_noop!(Assets::approve_project(RuntimeOrigin::signed(CUSTODIAN), id), Error::<Test>::BadProjectStatus);
+		ProjectApprovalRequired::set(false);
+	});
+}
+
+#[test]
+fn custodian_log_keeps_latest_custodian_actions() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		let entry = |action, account, amount| CustodianLogEntry {
+			action,
+			asset_id: id,
+			account,
+			amount,
+			block_number: 1,
+		};
+		assert_eq!(Assets::custodian_log(), vec![(0, entry(CustodianAction::Mint, Some(user), 500))]);
+		// owner transfers are not logged
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 100));
+		assert_eq!(Assets::custodian_log().len(), 1);
+
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(CUSTODIAN), id, user, None));
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(CUSTODIAN), id, user));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, 2, 40, CustodialBurnReason::Invalidation, None));
+		// the mint is pruned
+		assert_eq!(
+			Assets::custodian_log(),
+			vec![
+				(1, entry(CustodianAction::Freeze, Some(user), 0)),
+				(2, entry(CustodianAction::Thaw, Some(user), 0)),
+				(3, entry(CustodianAction::Burn, Some(2), 40)),
+			]
+		);
+	});
+}
+
+#[test]
+fn serial_ranges_cannot_overlap_and_are_consumed_by_retirements() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let unit = 1_000_000_000;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 2 * unit, Some((100, 101))));
+
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, unit, Some((101, 101))),
+			Error::<Test>::SerialRangeOverlap
+		);
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, unit, Some((200, 201))),
+			Error::<Test>::BadSerialRange
+		);
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, unit, None),
+			Error::<Test>::SerialRangeRequired
+		);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, unit, Some((50, 50))));
+		assert_eq!(
+			Assets::serial_ranges(id).iter().map(|r| (r.start, r.end)).collect::<Vec<_>>(),
+			vec![(50, 50), (100, 101)]
+		);
+
+		let anchor_of_last_retirement = || {
+			System::events().iter().rev().find_map(|record| match record.event {
+				RuntimeEvent::Assets(crate::Event::RetirementAnchored { anchor, .. }) => Some(anchor),
+				_ => None,
+			})
+			.unwrap()
+		};
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, unit + unit / 2, None));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::SerialsConsumed {
+			asset_id: id,
+			account: user,
+			ranges: vec![(50, 50), (100, 100)],
+		}));
+		assert_eq!(Assets::retirement_serials(anchor_of_last_retirement()), vec![(50, 50), (100, 100)]);
+
+		// serial 100 is retired in part by both retirements
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, unit, None));
+		assert_eq!(Assets::retirement_serials(anchor_of_last_retirement()), vec![(100, 101)]);
+
+		// assets minted without serial ranges keep minting without them
+		Balances::make_free_balance_be(&5, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(5), "Other".as_bytes().to_vec(), "Other".as_bytes().to_vec()));
+		let other = Assets::get_current_asset_id(&5).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), other, unit, None));
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(CUSTODIAN), other, unit, Some((1, 1))),
+			Error::<Test>::BadSerialRange
+		);
+	});
+}
+
+#[test]
+fn deprecated_aliases_forward_to_new_calls() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		assert_ok!(Assets::mint_v1(RuntimeOrigin::signed(CUSTODIAN), id, 100));
+		assert_eq!(Assets::balance(id, user), 100);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DeprecatedCallUsed {
+			call: DeprecatedCall::MintV1,
+			who: CUSTODIAN,
+		}));
+		assert_noop!(Assets::mint_v1(RuntimeOrigin::signed(2), id, 100), Error::<Test>::NotAssetCustodian);
+
+		assert_ok!(Assets::freeze_v1(RuntimeOrigin::signed(CUSTODIAN), id, user));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 10), Error::<Test>::Frozen);
+		assert_ok!(Assets::thaw(RuntimeOrigin::signed(CUSTODIAN), id, user));
+
+		assert_ok!(Assets::burn_v1(RuntimeOrigin::signed(CUSTODIAN), id, user, 10));
+		assert_eq!(Assets::balance(id, user), 90);
+		assert_eq!(CustodialBurns::<Test>::get(user, 0).map(|r| r.reason), Some(CustodialBurnReason::Unspecified));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DeprecatedCallUsed {
+			call: DeprecatedCall::BurnV1,
+			who: CUSTODIAN,
+		}));
+
+		assert_ok!(Assets::self_burn_v1(RuntimeOrigin::signed(user), id, 10));
+		assert_eq!(Assets::balance(id, user), 80);
+		assert_eq!(BurnCertificate::<Test>::get(user, id).map(|c| c.amount), Some(10));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DeprecatedCallUsed {
+			call: DeprecatedCall::SelfBurnV1,
+			who: user,
+		}));
+
+		assert_ok!(Assets::freeze_asset_v1(RuntimeOrigin::signed(CUSTODIAN), id));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 10), Error::<Test>::Frozen);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DeprecatedCallUsed {
+			call: DeprecatedCall::FreezeAssetV1,
+			who: CUSTODIAN,
+		}));
+
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Other".as_bytes().to_vec(), "Other".as_bytes().to_vec()));
+		let other = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::set_project_data_v1(RuntimeOrigin::signed(user), other, b"http".to_vec(), CID_V0.as_bytes().to_vec()));
+		assert_eq!(Metadata::<Test>::get(other).url.to_vec(), b"http".to_vec());
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::DeprecatedCallUsed {
+			call: DeprecatedCall::SetProjectDataV1,
+			who: user,
+		}));
+	});
+}
+
+#[test]
+fn cleanup_calls_are_rewarded_from_deposits() {
+	new_test_ext().execute_with(|| {
+		CleanupReward::set(1);
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		Balances::make_free_balance_be(&5, 10);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+		for who in 2..=3 {
+			Balances::make_free_balance_be(&who, 10);
+			assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, who, 10));
+		}
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(user), id));
+		let reserved = Balances::reserved_balance(&user);
+
+		// the owner is not rewarded for its own cleanup
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(user), id));
+		assert_eq!(Balances::reserved_balance(&user), reserved);
+		// the reward is capped by the asset deposit
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(5), id));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::CleanupRewarded {
+			asset_id: id,
+			who: 5,
+			amount: 1,
+		}));
+		assert_eq!(Balances::free_balance(&5), 11);
+		assert_eq!(Balances::reserved_balance(&user), reserved - 1);
+		assert_eq!(Asset::<Test>::get(id).unwrap().deposit, 0);
+
+		// expired approvals are cleaned for a part of their deposit
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::approve_transfer_with_expiry(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 10, 3));
+		System::set_block_number(3);
+		assert_ok!(Assets::clean_expired_approval(RuntimeOrigin::signed(5), PREEXIST_ASSET, 1, 2));
+		assert_eq!(Balances::free_balance(&5), 12);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		CleanupReward::set(0);
+	});
+}
+
+#[test]
+fn transfer_all_moves_reducible_balance() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		Balances::make_free_balance_be(&2, 10);
+		Balances::make_free_balance_be(&3, 10);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		let min_balance = Asset::<Test>::get(id).unwrap().min_balance;
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+
+		// the frozen amount stays with the sender
+		set_frozen_balance(id, user, 30);
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(user), id, 2, false));
+		assert_eq!(Assets::balance(id, user), 30 + min_balance);
+		assert_eq!(Assets::balance(id, 2), 100 - 30 - min_balance);
+		clear_frozen_balance(id, user);
+
+		// keep_alive leaves the minimum balance
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(2), id, 3, true));
+		assert_eq!(Assets::balance(id, 2), min_balance);
+		assert_eq!(Assets::balance(id, 3), 100 - 30 - 2 * min_balance);
+
+		// otherwise the sender account is reaped
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(3), id, 2, false));
+		assert!(Account::<Test>::get(id, 3).is_none());
+		assert_eq!(Assets::balance(id, 2), 100 - 30 - min_balance);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Transferred {
+			asset_id: id,
+			from: 3,
+			to: 2,
+			amount: 100 - 30 - 2 * min_balance,
+		}));
+	});
+}
+
+#[test]
+fn allowance_can_be_increased_and_decreased() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		System::set_block_number(1);
+
+		let approvals = Asset::<Test>::get(PREEXIST_ASSET).unwrap().approvals;
+
+		// an increase creates the approval
+		assert_ok!(Assets::increase_allowance(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 10));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_ok!(Assets::increase_allowance(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 5));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AllowanceChanged {
+			asset_id: PREEXIST_ASSET,
+			owner: 1,
+			delegate: 2,
+			amount: 15,
+		}));
+		assert_eq!(Approvals::<Test>::get((PREEXIST_ASSET, 1, 2)).unwrap().amount, 15);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		assert_noop!(
+			Assets::decrease_allowance(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 16),
+			Error::<Test>::AllowanceUnderflow
+		);
+		assert_noop!(
+			Assets::decrease_allowance(RuntimeOrigin::signed(1), PREEXIST_ASSET, 3, 1),
+			Error::<Test>::Unapproved
+		);
+		assert_ok!(Assets::decrease_allowance(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 5));
+		assert_eq!(Approvals::<Test>::get((PREEXIST_ASSET, 1, 2)).unwrap().amount, 10);
+
+		// a decrease to zero removes the approval and unreserves its deposit
+		assert_ok!(Assets::decrease_allowance(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 10));
+		assert!(Approvals::<Test>::get((PREEXIST_ASSET, 1, 2)).is_none());
+		assert_eq!(Asset::<Test>::get(PREEXIST_ASSET).unwrap().approvals, approvals);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+
+		// the expiry is kept and an expired approval cannot be changed
+		assert_ok!(Assets::approve_transfer_with_expiry(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 10, 3));
+		assert_ok!(Assets::increase_allowance(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 5));
+		assert_eq!(Approvals::<Test>::get((PREEXIST_ASSET, 1, 2)).unwrap().expiry, Some(3));
+		System::set_block_number(3);
+		assert_noop!(
+			Assets::increase_allowance(RuntimeOrigin::signed(1), PREEXIST_ASSET, 2, 5),
+			Error::<Test>::ApprovalExpired
+		);
+	});
+}
+
+fn create_whole_tonne_asset(user: u64) -> AssetId {
+	Balances::make_free_balance_be(&user, 100);
+	assert_ok!(Assets::create_with_decimals(
+		RuntimeOrigin::signed(user),
+		"Tonne".as_bytes().to_vec(),
+		"TCO2".as_bytes().to_vec(),
+		0
+	));
+	Assets::get_current_asset_id(&user).unwrap()
+}
+
+#[test]
+fn whole_tonne_asset_is_created_without_decimals() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		assert_noop!(
+			Assets::create_with_decimals(
+				RuntimeOrigin::signed(user),
+				"Token".as_bytes().to_vec(),
+				"Token".as_bytes().to_vec(),
+				CO2E_DECIMALS + 1
+			),
+			Error::<Test>::BadDecimals
+		);
+
+		let id = create_whole_tonne_asset(user);
+		assert_eq!(Metadata::<Test>::get(id).decimals, 0);
+		// the minimum balance is one tonne
+		assert_eq!(Asset::<Test>::get(id).unwrap().min_balance, 1);
+		assert_eq!(
+			Assets::display_amount(id, 42),
+			Some(AmountDisplay { whole: 42, fraction: 0, decimals: 0 })
+		);
+		assert_eq!(Assets::display_amount(AssetId::new([9; 24]), 42), None);
+
+		// assets created without decimals keep the default
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_eq!(
+			Assets::display_amount(id, 1_500_000_000),
+			Some(AmountDisplay { whole: 1, fraction: 500_000_000, decimals: DEFAULT_DECIMALS })
+		);
+	});
+}
+
+#[test]
+fn whole_tonne_asset_is_minted_transferred_and_retired_in_tonnes() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let id = create_whole_tonne_asset(user);
+		// one serial per tonne
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 10, Some((1, 9))),
+			Error::<Test>::BadSerialRange
+		);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 10, Some((1, 10))));
+
+		Balances::make_free_balance_be(&2, 10);
+		Balances::make_free_balance_be(&3, 10);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 3));
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(2), id, 3, true));
+		assert_eq!(Assets::balance(id, 2), 1);
+		assert_eq!(Assets::balance(id, 3), 2);
+
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 2, None));
+		assert_eq!(BurnCertificate::<Test>::get(user, id).map(|c| c.amount), Some(2));
+		assert_eq!(Assets::serial_ranges(id)[0].consumed, 2);
+
+		// tonnes convert to CO2e without rounding
+		assert_ok!(Assets::retire_co2e(RuntimeOrigin::signed(user), vec![(id, 3)]));
+		assert_eq!(Co2eRetirements::<Test>::get(user, 0).unwrap().co2e, 3_000_000_000);
+		assert_eq!(Assets::balance(id, user), 2);
+		assert_eq!(Assets::total_supply(id), 5);
+	});
+}
+
+#[test]
+fn constants_are_exposed_to_other_pallets() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::asset_deposit(), 1);
+		assert_eq!(Assets::approval_deposit(), 1);
+		assert_eq!(Assets::string_limit(), 50);
+		assert_eq!(Assets::max_mint_batch(), 5);
+		assert_eq!(Assets::custodian_change_delay(), 5);
+		assert_eq!(Assets::attribute_deposit(4), 5);
+
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "TKN".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_eq!(Metadata::<Test>::get(id).deposit, Assets::metadata_deposit(8));
+		assert_eq!(Balances::reserved_balance(&user), Assets::asset_deposit() + Assets::metadata_deposit(8));
+	});
+}
+
+#[test]
+fn approve_with_signature_uses_permit_once() {
+	new_test_ext().execute_with(|| {
+		let owner = 4;
+		let relayer = 5;
+		Balances::make_free_balance_be(&owner, 100);
+		Balances::make_free_balance_be(&relayer, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(owner), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&owner).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+		let reserved = Balances::reserved_balance(&owner);
+
+		let permit = ApprovalPermit {
+			owner,
+			asset_id: id,
+			delegate: 2,
+			amount: 100,
+			nonce: 0,
+			deadline: 10,
+			genesis_hash: System::block_hash(0),
+		};
+		let sign = |permit: &ApprovalPermit<u64, u64, u64, sp_core::H256>| {
+			TestSignature(owner, Assets::approval_permit_message(permit))
+		};
+		// the signature must cover the domain tag
+		assert_noop!(
+			Assets::approve_with_signature(RuntimeOrigin::signed(relayer), id, owner, 2, 100, 10, TestSignature(owner, permit.encode())),
+			Error::<Test>::BadSignature
+		);
+		assert_noop!(
+			Assets::approve_with_signature(RuntimeOrigin::signed(relayer), id, owner, 2, 200, 10, sign(&permit)),
+			Error::<Test>::BadSignature
+		);
+
+		assert_ok!(Assets::approve_with_signature(RuntimeOrigin::signed(relayer), id, owner, 2, 100, 10, sign(&permit)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovalPermitted { owner, nonce: 0, relayer }));
+		assert_eq!(Approvals::<Test>::get((id, owner, 2)).unwrap().amount, 100);
+		assert_eq!(Balances::reserved_balance(&owner), reserved + 1);
+		assert_eq!(Balances::reserved_balance(&relayer), 0);
+		assert_eq!(Assets::permit_nonce(owner), 1);
+
+		// no replays
+		assert_noop!(
+			Assets::approve_with_signature(RuntimeOrigin::signed(relayer), id, owner, 2, 100, 10, sign(&permit)),
+			Error::<Test>::BadSignature
+		);
+
+		let next = ApprovalPermit { nonce: 1, deadline: 2, ..permit };
+		System::set_block_number(3);
+		assert_noop!(
+			Assets::approve_with_signature(RuntimeOrigin::signed(relayer), id, owner, 2, 100, 2, sign(&next)),
+			Error::<Test>::PayloadExpired
+		);
+	});
+}
+
+#[test]
+fn freeze_amount_locks_part_of_the_balance() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		Balances::make_free_balance_be(&2, 10);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+
+		assert_noop!(
+			Assets::freeze_amount(RuntimeOrigin::signed(user), id, user, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::freeze_amount(RuntimeOrigin::signed(CUSTODIAN), id, user, 101),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::freeze_amount(RuntimeOrigin::signed(CUSTODIAN), id, user, 30));
+		assert_ok!(Assets::freeze_amount(RuntimeOrigin::signed(CUSTODIAN), id, user, 20));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AmountFrozen {
+			asset_id: id,
+			who: user,
+			amount: 20,
+			frozen: 50,
+		}));
+
+		// the frozen amount and the minimum balance stay in the account
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 50), Error::<Test>::BalanceLow);
+		assert_noop!(
+			Assets::self_burn(RuntimeOrigin::signed(user), id, 50, None),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(user), id, 2, false));
+		assert_eq!(Assets::balance(id, user), 51);
+
+		// an external freezer adds to the frozen amount
+		set_frozen_balance(id, user, 1);
+		assert_eq!(Assets::reducible_balance(id, &user, false), Ok(0));
+		clear_frozen_balance(id, user);
+
+		assert_noop!(
+			Assets::unfreeze_amount(RuntimeOrigin::signed(CUSTODIAN), id, user, 51),
+			Error::<Test>::NotEnoughFrozen
+		);
+		assert_ok!(Assets::unfreeze_amount(RuntimeOrigin::signed(CUSTODIAN), id, user, 50));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AmountThawed {
+			asset_id: id,
+			who: user,
+			amount: 50,
+			frozen: 0,
+		}));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 51));
+		assert_eq!(Assets::balance(id, 2), 100);
+	});
+}
+
+#[test]
+fn migration_v7_adds_frozen_amount() {
+	use frame_support::{storage::unhashed, traits::{OnRuntimeUpgrade, StorageVersion}};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(6).put::<Assets>();
+		let key = Account::<Test>::hashed_key_for(ZERO_ID, 3);
+		unhashed::put_raw(&key, &(50u64, true, ExistenceReason::<u64>::Sufficient, ()).encode());
+
+		crate::migration::v7::MigrateToV7::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Assets>(), 7);
+		assert_eq!(
+			Account::<Test>::get(ZERO_ID, 3),
+			Some(AssetAccount {
+				balance: 50,
+				is_frozen: true,
+				frozen: 0,
+				reason: ExistenceReason::Sufficient,
+				extra: (),
+			})
+		);
+	});
+}
+
+#[test]
+fn sponsored_accounts_are_clawed_back_when_inactive() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&CUSTODIAN, 100);
+		assert_noop!(Assets::sponsor_account(RuntimeOrigin::signed(2), 20, None), Error::<Test>::NoPermission);
+		assert_noop!(
+			Assets::sponsor_account(RuntimeOrigin::signed(CUSTODIAN), CUSTODIAN, None),
+			Error::<Test>::NotNewAccount
+		);
+
+		// the asset-account deposit is sent along
+		assert_ok!(Assets::sponsor_account(RuntimeOrigin::signed(CUSTODIAN), 20, Some(PREEXIST_ASSET)));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AccountSponsored {
+			who: 20,
+			sponsor: CUSTODIAN,
+			amount: 15,
+			asset_id: Some(PREEXIST_ASSET),
+		}));
+		assert!(Account::<Test>::contains_key(PREEXIST_ASSET, 20));
+		assert_eq!(Balances::free_balance(&20), 5);
+		assert_eq!(Balances::reserved_balance(&20), 10);
+		assert_eq!(Balances::free_balance(&CUSTODIAN), 85);
+		assert_noop!(
+			Assets::sponsor_account(RuntimeOrigin::signed(CUSTODIAN), 20, None),
+			Error::<Test>::NotNewAccount
+		);
+		assert_ok!(Assets::sponsor_account(RuntimeOrigin::signed(CUSTODIAN), 21, None));
+
+		assert_noop!(
+			Assets::clawback_sponsorship(RuntimeOrigin::signed(CUSTODIAN), 20),
+			Error::<Test>::SponsorshipNotExpired
+		);
+		System::set_block_number(11);
+		assert_ok!(Assets::clawback_sponsorship(RuntimeOrigin::signed(CUSTODIAN), 20));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::SponsorshipClosed {
+			who: 20,
+			sponsor: CUSTODIAN,
+			clawed_back: 15,
+		}));
+		assert!(!Account::<Test>::contains_key(PREEXIST_ASSET, 20));
+		assert!(!System::account_exists(&20));
+		assert_eq!(Assets::sponsored_account(20), None);
+
+		// only the sponsored amount is clawed back
+		assert_ok!(Assets::sponsor_account(RuntimeOrigin::signed(CUSTODIAN), 22, None));
+		Balances::make_free_balance_be(&22, 12);
+		System::set_block_number(21);
+		assert_ok!(Assets::clawback_sponsorship(RuntimeOrigin::signed(CUSTODIAN), 22));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::SponsorshipClosed {
+			who: 22,
+			sponsor: CUSTODIAN,
+			clawed_back: 5,
+		}));
+		assert_eq!(Balances::free_balance(&22), 7);
+
+		// the deposit of an asset-account holding assets stays held
+		assert_ok!(Assets::sponsor_account(RuntimeOrigin::signed(CUSTODIAN), 23, Some(PREEXIST_ASSET)));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(CUSTODIAN), PREEXIST_ASSET, 23, 10));
+		Balances::make_free_balance_be(&23, 8);
+		System::set_block_number(31);
+		assert_ok!(Assets::clawback_sponsorship(RuntimeOrigin::signed(CUSTODIAN), 23));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::SponsorshipClosed {
+			who: 23,
+			sponsor: CUSTODIAN,
+			clawed_back: 5,
+		}));
+		assert_eq!(Assets::balance(PREEXIST_ASSET, 23), 10);
+		assert_eq!(Balances::free_balance(&23), 3);
+		assert_eq!(Balances::reserved_balance(&23), 10);
+
+		// nothing is clawed back from an active account
+		System::inc_account_nonce(&21);
+		assert_ok!(Assets::clawback_sponsorship(RuntimeOrigin::signed(CUSTODIAN), 21));
+		assert_eq!(Balances::free_balance(&21), 5);
+		assert_noop!(
+			Assets::clawback_sponsorship(RuntimeOrigin::signed(CUSTODIAN), 21),
+			Error::<Test>::NotSponsored
+		);
+	});
+}
+
+#[test]
+fn holds_and_freezes_lock_the_balance() {
+	use frame_support::traits::tokens::fungibles::{InspectHold, MutateHold};
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		Balances::make_free_balance_be(&2, 10);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+
+		// the minimum balance cannot be held
+		assert!(!<Assets as InspectHold<u64>>::can_hold(id, &user, 100));
+		assert_noop!(Assets::hold(id, &user, 100), Error::<Test>::BalanceLow);
+		assert_ok!(Assets::hold(id, &user, 60));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Held { asset_id: id, who: user, amount: 60 }));
+		assert_eq!(Assets::balance_on_hold(id, &user), 60);
+		assert_eq!(Assets::balance(id, user), 100);
+		assert_eq!(Assets::reducible_balance(id, &user, false), Ok(39));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 40), Error::<Test>::BalanceLow);
+
+		// freezes overlap each other and the held amount
+		assert_ok!(Assets::set_freeze(id, b"lending0", &user, 50));
+		assert_eq!(Assets::reducible_balance(id, &user, false), Ok(39));
+		assert_ok!(Assets::set_freeze(id, b"governan", &user, 80));
+		assert_eq!(Assets::reducible_balance(id, &user, false), Ok(19));
+		assert!(!Assets::can_freeze(id, b"staking0", &user));
+		assert!(Assets::can_freeze(id, b"lending0", &user));
+		assert_noop!(Assets::set_freeze(id, b"staking0", &user, 1), Error::<Test>::TooManyFreezes);
+		assert_ok!(Assets::extend_freeze(id, b"governan", &user, 10));
+		assert_eq!(Assets::balance_frozen(id, b"governan", &user), 80);
+		assert_ok!(<Assets as MutateFreeze<u64>>::thaw(id, b"governan", &user));
+		assert_eq!(Assets::balance_frozen(id, b"governan", &user), 0);
+		assert_eq!(Assets::reducible_balance(id, &user, false), Ok(39));
+
+		assert_noop!(Assets::release(id, &user, 70, false), Error::<Test>::NotEnoughHeld);
+		assert_eq!(Assets::transfer_held(id, &user, &2, 20, false, true), Ok(20));
+		assert_eq!(Assets::balance(id, 2), 20);
+		assert_eq!(Assets::balance_on_hold(id, &2), 20);
+		assert_eq!(Assets::balance_on_hold(id, &user), 40);
+		assert_eq!(Assets::release(id, &user, 100, true), Ok(40));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Released { asset_id: id, who: user, amount: 40 }));
+		assert_eq!(Assets::held_balance(id, user), 0);
+		assert_eq!(Assets::reducible_balance(id, &user, false), Ok(29));
+
+		// only an existing asset-account can be frozen
+		assert_ok!(Assets::release(id, &2, 20, false));
+		assert_ok!(Assets::set_freeze(id, b"lending0", &2, 5));
+		assert_ok!(<Assets as MutateFreeze<u64>>::thaw(id, b"lending0", &2));
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(2), id, user, false));
+		assert!(!Account::<Test>::contains_key(id, 2));
+		assert_noop!(Assets::set_freeze(id, b"lending0", &2, 5), Error::<Test>::NoAccount);
+	});
+}
+
+#[test]
+fn export_retirement_is_attested_apart_from_retirements() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let id = create_whole_tonne_asset(user);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 10, Some((1, 10))));
+
+		assert_noop!(
+			Assets::export_retirement(RuntimeOrigin::signed(user), id, 3, vec![0u8; 51], b"VCS-1".to_vec()),
+			Error::<Test>::BadMetadata
+		);
+		assert_noop!(
+			Assets::export_retirement(RuntimeOrigin::signed(2), id, 3, b"Verra".to_vec(), b"VCS-1".to_vec()),
+			Error::<Test>::NoAccount
+		);
+		assert_ok!(Assets::export_retirement(
+			RuntimeOrigin::signed(user),
+			id,
+			3,
+			b"Verra".to_vec(),
+			b"VCS-1".to_vec()
+		));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::CreditsExported {
+			export_id: 0,
+			asset_id: id,
+			who: user,
+			amount: 3,
+		}));
+		let attestation = Assets::export_attestation(0).unwrap();
+		assert_eq!((attestation.account, attestation.asset_id, attestation.amount), (user, id, 3));
+		assert_eq!(attestation.dest_registry.to_vec(), b"Verra".to_vec());
+		assert_eq!(attestation.dest_ref.to_vec(), b"VCS-1".to_vec());
+		assert_eq!(attestation.serials.to_vec(), vec![(1, 3)]);
+
+		// an export is not a retirement
+		assert_eq!(Assets::total_exported(id), 3);
+		assert_eq!(Assets::total_retired(id), 0);
+		assert_eq!(BurnCertificate::<Test>::get(user, id).map(|c| c.amount), None);
+		assert_eq!(RetirementCount::<Test>::get(user), 0);
+		assert_eq!(Assets::total_supply(id), 7);
+
+		assert_ok!(Assets::export_retirement(RuntimeOrigin::signed(user), id, 2, b"Gold".to_vec(), vec![]));
+		assert_eq!(Assets::export_attestation(1).unwrap().serials.to_vec(), vec![(4, 5)]);
+		assert_eq!(Assets::total_exported(id), 5);
+	});
+}
+
+#[test]
+fn transfer_whitelist_restricts_counterparties() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 100);
+		Balances::make_free_balance_be(&2, 10);
+		Balances::make_free_balance_be(&3, 10);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 100, None));
+
+		// the custodian is the admin of the asset
+		assert_noop!(Assets::add_to_whitelist(RuntimeOrigin::signed(user), id, user), Error::<Test>::NoPermission);
+		assert_noop!(
+			Assets::set_whitelist_enforcement(RuntimeOrigin::signed(user), id, true),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::set_whitelist_enforcement(RuntimeOrigin::signed(CUSTODIAN), id, true));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::WhitelistEnforcementSet {
+			asset_id: id,
+			enabled: true,
+		}));
+
+		// both counterparties must be whitelisted
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 10), Error::<Test>::NotWhitelisted);
+		assert_ok!(Assets::add_to_whitelist(RuntimeOrigin::signed(CUSTODIAN), id, user));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 10), Error::<Test>::NotWhitelisted);
+		assert_ok!(Assets::add_to_whitelist(RuntimeOrigin::signed(CUSTODIAN), id, 2));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AddedToWhitelist { asset_id: id, who: 2 }));
+		assert!(Assets::is_whitelisted(id, &2));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 10));
+
+		assert_ok!(Assets::remove_from_whitelist(RuntimeOrigin::signed(CUSTODIAN), id, 2));
+		assert!(!Assets::is_whitelisted(id, &2));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(2), id, user, 5), Error::<Test>::NotWhitelisted);
+
+		// forced transfers of the admin are not restricted
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(CUSTODIAN), id, 2, 3, 5));
+		assert_eq!(Assets::balance(id, 3), 5);
+
+		assert_ok!(Assets::set_whitelist_enforcement(RuntimeOrigin::signed(CUSTODIAN), id, false));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), id, 2, 5));
+		assert_eq!(Assets::balance(id, 2), 10);
+	});
+}
+
+#[test]
+fn migration_v8_adds_whitelist_flag() {
+	use frame_support::{storage::unhashed, traits::{OnRuntimeUpgrade, StorageVersion}};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(7).put::<Assets>();
+		let key = Asset::<Test>::hashed_key_for(ZERO_ID);
+		let old = (0u64, 0u64, 0u64, 0u64, 100u64, 1u64, 1u64, true, 2u32, 2u32, 0u32, AssetStatus::Frozen);
+		unhashed::put_raw(&key, &old.encode());
+
+		crate::migration::v8::MigrateToV8::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Assets>(), 8);
+		let details = Asset::<Test>::get(ZERO_ID).unwrap();
+		assert_eq!((details.supply, details.accounts, details.status), (100, 2, AssetStatus::Frozen));
+		assert!(!details.whitelist_enabled);
+	});
+}
+
+#[test]
+fn asset_id_has_canonical_text_forms() {
+	use sp_std::str::FromStr;
+
+	let mut bytes = [0u8; 24];
+	bytes[1] = 0xab;
+	bytes[23] = 7;
+	let id = AssetId::new(bytes);
+	assert_eq!(id.encode(), bytes.encode());
+	assert_eq!(id.nonce(), 7);
+
+	let hex = id.to_string();
+	assert_eq!(hex, "0x00ab00000000000000000000000000000000000000000007");
+	assert_eq!(format!("{:?}", id), hex);
+	assert_eq!(AssetId::from_str(&hex), Ok(id));
+	assert_eq!(AssetId::from_str(&hex[2..].to_uppercase()), Ok(id));
+
+	let base58 = id.base58().to_string();
+	assert!(base58.starts_with('1') && base58.len() <= 33);
+	assert_eq!(AssetId::from_str(&base58), Ok(id));
+	assert_eq!(AssetId::from_str(&AssetId::default().base58().to_string()), Ok(AssetId::default()));
+	assert_eq!(AssetId::from_str(&AssetId::new([255; 24]).base58().to_string()), Ok(AssetId::new([255; 24])));
+
+	assert_eq!(AssetId::from_str("0x00ab"), Err(ParseAssetIdError::InvalidLength));
+	assert_eq!(AssetId::from_str("0xzz"), Err(ParseAssetIdError::InvalidCharacter));
+	assert_eq!(AssetId::from_str("0OIl"), Err(ParseAssetIdError::InvalidCharacter));
+	assert_eq!(AssetId::from_str("2"), Err(ParseAssetIdError::InvalidLength));
+	assert_eq!(AssetId::try_from(&[1u8; 23][..]), Err(ParseAssetIdError::InvalidLength));
+}
+
+#[test]
+fn retirement_registry_records_every_burn_in_order() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let provider = 5;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 500, None));
+
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 10, None));
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::RetirementRegistered {
+			certificate_id: 0,
+			account: user,
+			asset_id: id,
+			amount: 10,
+		}));
+
+		System::set_block_number(2);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(user), id, provider, 60));
+		assert_ok!(Assets::retire_from(RuntimeOrigin::signed(provider), id, user, 40, Some(6)));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(CUSTODIAN), id, user, 5, CustodialBurnReason::CourtOrder, None));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::CustodialBurned {
+			account: user,
+			asset_id: id,
+			amount: 5,
+			reason: CustodialBurnReason::CourtOrder,
+		}));
+
+		let retired = Assets::retirement_record(1).unwrap();
+		assert_eq!((retired.account, retired.beneficiary, retired.amount), (user, 6, 40));
+		assert_eq!((retired.block_number, retired.reason), (2, RetirementReason::SelfRetirement));
+		let burned = Assets::retirement_record(2).unwrap();
+		assert_eq!((burned.account, burned.beneficiary, burned.asset_id), (user, user, id));
+		assert_eq!(burned.reason, RetirementReason::Custodial(CustodialBurnReason::CourtOrder));
+		assert_eq!(Assets::retirement_record(3), None);
+
+		let page: Vec<_> = Assets::retirement_records(1, 5).into_iter().map(|(certificate_id, r)| (certificate_id, r.amount)).collect();
+		assert_eq!(page, vec![(1, 40), (2, 5)]);
+		assert!(Assets::retirement_records(3, 5).is_empty());
+	});
+}
+
+#[test]
+fn owner_batch_applies_all_calls_or_none() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Token".as_bytes().to_vec(), "Token".as_bytes().to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+		assert_ok!(Assets::register_methodology(RuntimeOrigin::root(), 1, b"VM0007".to_vec()));
+		let key = frame_support::BoundedVec::<u8, <Test as Config>::AttributeKeyLimit>::try_from(b"tag".to_vec()).unwrap();
+
+		assert_noop!(Assets::owner_batch(RuntimeOrigin::signed(user), id, vec![]), Error::<Test>::BadOwnerBatch);
+		assert_noop!(
+			Assets::owner_batch(RuntimeOrigin::signed(user), id, vec![OwnerCall::SetMethodology(1); 4]),
+			Error::<Test>::BadOwnerBatch
+		);
+		assert_noop!(
+			Assets::owner_batch(RuntimeOrigin::signed(5), id, vec![OwnerCall::SetMethodology(1)]),
+			Error::<Test>::NotAssetCustodian
+		);
+
+		// the invalid cid fails the batch after the attribute was set
+		let metadata = Metadata::<Test>::get(id);
+		assert_noop!(
+			Assets::owner_batch(
+				RuntimeOrigin::signed(user),
+				id,
+				vec![
+					OwnerCall::SetAttribute { key: b"tag".to_vec(), value: b"REDD+".to_vec() },
+					OwnerCall::SetProjectData { url: b"http".to_vec(), data_ipfs: b"4h6g".to_vec(), details: None },
+				]
+			),
+			Error::<Test>::BadMetadata
+		);
+		assert!(Attributes::<Test>::get(id, &key).is_none());
+
+		assert_ok!(Assets::owner_batch(
+			RuntimeOrigin::signed(user),
+			id,
+			vec![
+				OwnerCall::SetAttribute { key: b"tag".to_vec(), value: b"REDD+".to_vec() },
+				OwnerCall::SetProjectData {
+					url: b"http".to_vec(),
+					data_ipfs: CID_V0.as_bytes().to_vec(),
+					details: None,
+				},
+				OwnerCall::SetMethodology(1),
+			]
+		));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::OwnerBatchExecuted { asset_id: id, calls: 3 }));
+		assert_eq!(Attributes::<Test>::get(id, &key).unwrap().value.to_vec(), b"REDD+".to_vec());
+		assert_ne!(Metadata::<Test>::get(id), metadata);
+		assert_eq!(Assets::asset_methodology(id), Some(1));
+
+		assert_ok!(Assets::owner_batch(RuntimeOrigin::signed(CUSTODIAN), id, vec![OwnerCall::ClearAttribute { key: b"tag".to_vec() }]));
+		assert!(Attributes::<Test>::get(id, &key).is_none());
+	});
+}
+
+#[test]
+fn metadata_validator_rejects_malformed_metadata() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		Balances::make_free_balance_be(&user, 1000);
+		StrictMetadataChecks::set(true);
+
+		assert_noop!(
+			Assets::create(RuntimeOrigin::signed(user), b"Forest\n".to_vec(), b"FRST".to_vec()),
+			Error::<Test>::MetadataRejected(MetadataRejection::BadName)
+		);
+		assert_noop!(
+			Assets::create(RuntimeOrigin::signed(user), b"Forest".to_vec(), b"FR ST".to_vec()),
+			Error::<Test>::MetadataRejected(MetadataRejection::BadSymbol)
+		);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(user), "Лес".as_bytes().to_vec(), b"FRST".to_vec()));
+		let id = Assets::get_current_asset_id(&user).unwrap();
+
+		let data_ipfs = CID_V0.as_bytes().to_vec();
+		for url in [&b"http://example.com"[..], b"https://", b"https://example.com/a b"] {
+			assert_noop!(
+				Assets::set_project_data(RuntimeOrigin::signed(user), id, url.to_vec(), data_ipfs.clone(), None),
+				Error::<Test>::MetadataRejected(MetadataRejection::BadUrl)
+			);
+		}
+		assert_ok!(Assets::set_project_data(RuntimeOrigin::signed(user), id, b"https://example.com".to_vec(), data_ipfs, None));
+		assert_eq!(Metadata::<Test>::get(id).url.to_vec(), b"https://example.com".to_vec());
+
+		StrictMetadataChecks::set(false);
+		assert_ok!(Assets::set_project_data(RuntimeOrigin::signed(user), id, b"http".to_vec(), CID_V0.as_bytes().to_vec(), None));
+	});
+}
+
+#[test]
+fn viewer_keys_authorize_privileged_reads() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let viewer = 7;
+		let id = create_whole_tonne_asset(user);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 10, None));
+
+		assert_noop!(Assets::add_viewer_key(RuntimeOrigin::signed(user), viewer), BadOrigin);
+		assert_noop!(Assets::remove_viewer_key(RuntimeOrigin::root(), viewer), Error::<Test>::NotViewer);
+
+		let request = ViewRequest { viewer, query: ViewQuery::AssetHolders(id), valid_until: 5 };
+		let signature = TestSignature(viewer, Assets::view_request_message(&request));
+		assert_eq!(Assets::view_asset_holders(request.clone(), signature.clone()), Err(ViewError::NotViewer));
+
+		assert_ok!(Assets::add_viewer_key(RuntimeOrigin::root(), viewer));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ViewerKeyAdded { viewer }));
+		assert!(Assets::is_viewer(&viewer));
+		assert_eq!(Assets::view_asset_holders(request.clone(), signature.clone()), Ok(vec![(user, 10)]));
+		assert_eq!(
+			Assets::view_asset_holders(request.clone(), TestSignature(user, Assets::view_request_message(&request))),
+			Err(ViewError::BadSignature)
+		);
+		assert_eq!(Assets::view_account_dump(request.clone(), signature.clone()), Err(ViewError::WrongQuery));
+
+		let dump_request = ViewRequest { viewer, query: ViewQuery::AccountDump(user), valid_until: 5 };
+		let dump_signature = TestSignature(viewer, Assets::view_request_message(&dump_request));
+		assert_eq!(Assets::view_account_dump(dump_request, dump_signature), Ok(Assets::account_dump(&user)));
+		let balances_request = ViewRequest { viewer, query: ViewQuery::AccountBalances(user), valid_until: 5 };
+		let balances_signature = TestSignature(viewer, Assets::view_request_message(&balances_request));
+		assert_eq!(Assets::view_account_balances(balances_request, balances_signature), Ok(vec![(id, 10)]));
+		let position_request = ViewRequest { viewer, query: ViewQuery::PositionStatement(user), valid_until: 5 };
+		let position_signature = TestSignature(viewer, Assets::view_request_message(&position_request));
+		assert_eq!(
+			Assets::view_position_statement(position_request.clone(), position_signature.clone()),
+			Ok(Assets::position_statement(&user))
+		);
+		assert_eq!(Assets::view_account_balances(position_request, position_signature), Err(ViewError::WrongQuery));
+
+		System::set_block_number(6);
+		assert_eq!(Assets::view_asset_holders(request.clone(), signature.clone()), Err(ViewError::Expired));
+
+		System::set_block_number(1);
+		assert_ok!(Assets::remove_viewer_key(RuntimeOrigin::root(), viewer));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ViewerKeyRemoved { viewer }));
+		assert_eq!(Assets::view_asset_holders(request, signature), Err(ViewError::NotViewer));
+	});
+}
+
+#[test]
+fn compliance_window_locks_transfers_of_compliance_assets() {
+	new_test_ext().execute_with(|| {
+		let user = 4;
+		let id = create_whole_tonne_asset(user);
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(CUSTODIAN), id, 10, None));
+
+		assert_noop!(Assets::set_compliance_window(RuntimeOrigin::signed(user), Some((2, 5))), BadOrigin);
+		assert_noop!(
+			Assets::set_compliance_window(RuntimeOrigin::root(), Some((5, 2))),
+			Error::<Test>::BadComplianceWindow
+		);
+		assert_noop!(Assets::set_compliance_market(RuntimeOrigin::root(), ONE_ID, true), Error::<Test>::Unknown);
+		assert_ok!(Assets::set_compliance_window(RuntimeOrigin::root(), Some((2, 5))));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ComplianceWindowSet { window: Some((2, 5)) }));
+		assert_ok!(Assets::set_compliance_market(RuntimeOrigin::root(), id, true));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ComplianceMarketSet { asset_id: id, enabled: true }));
+
+		// before the window
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 1));
+
+		System::set_block_number(2);
+		assert!(Assets::is_compliance_locked(id));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 1), Error::<Test>::ComplianceLocked);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(user), id, 3, 1));
+		assert_noop!(Assets::transfer_approved(RuntimeOrigin::signed(3), id, user, 3, 1), Error::<Test>::ComplianceLocked);
+		assert_ok!(Assets::self_burn(RuntimeOrigin::signed(user), id, 1, None));
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(CUSTODIAN), id, user, 2, 1));
+
+		System::set_block_number(6);
+		assert!(!Assets::is_compliance_locked(id));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 1));
+
+		System::set_block_number(3);
+		assert_ok!(Assets::set_compliance_market(RuntimeOrigin::root(), id, false));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 1));
+		assert_ok!(Assets::set_compliance_market(RuntimeOrigin::root(), id, true));
+		assert_ok!(Assets::set_compliance_window(RuntimeOrigin::root(), None));
+		assert_eq!(Assets::compliance_window(), None);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), id, 2, 1));
+	});
+}
+
+#[test]
+fn data_ipfs_is_stored_as_binary_cid() {
+	use crate::cid::Cid;
+
+	let cid = Cid::parse(CID_V0.as_bytes()).unwrap();
+	assert_eq!((cid.version, cid.codec, cid.hash, cid.digest.len()), (0, 0x70, 0x12, 32));
+	assert_eq!(cid.encode().len(), 42);
+	assert_eq!(cid.to_text(), CID_V0.as_bytes().to_vec());
+
+	// CIDv1 is rendered in base32 whatever multibase it was given in
+	let base16 = b"f0155122028d79d50ff515a797803c4d5297b8feb12e0bbdb60fd38441aba6ffaf42a0b5f";
+	let base32 = b"bafkreibi26ovb72rlj4xqa6e2uuxxd7lclqlxw3a7u4eigv2n75pikqll4";
+	assert_eq!(Cid::parse(base16).unwrap().to_text(), base32.to_vec());
+	assert_eq!(Cid::parse(base16), Cid::parse(base32));
+	let v1 = Cid::parse(CID_V1.as_bytes()).unwrap();
+	assert_eq!(Cid::parse(&v1.to_text()), Some(v1));
+	assert_eq!(Cid::parse(b"4h6g"), None);
+}
+
+#[test]
+fn migration_v9_parses_data_ipfs() {
+	use frame_support::{storage::unhashed, traits::{OnRuntimeUpgrade, StorageVersion}};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(8).put::<Assets>();
+		for (id, data_ipfs) in [(ZERO_ID, CID_V0.as_bytes().to_vec()), (ONE_ID, b"4h6g".to_vec())] {
+			let old = (3u64, b"http".to_vec(), data_ipfs, b"Token".to_vec(), b"TKN".to_vec(), 9u8, false);
+			unhashed::put_raw(&Metadata::<Test>::hashed_key_for(id), &old.encode());
+		}
+
+		crate::migration::v9::MigrateToV9::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Assets>(), 9);
+		let metadata = Metadata::<Test>::get(ZERO_ID);
+		assert_eq!((metadata.deposit, metadata.decimals), (3, 9));
+		assert_eq!(metadata.symbol.to_vec(), b"TKN".to_vec());
+		assert_eq!(metadata.data_ipfs.map(|cid| cid.to_text()), Some(CID_V0.as_bytes().to_vec()));
+		assert!(Metadata::<Test>::get(ONE_ID).data_ipfs.is_none());
+		// the malformed link is kept as text
+		assert!(Assets::legacy_data_ipfs(ZERO_ID).is_none());
+		assert_eq!(Assets::legacy_data_ipfs(ONE_ID).map(|text| text.to_vec()), Some(b"4h6g".to_vec()));
+	});
+}
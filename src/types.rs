@@ -20,17 +20,40 @@
 use super::*;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{fungible, tokens::BalanceConversion},
+	traits::{fungible, tokens::BalanceConversion, BalanceStatus, Time},
+	weights::Weight,
+	PalletError,
 };
 use sp_runtime::{traits::Convert, FixedPointNumber, FixedPointOperand, FixedU128};
 
-pub type AssetId = [u8; 24];
+/// Identifier of a carbon asset, see `CarbonAssetId`.
+pub type AssetId = CarbonAssetId;
+
+/// Number of decimals of the tonnes of CO2e in consolidated retirement records.
+pub const CO2E_DECIMALS: u8 = 9;
+
+/// Number of decimals of the assets created without explicit decimals.
+pub const DEFAULT_DECIMALS: u8 = 9;
 
 pub(super) type DepositBalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
 pub(super) type AssetAccountOf<T, I> =
 	AssetAccount<<T as Config<I>>::Balance, DepositBalanceOf<T, I>, <T as Config<I>>::Extra>;
 
+/// Status of an asset class.
+///
+/// `Live` and `Frozen` encode as the `false` and `true` of the former `is_frozen` flag, so the
+/// stored `AssetDetails` decode unchanged.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum AssetStatus {
+	/// The asset is active and can be used.
+	Live,
+	/// The asset is frozen for non-admin transfers.
+	Frozen,
+	/// The asset is being destroyed, see `start_destroy`.
+	Destroying,
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct AssetDetails<Balance, AccountId, DepositBalance> {
 	/// Created asset.
@@ -56,8 +79,11 @@ pub struct AssetDetails<Balance, AccountId, DepositBalance> {
 	pub(super) sufficients: u32,
 	/// The total number of approvals.
 	pub(super) approvals: u32,
-	/// Whether the asset is frozen for non-admin transfers.
-	pub(super) is_frozen: bool,
+	/// The status of the asset.
+	pub(super) status: AssetStatus,
+	/// If `true`, only the accounts in `TransferWhitelist` of the asset can send and receive
+	/// unprivileged transfers.
+	pub(super) whitelist_enabled: bool,
 }
 
 impl<Balance, AccountId, DepositBalance> AssetDetails<Balance, AccountId, DepositBalance> {
@@ -72,12 +98,21 @@ impl<Balance, AccountId, DepositBalance> AssetDetails<Balance, AccountId, Deposi
 
 /// Data concerning an approval.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, MaxEncodedLen, TypeInfo)]
-pub struct Approval<Balance, DepositBalance> {
+pub struct Approval<Balance, DepositBalance, BlockNumber> {
 	/// The amount of funds approved for the balance transfer from the owner to some delegated
 	/// target.
 	pub(super) amount: Balance,
 	/// The amount reserved on the owner's account to hold this item in storage.
 	pub(super) deposit: DepositBalance,
+	/// The block from which the approval can no longer be used, if any.
+	pub(super) expiry: Option<BlockNumber>,
+}
+
+impl<Balance, DepositBalance, BlockNumber: PartialOrd> Approval<Balance, DepositBalance, BlockNumber> {
+	/// Whether the approval can no longer be used at block `now`.
+	pub fn is_expired(&self, now: &BlockNumber) -> bool {
+		self.expiry.as_ref().map_or(false, |expiry| expiry <= now)
+	}
 }
 
 #[test]
@@ -119,6 +154,9 @@ pub struct AssetAccount<Balance, DepositBalance, Extra> {
 	pub(super) balance: Balance,
 	/// Whether the account is frozen.
 	pub(super) is_frozen: bool,
+	/// The amount frozen by the freezer of the asset with `freeze_amount`, which cannot be
+	/// transferred or burned by the holder.
+	pub(super) frozen: Balance,
 	/// The reason for the existence of the account.
 	pub(super) reason: ExistenceReason<DepositBalance>,
 	/// Additional "sidecar" data, in case some other pallet wants to use this storage item.
@@ -133,8 +171,8 @@ pub struct AssetMetadata<DepositBalance, BoundedString> {
 	pub(super) deposit: DepositBalance,
 	/// Url for IPFS
 	pub(super) url: BoundedString,
-	/// Hash link for project data and serial number on IPFS storage
-	pub(super) data_ipfs: BoundedString,
+	/// Hash link for project data and serial number on IPFS storage, stored in binary
+	pub(super) data_ipfs: Option<cid::Cid>,
 	/// The user friendly name of this asset. Limited in length by `StringLimit`.
 	pub(super) name: BoundedString,
 	/// The ticker symbol for this asset. Limited in length by `StringLimit`.
@@ -145,6 +183,727 @@ pub struct AssetMetadata<DepositBalance, BoundedString> {
 	pub(super) is_frozen: bool,
 }
 
+/// Value of an asset attribute together with the deposit reserved for it.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AttributeValue<AccountId, DepositBalance, BoundedValue> {
+	/// The value of the attribute.
+	pub(super) value: BoundedValue,
+	/// The account which reserved the deposit.
+	pub(super) depositor: AccountId,
+	/// The balance deposited for this attribute.
+	pub(super) deposit: DepositBalance,
+}
+
+/// Burn certificate of an account for an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct BurnCertificateEntry<Balance, BlockNumber> {
+	/// The total amount of the asset retired by the account.
+	pub(super) amount: Balance,
+	/// The block of the last retirement added to the certificate.
+	pub(super) last_block: BlockNumber,
+}
+
+/// Confirmation that burned carbon assets were also retired in the external registry.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RegistryConfirmation<Balance, BoundedString> {
+	/// The total amount of burned carbon assets confirmed in the external registry.
+	pub(super) amount: Balance,
+	/// Reference of the last confirmed retirement in the external registry (e.g. Verra serial).
+	pub(super) external_ref: BoundedString,
+}
+
+/// Domain tag prefixed to the signed message of a `RetirementVoucher`, so the signature cannot
+/// be taken for the signature of another payload.
+pub const VOUCHER_DOMAIN: [u8; 21] = *b"carbon-assets:voucher";
+
+/// Off-chain payload of a retirement voucher, signed by `signer`.
+///
+/// Anyone may redeem the voucher until the `expiry` block, burning `amount` of asset `asset_id`
+/// from `signer`. Each `nonce` of a signer can be redeemed once.
+///
+/// The signed message is the SCALE encoding of `(VOUCHER_DOMAIN, pallet name, genesis hash,
+/// voucher)`, see `Pallet::retirement_voucher_message`, so the voucher cannot be redeemed in
+/// another pallet instance or on another chain.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct RetirementVoucher<AccountId, Balance, BlockNumber> {
+	/// The account which signed the voucher and whose assets are burned.
+	pub signer: AccountId,
+	/// The asset to be burned.
+	pub asset_id: AssetId,
+	/// The amount to be burned.
+	pub amount: Balance,
+	/// Unique number of the voucher among the vouchers of `signer`.
+	pub nonce: u64,
+	/// The last block number at which the voucher can be redeemed.
+	pub expiry: BlockNumber,
+}
+
+/// Domain tag prefixed to the signed message of a `SignedBurnPayload`, so the signature cannot
+/// be taken for the signature of another payload.
+pub const SIGNED_BURN_DOMAIN: [u8; 25] = *b"carbon-assets:signed-burn";
+
+/// Off-chain payload of a gasless retirement, signed by `signer` and submitted by a relayer
+/// via `self_burn_signed`.
+///
+/// The signed message is the SCALE encoding of `(SIGNED_BURN_DOMAIN, pallet name, payload)`,
+/// see `Pallet::signed_burn_message`. The `nonce` must be the next `SignedBurnNonce` of the
+/// signer and `genesis_hash` the hash of the genesis block, so the payload cannot be replayed
+/// on this or another chain.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SignedBurnPayload<AccountId, Balance, BlockNumber, Hash> {
+	/// The account which signed the payload and whose assets are burned.
+	pub signer: AccountId,
+	/// The asset to be burned.
+	pub asset_id: AssetId,
+	/// The amount to be burned.
+	pub amount: Balance,
+	/// The next signed burn nonce of `signer`.
+	pub nonce: u64,
+	/// The hash of the genesis block of the chain.
+	pub genesis_hash: Hash,
+	/// The last block number at which the payload can be submitted.
+	pub expiry: BlockNumber,
+}
+
+/// Domain tag prefixed to the signed message of an `ApprovalPermit`, so the signature cannot be
+/// taken for the signature of another payload.
+pub const PERMIT_DOMAIN: [u8; 20] = *b"carbon-assets:permit";
+
+/// Off-chain approval of `delegate` to transfer `amount` of asset `asset_id` of `owner`, signed
+/// by `owner` and submitted by anyone via `approve_with_signature`.
+///
+/// The signed message is the SCALE encoding of `(PERMIT_DOMAIN, pallet name, permit)`, see
+/// `Pallet::approval_permit_message`. The `nonce` must be the next `PermitNonce` of the owner and
+/// `genesis_hash` the hash of the genesis block, so the permit cannot be replayed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ApprovalPermit<AccountId, Balance, BlockNumber, Hash> {
+	/// The account which signed the permit and gives the approval.
+	pub owner: AccountId,
+	/// The asset to be approved.
+	pub asset_id: AssetId,
+	/// The account to be approved.
+	pub delegate: AccountId,
+	/// The amount added to the approval.
+	pub amount: Balance,
+	/// The next permit nonce of `owner`.
+	pub nonce: u64,
+	/// The last block number at which the permit can be submitted.
+	pub deadline: BlockNumber,
+	/// The hash of the genesis block of the chain.
+	pub genesis_hash: Hash,
+}
+
+/// Domain tag prefixed to the signed message of a `ViewRequest`.
+pub const VIEW_DOMAIN: [u8; 18] = *b"carbon-assets:view";
+
+/// Privileged read of a permissioned runtime API, see `ViewerKeys`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ViewQuery<AccountId> {
+	/// `account_dump` of the account.
+	AccountDump(AccountId),
+	/// The holders of the asset with their balances.
+	AssetHolders(AssetId),
+	/// `account_balances` of the account.
+	AccountBalances(AccountId),
+	/// `position_statement` of the account.
+	PositionStatement(AccountId),
+}
+
+/// Request of a registered viewer for a privileged read, signed off-chain with the viewer key.
+///
+/// The signed message is the SCALE encoding of `(VIEW_DOMAIN, pallet name, request)`, see
+/// `Pallet::view_request_message`. Anyone holding the signed request can repeat the read until
+/// `valid_until`, so viewers should keep it short.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ViewRequest<AccountId, BlockNumber> {
+	/// The viewer key, registered in `ViewerKeys`, which signed the request.
+	pub viewer: AccountId,
+	/// The requested read.
+	pub query: ViewQuery<AccountId>,
+	/// The last block number at which the request is answered.
+	pub valid_until: BlockNumber,
+}
+
+/// Reason of a permissioned runtime API to refuse a `ViewRequest`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ViewError {
+	/// The viewer key is not in `ViewerKeys`.
+	NotViewer,
+	/// The request is not signed by the viewer key.
+	BadSignature,
+	/// The request is past its `valid_until` block.
+	Expired,
+	/// The request is for another query.
+	WrongQuery,
+}
+
+/// Funding of a new account by the custodian, see `sponsor_account`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Sponsorship<AccountId, DepositBalance, BlockNumber> {
+	/// The custodian which funded the account.
+	pub(super) sponsor: AccountId,
+	/// The amount sent to the account through `Deposits`, with the deposit of the pre-touched
+	/// asset-account. At most this amount is clawed back.
+	pub(super) amount: DepositBalance,
+	/// The asset the asset-account was pre-touched for, if any.
+	pub(super) asset_id: Option<AssetId>,
+	/// The block number of the sponsorship.
+	pub(super) since: BlockNumber,
+}
+
+/// Carbon assets burned to be moved to another registry system, see `export_retirement`.
+/// Unlike a `RetirementAnchor`, an export is not a climate retirement and is not counted as an
+/// offset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ExportAttestation<AccountId, Balance, BlockNumber, BoundedString, Serials> {
+	/// The account which exported the assets.
+	pub account: AccountId,
+	/// The exported asset.
+	pub asset_id: AssetId,
+	/// The exported amount.
+	pub amount: Balance,
+	/// The name of the destination registry.
+	pub dest_registry: BoundedString,
+	/// The reference of the credits in the destination registry, e.g. the account or the
+	/// issuance to credit.
+	pub dest_ref: BoundedString,
+	/// The serial ranges of the exported credits, if the asset has any.
+	pub serials: Serials,
+	/// The block number of the export.
+	pub block_number: BlockNumber,
+}
+
+/// Why carbon assets entered the `RetirementRegistry`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum RetirementReason {
+	/// The holder retired the carbon credits, e.g. with `self_burn` or `retire_from`.
+	SelfRetirement,
+	/// The custodian burned the carbon credits from the account with `burn`.
+	Custodial(CustodialBurnReason),
+}
+
+/// Entry of the public `RetirementRegistry`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RetirementRecord<AccountId, Balance, BlockNumber> {
+	/// The account the assets were burned from.
+	pub account: AccountId,
+	/// The retired asset.
+	pub asset_id: AssetId,
+	/// The retired amount.
+	pub amount: Balance,
+	/// The block number of the retirement.
+	pub block_number: BlockNumber,
+	/// The account the retirement is claimed for, the same as `account` unless it was retired
+	/// on behalf of another account.
+	pub beneficiary: AccountId,
+	/// Why the assets were retired.
+	pub reason: RetirementReason,
+}
+
+/// A freeze of an asset-account placed through `MutateFreeze`, see `Freezes`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct IdAmount<Id, Balance> {
+	/// The identifier of the freeze, e.g. of the pallet which placed it.
+	pub id: Id,
+	/// The frozen amount.
+	pub amount: Balance,
+}
+
+/// Holding of a single asset in an `AccountDump`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AccountAssetDump<Balance, DepositBalance> {
+	/// The asset.
+	pub asset_id: AssetId,
+	/// The balance of the asset.
+	pub balance: Balance,
+	/// Whether the asset-account is frozen.
+	pub is_frozen: bool,
+	/// The amount frozen with `freeze_amount` and by the `Freezer`, if any.
+	pub frozen_balance: Option<Balance>,
+	/// The deposit held for the asset-account, if any.
+	pub deposit: Option<DepositBalance>,
+}
+
+/// All the data the pallet stores about an account, see `Pallet::view_account_dump`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AccountDump<AccountId, Balance, DepositBalance> {
+	/// Holdings of the account.
+	pub assets: Vec<AccountAssetDump<Balance, DepositBalance>>,
+	/// Assets owned by the account with the deposits held for them and their metadata.
+	pub owned_assets: Vec<(AssetId, DepositBalance)>,
+	/// Approvals given by the account: asset, delegate, amount and deposit.
+	pub approvals_given: Vec<(AssetId, AccountId, Balance, DepositBalance)>,
+	/// Approvals received by the account: asset, owner and amount.
+	pub approvals_received: Vec<(AssetId, AccountId, Balance)>,
+	/// Burn certificates of the account: asset and burned amount.
+	pub burn_certificates: Vec<(AssetId, Balance)>,
+}
+
+/// Position of an account in a single asset, see `Pallet::view_position_statement`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AssetPosition<Balance> {
+	/// The asset.
+	pub asset_id: AssetId,
+	/// The balance of the asset.
+	pub balance: Balance,
+	/// The amount which cannot be transferred: the whole balance of a frozen account or the
+	/// amount frozen by the `Freezer`.
+	pub frozen: Balance,
+	/// The total amount of the live approvals given by the account.
+	pub approvals_out: Balance,
+	/// The total amount of the live approvals received by the account.
+	pub approvals_in: Balance,
+	/// The total amount of the asset retired by the account.
+	pub retired: Balance,
+	/// The name of the asset.
+	pub name: Vec<u8>,
+	/// The ticker symbol of the asset.
+	pub symbol: Vec<u8>,
+	/// The number of decimals of the asset.
+	pub decimals: u8,
+}
+
+/// Amount of an asset split at the decimals of the asset for display, see
+/// `Pallet::display_amount`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AmountDisplay<Balance> {
+	/// The number of whole units, e.g. tonnes of CO2e.
+	pub whole: Balance,
+	/// The rest below one whole unit, in the smallest units of the asset. Always zero for assets
+	/// without decimals.
+	pub fraction: Balance,
+	/// The number of decimals of the asset, i.e. the digits `fraction` is padded to.
+	pub decimals: u8,
+}
+
+/// Reason of a burn done by the custodian from a user account.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum CustodialBurnReason {
+	/// The carbon credits were invalidated in the external registry.
+	Invalidation,
+	/// The burn was ordered by a court.
+	CourtOrder,
+	/// The user asked the custodian to burn the carbon credits.
+	UserRequest,
+	/// No reason was given, the burn was done by the deprecated `burn_v1`.
+	Unspecified,
+}
+
+/// Record of a burn done by the custodian from a user account.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CustodialBurnRecord<Balance, BlockNumber, BoundedString> {
+	/// The burned asset.
+	pub(super) asset_id: AssetId,
+	/// The amount actually burned.
+	pub(super) amount: Balance,
+	/// Why the custodian burned the assets.
+	pub(super) reason: CustodialBurnReason,
+	/// Optional reference of the burn, e.g. the number of the court case.
+	pub(super) reference: Option<BoundedString>,
+	/// The block number of the burn.
+	pub(super) block_number: BlockNumber,
+}
+
+/// Retirement recorded under its content hash, so off-chain certificates can embed the hash as
+/// an anchor verifiable on-chain.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RetirementAnchor<AccountId, Balance, BlockNumber, BoundedString> {
+	/// The account which retired the assets.
+	pub account: AccountId,
+	/// The retired asset.
+	pub asset_id: AssetId,
+	/// The retired amount.
+	pub amount: Balance,
+	/// The block number of the retirement.
+	pub block_number: BlockNumber,
+	/// Optional note about the beneficiary of the retirement.
+	pub note: Option<BoundedString>,
+	/// The index of the retirement among the retirements of the account.
+	pub index: u32,
+}
+
+/// Consolidated record of a retirement of a basket of carbon assets.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Co2eRetirement<Balance, BlockNumber, Basket> {
+	/// The retired assets and amounts, in the units of each asset.
+	pub(super) basket: Basket,
+	/// The total retired tonnes of CO2e with `CO2E_DECIMALS` decimals.
+	pub(super) co2e: Balance,
+	/// The block number of the retirement.
+	pub(super) block_number: BlockNumber,
+}
+
+/// Counters of the operations of an account, see `AccountStats`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AccountActivity {
+	/// The number of transfers from the account.
+	pub transfers_out: u32,
+	/// The number of transfers to the account.
+	pub transfers_in: u32,
+	/// The number of voluntary retirements by the account.
+	pub retirements: u32,
+	/// The number of transfer-approvals granted by the account.
+	pub approvals_granted: u32,
+}
+
+/// Kind of a notable event queued in the `Outbox`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum OutboxKind {
+	/// A carbon asset was created by `account`.
+	Created,
+	/// Carbon assets were minted to `account`.
+	Minted,
+	/// Carbon assets were retired by `account`.
+	Retired,
+	/// Carbon assets were exported to another registry by `account`.
+	Exported,
+}
+
+/// Pot of `Currency` held from its funder to pay the fees of the retirements of an asset, see
+/// `fund_sponsorship_pot`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SponsorshipPot<AccountId, DepositBalance> {
+	/// The owner or the custodian of the asset who funded the pot.
+	pub(super) funder: AccountId,
+	/// The amount left in the pot, held from the funder.
+	pub(super) amount: DepositBalance,
+}
+
+/// Responsiveness of the custodian reported by the off-chain worker, see `Pallet::report_custodian_sla`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CustodianSlaReport<BlockNumber, Balance> {
+	/// The block the report was made at.
+	pub reported_at: BlockNumber,
+	/// The block of the last retirement confirmed by the custodian, if any.
+	pub last_action: Option<BlockNumber>,
+	/// The number of blocks the retirements awaiting confirmation have waited for the custodian.
+	pub waiting: BlockNumber,
+	/// The amount of retired carbon credits awaiting confirmation by the custodian.
+	pub pending_amount: Balance,
+	/// The number of mint requests awaiting the decision of the custodians.
+	pub pending_mint_requests: u32,
+	/// The number of blocks the mint requests have waited for a decision of the custodians.
+	pub mint_requests_waiting: BlockNumber,
+}
+
+/// Notable event queued in the `Outbox` for the delivery to webhooks by the off-chain worker.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct OutboxMessage<AccountId, Balance, BlockNumber> {
+	/// What happened.
+	pub kind: OutboxKind,
+	/// The asset concerned.
+	pub asset_id: AssetId,
+	/// The account concerned.
+	pub account: AccountId,
+	/// The amount concerned, zero for `Created`.
+	pub amount: Balance,
+	/// The block number of the event.
+	pub block_number: BlockNumber,
+}
+
+/// Details of a retirement by `retire`, stored in `RetirementCertificates` under the
+/// certificate id of the retirement.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RetirementCertificate<BoundedString> {
+	/// The name of the person or organization the retirement is claimed for.
+	pub beneficiary_name: BoundedString,
+	/// Why the credits were retired, e.g. the offset claim.
+	pub reason: BoundedString,
+	/// The retirement date as a Unix timestamp in seconds, as given by the holder.
+	pub retirement_date: u64,
+}
+
+/// Request of the owner of an asset to mint it, queued in `MintRequests` for the custodian.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct MintRequest<AccountId, Balance, DepositBalance, BlockNumber, BoundedString> {
+	/// The asset to mint.
+	pub asset_id: AssetId,
+	/// The owner of the asset who requested the mint.
+	pub owner: AccountId,
+	/// The requested amount.
+	pub amount: Balance,
+	/// The deposit held from the owner until the request is approved or rejected.
+	pub deposit: DepositBalance,
+	/// The IPFS CID of the document with the evidence of the carbon credits to mint.
+	pub evidence: BoundedString,
+	/// The block number of the request.
+	pub requested_at: BlockNumber,
+}
+
+/// Carbon registry standard the project of an asset is certified under.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum RegistryStandard {
+	/// Verra Verified Carbon Standard.
+	Verra,
+	/// Gold Standard.
+	GoldStandard,
+	/// American Carbon Registry.
+	Acr,
+	/// Climate Action Reserve.
+	Car,
+	/// Puro.earth.
+	Puro,
+	/// Another standard, named in the project data.
+	Other,
+}
+
+/// Structured details of the project behind an asset, see `ProjectDetailsOf`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ProjectDetails<BoundedString> {
+	/// The registry standard of the project.
+	pub standard: RegistryStandard,
+	/// The code of the methodology in the registry, e.g. `VM0007`.
+	pub methodology_code: BoundedString,
+	/// The vintage year of the credits.
+	pub vintage: u16,
+	/// The ISO 3166-1 alpha-2 code of the host country, e.g. `*b"KZ"`.
+	pub country: [u8; 2],
+	/// The id of the project in the registry.
+	pub project_id: BoundedString,
+}
+
+/// Sensitive owner action of an asset under dual control, waiting for the confirmation by the
+/// co-owner.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum OwnerAction<AccountId, BoundedString> {
+	/// `transfer_ownership` to the account, which has to accept the ownership.
+	TransferOwnership(AccountId),
+	/// `set_project_data` with the url and the ipfs data link.
+	SetProjectData { url: BoundedString, data_ipfs: BoundedString },
+	/// `destroy` of the asset, the witness is given on confirmation.
+	Destroy,
+	/// `set_co_owner` to another co-owner or back to a single owner.
+	SetCoOwner(Option<AccountId>),
+	/// The project details given to `set_project_data`.
+	SetProjectDetails(ProjectDetails<BoundedString>),
+	/// `start_destroy` of the asset.
+	StartDestroy,
+}
+
+/// Owner action in the `PendingOwnerActions` queue of an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PendingOwnerAction<AccountId, BoundedString> {
+	/// The id of the action, unique across the assets.
+	pub id: u32,
+	/// The action to perform.
+	pub action: OwnerAction<AccountId, BoundedString>,
+}
+
+/// Owner-level call of an asset in `owner_batch`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum OwnerCall {
+	/// `set_project_data` with the url, the ipfs data link and the project details.
+	SetProjectData { url: Vec<u8>, data_ipfs: Vec<u8>, details: Option<ProjectDetails<Vec<u8>>> },
+	/// `set_attribute` with the key and the value, e.g. a tag of the asset.
+	SetAttribute { key: Vec<u8>, value: Vec<u8> },
+	/// `clear_attribute` with the key.
+	ClearAttribute { key: Vec<u8> },
+	/// `set_asset_methodology` with the id of a registered methodology.
+	SetMethodology(u32),
+}
+
+/// Optional part of the pallet interface which a client can detect via `InterfaceVersion`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Capability {
+	/// `mint_batch` call and `BatchIssued` event.
+	MintBatch,
+	/// `mint_reserve_backed` call and `ReservedSupply` storage.
+	ReserveBackedMint,
+	/// `set_attribute` and `clear_attribute` calls.
+	Attributes,
+	/// `set_organization` and `clear_organization` calls with per-organization retirements,
+	/// and `register_organization` and `approve_organization_member` calls.
+	Organizations,
+	/// `RetirementAnchored` event for every retirement.
+	RetirementAnchors,
+	/// `redeem_retirement_voucher` call.
+	RetirementVouchers,
+	/// `ack_outbox` call and webhook delivery of the `Outbox`, enabled by `MaxOutbox`.
+	Outbox,
+	/// `LedgerEntry` events, enabled by `LedgerEvents`.
+	LedgerEvents,
+	/// `AccountActivityOf` counters, enabled by `AccountStats`.
+	AccountStats,
+	/// `set_co_owner`, `confirm_owner_action` and `cancel_owner_action` calls.
+	DualControl,
+	/// `Methodologies` registry and `set_asset_methodology` call.
+	Methodologies,
+	/// `transfer_with_splits` and `set_asset_splits` calls.
+	TransferSplits,
+	/// `set_asset_custodian` and `accept_asset_custodian` calls and `Custodians` storage.
+	AssetCustodians,
+	/// `request_mint`, `approve_mint` and `reject_mint` calls and `MintRequests` storage.
+	MintRequests,
+	/// `set_alias` and `clear_alias` calls and aliases in `CarbonCreditsBurned`.
+	Aliases,
+	/// `retire` call, `RetirementCertificates` storage and the certificate id in
+	/// `CarbonCreditsBurned`.
+	RetirementCertificates,
+	/// Project details in `set_project_data` and `ProjectDetailsOf` storage.
+	ProjectDetails,
+	/// `start_destroy`, `destroy_accounts`, `destroy_approvals` and `finish_destroy` calls.
+	StagedDestroy,
+	/// `fund_sponsorship_pot` and `withdraw_sponsorship_pot` calls and feeless `self_burn` of
+	/// sponsored assets.
+	RetirementSponsorship,
+	/// `report_custodian_sla` unsigned call and `CustodianSla` storage, enabled by
+	/// `SlaReportInterval`.
+	CustodianSla,
+	/// `approve_transfer_with_expiry` and `clean_expired_approval` calls.
+	ExpiringApprovals,
+	/// `set_asset_badges` call and `AssetBadges` storage.
+	CoBenefitBadges,
+	/// `transfer_with_memo` call and `TransferredWithMemo` event.
+	TransferMemo,
+	/// `create_with_vintage` call and `VintageSupply` storage.
+	Vintages,
+	/// `grant_retirement_quota` and `self_burn_from_org` calls.
+	RetirementQuotas,
+	/// `propose_custodian` and `accept_custodian` calls.
+	CustodianTimelock,
+	/// `accept_ownership` and `force_transfer_ownership` calls, `transfer_ownership` only
+	/// proposes the new owner.
+	OwnershipAcceptance,
+	/// `self_burn_signed` call.
+	SignedBurns,
+	/// Reasons of `freeze` and `freeze_asset`.
+	FreezeReasons,
+	/// `retire_from` call.
+	DelegatedRetirement,
+	/// `pause` and `unpause` calls.
+	EmergencyStop,
+	/// `submit_project` and `approve_project` calls.
+	ProjectVerification,
+	/// Serial ranges of `mint` and `SerialsConsumed` event.
+	SerialRanges,
+	/// `*_v1` alias calls and `DeprecatedCallUsed` event.
+	DeprecatedAliases,
+	/// `transfer_all` call.
+	TransferAll,
+	/// `increase_allowance` and `decrease_allowance` calls and `AllowanceChanged` event.
+	AllowanceAdjustment,
+	/// `create_with_decimals` call and `display_amount` query.
+	ConfigurableDecimals,
+	/// `approve_with_signature` call and `ApprovalPermitted` event.
+	ApprovalPermits,
+	/// `freeze_amount` and `unfreeze_amount` calls and `AmountFrozen` and `AmountThawed` events.
+	PartialFreezes,
+	/// `sponsor_account` and `clawback_sponsorship` calls and `AccountSponsored` and
+	/// `SponsorshipClosed` events.
+	SponsoredAccounts,
+	/// `fungibles::InspectHold`, `fungibles::MutateHold`, `InspectFreeze` and `MutateFreeze`
+	/// implementations and `Held` and `Released` events.
+	HoldsAndFreezes,
+	/// `export_retirement` call, `ExportAttestations` storage and `CreditsExported` event.
+	RegistryExports,
+	/// `add_to_whitelist`, `remove_from_whitelist` and `set_whitelist_enforcement` calls and
+	/// `TransferWhitelist` storage.
+	TransferWhitelist,
+	/// `RetirementRegistry` storage, `retirement_records` query and `RetirementRegistered`
+	/// event.
+	RetirementRegistry,
+	/// `owner_batch` call and `OwnerBatchExecuted` event.
+	OwnerBatch,
+	/// `add_viewer_key` and `remove_viewer_key` calls, `ViewerKeys` storage and
+	/// `CarbonAssetsViewerApi` runtime API.
+	ViewerKeys,
+	/// `set_compliance_window` and `set_compliance_market` calls, `ComplianceWindow` storage and
+	/// `ComplianceLocked` error.
+	ComplianceWindow,
+}
+
+impl Capability {
+	/// The bit of the capability in `InterfaceVersion::capabilities`.
+	pub const fn bit(self) -> u64 {
+		1 << self as u8
+	}
+}
+
+/// Version of the pallet Call/Event surface, see `Pallet::interface_version`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct InterfaceVersion {
+	/// Bumped on every incompatible change of the existing calls, events or errors.
+	pub major: u16,
+	/// Bumped on every added call, event, error or capability.
+	pub minor: u16,
+	/// The supported capabilities, one `Capability::bit` each.
+	pub capabilities: u64,
+}
+
+impl InterfaceVersion {
+	/// Whether the interface supports the `capability`.
+	pub fn supports(&self, capability: Capability) -> bool {
+		self.capabilities & capability.bit() != 0
+	}
+
+	/// Add the `capability` to the interface.
+	pub const fn with(mut self, capability: Capability) -> Self {
+		self.capabilities |= capability.bit();
+		self
+	}
+}
+
+/// Storage scanned by the housekeeping in `on_idle`, see `HousekeepingCursor`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum HousekeepingStage {
+	/// `Metadata` of destroyed assets.
+	Metadata,
+	/// `Approvals` of zero amount.
+	Approvals,
+	/// Empty `Account` records without a deposit.
+	Accounts,
+}
+
+/// Numbers of records removed by the housekeeping.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct HousekeepingReport {
+	/// Metadata of destroyed assets.
+	pub metadata: u32,
+	/// Approvals of zero amount.
+	pub approvals: u32,
+	/// Empty asset-accounts.
+	pub accounts: u32,
+}
+
+/// Who initiated the burn reported in a `CarbonCreditsBurned` event.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum BurnKind {
+	/// Voluntary retirement by the holder, recorded in the `BurnCertificate`.
+	SelfRetirement,
+	/// Debit by the custodian, recorded in `CustodialBurns`.
+	CustodialBurn,
+}
+
+/// Deprecated alias call reported in a `DeprecatedCallUsed` event.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum DeprecatedCall {
+	/// `mint_v1`, replaced by `mint` with the serial range.
+	MintV1,
+	/// `freeze_v1`, replaced by `freeze` with the reason.
+	FreezeV1,
+	/// `freeze_asset_v1`, replaced by `freeze_asset` with the reason.
+	FreezeAssetV1,
+	/// `set_project_data_v1`, replaced by `set_project_data` with the project details.
+	SetProjectDataV1,
+	/// `burn_v1`, replaced by `burn` with the reason and the reference.
+	BurnV1,
+	/// `self_burn_v1`, replaced by `self_burn` with the note.
+	SelfBurnV1,
+}
+
+/// Kind of a balance mutation reported in a `LedgerEntry` event.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum LedgerOp {
+	/// New assets were credited to an account.
+	Mint,
+	/// Assets were debited from an account and removed from existence.
+	Burn,
+	/// Assets were moved between two accounts.
+	Transfer,
+	/// Dust of a transfer below the minimum balance was burned.
+	Dust,
+}
+
 /// Witness data for the destroy transactions.
 #[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct DestroyWitness {
@@ -159,6 +918,167 @@ pub struct DestroyWitness {
 	pub(super) approvals: u32,
 }
 
+/// Purpose of a deposit reserved by the pallet, see `TotalDeposits`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum DepositPurpose {
+	/// The deposit of an asset class, held by the asset owner.
+	Asset,
+	/// The deposit of the asset metadata, held by the asset owner.
+	Metadata,
+	/// The deposit of a transfer-approval, held by the approving account.
+	Approval,
+	/// The deposit of an asset-account created by `touch`, held by the account.
+	Account,
+	/// The deposit of an asset attribute, held by the depositor of its value.
+	Attribute,
+	/// The deposit of an organization identity, held by the account.
+	Organization,
+	/// The deposit of a display alias, held by the account.
+	Alias,
+	/// The deposit of a mint request, held by the requesting owner of the asset.
+	MintRequest,
+	/// The sponsorship pot of an asset, held by its funder.
+	SponsorshipPot,
+}
+
+/// Badge of a verified contribution to the UN Sustainable Development Goal `sdg` (1 to 17) in
+/// `CoBenefitBadges::badges`. Zero for any other `sdg`.
+pub const fn sdg_badge(sdg: u8) -> u32 {
+	if sdg >= 1 && sdg <= 17 {
+		1 << (sdg - 1)
+	} else {
+		0
+	}
+}
+
+/// Badge of a verified biodiversity co-benefit in `CoBenefitBadges::badges`.
+pub const BIODIVERSITY_BADGE: u32 = 1 << 31;
+
+/// All the defined badges: the 17 SDGs and the biodiversity badge.
+pub const KNOWN_BADGES: u32 = BIODIVERSITY_BADGE | ((1 << 17) - 1);
+
+/// Minting in the `Paused` operations: `mint`, batch and reserve-backed mints.
+pub const PAUSE_MINT: u8 = 1 << 0;
+
+/// Burning in the `Paused` operations: retirements and custodial burns.
+pub const PAUSE_BURN: u8 = 1 << 1;
+
+/// Transfers in the `Paused` operations, including approved and forced transfers.
+pub const PAUSE_TRANSFER: u8 = 1 << 2;
+
+/// New approvals in the `Paused` operations. Cancelling approvals is not paused.
+pub const PAUSE_APPROVALS: u8 = 1 << 3;
+
+/// All the operation classes which can be paused.
+pub const PAUSE_ALL: u8 = PAUSE_MINT | PAUSE_BURN | PAUSE_TRANSFER | PAUSE_APPROVALS;
+
+/// Verification stage of the project behind an asset, see `submit_project` and
+/// `approve_project`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum ProjectStatus {
+	/// The owner is still preparing the project data.
+	Draft,
+	/// The owner submitted the project data for the audit.
+	Submitted,
+	/// An auditor approved the project data.
+	AuditorApproved,
+	/// The custodian approved the audited project, its carbon assets can be minted.
+	CustodianApproved,
+}
+
+impl Default for ProjectStatus {
+	fn default() -> Self {
+		ProjectStatus::Draft
+	}
+}
+
+/// Privileged action of the custodian recorded in the `CustodianLog`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum CustodianAction {
+	/// Carbon assets were minted to `account`.
+	Mint,
+	/// Carbon assets of `account` were burned by the custodian.
+	Burn,
+	/// The asset-account of `account`, or the whole asset without `account`, was frozen.
+	Freeze,
+	/// The asset-account of `account`, or the whole asset without `account`, was thawed.
+	Thaw,
+	/// The project data of the asset were updated.
+	MetadataUpdate,
+}
+
+/// Serial numbers `start..=end` of the carbon credits issued by a mint, see `SerialRanges`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SerialRange<Balance> {
+	/// The first serial number of the range.
+	pub start: u64,
+	/// The last serial number of the range.
+	pub end: u64,
+	/// The amount of the asset per serial, one whole unit with the decimals of the asset.
+	pub unit: Balance,
+	/// The amount of the range consumed by retirements and custodial burns, from `start` on.
+	pub consumed: Balance,
+}
+
+/// Entry of the `CustodianLog`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CustodianLogEntry<AccountId, Balance, BlockNumber> {
+	/// What the custodian did.
+	pub action: CustodianAction,
+	/// The asset concerned.
+	pub asset_id: AssetId,
+	/// The account concerned, if any.
+	pub account: Option<AccountId>,
+	/// The amount concerned, zero for `Freeze`, `Thaw` and `MetadataUpdate`.
+	pub amount: Balance,
+	/// The block number of the action.
+	pub block_number: BlockNumber,
+}
+
+/// Co-benefits of an asset attested by a verifier.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CoBenefitBadges<AccountId, BoundedString> {
+	/// Bitmask of the badges: `sdg_badge(sdg)` for every SDG and `BIODIVERSITY_BADGE`.
+	pub badges: u32,
+	/// IPFS CID of the verification evidence, if any.
+	pub evidence: Option<BoundedString>,
+	/// The verifier which attested the badges.
+	pub verifier: AccountId,
+}
+
+/// Retirement quota of a sub-account drawn from the balance of an organization account.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RetirementQuota<Balance, BlockNumber> {
+	/// The amount the sub-account may retire per period.
+	pub per_period: Balance,
+	/// The first block of the period of `used`.
+	pub period_start: BlockNumber,
+	/// The amount retired in the period starting at `period_start`.
+	pub used: Balance,
+}
+
+/// Totals of the carbon credits of a vintage year, in the units of the assets.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, MaxEncodedLen, TypeInfo)]
+pub struct VintageTotals<Balance> {
+	/// The amount minted.
+	pub issued: Balance,
+	/// The amount retired by the holders.
+	pub retired: Balance,
+}
+
+/// The `destroy` call the chain would accept for an asset at the current block, see
+/// `Pallet::destroy_preview`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct DestroyPreview<Balance> {
+	/// The witness matching the current state of the asset.
+	pub witness: DestroyWitness,
+	/// The weight of `destroy` with the witness.
+	pub weight: Weight,
+	/// The weight fee of `destroy` with the witness, without the length fee, the base fee and
+	/// the fee multiplier.
+	pub fee: Balance,
+}
+
 /// Trait for allowing a minimum balance on the account to be specified, beyond the
 /// `minimum_balance` of the asset. This is additive - the `minimum_balance` of the asset must be
 /// met *and then* anything here in addition.
@@ -191,6 +1111,211 @@ impl<AssetId, AccountId, Balance> FrozenBalance<AssetId, AccountId, Balance> for
 	fn died(_: AssetId, _: &AccountId) {}
 }
 
+/// Hook for the balance movements of carbon assets, e.g. to mirror balances in a staking or
+/// reputation pallet.
+pub trait OnCarbonTransfer<AssetId, AccountId, Balance> {
+	/// Called after `amount` of `asset` moved from `from` to `to`. `from` is `None` for mints and
+	/// `to` is `None` for burns.
+	fn on_transfer(asset: AssetId, from: Option<&AccountId>, to: Option<&AccountId>, amount: Balance);
+}
+
+impl<AssetId, AccountId, Balance> OnCarbonTransfer<AssetId, AccountId, Balance> for () {
+	fn on_transfer(_: AssetId, _: Option<&AccountId>, _: Option<&AccountId>, _: Balance) {}
+}
+
+/// The reason a `Screening` rejected a transfer or a mint.
+#[derive(
+	Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, PalletError,
+)]
+pub enum ScreeningRejection {
+	/// The sender is on a sanctions list.
+	SanctionedSender,
+	/// The receiver is on a sanctions list.
+	SanctionedReceiver,
+	/// The sender or the receiver is in a restricted jurisdiction.
+	RestrictedJurisdiction,
+	/// The sender or the receiver did not pass the KYC checks of the custodian.
+	NotVerified,
+	/// The KYC of the receiver expired and it has to be re-verified.
+	KycExpired,
+}
+
+/// Trait for screening the parties of asset movements, e.g. against sanctions lists of a
+/// regulated custodian.
+pub trait Screening<AssetId, AccountId> {
+	/// Check a movement of `asset` from `from` to `to`. `from` is `None` for mints.
+	fn screen(
+		asset: AssetId,
+		from: Option<&AccountId>,
+		to: &AccountId,
+	) -> Result<(), ScreeningRejection>;
+}
+
+impl<AssetId, AccountId> Screening<AssetId, AccountId> for () {
+	fn screen(_: AssetId, _: Option<&AccountId>, _: &AccountId) -> Result<(), ScreeningRejection> {
+		Ok(())
+	}
+}
+
+/// Source of the KYC expiry of accounts, e.g. the account registry of the chain.
+pub trait KycExpiry<AccountId, Moment> {
+	/// The moment the KYC of `who` expires, `None` if it does not expire.
+	fn kyc_expires_at(who: &AccountId) -> Option<Moment>;
+}
+
+impl<AccountId, Moment> KycExpiry<AccountId, Moment> for () {
+	fn kyc_expires_at(_: &AccountId) -> Option<Moment> {
+		None
+	}
+}
+
+/// `Screening` rejecting the receivers whose KYC in `Kyc` expired by `Now::now()` with
+/// `KycExpired`, after the `Inner` screening. Holders with an expired KYC can still hold, send
+/// and retire their assets, but cannot receive new ones until they are re-verified.
+pub struct KycExpiryScreening<Kyc, Now, Inner = ()>(PhantomData<(Kyc, Now, Inner)>);
+
+impl<AssetId, AccountId, Kyc, Now, Inner> Screening<AssetId, AccountId>
+	for KycExpiryScreening<Kyc, Now, Inner>
+where
+	Kyc: KycExpiry<AccountId, Now::Moment>,
+	Now: Time,
+	Inner: Screening<AssetId, AccountId>,
+{
+	fn screen(
+		asset: AssetId,
+		from: Option<&AccountId>,
+		to: &AccountId,
+	) -> Result<(), ScreeningRejection> {
+		Inner::screen(asset, from, to)?;
+		match Kyc::kyc_expires_at(to) {
+			Some(expires_at) if expires_at <= Now::now() => Err(ScreeningRejection::KycExpired),
+			_ => Ok(()),
+		}
+	}
+}
+
+/// The reason a `MetadataValidator` rejected the metadata of an asset.
+#[derive(
+	Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, PalletError,
+)]
+pub enum MetadataRejection {
+	/// The name of the asset is malformed.
+	BadName,
+	/// The symbol of the asset is malformed.
+	BadSymbol,
+	/// The project url is malformed, e.g. it is not an `https` url.
+	BadUrl,
+	/// The ipfs data link of the project is not accepted, e.g. a CID version the indexers of
+	/// the chain do not support.
+	BadDataIpfs,
+}
+
+/// Trait for checking the structure of the metadata of assets before it is stored, so
+/// indexers can rely on it.
+pub trait MetadataValidator {
+	/// Check the name and the symbol set by `create` or `fungibles::metadata::Mutate`.
+	fn validate_metadata(name: &[u8], symbol: &[u8]) -> Result<(), MetadataRejection>;
+
+	/// Check the url and the ipfs data link set by `set_project_data`. The pallet checks that
+	/// `data_ipfs` is a valid CID beforehand.
+	fn validate_project_data(url: &[u8], data_ipfs: &[u8]) -> Result<(), MetadataRejection>;
+}
+
+impl MetadataValidator for () {
+	fn validate_metadata(_: &[u8], _: &[u8]) -> Result<(), MetadataRejection> {
+		Ok(())
+	}
+
+	fn validate_project_data(_: &[u8], _: &[u8]) -> Result<(), MetadataRejection> {
+		Ok(())
+	}
+}
+
+/// `MetadataValidator` accepting names of UTF-8 text without control characters, symbols of
+/// printable ASCII characters and project urls of the `https` scheme.
+pub struct StrictMetadata;
+
+impl MetadataValidator for StrictMetadata {
+	fn validate_metadata(name: &[u8], symbol: &[u8]) -> Result<(), MetadataRejection> {
+		let text = sp_std::str::from_utf8(name).map_err(|_| MetadataRejection::BadName)?;
+		ensure!(!text.chars().any(char::is_control), MetadataRejection::BadName);
+		ensure!(symbol.iter().all(u8::is_ascii_graphic), MetadataRejection::BadSymbol);
+		Ok(())
+	}
+
+	fn validate_project_data(url: &[u8], _: &[u8]) -> Result<(), MetadataRejection> {
+		let host = url.strip_prefix(b"https://").ok_or(MetadataRejection::BadUrl)?;
+		ensure!(
+			!host.is_empty() && host.iter().all(u8::is_ascii_graphic),
+			MetadataRejection::BadUrl
+		);
+		Ok(())
+	}
+}
+
+/// Trait for taking the deposits of the pallet: asset, asset account, metadata, attribute,
+/// approval and alias deposits.
+pub trait DepositHandler<AccountId, Balance> {
+	/// Put `amount` of the funds of `who` on hold.
+	fn hold(who: &AccountId, amount: Balance) -> DispatchResult;
+
+	/// Release up to `amount` of the funds of `who` on hold.
+	fn release(who: &AccountId, amount: Balance);
+
+	/// Move `amount` on hold from `from` to `to`, where it stays on hold.
+	fn transfer_held(from: &AccountId, to: &AccountId, amount: Balance) -> DispatchResult;
+}
+
+/// Deposits reserved in the native `ReservableCurrency`, e.g. `Balances`.
+pub struct NativeDeposits<Currency>(PhantomData<Currency>);
+
+impl<AccountId, C: ReservableCurrency<AccountId>> DepositHandler<AccountId, C::Balance>
+	for NativeDeposits<C>
+{
+	fn hold(who: &AccountId, amount: C::Balance) -> DispatchResult {
+		C::reserve(who, amount)
+	}
+
+	fn release(who: &AccountId, amount: C::Balance) {
+		C::unreserve(who, amount);
+	}
+
+	fn transfer_held(from: &AccountId, to: &AccountId, amount: C::Balance) -> DispatchResult {
+		C::repatriate_reserved(from, to, amount, BalanceStatus::Reserved).map(|_| ())
+	}
+}
+
+/// Deposits held in the asset `Id` of the `fungibles` implementation `F`, e.g. a sufficient
+/// stable asset of `pallet-assets`, so the native token is only used for fees.
+pub struct FungiblesDeposits<F, Id>(PhantomData<(F, Id)>);
+
+impl<AccountId, F, Id> DepositHandler<AccountId, F::Balance> for FungiblesDeposits<F, Id>
+where
+	F: fungibles::MutateHold<AccountId>,
+	Id: Get<F::AssetId>,
+{
+	fn hold(who: &AccountId, amount: F::Balance) -> DispatchResult {
+		F::hold(Id::get(), who, amount)
+	}
+
+	fn release(who: &AccountId, amount: F::Balance) {
+		let _ = F::release(Id::get(), who, amount, true);
+	}
+
+	fn transfer_held(from: &AccountId, to: &AccountId, amount: F::Balance) -> DispatchResult {
+		F::transfer_held(Id::get(), from, to, amount, false, true).map(|_| ())
+	}
+}
+
+/// Helper to sign retirement vouchers in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Signature> {
+	/// Create a new voucher signer account.
+	fn voucher_signer() -> AccountId;
+	/// Sign `message` by `signer` created with `voucher_signer`.
+	fn sign_voucher(signer: &AccountId, message: &[u8]) -> Signature;
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub(super) struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
@@ -0,0 +1,95 @@
+// This file is part of Evercity.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! XCM support for carbon assets, enabled by the `xcm-support` feature.
+//!
+//! A carbon asset is identified in XCM by the location of the pallet followed by a
+//! `GeneralKey` junction with the 24 bytes of its `AssetId`, e.g.
+//! `(0, X2(PalletInstance(index), GeneralKey(id)))` on the chain of the pallet and
+//! `(1, X3(Parachain(para_id), PalletInstance(index), GeneralKey(id)))` on a sibling parachain.
+//! [`AsCarbonAssetId`] converts between such locations and `AssetId`s for a given prefix, and
+//! [`CarbonAssetsTransactor`] is the `fungibles` asset transactor of the XCM executor, which
+//! moves reserve-transferred carbon assets to and from the sovereign accounts of the siblings.
+
+use crate::{AssetId, Config, Pallet};
+use frame_support::{
+	traits::{ConstU32, Get, Nothing},
+	WeakBoundedVec,
+};
+use sp_std::{borrow::Borrow, marker::PhantomData};
+use xcm::latest::prelude::*;
+use xcm_builder::{ConvertedConcreteAssetId, FungiblesAdapter};
+use xcm_executor::traits::{Convert, JustTry};
+
+/// Location of the carbon assets pallet at index `PalletIndex` on the local chain.
+pub struct LocalCarbonAssetsPrefix<PalletIndex>(PhantomData<PalletIndex>);
+
+impl<PalletIndex: Get<u8>> Get<MultiLocation> for LocalCarbonAssetsPrefix<PalletIndex> {
+	fn get() -> MultiLocation {
+		MultiLocation::new(0, X1(PalletInstance(PalletIndex::get())))
+	}
+}
+
+/// Location of the carbon assets pallet at index `PalletIndex` on the sibling parachain
+/// `ParaId`.
+pub struct SiblingCarbonAssetsPrefix<ParaId, PalletIndex>(PhantomData<(ParaId, PalletIndex)>);
+
+impl<ParaId: Get<u32>, PalletIndex: Get<u8>> Get<MultiLocation>
+	for SiblingCarbonAssetsPrefix<ParaId, PalletIndex>
+{
+	fn get() -> MultiLocation {
+		MultiLocation::new(1, X2(Parachain(ParaId::get()), PalletInstance(PalletIndex::get())))
+	}
+}
+
+/// Converts the location `Prefix` followed by `GeneralKey(id)` to the `AssetId` `id` and back.
+pub struct AsCarbonAssetId<Prefix>(PhantomData<Prefix>);
+
+impl<Prefix: Get<MultiLocation>> Convert<MultiLocation, AssetId> for AsCarbonAssetId<Prefix> {
+	fn convert_ref(location: impl Borrow<MultiLocation>) -> Result<AssetId, ()> {
+		match location.borrow().match_and_split(&Prefix::get()) {
+			Some(GeneralKey(key)) => AssetId::try_from(&key[..]).map_err(|_| ()),
+			_ => Err(()),
+		}
+	}
+
+	fn reverse_ref(id: impl Borrow<AssetId>) -> Result<MultiLocation, ()> {
+		let key = WeakBoundedVec::<u8, ConstU32<32>>::try_from(id.borrow().as_bytes().to_vec())
+			.map_err(|_| ())?;
+		let mut location = Prefix::get();
+		location.push_interior(GeneralKey(key)).map_err(|_| ())?;
+		Ok(location)
+	}
+}
+
+/// Asset transactor of the carbon assets of `Runtime` identified by `Prefix`, for the
+/// `AssetTransactor` of the XCM executor configuration.
+///
+/// The assets are not teleported, so no checking account is used.
+pub type CarbonAssetsTransactor<Runtime, Instance, Prefix, AccountIdConverter, CheckingAccount> =
+	FungiblesAdapter<
+		Pallet<Runtime, Instance>,
+		ConvertedConcreteAssetId<
+			AssetId,
+			<Runtime as Config<Instance>>::Balance,
+			AsCarbonAssetId<Prefix>,
+			JustTry,
+		>,
+		AccountIdConverter,
+		<Runtime as frame_system::Config>::AccountId,
+		Nothing,
+		CheckingAccount,
+	>;